@@ -1,4 +1,4 @@
 __krnl_cache!("0.1.1-alpha", "
-abZy8000000@)b6yG3Z55xvN:*!ioex!}R-*nY04RA/6KP^(zUMElK5Qe-oRlrYFjwc*z{j74:(@1P1IUJgGdk9265@7=4?g=6]d{p{Ua[1a$3D4[Sp@[T3kNW/?5yT7V{0w}S0x=F%<Ke*PJWo?K*WQ%gD59{SpjfE{K(PnMQ59}=mZ]^ycDho[l9H6f5)r6qii{NW&JKU=SU!OlT+CYGmUe/O0MgZA:/3R!7)k}!wkKqo+eW3}/gp0$Ula*nfCV{A)Y%vGBwKOclWvL[Td<CF:n0g0?DedbkdVF=uSmyQr%m:jbhmzKGW727yt-WFEZqE%gV/Q.w=W0xgBdZQ3c@@ObY#?z21x:w=nW7yhRnd/]MiLJEPm!{:TOJgk[%a(Rm!N[.LS%0V.(@2Hz=21N(<SPk&^i=IV{bv2TLc:m?g[j6z0Krzfw3InCaEz8wsX}tDGSFS?v!YKxz{{rB3z/9>#@vU!BiiE{xCoH-(9rtujcYXh7gT-O]XoQ(1p+.pE0epji1PB{vg-iR?e>u^6C+6R(g^5>EwLL}p1yjCe&fA+>Np8}()0C3W{M]?x{<g:^lSAF{&GA-.x*?]TPH^(wXA=6)G]A>3N]/}X]hm{b*jG6M]yZ/v}:Dl/m+W&mB$32kuhF?>Y$ESJy[!XndF18jtI[w{7r@(-Lu#jOK0x^Jq}T+u<o9ZT4sXjWEz^+u@HG1&?G%*i::L2B++C@^MG(xZY(**z&1FR0Ztt0}iqJ<QHV1Zqdv0C:w<c1akUUyJ#fPzW$WJqNu8@lVKOYoEeAZ5([10E[tQ}>vD/Hz*IyY{9c%8kCv+j^Fu-9denbx{+?iI-{iM7oTBpNvv(RrD0]]uPQ@]f8Kd.L*+17gCFk!G?9{(=u[VVQ+uYpFtOZMkWgh$Vj.eZLNBJRSw:*sq)Ec%%{9fwD}.C&l^hRghlvj.L=hTgeKlIU{r*VgP(4#Gws?&7+Dcj!L}&6fu>j}g*@/MEdNT8TjuZFe^)7e5L
-Jm-F7WRPB6%gbj9v!GTt[4])+{Ze*R[aJ8}Yk)W?cIuhzFLQN/=vozqh!Nwx&%UKdGt9]j:KEc!eM<?3zjgmeD1Q@w6#1/J@2$IsZ9i3Dug02(*blM]gCwLO{^pTt<D}UT7eW)98z0U&@t1<b+UVKI>?QD[%8?{IhoGPG8R]}e^BD(j&V?[>p0NX08<bgP)kn#zY6IqHYn}aM?3{dR1NN$Ql1Z}6rI2Q[><gIq/zM/Q{i*6I]z.btGc2Mb6O&2h^>hlVq?DzTxOLvyN}&(v0tCH(IV<E*00000
+abZy8000000@)b6yG3Z55xvN:*!ioex!}R-*nY04RA/6KP^(zUMElK5Qe-oRlrYFjwc*z{j74:(@1P1IUJgGdk9265@7=4?]Qjy5V&w>W[18YtjD&7.ER}>Po?[B3WDqv%0gEq$g.b${n7zp+tcEo[tEgWkHXj&T9AR%n]=qCWHXkyR&8UlNjPcCR4Ow4J{puhXOPLx$mQjv/sL)S!wZ[mR/w(zH+kfm>(U6@4{mkBMkKqo+f3=rhgo@zdla*nfCV)J9uxEBlTz/EGXauaQgub2bqrj=@9>cr/n#7l7ypd{y%81@OJQsj>)D(>P/w(WV)xyP=y<we8BdPR/*[#tbmEfcHs6kS>081mZ3d-:[6XFI-^QlkBLxWGETm?aED)OqDbwN*f(oxa.HHo8BEUe*X^kg1HIso&#^%+TTV8Rd=Dlq<l^xiu#WXaZ:(2?2N5ihbG]#@:>?v!MGDWUEFXU2ELBv&5B])u.CDXo1vw1{.{uKDVUh7gT-O]XoQ(1p+.pE0epjh$Ln}$M#Qq:C^-^6C+6R(g^d>qZH4}p1yjDDbeU+>NpE}{qn13W{M]?x{<g:^lSAF{@MB-.x*?]TK$p[@ln>75OA.=a&/L@tK&8}A8TK6N2E./xT(Tl/m+W&mB$32iT74?>Y$ESJuwqibs[H8jtJUVPqg$XHVF$=V{Lq]OmbuVkg1J{C)fsc*pwE}pcD-{=9[2N?aMNu]c-{%bS#$)FQ)FN}35/!o}Na[vJGl]2ybtW+#w!{*2U7a7bF9V=qEt[TEC&V^=7&%bXjT7zvbHYf)w]/iYe={iz=9]Ib7f+34hm@3P0rgf^4o9r/!C8]6xP/JqBeM1:plpNF59vJH[.7o3zmNfJs*wBt=0MPK8(hT.&n7snzV]YmZsl[TxH{$t@JXGiJrI{gD0(OrX>u*WrW+>rmD66?r^dBxacEfxo:AHM[Z}5!68]&>&eWwCq}T0ks2?8]oqH<.+VgJQsSIgo7jXN$nh)DU@n-ibPZ
+=XPnEv-{90)vPqM607]#CO!84--FK0EyD#L*M/WVgPx<p3Vbaa[bcm0C5$W(yIYYUz@wah8>3MLZ9Ky<?<glWN%h)6sk^wI.YFypu(G-V(EBfsS9N<WEc8-81:!tZK@Mn&-V<Y8uXp/f?D{(f-sf-fJ?gV=]iVbmM7[=dRR}$^8d5rUbr^q{!8ZB)YB^d.&A?dHQF1@IY.=Vm&Oui2pV9>y8*CAxs()}[lUDAFXut<5X9]k2+(rsv2r-5mdk&?(RD>ysRV]>q=<V#PN}}6-1ftoSrT5{i00000
 ");
\ No newline at end of file
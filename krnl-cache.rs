@@ -1,76 +1,76 @@
 __krnl_cache!("0.1.1-alpha", "
-abZy8000000@}Rn2BDn}/7A)@mn.pk3NcX{F^z.@l>4nckBq6ms3D<md5goETn#<^Op/(BnRwlm5HBw3ld4M0=s6^FyyVfykLKL^(c]t0Z%1H<fQf[$+t=6JX#SBt*!.2Fn+?h1c!7kK?TP#JXAhB/.&W}5%6yifo/FO&A@g>m869PR}q*<6*&=RK8/v[o<C.q4zFmI7:Wnu@TjgDCM65:qAIh}>+y:vV3gNAh{{YUb&Lj^I)*1S893z7A8l8i:+hfYeyp+Ctw.%lUD.EJ.)^A@ZZK{ObkA)brV!%RqcrmJH8%}ZotrpR&zjvOzZuLtMzv:].mp>9aAc7]1h)*Ytt2K+!L-/({X82<fMFkvlRbzLZ>L&:#nEktT3JBpoFv{B6{cNRxLVFiqR6@J7[>8<q?PjC0{JH^#)r}KJk29iB=ty9l)N@9b}=0E(6y49ZWYbjcx@7=XW8$B)(t9%(Ron[.^TvTcu=Ypn}ZXqdU8NieR0Eavtw-WS*B4c*WvHUB@hOYK+Cr<RMsB$n?52UYOsqXT]?7}Qip#s+!gX:30Eo@IUwbq=3HVC[xJ)B&?#i7bh1}GKN7r*8Q[f:)XXz:(?Ws&^}sO.V=46ddC:o%R(ad.j@zSa2{i5Wd4<%U^9G]p=ga.k#Bl6NXO?@:BRF63I(63Vg?PF/@DD*FW:kDz+qKBpU[%U^e?SP)<=bNgTzM&-8{c&qa@2jX<<u%]oY&NCY{a4vlhYOD9A8e3keW1oN*{S8Wj)Asm?gf?FuK#kNH<V)yxxn+R[6$bHKnuvZ=c=0Hqd/GsP)zY$?Mnhw9T5+$X5JpGZYa9$)Ys>#X1/1go)tW$i{cA<+X^wQ@h{0k*Di!-%db!pxt8SnQ5zae{:L@j*(jkb<Jt<uDVBRb^dOKMs&W<8]%)o.TMaNV+1MaTm{Yl:fb%G0ZR/%275c*kvcwP{Hws{[<!}m-(JN=k/bXZCR2eTB]0o22Y3OdRF+AllxiG^Oe#4i(P142ffDTrm
-?h{4an-6#B*o8mCC0S-nGM^UPY8ZgrBCcfAXkaOw:m#pF/mep[OYarzF5g0?EbsZP@D[oB{[It.aAd.SIvcAD>KR&WqguoL6}B:RH4{a6W3ek01]?Rv(!rwbnaVuJ&QqXN.WYlj)JaTJ?mUjPfxTp30dx5:q^Kh38*-ZHiwxAYC[xz%uz@uc!JV3!@}s71jmZSE>YXYAtuo9?s3:P3}Fx#az&:)Agqk]h[X0R=>m?fd]vedApeAn{m:pL.13eQJPBW@{4*Ra>EH$k^{X!Hv!W?WqN%lS&)]odHh:+c7O9+KEPNlPr!npj/3!c:S?{jaH}tCcpI[U*yCI@w]/f3^t=5q8*/<U+EktO0wxjH^!O@vqikI%/b=IaV#hOL/Y6.qc7S{EgdPqy(/s*7Yt2B4<L]-YheE@JmvV#r!$V)pbA4[VK.9ZTkP^V?kc]w@5#Zgr0CE8#(oY^O)p</9.8lvwPPos<&ru+O=3oq-Qo[N!$*K1WbO>]eINR#FV]M-Z{(o-9l}omq@tf@TbW^E+c{&^tqE-tv[}!uZ/i}4NDs3SFh0[Mj[i!/%7hB%=GRX%J3c(G@#7($+bVZR4o.9Q0(@&E4]kALtMbP]Oj773!d)VfN@l!@xr5ky{Kph>!EX?aWH!Q<xIy+?/}uQw}ZJtKTMyU(&>rCI[zf5R5KKI@f0W-NlGQ6yx.ifu^.]G$d(y}91vZWMX[c{*R^&/qYIaYvSg%1Z.MjDF1Mi8#5CLu]k/6+d.0h{*OvHHpcu4hAf0hX%Hc!zR&6G[4bpcEo<eF-Cg5V+BJ(Epr2DfI[W#pZ6=Xn&Cl%Cu!7uW[0Ey5m{dTjSHM3)>mlpBO)Sfdc9]luE$Cc0-Cek0tvjDo{4d<}ysEh@kegj%)eZN%3jg7x3IrDlHW%/PWlIw[F2j)DPa!}bF5e?5fi}2Du:f-.XR/@J<:F/N}P^mZ{Jl573DzcT>s.YjpYYiY1n*BtLkLNS)mQE(]fR=mGk[2H(Pi!@Las%E
-goD3E+fAuIE3yh-ZIH)S+xvhku:^p#[xl0?%fr@1/?gC@+HSSJW.uNq8xMDRfwxH]zOEBF8jpro8$(]7W-}IvWZ(yM]LX0/)sa9r}#LY=YcNr^41m:vM:)6$9dqFxc46vl{L?qJ}9(A%[qPLn^ZuZ8c9D6bumC6Hs^/a/iJW!&B(}o(so^</Rmy#+]uA!/[xCG9>p$mv]TJyfd/a(v4%=jOj*eBtcgZI)!(AQAM#.&zi1ZUb.$QOXZpi!9T#yRn>ZdV?]sWrz}qb(+1l+9^2&zE*b^I#&E@tp*?]-PGQt22H(wKsqeP6y:d.c^zib[T]1(<}?Aq$%$r7u]/)Te8iR0>sW+zo>$RkkGTcQn+g@G2:>MFzG>EiF3BRt([EyObTq}.:}U.]FRgP<.mqE!>7/naSPw?Fp:4YkX?8OUW-.1at@=.8HU9}vjQ1CJok99x<X+F)f%:xw^6Ajmk@F@f<zrCsVowyLqC:!Vhvo+=UWM)XCewq[Y!tx7+O?Ds{6/DR!rj^n4G!HRxmG(atQP?&6hW4B:=nxzNUlGSD./RqFOA{&ZmTt(Sz&*zO[DMIxtol^j9/PbWTQ4Gj$Ijjy!2jeN7@2L9jJE0Xd%CI)NgX}=Hq(7&WNX7Zz:RtvSc(BIr4ND/*}ToX:(ItQXUDKLeSdx4O2(yJQs%3Qeah^iWzwz-2.IR!%t@CVXZuvQf+)BcQv?:3XjlhohV!)L<9oYFhq)FCugYPp6l+zklVHn10hyc9zL%4d5$p*kH[NFN}SWJ&RFW9LH4:nnJ<Gc2WtlJ-L(/bPY7.YsIQjsxOBtRd4&}<NPv[Q@$o=Jrc4.H5iZ+9ydA0<n@2J5Fe)t^$)DcNv^epVI3D*k<oRpMSV2j{xMQCj=Def]C3}?uX%O(KP-*@B!oIfSb0DEQFg/)e4]rN3kmB<w7u]5S/8k<pl.b5S*^G213Q2JdGc#ixvDwGnp)8}}Gmb{I#Wf<KPRt^gHmF]t}U4OaGImIl/rueu}tc
-2<o{xKNaC6/s<yOiM6*vgD?])W.cSJD1pHgPo:Q0B<p$TZfy/hd.@=OiinAMJk5PB]?G-0]:DRt=lMZS?e=GunJ!DSQDdOT37OvU?CYR?z5^WN::4n0io?i+pVwq-)F]+FIUoQYBSf/4F]YuX&?n{-BY{%*yr>b-Sf$O3TlkLsCc6qesuYq>uCS53V#hwuywIs{2uz)j[^&&{=>YYC(Q*A/a*2TjxL2zS>5+D}&5P:Y=QerJz=kI9AvnKqOna>JLupQ&j=e?SWXa[OmfnsV74kg0vS@oreMF*^bSlz)t%Gf{>#)wAzHeCIfZJK1Yir7VDLU{wUF@Yj@uS7izYojTpNxBM>?5{8?.9.ChEP@BMaeppgw(MSxr!5EDkt*Hy-#{EwEf{YhOt}II.jSNhNr@ChLTPNG@9m3((*{)Vecm2i9U-nFPHjfxr*qetx0!.H1%yEJ>5P%zXUgPWSH*?NpURkqDN7a(TbHnJ}PN:wlE!D?4]+t^^s&WT:LLk?-ig33uKtqs4*4e:i(0(*bbvK>Gq>tI=E6(MG}0wwVm^<.vxZLNFi2UKVX504z/.]GrG.u.zqyh)gfS+]vxHc^/x3[!vU[N+s.%3oPo0Tm*4#mxAkFiPeze!Sk/*beX(0dPL2q:FgW>iDCe{%0bQRR!:5YW2g9((r<c#]8O=uq8qu5nI#*(91}/Rr]1!Z%-X6je3:n!rQYGmvVp>uqIHr$PM)Fa7fBVpTv6*!r@twuGZ+z#8EF#XWE?Y[Dg{FyD8bwzu!1-D9xKUqlh>hZPf)Hf!.250{@VcZ<^wc/4-K<Ck^[0}UEnFJLFn{Vl0pxt^L&BF6HL5q@GA:apF13S@8eQ+I{.<!^nb46iJQD%SL#D97l25E<ztNe:9bqLo^<rINWE56B=[^z%:ccYYI.]%LQYFXeNC(BSjd.TS30kskRrS&C]9xKvO^03Zz]Lb(02zxqSNv^u[i+1VJaVSj]43aT.]MQZD5E*pN^}H=?wbVljC{.L
->]b(mhe8*l*$%>(.+$lY95nFWtZl+N4E:*e1)<pS=:Hy0cZ1ca]4Na13GKAvzc3/4dYP@+B*YDW@I#60==u:>C]s.cl&Bm4cVGW1-Y.]E}c8>BC^Qc0..yME!r(0x@$L4U^y<)})WYVE=&31VEYPEDkA0m.R#DY7}ptOz^E9BLC..Wj0Kz(pk2mLH@Zc5wmymE^]?pa([dJDtyG2pkL>M%7gF3vbLbEAB<O&FtYJq5DUI*z3Zz*2jrL3Gcf)qeR9+){@0a[qhGeVW44Eiy^{]Z=@WR)z9t)-Vf}WQtez{}%v7XlBR}t8f68{(1bpUhSdalrz+-c3DT{TPdiE[nFzKmo&e2Mrh=Kc9!o}TFH@<kXU3bKAE2KL+PRONGe:K&En8?RfcBJPa-Ch=uoQ=zOj/=^qU&DDgz[}=Yt9hLP5vXhD##R1:T2x.%mjE[5F]cc2&<NG@<YZb(&-k=icd3Iiu]!zyMf>ty$xDCs(/iD0)O4F3V#}slbJ6yo4PHRcq0]y4*/wbVO^ZWCaUd7#/)kyM2nEN9-SLk^u0uQ[Rp3hDYH/7Gs!+u(@?kCDy#7%ygN7P)qfS6}[D%0#}1?/mL*P$z$#%cyVf3c0:tXS6w+-OQE28s&n4s:Htp[>fc{@<KeNBp^PDVnQflT0{y<E)%s[OuWKm(I-jr>F^.k*L&-q)T7z/Mi>AL<A!gW9^c<kZL7[u6nkm}Rd==E!pLauwP>&Z4z)454&Si[Kt/xEz.sg2wRmk=5]:=A5)rPP3QPrT7pk75M{C:AfnFYj@Z2g=f51O[+Yl19i#snuaBsERoM!=ZnBuHAc{4VOR&]*wKEVG:ctaK-oVd]J8oR+4a+bT?I3kF-eOF-r/]ulrEqU?Ka+ip2%3y^BlH#LhF%$5TAJYmy[G{8hlmUUwt6uivWu!r]TtOIkGq!^ma[!}IO6{iySL07ut:enYYD]70Rjf&W&5f&^OQ1Q:?aQ.)b5YR[D6$Ea}=mge?Ziub!A)(cn.K7)
-lv33tR4xii8#jrkX<}]TqK{S@]/t:KnY{+BW0D16/^8&pgol-[:Tj0&c]pLN+XZTxt6qnO/g!On^nP3m?Th1pC-wP]b3wJvVvdbMbXp&8ojTjJGl=eMzf/9fy.5cQnOL>!N9U.[klf.VJ<sbH(5fUkcQ2mp4<SSmD3{$]nE-LGQ95kl@QGsR<pvG@bScCcL79hnkMhWNDOXFxgS6^B)UVfVpd8cx6MWaFQ:AUh5$kQ=&bp{oKRj8uy<X-AX}$tZJJXUGs.]UyY3xod>YZE9.>2eC/n=s(yna9ySMuL#gULVrphXypx1lsVeeUL7Gl5p[nOL-6Q]1magx>oZcSOm&t>x3{8?1if@8RsR*0?@jsittUDK^34]c0GeqCVB1Zyvd&3Y]pFAYhr*(!s+Eyd([gl=-)$As}>r+HHBo81F-/YJG{WvU{Pcx5F81I{c0#ibkg}t%]xP37^6gb@i.VuZsO%W2QPZMp[/m(Ok$DC26eHZk/K%*3As0sQFrPn.e+4sU2T6WP=w7/d7eVl&DbRs2H&2SQsIW/=mw63n<yuw.yR58&B9qvyabp>1RK[Z(@B@N]yt9!7iJDvkPS30tHItg6{Yw*x&Me6Mx%v6Km)Hcvz7m!>7]HXLCP31Ygd7XX/MA0W{.LY90enS0[7+A!8dtSa=JpKOfMCH&?>kPUxq4Q3g4j[!f&#Xb%^QKCsgKYq/A*ix(66Wa(:K*)dAvL}!tWP>*+M&Pwrk%idRg+<IlXOHG1/NubzY[WH+7Xa5{$cRr)egtwLD:kW@UTR23ONIR6Zw&uOL/T66<y8bA:4UH]VVA2uTYi!1?scr25Vq}OQ[HF9kWw9h&J}(MZ2laMQuZ-d.Th}DZ?(P1R]@MYIr)K}S.cj4b<w/-)jzS:[szIH(szHxU}HZ[C?j[9rq4vxzl9+oJ/6lA7hDRF+RbUDtX$3^m2iFVrm8px=4FlAP.=UWL[5)>7]]dASr)K>Q.c2CrfJFMo[R<)dgUdHKfXhnz
-8S3/R->4^PT{6*)S3}fra$F/Nn{NU{&(VvaBnYQoyK3){C@>!98t0bkY7O+a=VTo7&-wFy0&jc>RyYj{}S%%WjBu+/!W9(AVEYEfizeiH=T7/kD!<2NQ6gaw/AewgoWv5trj={uHGSjM/%ID}?lQg3CJAH?{f=xd9Y/}E^%g6@S![C}1(9qB+t/g:Y(}iG)lb*?)a5Bz=T40}uopX#V{n#:uuVUFNPP{&N:F7)KBjv=VyX:}de4$].^mbx)aO5ADIe&yJVb$sD--mqLTe/M]xf/V(L:fZmNa4}M$[5F=Hx6dgGR[cj--gTBiP%gSdtx6tZ46bj?g19]^<ZTn9kDmw&v:SUo!D40vaNqup#tF?9d/2.NXIMb^ku25[1HqI:7S4q:=qDguIFcY8K*vJZc@70.%GafgJJ0}F)ggxhqz*n+BuK{A=^P>rS0I)QRHmg>zDCRD}}snI8M*T^I<XbJ-fqJ*!UZ*60A%VXizH&)6K(-WOyqq47x7R-Qz&HqM2}GK:Bx?Dj3c&&.u@-SK[sNie2/<j@zN4O[{wN1I3]IjM8vP19$O-etpNQvDa9!nB*![wkBSk8y7J/-tBOaAoSj7FUcKvr*-/:J/XWpr:1%OviG-vp>ZPDem1]s*7XKCA%Y+:5A-Rq7RZ!pw?xnA^L*IL0*<BX1+nGxYyxXl/B2)O${W}(pHo3LggngYWA^&CW])sgg]V!*Y2BnL$?IsWo{57P0:>L]jz0TrAC%(!JvTu>fOGho#T?mlrn0fBz+wJVudT#Qv1^^x4lrhe%S}X]!MC[Id-@ZWr*A?s.!RN/2]s:H0}utWEaKahm0<}hksGMXUW+T(MH*(T>}Sjim-yL&m$xG*GIp)Wie0so17N2HSMU*}D/rHxiaxuu+J#u/L!eou6?Cyn{0F9RRuVgtyfU/TjkqmB(dZ&rr8mk9f5w!>!pZ>1j8XyYc=[k/79P$E}tP:s7k&#euNcxowKa.?V&LHaScL7{@W/OA:$Q^[4Z5f
-:l^1^-&)Z.<@Gm+uw#SPg5Mws-]1:z@c2d{gYD*v:bE+r[%P4x+.bDWlyDx&OD[izGefHVhXi{=4}O!BE&:L#0lQma4fTVJvPOhru1%axfH]SpOa5>#]BLHf{Mm1p@6MQ)JgTm7g5+QamH8P.5XC@q[EC:0=$vRH[<^bom?XLPbqqRG>$$i4g+*NChQ-Jy7}ZUP&9wtQ%bGCBxKZ)H[.M9Sg)eXTajJf/kmT$7nowVX5}.f+ixe2BAuVn1b(zIrE>].MMIy0+<aiGM<a1h#g{(Tqzf8UTMqr&z.}8oBI80t{93m$nE?<^rNz!(kQS^YuPDhw2XRd3B]cuDHs8)yn)GbGUHo6j3G#A+]@atpy}W*10:L]UfVTXt7mQvQ/tA3kC5<Lk%5-ht1(3ha8SaT.^GSWR:]>r8>5/)rOzi)[qg{Ck&:b]fU?VM37{I]&iTWHhL]kRGu^:t:7k-SlT37O5{?yv49gpceEm.R2goc<F6&wG9}+x<(@&a?4SEv)YiZk=nlIh]/[j2J[]]/[fdC69hARHbl5(M[VZuI?10}U@by^W{snfxuvqPglC9qSw^bj{PpwG.W-sywwmFZzCq>H14)@uC/ChzMMTT?b8$K:J>#O8+uM^ZMOFv74LX8@>sb5U(nJ9OMC5pu=Q/8Gl:A]CMTu2xBJ-=?8zpEz@FstMFf6j@b%KQU<BKE7qiB-@AWG>HVW]H:MGt7?>$nu[^/+<al2sB.29zBQ^7U[>TUzOY[ur0?P9ZvoA1SA}TH*Um@55e}fY0Dvz1mQHwIql*Ai8F&+LeBqLgwxR[6N*^eD%&:9-{At9<g^QtxK)hCm$:{XkLr}ZHlGm=Tp<-@3jk2MElbi$BkuBx[JvM[LUo@ZL*gKgE0)}%wgyAu0!NKASq3MV8bVve6HV<yM=bE#!%#]b)h}Ev{v9(3T3r[GW]61jxz@@gtejiVugo:MG?nWM&bv@^}AM5P90lsz>1IxQ@H0eF8v/u*kr8J@e!+<.Wr.
-W(0ktfT+xkzf#yk]47O2KPcow*abZXuN=uMZA)3VGd#hFWvb2}WkQ+<uw)zm+]b<gSQuygAbGFSYuNo&[4LZ0zKzR{n3kv]>tx</]gM4Ik0!%bsC?nKm>FrXqd/Mr?YR?3{N}5C3WIsS]5!wwqz[*nf9co=foKj&ZAzOxy+:a:J2r[)[]wAD((Q]F>C4tL.yMHRXo0iY1g4.[}k06irQG(Nc{4VOR&]*wKEWSzqs=nIbihCo990)0}6EnmK}-+(eoT7^/*AhTg1dx6=XqATNF^JrY#!G/+Ne8uD=AP$=X:VHD6qw%oLh%X<-9?6hr[*&S9GT9@vt^mz-aHyY/?}e*OR[Tx@eEDVvV5!iAclMbs$U[{0f8jXOSW(xoxUAZR0lpW[pgKX>RfhSm@RoG1qRrG.1K$jT6VHh$CS8B=N/qPGn^<(uez66v:YzVWb[5/-WN]gol-[:Ti%?c]l26+XHHvn{hPU!>FEH^nP3m?0L!nAe*gSb3wJf(wZfHl0q$g5$bA?v9XHiO1wc4ZuySDPTn2&jralYklf.VJ<9#F(5fUkcQ2mp4<ZBHD3{$]k[gXTRxsUT@QGsR<pvG@1t}4+P)(^CkMhWNDOXFxV+^o{)UVfVpc(0v6MWaFQ:ARg5$kE.&bp{oKRj8qy<X-AX>c/%JJXUG8eH>TY3xib>YZE9ZN--y/vuk{yna9ySl3C$V!ne/phXx]x1lsVeeUL7ZJgUQl20($PR.?6gx>0Rc!lotsOedx8?1if@8RsR*0?@jsbH?zDjD]#]c0GeqB/<]Zyvd!3Y]pFAYhr!(!s+Eyd([gl=-]FA1R+q+HGp[81F-/YJG{WvU{P8x5JRII{b{%ibkd{t%%{837^6gb@iCNuZsO%W2QPVMp[!H(Ok$DC26eHZk/K%*015RsQFrPn.e+4sU2T6WP=w7!*+5Ul&DbRs2H&2SQsIW/=mw63BIz&w.yQ>8&B9qvy7<K>1RK[Z(@B@N]yn7+HUWvvymTJ0tpwr
-g67co*x&Me6Mx%v6G&Sbcvq1l!>7]HXLCP31XsN#XX/MA0WHCHObWR>S0[7+ASB8?Saul5KOfMCH?b-4PNMt)AlCqlS:U0nEI#@8qGBtGP(PJHyfkFpW]FxJA4^omOG05.8fs)RqD>SD)N8!K>2N+ywu5{FIEdaX<7<6<T=GUqY7>0ybZAySzcbVQyM4o&s6F:W74?c0t[$}-@*{RiyE}.lCbi3AT5F#ZDPiLGTh27J3APHXm/7n{tDHB%B<bRQfYqzX}oPK:uiLG#@+*?2S+nr5RfXCh1t6jrnNqOK+cPQNFU=@]F#96oSnC}c!zlZ)9{6SQ8DsFaxq}G0>98SZM}p(kTij7f4A$OZH*5zNhe<1w9ereSE{.QYS+no4P)zC%1pRXz0Z<wS5ZKpijWWEx1vIOeSnUKtgsVo@AhGV1Iv@d!V>tafn{NU[&((j4q@6DcrJ(cp2)kXYy{dq.93R*)3hl{^Aurcf64dNsEng8RZ0gIA?S%T-S+nx7TRITQY*d:]@]XKmY35:>?AfV*>HiJ7(?g0*WiTB9yDupy7s/RI/@zJZo8=K7)zlk9MD(SabUNr0o%?Ovzen8>U9cqiWfczC[7BZNTdDCtWPS/IWsY-K%j.[eX&WdD+TeL:J7alY<DDZi]>.4*V^>/>WPYf=/y<4hVSka%6Hh(PygUR&43Exb(1EDo2jlf=8M[7Ey9@@9[j.v*rNjScq.n6FNKMuQ02.xc)LpU=An^kIsP@xp9QtN$!3m1GF5UKOTi3s895k9D2[j2kzpM6B8eyn+rF(syr(S!xaR[7rsKJ63Ywb2JYpdQ>/MB02zSxpR{T-KiJXUz8N:5fv=TG4}?tHF)I-+9T]ps9mN[vZykPOfWI]?w>BsnY/sGy2TnX[LCw!PU(TLVp}*G<:Z.BnsVhqoRnuxWnY{n(/Jr)K@jyB}8MY&ZC)1x{c5X)8z&+Q9GP{ABf}T>h^l1LL]y0jpq9!SKSmO:G+W3@o8X4e>p]PVA3T
-<#A9YX<ET2kiy!^dwS(6O<I>Q6TydeG/3}iMFb6&0M$fq*9Y+qdlx^ja0+4NqPtYKeEViX2hao0-&n^bJmkj78ttQuwU!akBh(BIJQCK!q(w/q-FllIB]2g0(q#-Z9mHxpod[om{lTY-=e+<B)gN0p9OWQT+CwXg=9e</o5eGM8udurYVFT]rNj-fu!].oDryqR)tQ!9h*K*nLJJCB/uzb3/nJ#ls#@j?9UJ.%X#jpB4*[?x4)g{=Sw-8n=!T>5osCy{tHg<kb88o%j9K:/srr%cr?)xyX#!x-]Lg@T{].3R1?mfDTxWW^c}A%lpr*9[?gq.6^&1LccHWzcZGX)+=hA{7A:r1B9Kr6y*+0Vj}e^N*kzFaMW.T]i3ZzXhgKfif[P!(}LKSNtO&2[3@pn>8OR.px(*Tu}4/-#zBEvS5m)l1(j=[1/YjSh.YbeR>uHH2KCor5LY$O^Y)%VW>DJ0D[?VDX!tXtfk1}Yx7l%Jm92zp5mX7=P8TepT-%gTL88ug+nEcft:Jw*=04a0QmnK7*IipVDC?#-:qJZS<{JpgDtepu)DngcNynSLjP.!h%/@#M/p*x+EXm=Jr5:radODO134--CRxal[G&->PLW{5G@{+1Q/o<1Q^]GuD%(%dj[UJEK.[*Sp6)B9x:J[{xJ4WvJ5XEw*#)WBK<j66^9.B#]fBNv93{Igr?}@YMFu{Iu6i)I@Q]ixrBBWs1N0xxZ]ljI>xOxmU4s68#p1^Q*o&uMPuTOFeTwD3ilR.]MQZD5E*pth<hWf2CkEcy}cF81)klME4H5XT>gXoRKsTT)Hd==F#sy[!*{p&g1cq&69fIWW37yvlN6MnJFK03coxWd$I:R!MW:st4V%.U13b*btfrXy#mb156Gqj{UY/wv%%#Mmzs^-8&q>tY/O9G&Q]A?@c0hJO#c>j<)aiP4{]drP8eEr}Qg#i%1ACq%dq=)xhW+gvM>4v4UTTuvqtPCXXw%d{j.0L8d7iN{?)id=d#jM
-+Ge0LC]GqCMCnQWDLM9TQ5I=XyQ0!Qk+h0vi:Y}Wu4WB3@DFBi)pV{+mbo/2ZI&8yX%z6:JR)@G)6b>s{.HLqBnYy=DXZ+HD=UywSO:Q?!::k>E&)Vhkev6XEwx96z%fZ^uCh0<ytN1LUs[&?h#r6HmcvXL2L&+cWpcZ3K?QR$?RfcBJPa-Ch=uoQ=zOj/=^qU&DDgz[}=Yt9hLP5vXhD##R1:T2x.%mjE[5F]cc2&<NG@<YZb(&-novt]sc$UAP-dBskAAxe^!&AE+YaPVz&/e{=6JwkONzi*jlf#3N17fA9}HGq@^yBnm^ksg}%+{c]-JSbK]g*{%2I7wo##I5W6&iCsPi]S@1i^{.DpYMZg:6AmEpa:}^nQ4%bN0Q4DRB)@<K-Dn?^XY+X..eda^4+=5?@A:>wmoMTqiF]s(OORgQFVM9)41i<(^%5mgpC%ihCE>C^)wF]CQzk!CptyWY0nSCXaDD}gtfYxr5y?T:ys)MJ@ijY)O+dO5(c3SgKgyU{oj2ks!XN*TJk6y$qlB5B[4*B&OKNLf*4yqg[L2w?e$FS7D(/yA@}UiWl-)Dpkh48KLiGT=8OirMO.9qul+*/r+yk}1/1A&k8Q36pY5^FrhWxcMzg-2I+{+k0wcF07I9@vh$qX$d#?j]pIoQ<-Z$UMs5%xZVyjD-^zn8rY.C6(Q[w]9!:Os(<UawhRks4jgiXS^!PoZ16]{wB<))z<}vQRiMNG:Qg@g<@<lHs#<pM8f.Nxw!on7Df+1z+og]cl<xssbYjg8ue=6Zj5FG@)>i96bc#FrPaacYnu$KW6uWy.xgT#!?hs4?/f$&ilrL.yDFaD$5Bbcdq[*Je!S>}@CoP#w[knT6APZ}TIjy585{]-(gyiUyj@YfRzfH*BlvyLaD.s8e(q7^Vm?4^*Uoh[JF8?cQ{)NzBa8}62?qp^mAma&Bnlxh9I3#.6[ug#g{Ex-hi@]6Ub@b-n->e2*@{t+@2JjCELT@[r0[*(bf3dL0
-][D]=ym&##hE5O3=<oL3xGG9o1dk8pc}Mw4JI=l@AY3EGmo!v#iU.JBJaojd*h!ye8h6tewdUh*gb-OJB(}=UJ97/@4Bkao>161]^SdbHo:t&O3HToRII.NY>SrSt}zF-ST/cfASC+$yGW>[D98:lcleAuL9lZ{48=vdYaM8txy:dcPW$P{]My+<DKK3LMbi>U-Ti>Jl<unDEVK2*m5&T<Q.25x)8mZ/dLR^f/HZrL:jqOJ3weJ!fetZ6]9w]k%MVRoov%1vfO&&3X9Cn9M^Azx(6TB3*Tq85ErWcBrX0dP0rLRZ>}PA(Zy+ff%?pivGiCt(on(uewVDl=r6Yfkmw}!dEN)MN]c^^x3dhF^aT&i9ln(ya&2o3lCKEH<g6a1gU&7/y.UWwaM>QHkDLo9CsPn4d&9VdK<HE5uL511s{-{jQw8tUAn&dJaFLhJa4{8eOO[][[DF1R<2?6>%V!?nYDv[wgE@r:&ASvEskS!$^e)-^F#K=&YZ]xm3OruW5l!&!8PQeMBOVnl7?TeHzBS/G^Bqk(-ehnr1/cRr[5qmpI/n2m0&Sml&P7hyM+rw*-zs5>I9QYcw=kDW=OTmb1{!Ji!Py/KE=I46oDg1PQR]&xchS0o3%@*j!LKMGLysk@WHP1/QJ(ImHzUslllUAXacQ/@6yX{HJUdHZ*KxI:hm>Dug^ZAhIa/bvS6HuC+iZzr0EA)NA&+^kHSt5dILryof=up$+1QM9e%*U}RAj:lOO!Pj*ru5KlBWObLY7j+zPyW{O<8hm4L9uc(W)?5K@C[a4b4Y/mMo8GmHn(SM6g2PZo.XN.iT61KQ7]g$I6&OPXvo7f)r:1PKrWBK{k(d0W.IsROK3nrl]h%sGj=.fA9^-C--$j^3Df^fzV1n87HjA5$=36OE>DMtX7f/nD=9:H0eZE}ueMJi2ova.+*f$wgUc>g[!+JhUEyPZpqWX2:o5/M%4/0VH>DQS0Dlhx$y.uf?4W4/8^baxD?gAWV7kx>w
-g?]0bPffM->DRSdK3hjQV9*MWzo(YPKcNP56L8:mr(NnBPLJ8*BU@@m4x=J=}F?IjvkEB$HuC!j.*h*UiCYpD/I1>%dAH##C9^j3lWa6#P2zXTDoKr8RP6rb8mCgRUX^2kH20<H2%^g^5->Kfk>$:ND5D3=kTt}fdQ0f-2Igp>kXR-U)ckdwdN#tWmeQtd7T(:ozWL?(}j$[XRqi16WI!H&qoq5[[t@C6Vm+/b/3^.%v[M@:Y^%WjD=Y3WU$VJ8Q[A:CVazRBWP4Y?y?wTCtd6w+3Ei9pN*/0wW+d}mboi1PH3z:)+#!^Ngq@O/>ddlXVOHVsojBhFxn##=mvn<gFpiM#4w<%4[0oMMDE?v:T7OMNJi#$y:PhI@j.@>9szIJjG2Fm@y{iHI}mTfeLMJW6ug&gtmrtbRR(#YaDIV{>yJl8LeFvn.xf7<^!7rP+a3<Jfi<a7au68w6![Ywa0!l^9}d)k?W?:{v7hU5SBCC}5d8ypMCqbVS2XL#O:Mz1IAl63ZuqC=8R18FB@5/=b9mw]Hj^OJxMCsV6gMFqPLxRKkk7Jt#On(WYAk&ct7RlbRdSt^k8k/=zuyV1Ij#?PfOX#y43=r&r(nt2qi?n5F5{RZ=zjQ{>M<i/uY:zA%!7W0]z$ziIkW}<zBN7S>]$YJpqVj)#9z0mFtorWPr?]0gBx:OifXgtc]V=aQX)M*:r<3=-L+X7RR@?/W)M*Ir^&0y9oX}TnF47.JObjZe<*}(/j7J6!!lzh+Bn4OqCmzwFWzzQJ3ZzW.g<Grg[I$KlRG43jhz%}*@pn>zOR=aU(U#!P4+FfSHmdvKm)l2hj=)#{ZH[P)ZzC4]uHH1^EJK!SY$O=h@Ecf3DJ0D[}u6MaYLnMy1}W%Rm7=dn2zj/JKkbzbYqyt{/t0wS8p]%/EcgpH4#//+..TwR^HVl@pf-n=C.!MrbAUg$<2-.85*bgP^N/Hu^XzUu[GBHz]#C#NirFV{K[:bm[<:wBab/((AIX/o
--RMmgAS6)sjWt-em3CdwC*a1.&e[X?]^wZp<na$!CcY3%bovw%@2OszzkT7Aa8mojzma=5G.&9+OLE%=cG+8F&[Q>@(^3&Vko1f*i%Ud$pr>d<eUsAHa&xg0K8L][sUKaV@WYs$UJmYM%jSlUz3IExly]hw@4NXBqqL^yWu*O@.&a3LO>jXDfgFapH9Z{Jtcna$e%CI*utEeTQjXtuT#}Ep2kxK$0L%?(Pkt(nL:J3#EV+fNEGBL>)mMqwmFfYPU9N:(.(F]r==u.<kLfgf]#3u(-67QG{GltoyMU*X!KDnnEd6*&x)xOLa?]5NYqK]Iy5dRX?.TGpDG$Kp>U3o/%g:Ul@Ek:MO>j]=I1@2Ai$QMCGSj5^<O&EN*zr=lwoXLp&K=GQhfRAkfc-Yl<zu2+tk?dZ)]vCQH5gV+Uef$D0D?.B(WfZ.CmAYd{tVP&WXCN46lSi9@>s9P(rm%1hUbR0U/0{r&9k?i=gMo7)&v>2)&!ql)Ty!hrM^RF@E6H/{Yf=-{MtDpZm=UcD?v)BT9C^iZB]it?@@b35/{Gualr1]??HWnm7R!lCYe7ZhJ1X^?BJ7Ci?LNlk12iy>]GvQ)RuaB/[:%D&O2&@K(2b9PZwT<@#3*VML/rExDE6/}r*w$aKPf7uTZWYG8cBq%2/OXnBK3>f#[cUZD{.:g*^$[Cy[Vp)#S(cwbXTZDcy=h}$CK78(ro^)8LgJ/agBLmdL?Z@oF2EhZa*l^+sW=wbcIL[<*7.31HJl}L5aY6$3F-)sg#f@YxMBh<y=P}qoR/e5x>&f$lVVPX9GGhBEEZbX%Oau4e:}+(r#tL[7VUsjQ$4yC/JG>c7--N>@Twq@6Uw&Or7oCahvX?Hef#?7?p>J:dS*=.EUR>A/33Es*%nUOuF7J[R+]QrJ5SZcRJ6)M]>(7XlYo<JTK:Q?*QJuJPTLuN1mTENh3=/MJL.88B*^<(K&]//]+%=QDOaGJP]kAPWyA24Wra{EQd{Tk0/ep<9uf
-p9)B:auJ8/Gyq*=m97^LiJs<0]g.NJbQ<U^s)Skmje=+rWLH6P@-&04<jRh]?w:!N[HRC{VUb!P]@Jz#NkZ-8I192TT2{)WTvAea4f=8vS^!P89B1Isn{0>b]0P#!qcI9gMAhi]zvN#fT#1h&YXY5zhRlg<]^]W+<cM8!(q&+2pQ-{J7<iEeJzT[]J.[OrS3+0e))YFWQWBmPv]K?us?Sazy(C}wwMD0)bPAI:p?Gyacd%c=6>KoqO1V@mK&)8W<HN]Nd0!IvIq8qWc:16hul)ZMqn5ar-WCSSt{r=Ta3oS*^55h-R.v[w-WE$v56)<$<px63NSCLLAqy^m?:[t$^GtuAsybu>J8tk%?SY/tqgFH90qMZygdz.)D-na^)t(-4&na)b:IoFjVB=&pLa$>t4Afspkb*nOXp?(25+]OSCbdKChYHTKL5x4]p2&qoy7ItxWYPZSdeEi^2xh5$x9UTUQB5PzJgbXXhLpI4>h=jAxOFOT=W$cv}-Ev)w0ApGf>@+Nj5Wo]Bw!nM5l^$9V^Dl+:zTH}N[XsWAWw)#VGEszhXkFZaJm>P]4PmnVh<XUcUB93X4TCETHv^0j+Oh-5eOj3V%j5fIY}K$+:R3(fp6b0z3F>yV=G@QH[=7@*EjJQM/bA0sr:Jg{gkYCmEc)byQ{kWWJqjvwCte8<M!^vNHKe=M./!KOmF-)KHk.%zO8:vBGCR:gsPV>DU3XwO9Ee@N9v}eRbkJVp)K/WYp]0{7=A)azR=7N2rW>pVQ}bJ1-k&.8ckbP6eX9sb4GKBW4fEHpxtV%l&!3(v7YM3w1Am9Lkv7DtHpq<K#<8TeOJ2ZUP(A@H%19sao#GAH>pxl1zKG9Ncoo+4EaF@e4UqNPP$(s(8+W>.kSJKgpwXFV{cr$WZ-K8qJc/Ufoql^8=o/SBlalpssQcmlX]!g}CA@NdR7#Yv^uZAPtY?)AqwZ4aarEIz*9G6{9rk^hP)pZ@r/YpZjJzUc^5q!A5BP.Cowp]
-e4yQY{&HUUuWSEFTa52?&w)*Uy9/0JZCs}Pe0r)M]p)j.@}*hOg^^ZkK6NQSty3kk}k-XZP1=3wF4X:yh{=s3}rI}muO-8cuO-RIal99iIq+TdiScn?!VW%cTllW(<3thXg0.E1iw&H]SV-o!*TT7%Wq&Y(jn:Jq>Dpz*DHy7eyy$KqB{V%O0-h16e=KJ.QCgGhw21!Oj:H)d=TBrNVwGnVOWx8I-7CsA*V3C5j+}&N99foq90(.91?.3Fy8!COr%!Q<}/9W%XdWjaaBM#><IPkYY/}g^]h%sGj=.fA9^-C--$j^3Df^fzV1n87HjA6Y=36OE>DMtX7f/nD/%N=ceZE}ueMJi2ova-)**]UOyFRlTE:{<vTi!o<UiNM]*HAt6[aJu[Dlhx$TMM&SZ*/C%AX6I6=TReb9?cpH9?gMQ&M##y/S}9?)LWi3xOTTVRUWX-Km+Ro3lJid0uLC.]dkKP>?Fiq)xe:!*pj1}91FD{ZCw0c]=hL7sKgAlFwU$>A.F=N>DINS.YDw>9qg@CU*@uYvkx:qPVN3=>[Fq*0s&gAtllkt@]^bT+2TJJu+3rZW}C7Us1<$y=n/B]E=(/Di{rQU0I#]SV8ZkGSVoAZ6y*pSt%%fpusQvEBD+jA3*zUfV$>Ag!D5@[IR2<5{}5Ml<X{f5plUrk>$WD6j)ymN]GzCx^0-qN5kG1>A%>KL%ku/yE?qo<HwPqz+3BjcAICM:-wJ5Qqe]tgkH-^hy<Td>aQ-XYlBY/@r>Y5DUZLKap>!PQkucO>I65:<fq2LWWLF]CEhV^lf8Tq)9DS)hFU7NVB/f^y82!lxRcuLX@KTPgDpnO>Vy]*#fN2A?@CK@@)<s0uqT-EkmKN&S^W-axKeoLF{[iD@!q38TQ&)h&.TsrW=])Y*E+Qjq?7W#S)V63hBpTiIg%)09=su971Y/bV!0H$?<M(aZ[Q&T]FDx2=^k6DXz#:ft(-EJFTa/7C(L@OM=n?mU?!exp7qEJiRA}18
-4r*l#a=+nk9cCw28I9johJp9*T4/wWp[6}H+<7hflV1OJy4YFyY(Qgf@v20#<eDt)SY6@&n(q7fmVG{KqpFf9q73@<j%nmD2B?@qUbip>*sc?zz7K8M3NE&8(MS$(XA$M*VKo7N@UFVE0)*Z0JNT&8PN3xeNVq+]OO7N48l@f{LJY^+ps0[cgL@nr?zze$Dr)(DZphvvR+syxADy6b*b6=aGb<!J9SKB2Y{RDb^&uD2FoDiP!i1m.AghDb-4uI[V>d.R*vL%gk1aCy2<{*4@D$0LsiC-&WNRQ%56&()INE1v%a-a(*ozSsD(?BCE:o5WWp}U-Q}NHVPeTVo0{$kcT@{$3uN:%FyL]-!A9875fKjNpUoyqB+H+.K@3nH+R-wL#c!}2u(J*.rBSSA]&qj%WW:!E<Da9mP)mD2j.U<YiEp^?>*i^9L^mi9CUyw&kn0r/<[#ZjeSpJ/+]8!G+=wOSxc!iFG}f6C8QA7ATVaQkNTNf!+:9OuhphNyz4[7TQzqR1k]a[HdTWJ4g*tJrs^ewm}}f6IVHPABa/1hl])68ePSnvBnfcw<p0Qvx{M(^Kf-E5YLQ$ktEEr#7??]4s(HE!IuGb6#MD7:6hF8!nD->-jfSkv8bE)ljtZq5z:jMfs6q>J8Nz7:Fm2PVIoM=Ov2@:g?(<*@23/o31E:[(>K<@N^9>6WF[z(GrGv8fopaFiU6bWq]i4(x7Lf!!LXKJeiMaFgwDbCcYtb[8xSJS1u[>R94P/ActSvfWD1H8&$67<=z#mU5wX]BI5xc(2b!DQw&:>+e0B=LTm?%dpo&Ef=ORhT@BdJc#1xkGEsy*NK(<+3!!d-YeG5+hFC=GmE}:vh]Y3Jg(1>9{^nO8IE{BAtSa$Dhll51Z)MxJ6xVJwbERU]bjrj5AU=rcbO41./Hr)FPiUT907tzq6=It8ky{T<$/E:.Qd&#Baq.>Au[%j1:M57bm9(UQH>81gE-$k^di@MF3<&Eg?1)+jJouc2>F}]
-/DwyJJn>gh+A70FODaSe38oclf#/5oN9MSxF?S/K(jX]rh]$}P1v-Hgi0]c)QFRP+gE*yE+X8>YGRvH4BakLtQBxRdGfmdkb):8WQJ)BkgSDzEQ2&il@.rZrx[QEg<t1UYGd@]Ls{em$wbIruWewe6?=W-OLq7ppjJoud2>F}]*-T?QGe&7ul73LNd]laxS{YY7z[.I5Hv$MK}kl?}(I??1Q<.pg@+AIEF/}#)6k=5ZNpuaT2>tp*6E}NPeRsQk+q#y(f?}[(>$.7OywT5m-qHSepBBu]GaN=}Z=Xgw@CJnbyftFtdmK$UOB05vp${[W5CGdiO>LKKf9(L[1)i}R)=2wc!V@Hfq&HN*xY#nTq}<@%5vR%18XRqfl5sm#M<{4J@@<NGMI0UlvJkSi6p!Ym!B{f<-h%M]6a1j2aJgw)+Oc{XT02vhVk$z)J9s2})waI{*TXVDDMj.P6xQ2}H<H9}yG4?}GaIbcX$R>MSSw)aLDEw(*xnWqeNRQb+^G/[tml?GSA)aU-mqfYc@V6%Z]lQ}d]C6{l5n.ON3w0HSVYlZ2f+lt)=>}k!V%6vq&FRAqnC*CmR$i&a-nPlrV]RBaJgw++Nh>/NJPM0Vq8/DJ01PIKDR>U*fL.KXq7aRaI#thlFPFeC[vx}e^ME8To)2-v&]9yB<*d-*V%F6{w!P!ccNrW5CDfjMzF:-1%HdnhaB0y(&&u@pi[NdDS7Hn)GpXYGN4RI&{D])^:WYJaJh*o7av{<Gs8ANCzSkEc7z-Ml5rb?fN]z{]r]^W?3YQ+q[H!p5lE62mCf1cet:mGjlW&^)hXGG}ZYxbkndUHaJji[?&<Xi?9lJ6{Gc1Y4cbar{$wstM!HplGT:kF]j#jQd*QcA1M[s=)^qjo@sO[R/yU6mcKwEwOJzF/Fv[RT*{].D*Js1y@hW)yAC(At>}pxu}DXXb%6BaYVW#1z^>W$<9FBk&L8!lN2Z5XSVB>6vN4Cx!DtAl$+UHnJ=KLM[5RN-c
-kz^VJ>OY6H!#@yCN8+0h]%ROj%n8}cki{WC^BRZO/m32M??R6Zkm3(pZhthpDQ5cG^%>l{gE]HxkY)qoP?+OF*sVHPw)XXhBQ58z9P!/Tq-M!NEM/Z%+A)-n4mbq9+gmr&?g[9O/f!p44z]XVKyv$5G0oXZ(zPR&4F1wI3>CWK!KV#aTu:K4cfhh3C%0nR*POJ)uQU@LwdWFypjX$:jYG]]xMKLzjemzi@r/]qmWP%65o!piMQgt.KN[o(EWGhR2$0rraOMFhj0g+{k^5ji067C5G&J(Ym&9w0Q0QaBqU+sWxt!6Er&]>}F{VYqO4LcDP}w&IDfv[&:nDp2m&UH4}C!f[7O#[oYYkYT5BrZ?RUP[<MQF4NJ^x86wa0R[/qFlc2W?1#@-oc]-s-XzWe:oB)(*lN?xPk#t]t)=JDEeIC5@-x(Bov-{Rf]g}Yg:}C/I5j)>q}$a3YjR{A1RGK(7jy[%tmd:A@#X{SdYxY.xG]Kg+kdDYL&l:A@#X{SdYxY.xG]Kg+kdDYL&l:A@$})Y##(D0@&a*aHF(ULCs>EI390HM1I#/sZC6<%2U{{AN+akxm1w{Vu3.]4]C:0Q)ZF:vCBLY=NQ)@0B).{G*}LJOf6XER.iX{V3BW{DC]P.s&WST/[}WxLJd@@@8#e[4EF&wg<8*(:U]]C@LTx<aYHpWyK(4d)qZNUjMKyY3:O+r{DnC<>gn<OP*ve(+5.9ufy2&)Mf!WAO2FiIm.5Wn.!EB*QS[t5{G[EIh2s0@uQ!()[WC$!S)&{(UJQ^-=mMgBZjarKBmK^Dazi9o5}DzbYRDiv2qIvHOo-0Vob-8XZMsb*&U!UL3NmCp?Gw&os0(h49CWdsv)XbK3/t=Yi*.b>I]AKzPSp37/8G<I4VlyW0X3&e]tVNd[b<OKT]lYA$su-QL2#%61=C>)l(!o>muMTgym[Bq?7WskK7qYDEIvU+0A=e3PyA%X3$u<Maev2^:K=V45eGxh=R6N}aODUR@sh)
-f7!qwhp}TPY-j)3X^J]{(y9O*8)WIVO-m@@@V4U?B1){cpXb<cJP@MiFwhn8Ni78Sk7@To(3+mV[ZRVRTbz?Cth8M]B{$^bL?G)F5^^ySmYhm9ft!^4e0odBXKrQ(wFhM0a48EPk8%!Gc.zI^eNGV{}}PW6.^d$eFPj::<t{S]K:hiYU?sf#b>xSwY{t){LU?3$IC#uLofS(hRDQb)ur-m=mSUGZD%na-3/p:MeAB53@9NKOi)TGfYuW3sZsvGfB>y<obj+bEuQu?$OXE>ITl3u+Zc.a+Ws5!X/TpO{WBGp$w-5P<]>:x+o<yR3Ib-&JKK77JBeN^>Dqh&z[B4TzDkDzzi!4XswMw+U!p0xikr#@v}i<.p{Lm$!(.%*/P23:t3nBl&KeNM<i@Z5>?cxdymJPg!%arvWE)oU7Tl17u{/5DzGsPn(.)Oi6{-aOCn?>JJ=?e}.ZlU?M&WY<Vr{Id+[n0()=C6L5h=ObU*(c4pBJjogAkd:^R1G{t<#HuOsT7uNi=.d#(%MhxOIr2oHl!)t)L*0DJ!W6=<t1T&<thL3^?%wMCBc8Fs=gC5Hm@ka?YmV?&BVZhBeH/-8n=dZoSO5NNB<jolloU62ra.OM]*&[:}qU)fC$%6>5nc%2>w?)v7s(c.}:HnAvtln0-Kt?gv2(G1&$hjkF$:>80jOaJeAqU7NT$dcWgnMre9JPi09#v-XqP$v]K+kdSwVknP2x.S1a9iAtmi{Ja8c@-&8DChq<STN+QL<q5/6yQyM8CJa8c@-YfnrJ!am}CYGn>-Td:E7<j!Hxae<VgE:KAi1W^I2ilmJJe0Cm7:)eA=ktXsxn<&-^fM[>RSu>@JftACf}b@6=ktXsxn>#aAsb%xR&*(cfIWx=-Ya6rCe=jWi+bJtJk9k{DNuq14Dx1Xv#N+Mw[IJ[R)CZA?06kj)M9My}l65YEb{[bt#ejXeY:He>[-s:gmbR-VcKY&K?62!JErdYgQ)kr&5S/Q<}KYd(e&p&
-{zgLbJweRV?u67xq9e<1W$2BLfD9)+1/Ap!XzrRRVNGsKtFi3JN]hs?T2C!M*C4[&sp7[>caI+I1g52pNP)DWfDi?IBe}lO8@06bReNqEadIhiUlSqi3dEX{])?rIWkkprj?c^^QB7V}AK]+l*X>kZ9.&3:rZdv!m]IG<S#?Y=Hga5T[fQ[io(N.@1M>&1x(rQjZ=W!lNoNuK*$muUK&1K^t}%g.ukn&.6q&s<S@?)iyg?CSST6g<J6/3lJNJUfXznUpVPIV+tFhjm+tQGzT5<#u*+u(FM(S9FcaI+w1)gw?x(rPhFQ5xCgQ)jOAgE}qNih?U<Ac^)W%Hby)N@@frXBZ4Ew@^F!YU^KzwUUJG**2ynn#hc0Ci^$g}d=Gv3WKg>2Kbq/stsaMmgKL!0?Vt)h=o0h&flkTSvGlh!(#y]-hdo.$VJ(fJH%GaN9!r=g)R5m40aq?&d3CpT)XB(yhkVgk)>j3FBLkMY*=W@@FG]5Via?E/ORj!xV>mks00WIOs8)gqsj+B-TaOjg(VT/n@8)9(&AW8e/g^61KQ:zqJ@=OVx%imu<]0Cx$7CB!f5KAhK&Dpb+bz(8<8yh[!>$I9!k!B/}[hF5q+iUjAYNqcQ/VMcMM7uG-]K=ED^*+w<9El0[%yAD/18/@.EupiVQi3f*av5EO1N(vn9./E][M=8%b6yIrCG(y9cjT&f5Z=+LNo)nRw<^3)@^ADmPXm.lu5UjA*T8?o@z)Bjo.7#io?&Hn7B{4%u/9LJD/hEziPY/Q*Z4O]T5ex2!WG66tG*sG*kUoTQQf89yj)HB.CQgC@7/yx#VxgDm]bJ8sne$[n=ugt:eB$gTl=lwah?/9(N:Sg&+@IbE^sMh#bmXPfFL=phGKtv$-sy[BVL?>y7jhTlb}rVBNJsb7:=-C:ZWfh0Ob<#o3e=BTT<K%ht9rk?5))2Yt@o8/k%0mLFRVh>vBd8wmRFttrwFew3?u[:FD*=>*{R<fxD1W0k]$EsZN:hF:
-@@i9u=1xGbufJoL7TG?()H]oA{Mh[*ZnF[$]$qTsTm4SWuWT2M}%a5t/v%9XZiR>/:Js-U*/GNdHT]P?<S<!N+uq>D+I8u%f#rzg(?!})X@U:6UyrZ]M:Kf6@YVho(uL$#G%WVi=oi{&Z4{Nssi<6P{sj982HycXztAbys8QoET2AtcEF1mJw}5c0Z)EUJ.PrCz2^)]=)nCol/1ICODqdfUtI$bV+y5#a-T@4jT)GUQEd+$Tuv8V?RCI=^c:gmgTV4o?ZzUa+IsEb.ABj9N-fW(oDzV)$mzdgwAWpka==iCEM:-OhKQ(@cm85HTLd[Qcrmz}zX#3df4$bUQdGhD/=Y-PAR{r9#[kG20@%g<^lFIWf8@7cKJp}tSELdI/!Pgt[8^!8F*aCXCANYdXWBGr<XI6o$9m<+y:hDoYIHsMEoxrd6oD)<x6dtV(6>>TeAWxWVX++8.obck#Xnf3>Ar{L*n:7T.u&p[1)aDf9=a:3%:y?1rq?Rpm(vid]^EIzJGfGD-RPWXR{o>Q#Soefg}dq?<]+d2pkoU2NEuCYK35OMA+[1-nup2Xa!Xv5<Yws0g:g?yK>Z6TwEEum3EWeT34TEzlEUlu?X++guLjUQnz@Wqun#f}<L6lMHO6P{UM?*QqLdh4VMgWui/&OgO}PDs34]J66.U:Ynf6j8=(h8M$z/qxHtx*u7RR%zH@wTz}Ed^JQEk+Kic=/!8@yfwtH4{]2UA(kc@bWZXgNLxB3Tx:S1ZxB2ZmkcDPxD5LbWX:Baodr*+s#j-jP1AU2Gy%!=Z7{])VkC4*[a<htTz{:{&6@(8D$O*Y[o-^*8n{L<tj!]GRvI)e8IL#La:+uR2q8m.<mU=-:k(-gdA.-UK8H2}qeE)y7h+es9Y:kowgm[aj$rCv{^a92FP^cxO>sVtgix$2G8>mgSxE1k@lJiGMd0t<P<n.WkCs!EszHtMB5D/vx=<&mTq<g48ho%^#}v3W50Ti2G8>mgE-X=3(F2z.L^PA
-}4k{1!nL<Gy5:9zvn2q(Qz!I6JbZaKrd?yLS32ngXRuASs8/{gnJQCiF@+WwJWxY*[rIPiQnsM0xI0KW2G8>mgSBC7t}%v?GMd#V<-Mh?:)f<G^TEdcvn2q(QDT6CJm0ef(CDXLqv>Cwt%?ospJOpbnJQAsFV>g<NI576{@Y6f^gUK#W4$x$GjdBb8rjY>p-npI.z&pA[?U(h(gC@#^TE7avn2q(<8sz@gSC&o[I*m1=KMV}<.FK3}^guL]t9kqeOO<8@Rt@D{MJa<@^Q3#}S=teEKYDMp4lLCPuW@a>{h[#s.Jdu}k.-qEINr3x9WV]!Z5&J?y{Gk&Tvi9<A4/b]>a&k}p6m}@tv]$qUq*T.^Bq%[6PuJc&8K[17<vag[TRUZ?:^ZficSf?ydaYah8cN<Fm#!)OemT5[dk8Y^UTh3EUovh:{z(vov[Lt{(i7B8I65X}B=Y]-&jk8(bb!z?ahT.)3UCi.?-T>ybJE(KY@?!q<&DOGJuW}TK6E8huae9P:8XEc%Gb]c?EjPzD%JjsS)eS^V!:apMfE-eX/+nJ#>[FQ4xbNOS(Mu(/]Qfi7jHAs^>k[O-]2^N=T%.?4R@.zE[Y&LtwjKIjY)AqnYc.@yI5Cg5@C{WV9ZM[V^.ulkEs2Ka[i+FNz17-6r+PoA4vXGqa3@rQ(1(ivxiZAv%7}HOd^8?#su)^l*lEd3>kB8O]LPzDpq*=F5lS>Hcl][D]BRctjppv+@Z0{gnPfWlI43yC3yEw}Jw}05sa&:$bf>{h[#z$]Smb24Qc%aZ0:cp}NA@U]VXHg8*N?-f<CPzIB1@dV[xdF(]R@d-}Y1aQkA^67fSz^jW+*hjxEh$B=}k>#ts2Hybg]%G1XBmn$-/&K^PkxdFm(DGNWZwq:pUIS1Lz^gVDMhGL4B2Q!.vZ[T?:xdmjE^E4D}rz*1Yx+8ZuWT3*M3@t/Gj/RzanTYX}F5WS}+*!Kn8uF7UB!CxR([Uz73Zqp>?97fEcFlc<zQYZ
-}sS1D[!xROPzDrg:i/5W>o!<cHRS8!GQf0y@pHY#(w3N45AX}WV/QKRKyv$I^*(uEvi5Omj{0Fzq^G0Q4[AZ^}T{EGZrFn5wZudl*3WV*t&Ua5YP<b!fKAG#G>B%O[JygfWNe#5EoRugOA9(gqHlS8zqm:<t#3=#C5xdVpdr?.}1373FyxAFUcd!9/?iH.qiFJ>Vx)[X:(h[+ZU&HpWwABfPHE?>=N+2ZkLJ=z?([Qc]$QlI@xkR2})RPT@YxOD{V{RN]@Q>B}{%DL?Bo?sYqsowmwb6AcVO4QRRs=8M^@D((!g-%Di5bF:#-eP>HBWem80[uuQJCx(1-3Fo<RC:?(V1(n.?TtY[vAi]BlafB[ts]TffpM@MZ81(3C!cT)4DWjZlKBMX}$Xh@zvyUDbP&}!y+$75ohKO5x:h?6IHz)&=YdEOYbF{J3bStJix[j}^Ru{M-m(ZmPDS}+bZCks1kx}HGpN!EMY%kIhwq(HM6G}k-=SA33U{6.*qeX$kpq}){XzPoPt{[.FYX}0N*CR*mgTMZve871m$.&7e.4)NG>7CrVNWG.1L3j^0*fz#K?K?lNS.EFq>>W%Bk-/(]T#wMFm2n+Q#{DuMv-PD%B5=6Jr%^RlH:d^sV/<V./SW(9IzESqX6=lMMPYpT!8{t9^/<dU{<hR@P9-R!E/F99y*(BUU]BmA1}HY3@+uYhK^eaCH.]pkD2c+U5ux)=E#>^$hT[5[%-BlqDs}n=[=K)&n)E1gqr?*b0vkjlU%^H^oY{eFy(*nW:geYF^Wkae5wnbgwD?mH>hzg4Xz@uw?B+v#vit(A(EQPIdd@9>JKD9z[OzF#PJId*?w}.Y2Z)Pi6xYskin]j:Y?-M>1e?zT]SW6>yuGzQP}Zi/Nokmu*Mg(6t+?KgQubwX@kkKn=[EQq3F]yb@SJ+]3UulU@<EciABO}w3RXO)1*DR9]Y:HsjlQd#J8]bvh4=G?A)mrNDLZfQh%xM(st)]%[Etcv>%CHjWB
-SQmrV:g*WfSi8x2fqh-T*RcFT>0Jw!(&2%4ZMO3p:h>:!]=vT(aAuqGe6CwG=.=2XPUzIC?Bu2[GFkRVZkI/UmeD{@>?D0k@@FYSI=j>QA6Ho1B&FT[3/2$0?d$.((^[.-XbI?qF4aPBh@BOyXk}kkEY]MJCJVJJZ4H*Y*v/y%{P5!4gs@.PXAl>9*>byM8n(yqJa4fT-WE=)OIwu(=ktXBxn?Ds!FVp5(wPe2B&C&du.5{}UG!0Es7^Fz}QDF3@oxkHES-TUS9=8I+=/qe>2VhOG8j1n.#e!UvsAvsd5ZHzg0D&D80j[jJdQ]R8n(yqJa4fT-WE=)OIwu(=ktXBxn?Ds!FVp5(wPe2B&C&du.5{}UG!0Es7^Fz}QDF3@oxkHES-T&Pd[MyFbopP@10ll9R75xch=tM[ELz}i$?>j5pS]X68gkZfy&Aa@NJNMQ5j7k)%K=(F/{TGkF:n@kwkyfF>#n[&7HT7.kEk@[p+9U<uD$^Ea):h-0.@d}$#a(0Rp2$51v#k&SWM7OFi.+kjbd<0Rp2$3i9*/&SWM7OFi.+kjbd<0Rp2vs:}(5YNY*+LI#+&R>1IPu{r$PH.?HhYNY*+LI#+&R>1IPu{r@ZH(iVLYNY*+LI#+&R>1IPu{nAV(NJc%7CIF8qOmLQW3fFM++]>CvONmyfjm0hQ<8}ix^Z>cM}$sF[Dd>4pkP&wPlsbBFCDL.HWR@1[Dd>4pkP&wPlsbBFCDN}./]EHCMEzIc=DEXpDH1iZ<K&v.GPvGCMEzIc=DEXpDH1iZ<K(k.GPvGC.5SVBTLco/Xk})CMDNEuczI96FCMuWF/knW%d{W*N!U:Y2t4@OpQKS))QvYL?v#<@OZ]GP)4WqZ52)bQKn6Yl^D>]l>x7x}+2@DWZCA:cb7b(8Z[}N{%4L:Ec{^[Z:^BVB=H)>HmUyz[?jA:hq=ay^xc=Phrqm](^)oKWhnI[WSu8fDi<vdKOU$vzs-P*uX=II)E{i6^Y[Oz>L>UH
-F8/+Bz=6*kCDjHr9[]r73$-<(J{qS&9k.qX{zhM)]pJihu+br8lhemEIEe@2w02q%{%@NxT/<0Bh&Y>*MAa}HjF2{U*UbzMkFd3woiW:WD2lG6W?9)0EdzaNM(H!Ps5W@Tl71&@TNc.W[eataGMTYD(<lQyfYf(=9qR^?wke?Fsy8H9k)D1aRuZLz=b*??M]up4fng#r2>Ll]DPc(JOTdQE@MNR)x48c%8?-TIN^qi?F?V9Ukmu>=@x[w<56O!{A@/Y:}9IiAWHqRy=$=.$GUv]eJYBK=dHtTHc3+zuO%zQ7}*f4F9?&FA1Lm5Wu5Q?9^SEgY/KXIkycO0+]c$Vh<W00)8ti:eaOruv52cH[2vWQi]O=1=CK<!^oSS=ScUQk%48Nz2rJS0h!:?n!J>]>t-=>#e^RBDQKmBNgCtDO$CwN]N/qia$9R#EvV^s=hF?T}u*3YtV6[3gRHhJ^+CNBz$kcH>FyN]9UF6T>/gzAjFUopGYBQqXz(og5vV?eOuZciu^zj:b>xk6G2:cY7Y]wCrB+Y&l^v-Rj-mh*<JmDY-.uL05rSqxy5Z29ek9jLD^isbkVQXRiD?u!weuQrMw]NqcA2>IN6TKv6@]4iMDFQUm(E.yHd8-jFHG.#=m8Q2QwfRuzl*p*3^}8lk<<?y%Y)AmPv7P)pD)^ArCF0YQ2})=8Fq@d:BN7[i(@kfV3Us[tHKaG7y}U7GXZELN[pltIAaK5.dwbS2x+Y^TilU36^5mPzO%axT@V0GXrhWnl@^7b[r?A+B]{SYCzP9BmoqS1n6zuo4RDWV%+@vwk?2L#cZCc==O=G(Bk*5p+f2(MEqchy8rh/x-bVOc{m@wX=rR]Z<!wF1Y]il(t]z2Y(*LUBpD*PK00rFpfF5Arl)e$iA:RG9-4^-UkI/fH*i1(}<xAVY(06{9iKCZ*W6QBFUfETzL$qn!(lAgy5u@jxT4V?y%fs9{Nze^E*%[{$z1E4YffaB-X*}9l.TxEnlbcn9(-
-lWgg![M2xAOYT(Zx4Z3+3hP@)JO/t[gm-i61io?)C&mlgBGYI#77ItImaU}1w/Ogy3n!ILvy/22Rz8p39^>$Usj[h%9p29AZ+G#YA]p5zRW>{o9YR.H&^>QMbPX}@*QVG}bPX}@sk[^v@uJBz/5b-(0eUT+Qk0>41xe}n2zaKD0(kTkFNx0OsZcAG?]9GL&]h[p/(G8(qqyLQ&=pxYz@L/D@pcn^*-$Oh-heVlE?Of]VGDb+.I^Lu5wK7Bj^9Ko3^XG$zSl*9}p&&wACVv9-X06xtO$wg+]0i7hGrdl3PqHNQs^zrf!y$of]xAN7SJW!gSy&2o<wKMG3tdcc]M6N3>1$6Jh1$yG!UdhJ4{i06bOV)u]b!JICms).?cbIQBn3uvgiJ4v#Nem-dY]%/O?LxvIoM$UWxobd2H-77@H%I7]5GqABxm-hD73d6bPip3C87L(t<HS6pk(c(O&?Fc+B*vM+G=>h/X7bYm7AWF^b^3-u)i2wy>+V^O1=Zcn4C)/kGVieQ!uHZLLR9?O-#!<Bm&fuxS3VI.gE{QC)f&j8nm.AP]84A$Yw47z:l-=iW@U?[<i--n%e=Hz:ejmQeWhHA&D=V4(nZ!jvU@ozuUZ=d39e}NZQOv!THLwo0Z+7%6oT&>YnqOzYv[1!StRt{+45<G1]>F?]w6+)+10nai&S&eWKR[n7jsgBu4ccq?MY+c>1r>%q)RzzklLipnQabXf!WcpuvGarDH7+cCziX7$N4C:K3ZJT3b]hu&&$-:P!nOR.)a&rV/U97lp=++Te54vOZicoOQDBtksq??kSqq20+f<%3EoRke4Dw5frsRqN<j+T.eduyQDd3+0!RKie([o)xp<Km<DB/[DtlGzE8hps)5l2s[c1Ad(^}KntXIZxh@?&mcVuGdj/3.4+{!sULLsOp(l:)mG#&mp<YHv[oTO6LpFwy{r$}&U^LH*!MfQtCv^L&=pF.Xfuy+OU?O{]Fx>&H@1SRt{QK.^m@RK6X$5R
-nyo/:FFpl[9.sWK&^?/SO:kI+[JWBsb5[.]kz=1#!1sS5mAAwb!6^JxArm-ii!n7yZa##Ow/Dpp10[qQ7:OSpI[{2pV(1JUlv3ECpB3pO7>E5AV>$mlFFG2=KRA[[FFM#E:LYswz3sD?POiLWM@wtkfVB+XPc$o?/78xRtz1dEFE>Luc%jtS@SsB?0XFjoap+=.WI6}]W0ieJNN}fGNFFYO@N0>rTV3Yg*Yxgbv%&b{PLqifdPaK}R(8Sv8al>Az(MiR78x.34C#{X@5U}{gB9smN1YE$h2J*/qq/zg!hq@ehE}>sYyWa[+4}Kd=]F4MNKomQ/fCk${/!w4S/uEm5^ryf@AI+JTk$movmo{1ywOp<J?J[xqD>:8u<!jFout+&40c*0l&^?wb)}1Ycq*CPv<<d*:i/}g<p^iE<TS:cdKcCHCnlXM.-XqkI3m7:Le[JC2h1rbJ{V?-YvKei()B7/-XmNBOKt:LpEA$S*wQhGI3EiDGm6ebCjK]%65K2Vf]qJ*v/WF/JpApNpS(rp-=$hpR@.2mHf:mvxVCVtfvPPeXDVo9ABP9UEo1X$0BzLftctWS7o3Z-?j3uyo(hgiij%@)4ZZE1}cc{9negZ2H5%hpsR^WDR@+Ms^geX!KNT%C4d:OZYo]=[wutOrEs01c?&NSjYrq!ln7o:hWd>Hj?2YhcJxvYT{Z=VV@R.AqUA&$dG=c<Fn[pG/4a/ln&Da8I>)pYtPxD6qbWX.L/UO5!%0q%FFy0.0iO%[#8+yNYu%B87vbgep]S%o}e=shoU?:^C./>cIJlN3H<R?@zm^ss!<9eNOJ4Ja-4e]yaAwdCHbX^6XfJ{0DuGr9RPO}XFJj/*<.5dU*PAX[<aQ/%W2Gz2c/Oqf6lloU6HRKtr/DkU.J8tP?Weo[-85h9h.z??(7#]0bw?.jF2?imvlKuc64$<Ewf+bB!3xSpBE[0/IwbDHY8JmKQfTOF9Oi&9gI7*zD9+cgvZZOkYF<o^+S7E[g
-ujCgj-I=ly!GG#dJZvaTw7i=p24Mdd3[XT^c.s!%b8>lq-#5}gN86SWaw^XBJWk8=kmU$g-w&(ipjLEDSeFC1-YaMY8JmKOfTOFROuv-DPd?fI9+gZ[ZZOj@GG{0^xIomD?uqbm-B}NPcQO}+g^+TPw1nco4Ar6R7*qwrOAbMEdQ!?SD5M.E0Y7XNUl-S=J248u<:R{OuORKH7?VbQw1nLp1JAzaFF@]tJS&$=K:9LrDb$gieZu(=SNgtgO7Ak[d(=vpOqd5@aDKph)u}HHX8rmX.sr<aPZ/Gl!p!vbir-D5X&Ega0fd&gjDh3DFtio4Ll9NAZ/+m^Jvz8-^#yue1-Mp6w(DNN0fliSoBxcW4KX!}!t6!^ps(nH7qOrmM<<tI0YR82gBUO:f-?){<5=U&&pKq7!=E?@0RpMMV&DbjZ!t9Z^6A6/q&EdVsI:l<!yBWXJS[N)GLpDb-@X4Ck&U@H0#KHc3ASfcrCDpI95Sp)bW<<Rm[)wr1.=zsBjz@]qExWad]GNV&hv1b.Wj=[ii03odl%TTF%3M5pZTqZ]R6^=0YRUhVPIV107PE=ciX6@sTPB.mMy*S&b*$]yxkAg0}OW]V&DaNFTEjBAvp(u45jjmr=CRUWy30{A>{E%fFd*I959MTg%c#*.y#2#TL!g=z7Qpk3[$r^RuH}60CkkWmI#tj7@*fA0XlmzNqr6yZ.3N}F?N[/0YQl?ciX5=2^=pA@oO(x0uvNPEFjV*&mGQBYwD.v-+9(q4c70q0XlnecagbXJVS/E6>7nC-$b%V3EFLG}QFWtJK{b}7<vc1.VzBTleZOP6Ql@J0t#><vy([&LGmDP0t#)LUyNGyQrB7/&6mUVY.z+mpDwH]sROIRFFUh)EJ?UUF^2t1m<7ibIgM9^kX2!Wi$IT2]G({XV(xE{v12P%}R0.N/Zq2Q/h1@T)Vg+sfh.:Fn5{7D(s}C@(G2dx?6Ydp{lA2#58.WpCanNsaI2(n{61)nOT85G(hzr^
-MnnU?18c0jSx7Fh.$5P1:!anF(u(}<C7Hj<+rY*B8BlopcNr-tYlNi0jrt0M}=.kzMbISrdc2r7jkT)v!L.NNec21&V7lVwk?&b*isk=<pxkEn-20.KMH](rcF9z>Dk7xoLIyCB@v3-}OCr/+L=!m[wVg4bC358]mya^oT@C7jZw2?Q]<pF-Y?!jk{Ux>1F*hPf(-T7RT0=yIo5GHG:m?^5NebRqmG$f@E>D#p&kh!f{D0lE^7MSyXVCJK1nxk+)N@p5Ziqfz7jS/QYNxr+Lvd:Bn9Xn#?*oL4{S*IF!fUEZ2m&:VH<X:!nB!8i{EGWw1OMbBMBFQEuwXNsL):&IO>[oU6lVs+yFe(p{^L?Jb<}e<e]/=@4#HhCDRi6L?+h$y}q8+U=g^vgz[-z@EF>CVN]+S/Zwd^x{STiSUJHg74*]J6)]B}J<c#X0{#Te0B3a]269M5RG5lYt=!O-.tg@)42Zn&oci$TNKky7GDW+Ypb?.UOzH#?])75Pd*+3d&ci$S5>wC[vpF6BDF-?tBh:MTM}p=46ACVu(-X06x5e-qyl12UC/Au:Wy.D@>d2H.g7@Fl$v2Q&j1C5pz69Mr6ICjT0..%!*Jh1@cFIy%O1Jz<Eo+neVhBg-fQsYQ1f(dHa-WDZs5e-qyl12UC/Au:Wy.D@>d2H.g7@Fl$v2Q&j1C5pz69Mr6ICjT0..%!*Jh1@cFIy%OGPqkf2%{<9{:cn{w.g*KAiFT8o+vfxUhowQX9S@=5phE3?VPTJ)d%A/Y2?bj1FHRq}B}IdDnnh(y>y2p@3zX#6nhKZ3bMX:%0w1mb&dUeZu[d96nlu.Xj)KuQ?1v{A1?>:*xtuu]x79}YyY1P1r?[25AX}Wq#=SvI2wk8meub=9/s5GT*no*mNj$3S+.PMzf99MI%9V]a76DFG<v8+8.j2%(5j+F9OQ]I*-?5G3+63Z7T.LLXZr.47szE4A*riEbkMq}{7#bSJg%MBz[ho&[-amsS6rhRK3zB%
-[PNR<@.Ts5<jx$2h4]f<R5$h=<hSn7@Rklg@%bSTUz?UqhqNNpNd]%lUyVN&T&ghi/wVhHqHE6xT4^$yTF6pwE73#([)9NfM*VnvZKglZ}@Je}*+mL-&^QYfpxbVl*+k<O4!!9uMh6qu{C)Y%P]O2Usp/rVB(baP)![B4V?G/@?*H3[@Q92#tb#N!{O1fH<AzY6Ed>e.LvRD9AfKIs5T8H8abI+Nh+Gni)XrS>]#oLd?*IO[kq.CJ<a5>I)0XR3wBE#/]<Z+jEh-YCB3F7^:GvE:wsqt667T-AzF!<e:Nqqg[15#ZgLbz]0q-EtCDA!c[23zMknAiuo}>:uo]6zd]N/[+S/%2zYWDu(KJu)ZGlNndY-f7(ZF=BZd@P(X]s4JiZyPtYSKK[onwyR{/<&AE6jI1]hO.rmex*AELLTK]n3vyoX+GwO?dHV^j73j-pBvWv{?GQ>&?:qVEI7p]Q{Z!jtp&{F@0mpO^*)Is9g[Kt?*HtHE!MtU]1cK&]ymQC+G#pj<z@b/A}OfK4=28g{x-}&Ho6!o6+Zt#PodbLY+n#gcvMZ2D2qnI.D[KZ[bt)^UAu]7Y+ZfjfeT&2tz-0%R&U&*=0*{HQ<+DoiLlY}/4jb%xhBwi]xbkBi@.I!{rt*SE7Om#^t[g[fvoxbX+Z+Zlo!?MWL6#?}?l$9tp&{E{eKW>c&L3!SJwO+}rMamZXX$>XbiN0bl6)hUxKNh}G=DY%2f.%C7K{m{IF4b/kE2}b*$Jal/hio@A5H$Zjr^F5:]LCkL8HpFCTkk{yAzbyVmMB9LPog!bv*qWq(9HNe-F[[bwfZ<1@UrKjM{KIHq2YPojHMs6JYY64}g%P84.#p5F8d&i&e-bCe%)jW4?OosOfdkbvT#}Yfby0c%KtETG6d3hGLIgHU$5zBjxm/+q#0ao8MJGz>O1[F?3p<9ew!A<Yaz5zoUbKrn$UGtA1:.+*ld<$/Fv]2E2Eu&J]j%6n0vE3I.?)8I3[WD)nvv3z%@
-EC@/nPx$oE:D6YF.c/Hwvw{)o00?Kb.z&9Q@x[UjZLqWY<S1Z5&#r:b&sx#-dIb6{}1sUNEUY(x:3n7&u{zwlSdgI7GhF3<4U[Aa3I^=OKrqliGtA1:.+!9th3Y)qvh]P*EHU&!TSY]Trr[fP{>CHwPJR*{znFmJE/i=$agu}4{+:62{tW9D}NIScfIS:H<kFPOK]fPx:D7?LGtA1:.+*wsEfVI<vkJHwZy*n{/n}8?O}B>dE8w^}wd]jdwwS0!ZY2XyMT8$c@kGiJ}%+au<!)kPpa81H&mE#V}j2#$ahtvs.g3^Hp^sKdG7$/fV/2iUePFM5/#Oyo)=PVNYbehbR>W4q4DuT3:+K%<Eckdn)o!0fBroAF=vv22UHH>G&gy4+O)!b$J8Mns:Wb+:@l{jxn%3:%%8.UuK}n<Si^!&1^gvdOOohT>Ei5seZ?:ZXUFSNxN2I./1wTY^-Xpc/-!^E!GDLW-ENvIm7]jvMzWsM26jI7..Cs}:WsJsRLDC}4UFSN9N8Yu](*NWI<$Ne%0W>+*zwZ]PqnU$b57o7*/9k&DaBLnVzd7{=H.n1ter>0r*)8tFANHb8]Mx@VFMTo&uX.#eM@K=d1GGx5vI/&g[FrMUaXk%}uM2kX3dvC:r%E!]5[g$[.#Bi-2lA&@cW!*MZ7d)VfB[Gr/wP^h<@>3=0:sdYwT:nhRX]p)(9rRKi@BRXXQPZP4/WUV)W>4nA1I%nHew<aE@-#lGy<rYwR6VPaBLnVsZ.jTaX5aQ@o/sfjntNg(e9!n9E.Zgn%3:%ZCER<aX5aY@o/sfgCBouC>#5AuX!Uy?S}{E}A1Mi[OXv}^QJAeHKEn#Z6hKJpwOf^uX!UqfU-$DB[!f6]lT5&5+)lLft)L[7*/n!=M)]V@<PYvlEKGAE*:ENbT1SPtJ/c}=]px+HY=<%son39TdN9V+1YvS3&UIDYplNIT3?B5D/i9ghsY$3E?35Q:Hy6G[W><p@E56pnXPSA}j3kQZj1Em
-kGrV#K3[Cv{33@ijfehk=dxZ+@qo?vZK7g%Me-7)=^hDfpXq4l{0%0pj/CND*JSL>g3NuQ!7nyi4e6UX>?4K+ug)D.pqc+@k1n?:UDjRj@OS3eGlw[s>&l02VttymM<7a8]]&gECK%FAy&GSVb%3tdXrU5qer^P8}^CujY{J6FDZJ%5T>v6wWVp#%}Ts-.(@oi^.KtGoiDGGE:<<F]MhJz$k16B7l?XAVyJsX1puwA7l*ZgXKV#{8-(BSE7:0$xmH+$:[nTH+[nW[A?Q:tSXC+zqQw^uPuY15HA{AbY}]aRqEbjKzu?VhyBT#3*5/@rpsBertbDKGO2Bj%DKw#RKMpNR}?FY0)FbGsq[.lomznnlV{Q$==e[vMCPxfS]40=jQT}HF>CAYK$YT#ag<uj^RbKW73a9/I@}]BaS*^*kwXZ4]4{MqJBNTKn}cbaLJ0f8#uwZu]uzh@=jK*>87ilc/S)>@<7E^w$u>Z$d{K39r^HE*iQ=u)RWT.m8*(!a^0*>Tfsz$Y(y+JEblBN%=ZY(xk4l+:ZOr=tR@}{lZDT)IbC?Ev(1VZ@C8)m$?LI!@5rpx(rqb:2p-y&&PDMIF(Hl{yH{T)Ex<EcsJ04W@tm4]v+MP*d*f/lPDA>HkLT1ljg@xwk@kEcPgK]B99gLx:d&No%j5yzKScYQ#<svq=SX]p:G@].)&/4F%nShc>5ewktF5p2IOZky70&ORbxp]OC9)ruI67@4hQ9[Tn.GP9IE}}Oxr3QId&+()pdZ+HGa{oRM=j^)6ddJ)A{gHYiVekj)}H}oQiNNC*tXC04cN5u.t5L*Fr8[WTy^8QB2]WUg!]kj)wr/{ODnhV7>Mba&-9(J*.rrSvwGQl]S/B3zptJ+J<YX0oHdg^wE]W@&s=FOi)m&$z9WOa2a%jDzL&lW]djr{8{3vitts:y^:j3LJicz{F@izc}]T{vk<(zm9TE+X{bIuHR)(N+yxfyUG{3({FnP(#{cIM?kB&)<ub45bCMV
-E&[S2O=n/wEv&jrPy3a4e%djO5=hh9(C5f*fV?xng4&>u>(nUJhY})CKJfMSpF=&n)):[6tcrr(VN{OPKQ}zit=V9M*E4MXdZ>}t>LxW1ZlU?m}Fg@sJi5!5Da]AJ5b!N$D}TcGOJk-:DuNsbK-mY<tPjuUK6a?%:BAwX/DfQxvE8O:<5:aAA}KBw=gr)h/ActSvsc6KHok93Jj)s36<}FEx<e&&Hw3.^&:yyW[@d2y/+[ow.yg5Ao>w4f!/9mfE^Xztvsc6KHok93I#%uX9m!{Dmc&gI=(R=Pit/hWjy^2&Ce=I2fB(69V<KgyzvL$RF3sMlD=O0$T.hp9n}Z4NyWrL1DCIFCHY0++4bjdvf(f0mv]KXiS-Rt@ka<bb<0.#U4bjdvf(f0mw7hXheK7dqaZr<mHw3Y[+e7qB<qC>^VOPQvGRvIF.}eF=r[CXt[]U]X*E4L!y=%<&!f+URzVy(JT&BZmD=T/YfL?nB-<2d5Jm0qjW7E&nLEw+Y4ECr*L7Ng&:tnNMxj)f42$8)iGu@90pP{DP<EndDWUD$P/M}.X{-0UGFBAuoax/O}Hw3Y[.[&Mu<qC>^V:gg#/0CaoXdwK=<HWP$]gLX.u2HzIXvqe6OC<G6GuN$B@}A+uK7pB&tsTcyF>#kp{%oGgrpXHV}QuTo?mJ5=E(.2b=!w8#krV?g@wRUg7!bhf^@S8(KIpeYra8p>Nc7.s6{{u@{[Us07S[U{ToFjABgM*ZZv5VvfDgU4}k*4=VTFCYX^q*H4c6tr^@Uw}aBS6)EpeTiNc7-T56LX1Ea4W1d{%j4:wpdDt0-1@{V3^bN>S91Eh<}Rc?%G#Gi#!QS/-{B!766xB)(.*s!dm>xi+[j!F0tUWgF%r7)Sfr=/[4/}NVAoZ]Qj%>cIh{+/QkQ(1V2IfPr]<pTwpyG7KiOM4i1*fWjlo378CuEa1qrlPo@a:wpdD1a{e/)$Q@naI]$c]<LTDF4$/P4$+zGf.MUOj/w2X^:#eO
-0l?:4x*g?+Fxv!t]gp27ag+[EmsGLT)?IU4D/26}R3UQ#/82Dh@R+(x?uMk^.$WjQkrU]]MgPj/DJzPy}IF#J(+d?<@^KDgyUulD*K#]7+UMNHa0(u>}(/^X@7JHuV&gY%6zzgPY7PGt}.Bcgo0&jDR#Fpi(!W9]>gto2(!l*>>gwbd@m<I6ZXTzkAKGGn{lUkqkjwF0u/Q{]/[V-A)r!uTUxb9F({B>O[jLfln9!<m93oGd{>gZl[+8nh}.(dr/0y>5X(2h?1m@kXFg[Hal{t.:z)aed?T0R#zgenW*G%AKpXNp+DJ&%j)6+i}}FbuH?m^=>{*iHD5GxvAZvY6E*srydi@e8PW?i=cZoQI[(!Fa^9y[>-OeZB+84o)y652YV^/Q>0Ym7<)24C>>jkJiU!$W1AUw26%GUEpu:Hy#tyNsiQV@Q-K=pUD(8qR}ug((HQ}-q$3rM9<r=hed!{TH{VxRd>WyK5Op*0IXB*i%jVe%D5G{Z[]o6>In5kWDeBY2Gd$5u.Bvv/UTU{7x@S0AYsOTtno?+iNN4s6]Mf{lxNCUN{YOh!@bhkkgupZwvul?c<Pno]l){!s#/E66/w)<]8^.LicaM}pwmRYaA]S(Vm5e?fTL=yt#$.Fo$Gd{VhQoUt-gxE{T>F:5^iR+Kl#?Y<rk+(A-5}tEKy[VN&p}r8mYeZ31o]{mx4E)(*dlP7^PT}[qe2Y7=a%VI?G1/)^@a/4D8.n9@E=@}<w4O[F7Kuvg28%9^+#D.giga4U}VD.gigj7E-8{ug6?CaO16?fU:({UjzGO]FR(i].0{?T=XmyT%w#de6lB3%Q6-7!7.7jy^3jB&C?!Z2{oJ!#QU%qs3D(7}a4Ef]xDeDb^6C(X[WPD^sH5TYYp}Qs:*:f(k8}v?=]s]nE9[!!$kh{mt[9tEhP(mx#OHw7Wk)-WD.T>nzg*Ttrsy(]&ViWQ#6+IEM41-Xp:.}%O:bZ2}Fn?*skGj[5cpfrPVFCm}W+YX!7)
-)(!B/Z316#ZDp<D=&i*8W!EC*=<NhOYTxQXEu)oF=UHnZj!BsX?G@O{]4@$z-Qt$8V?H6G5NQ31vsn1>Jt->ypGS)g@J^b:@BJ$B@c78(%iRFN}pzIuWg?*wRo:TC<mw]cEG1-q}UYs:1eb4+KHOU8!5<b-fw/5tZR6AGhlC=?*FyYa2R]nPguJ:b?9mERm[Xmf{$Alz(M>]ch[%*s<N6B>qr064O9nM.Ex#F*z)8un{s(RU?U^7G&5YH^%10x&cH>gs{eP#[Ax!>wf*y8@kk}e!@BXaPtr9oPtix$+*^#=kb&(UfKHH?L4d=9}je6s0AQxou=^[XoON)8qW=WKUw=q1P}dMe(?m:T1MIkrO[@yYAXhS01p/-]ZO2CcG:M=9qEjgfJ9TCaH4VG<tp+4SnY.ixL)$6ilZiovKjO)8@[PY!Sr6Gb{sd4%+e9Is-]qpMmsg(ynIq/fMnJZr/U/bMpHm%R#[/v]%d6v0*-n.O4n]?N@-wP0Xhe{jBW=eh+usv5T4ihMVAsI4HO=B-t64]&7&<$xyy563pTUy3zZZzIZ6MWWQ=<9ac-vBRFkf2fQ*LDMZd)eTZ[G+Gfo-pxp@+e@@!%?A&zGc9lf$]B?*acLA{u7Mp2p@3]nZwIyzH2ebIDv3(GLyiR]1CjJFOT=.V*=kfcH7yqJ6Q:#I!tAJC^IL^.7])(7JSBI6Z<0--W-QQ?&+!9zg0B*c[-y8>op*Ke.uu[^?:75WVv9G7&5{@WBK*]b3PhSb[/Dx+p}01n!!Fkt+VE%wFh%jjCCSeEh}T0pcH$Nte0slT4{9PA9813fX^RuMSZ$MMyyP#4x%=DiS7e[zZaLQGDoG.fi!bcBRsLb]{$$p<GlrY/.cmrPsK8EI*AYuSjRvO*(k-Ae0k>pf:%c4Ykq^:f:%c4tw!ODvI*f7{ShTX6#L116H4ogPoxi>2d[jTUFx[#u0MLO652Mb=?n0YHWKc#:wmXef.@er0l+xF[&[z}a0XJlWyb@k
-XA$VN/8FO3JCes#{09iI=NX#X(WjC5COx+l&VClPWS.-C<X5>07R[O&(WHTH(*RP}@QYGyfIT$aZcPDox:?:Bj08rdxBIph*&Cp9:j62[EW28EGZCsWgS/Um}tug*bkBC@7+-9@Qp<b)P6..>Yf#Uf?*$qp!.tX6-<E@i7=JOfPKsr<doXJt7-EhVYmWvE&T)3B{6+t/gkbfBTrlh-/1@p]s&+9i@sC497}a5Sw0wi%@JO0ykW9hX7ZAtFv&QCL1JEVe0#Q5{.z?X:@MrQymz(57D5Roa-3l%<)hrXR6:-[NsJt8sOL1Mj8s!T82/E@wHv2>]7/Z(f+D5F1v@1^FQDmD<-.?KZu<$^CFh7^KHl*Cy[5<kd8B!O+v#JE8m{(+WgK}iub[8v&4N8)S8/cuskPTB+mlySY/Q-b5qi9ArYGQ.5W=Vl%8BtGPOp(a&-&}Pd7]5/]xN7o^p&d![vvN:KJl@772-Jhs1#5I/.Qd<L&tYi.(v%pt<t1T(H^$?Y*Csvms}DBO@%.egtygo9xf^OGOdh8Mw7WvNJbZEUOp/EB]mp)5vvm7!f!LZ@0aU{E[C[J}K$=>B?#enA{Y>m9fxs>cmEXzP-@yVA@WcTs7SjqY)SisN]4=ni]u3O/2qau33XJYnwSTT6@Vt*2vuwjH}-<2FYd?5y@$04}7@<b4inWr18(fWpzWBUVGy<PGtlsA]1B>!(-4h-{3Ys#xBC?n&o$/]$AB$rD=?>n*jtT!}E>CgC45k&4E@I#AKImQa1U-ZW^!w390Nf19@p8uIVTsSfdGj$GJF3<X{056vg!uJ!Y/v^ocIVR<.&FEF]^G/(!aFs+ht8@W/=S&y>E&P78ZLjs8]IX^7@]LL2D9Q(^+0121lPbt}ABxvFo=J]kFwgF77u@+-Ez<rw@%:7@-ALH9I&e}EK)glpvMpCI*b/JS7MO4Our^aUC[Yi+P?=jECO-vLJbiTa8=dEHeB8)kr8}6nmbq56{%:Y(hea{1T.oL
-M{nqm&V[@)p(tqh]R1OEN!?]SekIU}UDFj9JW3odD{$$B7!cJy%kn1faBM#.P$&^LfWi%Oc[)VwQ.AW#HAYHgfV)k&2Db:w*S]rYMJMx:GLx=w+k)*!nx/aY4SJbo5M5xgRVQX6G{WADZ:}{w(IFsjwV5v)-rHAIsZvnelqibyy)Gsmv>oDqkYD)lzK7Nmj7nW3u#z&+jRha@wEREF?)/nlh%t/{GL[f@ZDY>nxsBlf^*mQHFoshr5:kil0P5QCc/M:LMC<u3eCn-FAaxzAYUV}lxkvaBnDhW%@G>sdjOv-s9dWp(Ccm>f-nxI{@@un-(zA>7%3&-@5MC7)[}Q6:+/w4T9r&8nnuaP!@M=/N*wHr)/8j&zSSh.MNNQeCU7:i}zs9^dOT+43?&TG{5+cl#q2Z+(J>%8NX[kmn0=K:/CC]rJP%W<zA:X=wHv5rffY3j}+53$$2TWp#2^M@.aYi6dOa^6g?6>UxEL0Llo5:W#2bf>k&#I}B!kzDBD5{zBgbPu(ky{mo33fnY&YWy!R4Nd8Wog(eymi>EaE8*M<sV+[9<i%*B$gTl=v0Z35b@Q+!e3kO9:37)PlzBm&JdTJjg4^7m9$0G2&G@.he7q94TD2LV[hq#4/r1=P}x80+i9qP0P/?Qs}Y<tIzAE=M2(t[b{8kC2yNuu[WKv%7}R*w}mT%y<KVNcE@0]zH({43H@NIO3Hx!^-ZBIt:R8PYg!#eMvJ[N-Y>x4.f+IURivr-xnSOY/Gyh}KLSbJhV..7baPbK8f+w&3c<Dy-)YTOwL/D]h9222WJHNet2U!VZ(:cl-o0*m*<^lA9TcHS-bq!jW&}<^}KnB4><UR?meA?kSUF$2!Br2fz(]Ix9zLu]ww5}b/)Am7X%8FA]9j+BiH2GjS>7R6d]bU32>/^Vq<(FOro^i%m)gPdl?YW0%[M&7rLY%P8}Zv<2{-wI5[3[Mb}&i+k+<a1m:?D^/d6[k5rpsU6MYi=3/oLS(lg}*.o-o8f
-oMQIeh)KE%KJqQv</mkj.Hp:bz!]@WD+&gEVu?.o8q3sH5aRqMHUIKrW{nm(-K$O{hOD4N@M(UV9C-v/Nbr7&.9A$FaJS{XOpj0tAma&V]DrcKME!]0F3rnhv7XO$<9-V.@%?D@aC>G^l?20TKadz)?*@o@GgG7N53&*vO-gd-@.RPm..qt6pS73C!ot.wbm91-lFCu]Zn69PkdWv@xNEi)v?cIL)o1aIkIypOW=su?Mtpq/-r5.{H[^.Z93o[}SQE<G68vLnvnu8H?#P5W)I94*iR:/RypTh*)oCjwGQWQb.eP7pfKk)WAsN6}]z7R3CH*Or=Qv=SGGUl$xg?xyhY@6oIpN*nG/>p-O}<%eb-ib8KnNOQHB@/+OLKsN{h39-Vkk<o:*3m7sK5bKYk?H(e[{(iFJ1Lv>sA9UtoiiCeNK9R[D^NLneQJ(D5<frr)oO+62>CAF{mf5+3Q55h&l]^rW]%xE5s.nJmy8v)/$d@0DEYLQ##hE*rWR(hvXr)==m-2pr28&gr8%3VTN@bz-)8}trs}9la0gNM}).Rl10Dv([KOT=pK6uXHO!HjMIIEXX/OqjMI%35S!!nQj19P?7PGqbt1jJrCC:HoLKBLkcAaiY]C!]Q03$8ixPP]s?xENg9]TNiyq-psRW@zwHD!0yN/+AR0Izx*OQ3@meZQ=]]maYtp]aoBuQYC)2fl<d.@+?&Xw)#<l@qCkZc}0)].LO/g>hQ*Tfqc:90:a%7.tfOJj6%^oOqvz8D=GCK-n5/iO8P?q@aHYkgL)XE4-!.SSQ7c0tCadE1B!bfi<<84PUK^)GCXu1568A/6n-r-?J9]PpyZ>zpkaB5[@ZLaF#)Xp@sKrZy[nDD%VX>SbX!My:piDqoE$(nY)JhtRi]BvA8Ip8*y{)3VcnUDWxc3x3tuRIaqLRk7Pt9vx530OT&QFdv+{s.A8pIb#:J^=3EKF*}=)YWz]}HE}87dJM+Ws)oPL?Ei.k9vuaFlxP]DVq524
-H0R82(OQZU.dx{$yzamQcq&sTuP1&<z6%9upDFk+T>bWcJol&3ftWsI(Qo{&c3l*YWPg.wou2Wivp7TLXA&ih@c=Wt=%!QZeHAM(=pZ&?Q4jW0Ui1D#xF(HHmaLHAWgA7Y+uq>DT}NpmpDzX7t8z2-yQP}!?0O&gTKRAmV(mI6e*H[6QODvYloYfxO+7iy)NrL](YN37Lz7=)MZ@[j^?MB{UuJ5Ko)q*tGx@R$).[ah5@V5R4wL<@f}j&(xU3cphgn2Yx/gs3=ST4s^Vve6(4-]E7UXIU@}4OuRxmR#Eu[?mpd!B<PDQJP!8eLZ}JzYj(a$qU}JCJ4tfNR>MSP.q8mW}wyG!#h3eiPLSgeHLRQV9e)cXL+{}}RD+WARTlSj)7mX:dDz9}/p=1KE8p2!qt)t6c*982rN1}o^CvRabljLByA*x(wMjxQz+vVelbcU5eEc*/)]^Rpa)r+6[d8192&:8=nJ&O3gBiMcvQCrhr0.[w-geYC8=(7(>N1u73MVYGUAvB3p!s<NrlctjE(aN.((Gjg[4g-j!k79.U#&b3Gaor]T>y)euE49J]k}l3mvQ$412?w>4qe1[GP5[&RInVNaJ(03-Ws+srvfPN(Hnpd(VgaW[)(FEmQ>1n+^8h>GZw2b%R3>l>O[[&g+:gcqQ]EUMkb^h6nb5MyX3ah!F2fnS]6{%6z0zOU?+oSo!C[S.Es=8&J^rldhT]!2<u}#suV5<4wLo9wqs3#V::I1kbJ!3E5Kzw(s!a<d.x)(5DWwcnHPV/-pk!jTNn#DC&C3{0PFC9kPIz06kyUaLdYHC&<Ejr+8r:<D[osCy{s/^tva!s0]R/<:UHJWBzOX#57!j)KI5kRcqN?b54th>rZCa-T1BoZ)o:?5[^[7d5BcbfhHf7NH4s]7g)P1m!}}T4%0{EK7v!qpMfhSpHN5r63%fT0aUu?ol]ux(S%UKhcweY[N%SE!F-t2VnKp1+Kx!Z-6wDzg)+qn1koSmebL?PfsY
-S.TWtLXy}mUu@tOrdycnY-Ay(q3fWc]-<JKmgz.Og1EI-7!?43k4oHsZ}+@=55/vZk4sz^mgyRERBxh2L=NqR*XTK18J0S$db298@!@3Mo6.FR]j{KIU]8wrQ^.I>wq(Y[(.IMkq!0:PtgAH}7MMJjAsz*!d@sn4z%G(VR>ADbScX)p{d5AqQ9-e0v6&OXQ%/e(j&8>1l/W}Nk9udwI^(N]JQG&NPfSc(8JBh+fV@T#mY(4^WcZUaxhWF4=]Nb/=MZv<2xLn9x&e}yI<^1pL>!b:CrT:JOlOs?]pVGyx7cfmCm*zI-^KkYS70HJeE7]iQ^ZW#^mJ]z}j}Z=+okcrdanKac*!%YG%)/QM[MrZTIf{fkloB:x9E<d=pWfXWDNNfU<YRL4j=AsOHJjyhMwP(!OoG(eBMf>WwHQA:H^5X1)Y0]WDdt/phx.nwhZ8[-sy<yFn:T3ve*Sky!KK3iPP^.7pE@sV^!WL&9n.zuprP=Apm}vYOciU.-*/C+Hv6C*KKS&Wl$l?ZjH.]:M3pYBXW{mC(s/?ZA8dx/xIdaXO/ZZRBhv:tl0Gy>X%kY%j+S7@>y8kcytqA]bRjZaK23oq{frU+Lfy+%l+RS{Yfjns5Nv!d9</++PIfMKVUkE4j?C/C]]$m%at9>@^GIY%ebbW1y(S+>aRGUbqv)17<}jL8(ZPAFMGGJz9HKtj9[]XZ{<8WN^jrE?wn1Q)eP)B&b:kIAjMqm7(d(4?%h{v*k9YT(8WLR{-xzef7/X:}^Cn4hLtAW9>6K1.C8LIP?^[Cu?(Z{!84vA&j^+*OCTuTwcWIYeoXl<g[ID>+S10kUUv7jDghzRymTlpuP{%TPmD}](B{6.W>j0chOMQO>E0cN!h?1a1IrojaUP50tSq5$D(?[cl3idlt*iz.[G]tert]O@h#d2c%2I5>j1#]t^+s03hKp{.K=DoX?V[zWM:k9ST!M?V)Jj?4yUsc/JGmz=Y%6QXnl25iJK-@DR1]dED##X?
-=a0XqH5y)v/1?W-ziE+Xz)F-lqcc/Kar24K)dhu8+B#A!9{X2:uG^bziYxk?YrXwJ[4NzMU5rF0hR$B!)P&yFBv+-.-?t6A*OKppJ-#u4mW-6=?9=vFmbAC*/U>-sUw%dH=#SVKQcn+G{(E(VkI.ouZqIQLgBot)NQ149nZC7/9h-9tPZuNj!d1[#Qu&OUzgWFgh.RVjg)j)UOVq4AKuo2]pknb65]y%?2)X&ilg^idrFCK2jd}4W6P{u/GdRKf[:ySME![M6b)lxz1P^GvX96jT?F/T@p4oNBj<ShQOMc.z&8I26!>*#U5Ag+yD-G.m(e}kf?zMVjn?g83K}?^<Nydnn>9<}2xxCqj)kx!{y+1)@xi%l*hiVZ.:A[{(a9=YC4$9rKO/q}3lBE$XAi2=t9]58h7Kjp@^Gp2.KFi4zu]-]Ijtr5G+.q}pj=l)5(hAk1EK?#]ox(B?XTA?>1a7]+QMmZ$6)bIXt?B^gf:!Rk=?yWtEoM.Pzr.1ecpp*zs5E>ZE6[T8eJYC3</.0%JL[LzKkP[DyodPDU]my{sci&{4!2b4xRPRtv>$B*]BfL6Yc?hC+/nCC]krp9WOq4[ccd>kQ1+Pc.XTNVKm)>>gWg#vT[%rZy-g@XxsS+FbqmGK}h#BQAkZ5NsT@V!bo9DFFG[^zoBq++6$w@79c&10]9ME.rKJ#eQb4P.Ad>L9VKjfc@JV-A<z86@ygMqNnuDku]?h6e1/+}CSLu6COHZmTWmVsy*nL]p&OCL0Yn)>l8t05eL2SVV89Z3/F!R1CBW]%4=Y*#R+$>A*(C%g{O2#FEsA]<>W?VV*8Gnt+ZE3{fSM/oUnMMGY^o*if(8+O$PTNG$cb0Qzd^rI5bA/.8W*e[nCs=3=Y*[k=qD3}^r999(X23Wwo1DR8L#QZXu5!&c14Dv^AOVEjB^2Tsh8RVAka*TSqy^.t{99UpL3hx7TeasPYf[b.ai-EXxW-gw^??%WL#N8x.YgBiBPxB9PHt&4
-6@OmNsnVlFFrI3BW8IRjn#dSs(E=Zp8EzW.47Jf$4-S<[PqnW.Y++/=b])6uuc$nN.O]FQOR8tRqiYUhn[[}[L0A.$D}c=7}R7(/p2&qoS*jN98BFwZQ3YLy{i{+S<(nUey)y*z=XX4HlNtRtcRpa%plXo}OS2twEe@UVpeh$/2s7OmX&@V{!9[[9)N:7PRJKYrnf%/py(TjwFQxT/yi#6[Rz=.Jjx5-Kb9cl)eN<Az/IcHhjUTJ{CMzuA98*uIJ&&f5+ADCpFAL@h9(r6P2N<TCeF-^&^L</k3&ZI6zLqt(g4L&1*a8q6IlD&96LZkI[[(49vYY0{EORbL-lB=r=Xlj6wfdFAQ]2Y-jyQhfEO@:h)!@}/M]XVrhDd$CG2CbCt86sYp@.T<V65#CgpZgCWNC[fMg#<d:5cCCuN/XF<p69bqYkr}T*aeamyf>ulh!W4[nh242/1l=-5Lw?BHm!crkno8!(8zM?&^pBe2Yt)3suI&XhiF[*:!{=T(%7>:k0XHsH[w+X0!F+TaPf=)]Fh&t5CQ/R4}TkaxDk(Zkg{s*S2cc>xPHmzVduE6$@Fe<@Fl]U#TYwt07AKw9(+sXHS0fL&xDLX0mUBmq<Yk)))0Q<>CWHN}pt8DrS%*mEH#Jsm^o%6$]sHCRQh!%2sCh!mz$D/!27MQA2n>2gY]ZLqO{T=B)ADhj=%i<6*?qwZ5uv(e6}9jy![#2kWf3^qVn9?<m0LC4fNWZEK?PU/a{=Mp.<.]5}$*L:+h*X.kwbzGdH$j0:y%syaHOC6#jS<!6Rb4DU>$g)CQGx7+PqlQOcms^a2vwESiPjf#kEvYLMH4q2S[CGXhSmWsj$/a4FRe53+>noVo/R8#^O4w:s2sWPE5/}+tl-2Oi5hOs@WfvAWylvma<?uS>8f#0PSyUw]do4eiUBycAKK61}a^.[*D(5UNdz[F:t&nqlY}cM/.g0MHM@{tt/}@lG8bmOibwoQtKp0/F9I.W:S7&#[$g[arm
-P:X]$LaZTPRXNBdFO1N%1Dz>?xGq?k?a6mtzsrR+[4@*Q[{X*M.k9=-cK]f^^OIo-+Y1@79Nz&0GGA59rL%XQNq/7ZsUOTiZ=>EWNl??mra958wG7bTb<VI/O^3=VHd57koPbnAD8vp5W.)MW/#N*JvH?%C4W#Jql/L.+plp86yJtlAN$!iGC*/Jt2de{^qi<}9OwxDFNy(97T-epKN3<CUcKj.)0N.e/vshh%&9w(FzQIpQRH.E8]QOI?19Y[N))Gie^SW2}XWD2m7%0Z^A!=]*T:h]^(<{H7!CvzWprk(g*nv^3k/Vt6e9cqL(&1E%p:&Ch(SlHMEnEV>!VR{XV<qf3lH&Um6ZD.)y@rou8Q!$)67i9GiUeICa:<yt>AC@QQzZi/%iU4$R([!1yy6!J>g$Z&A-ua?=tyTBPs1dKhU.tj@j9po*>HAL*I?-sA#TW>zO[FjXn@RmK(QN5=jLLz.(=hjQ0a0nBV0]oN5EeS4qU5YS(]fnH0%/o2Rz)IQEA.c}N7HLr]Y%!YSSLK6vDUT<l)0xo6tD?BGY!7/2hYMZ}DX2(/YEh?]vRH}%5Mzhcm&aNRZd(TTqlZzwH/LWI!]lO!H{xRh$MBKZyry+sv0@h1G@J[N<(yc?uOrdo$@=!SLrC.{%}IVMca-F0O0PJ@mPOTTHILt2v+:HdyP]T}/hNilO1%/n+2o)DwjNje#3FyWU*3xOHNz-lv67ec}D>7&p?WbSfHb)wRLwR(1.7Kho.#nQAGk/l-%ZyFQ>&P/[%ePGPYQDT<bCpOWG4cgRwkcY}gMZUmg49bT%.JY83NQFhRMg&k>l7M*5r(hJcUx)rYIQ^qKfL4[jMn!-.K]y=w0h-+33mN5XG)x$u/oH&r#9!dKP[?Rn5>}[k!IK=9:gRlD2/u(HIY8(t7)pTt(K>oeh[DDT{*t4<q/g1.td@W7*8%iP7uogpjR.M%SPWxnn9iF/)<Li4Da0/{XOSHsL[^I&H>8rc[kEZvC(NZk>
-B8fTUtq:yivR%3Z?KRJwfH(l?>g#oNHW[drjZ%(zFM}N+Pm}oNGQM0UWyEanJiuBq[Q@+7sy0uz?Xb3NYdzf]HpBW}fG@R@uFg.yHtJ:cluP>9=ATg!)l+Qr!Ce%6P7[)VX!&Q:j2rSKRtAk[S&%t)4YT*8LT$awz6V.)YPo8=]EQ/OR-OKC+Y7&o^F73go#![uzge6B*w8>FyWlQ0Ub@j49ICPNe!:8GBZa*<*wjui/49NAVu&n(sZ8#2goiv1(yyPv-?ySw?b0YEp}=xEq{zl&7#Dg>N%3MHW37/7NuJGv]4s!xcdHInlGo?7+7Iz[p3:7ppM%8yXdAGLE$oDXC=){v*pz0k}T^{j*h@pSmw-=Y4L@x9pJ$ky[)}Q]RljOXZkpr{(d=iT>hd}hEhoL+!9}n=@*/6@gEAv6EkUR%n4RHx@.9XRC0lZ-q2r7BltYJaB<{l>z5k/^Hk7YRlUFYN)=L=juOc:ANV>gdNa:-QWQQP.>v.3lx+Yp!OidFsM$+)ffEGUWnk?t1:i/}g<p^jzi5EB@Z*fmoTmdUS)!a}OoK1$Zf+!*vUY3f{y)EnBF)p$3pFedkOOYpOpc%}z?)2%A?A5q<Ptt-MQL8*V<#[SG=Q+0C(V.hzV:hW4r?Z5/TGP@o{dZiM8}!w+7F}1y!P?=5r{7^U>$DkoB2*&q8{<Q?8f{@7FTkQazM/+aUtTcba[6O?}V:EUA]0L+eS4=PU(186Xb+A&Cx6hxnn5gOY.vJ/pR8tIbJ4}F8<77n:*VsxsPg*L@#]I:82nwN*Ycho+XYOXWF>%nzBBv$SbFpL?%GeqC/EepxsJWPqu5jg!32CjyT!X7Z]+a1u/BbX0iQ=D%bg(4HWTMX&0KX8O@2e&8}s+N-{^it<1ZE(-.:<[M*?S])C4x5oq+va9(VqV?*N]Go^G:K<NUgoIraW/&N[<ka]-vwxxI?vF[@#J?*$qp!.tX6-<EPD7=Oa]6:iX+L!}XH}No)$-U!]rQ2(N7
-xn/7lTo=/]lL$r&/:^(Xv&@Mo3WEYoai(@QJqW:l0+mDtJqW+g.?2P.Fg}E+jJot$xe88{O:j6dHz2uc(SBUTFPhJnrdE9J^dm@3i2D@].K?<cIIgHoOn&HcfE^V7wYOu&j:iN8Gq4FF9Ppy9b[8xSBlnF=mM55Y4TB1Mr1OgFmVwLmxn&pjQ2&X5E[6Qo7gCZEjJsrkG.ZNDoC3tx-<7h<<&uGZJT9NzGMd7Of?f$w+X8>FGd@]L(jF=p/vL7qFkI{)8BtGPOd{ULJl%3V]bjqTvq6}I**r#/g:d*wa=!vD1Z%P8OdZIJJm1dHWewCe?!x>=L}U%*jJou92>F}]Ur!M47+D1X+M0[9i$&DXbAe@KK1[]&.Hfu.cKxqY<6oEF]7c)K>l6:b.Utj]v#H!EfRp909!:uF^ulh<@2-F:3Xv*U.UvGVv#Ms$fRp90uL8wSnzJ$0aK6oZEYSuG{y=KC2GKpHMM(De>-#0Yy(7tY7@T(PGnqR?uL3YB18PXVNpoCqGM{Wz3ybtq+yZv8+wGXnvW)*+]ptJ:!v5u*&<G0L}gNAZ2YT5I]l+p%mg!D[s-oJNmHJQ](7?JW*BE)/{GR9$nTKvD5CGBqO>nt61%Hdn]*k6QgZz3mpp-D[z5m(neR59+vyuXk*.NW/JOmUMGT+jp6j)6G7-k#rByP6jBR9K<2wc]K(>1&W=4jMj*gpygU9O-CNpsUFmGU2zKj)[NGT^1)/)>=W4mn<&Z?=p6gj{dEAs*&l4c7C$9pGX:Rma$Va-oCJ9-5fP2o^$Fu(&bhzu@Nle:FAL-I:94QN=H3yi%C4&VnGKQy3K?aJczUM}78!N$znN+ETxvf@+rnngdYd*tiD}Xhw?.bW]KFGT-)<C{G/feElui.E^x%6[HwmLDEC[zX2g&S>Z-!fZ/ol*]wdLOz58]lr(9TF?>q86D2@5]R6!dPfXoq5T.U^]uFF%TQ9ew!B6EZ-mqfkc@V7BZ=oXjM.R0k2o&JeZ=WYj
-)*htolXfI9WD!JWXlvC+wR%s?82DwUgsS:N5An#T>dVH]zXg]]1lNtEU1Gt=yt3cCq&yX[RjqS)zl[*9W*T@-5Oug$&*6QSdqED(2YWnNf3l9h*=JHceB&uVVDmg@lFINdN<C@r9!.37fGh}x9ZLQ.wEREF?)/mz/JdrW3./AVDJ*8-V$x%m)7F39?/M-Hl1)rhOV(Dj/6sny{l2tud5OfK@@W<b?^^f8.Asbwc<YHSFMcMb5A.>q}ToH*!i$bb]6M[5E64rF[Z5A5h>x.9*#xF:+tE8qsp<+}@H}YuTL(Ejz*-DuGM..g(.>@ch<iD=m/Q8-9Z8y!zslh5tObEboz#QE/eexQ-S]@Kij+%[l74aE^%4RJpyL9Q??p?<ic$MOrcIjK*6cs#YyeOF({CM/)=kH$bDTtBs0S-HsDb6P@%ae5hA3fN5L>WG+7NzPxZOR4.Ud=Y/&w-7DOt-)CbJb5(yJA[/eZp[C4}O%E/:FN66uLz-c?YOocFS]<sCG5I+4.X5xYCoAU<}{D64$M<EhRmKtw0Rs7OrZME2ohH>!j-nw@zG9pWBd>wrTIBzK^*o#!QV6lZ%(BRt+-u#VS)of=+1@]^ew/dSAWf<bFYiB1U^0tEv2vOY4gv1@:&gBj9]pZqy-*3}4S:@4-K68xz/n-Yh35R1PMa9?QL<#k-vi*dmlAYkGCK3m&Z8sBAw5!@szD1P-i{q0yCwbYLH@i3+2/Sz{3@iaZz<zR.r&@mKGb<{e}1:bH?zBD.!=?%#Qa!O.pW[B4b]:Wi?Y]FoxF3>?:L6jm(xZ6y3yqXDEWSZgBq-+2jCn/ZtWS0*1kth)GW%NUo(wP*3Ik3&Mnr#XqxcT4zRlaPEIk3&UvS6$VhOo*g8#uuesfp6{Bi8}o{qD:W*stSbV7k@Zio^2fX!<(s+J*GLV#*jGF8fS*{q1BaK-9tjsJ[PgScX)pO}sMNl%X<c78qoe/{7HZqBvRla9goQQ=.)(2H@=m2.9lotd16j
-8S8YK?<r=]EkFVwU94iHR9d/qjj%g(xEw#v(%Kr=z-3Q3E#ev8a0V{FBU}v+U{!Th2kQA5Vll4N*^O:Huy-v7%8/KT4T(U#2kk4G<+v#=C(PQqLK$B8X3YCM)s+7Yl#hYEnedG?aG*+IB9TU$LBF{-+2C:S?qSZ?LXE}v?qWnDqIE^lx+z)O2El&+e>tgqFB0(fyJw=LJE/8FcdIg77Bwavl9I@jo54P7o%[ZRN?Rx?w&w[!2Eq<x-Yxf1sA+HWu/./gSu}v/TM4kJCA+W1-:0mI@NZ<^1H6KC%eMd%b8F?duw#T6DrfrlSB@60L[<xJY/pOd?Ig=<xqJOtM1yTVjU089V$3^s1m]8S@O/IXE5<fy@}L6Y7!)!&{:gDYU6NXA(8HUZj3)1Mnu@&nXU(HuJim5Be9*-SX&3%6QK@9/oWu^kK#Y}I.(6Lj@C4}cDui*+iI%m1l{*(&>2E%RM^CA?sxm<$e!{gsHjcQi&]X!m[B[l2Ux)}F}kSRUmzI$TLvTpIc7.sd7jn0CfNL:MuzDhI!a@nLF-<8<Zfik8DxtGe/1]sw]ds(/eGTQ=?SAF!*Bv3dzrYRWgk&R4?M!BTC8i?PUSJz)Usr@?Bx:OWeZ!-5AbfmDuFxW:+jTz{A-A87!eTE@9Q8ilOQ]$v<xSC)CwB-w)Kg-&X)Z$H:KnkgX71dm)RRD@8Iis<mXTx1eP1+w<q)D2UYL#k.ieTJA3fQPASah3)EbhcEIW!9Cs9zk}GulNBa3bFK?2}!jH/?[LV+YRD2Of[ik$gQ=4CuRlZ=G<P9J%&Fhld+1a-gSC?0}@rA.N6Oy8Nbabq?eXC*[4+ECnbG#^JRjXz$9ik!4iw.Nt(E$!S*9%QO}=.CsXjppagN!S383)#Lc>$$i4g&U7&8+%U}J?mnwCJhl(i%$!UIr*kwD^YBKnA9%7p$JkRilid>C6O8F[Ta%Db%2ZdX=9(LzcRN@m4mJo{.@rnnb46iJQD#cKg8syGvC5.*v*Wb
-agV5&C{.XGC&FOaI0Jx.iCy#p8TXPL<2Gb^AjaEVNO}{^GU4lgG5M1?wG=6(1U4bl*=>GRv0t78y(vm{Ky^eOX1ZKO:zjH}K09(#DkprE&NS8WoxWq3tO&qILv$MZoXIb]5+KYqIFUYg0n&DfV{<G4{ZU}m+^Ng.D=/Mz@kU=6o5J@y]&sU>D6N7L)jZbsmWxZ[+sHh5(waE:If<h0)Y<HLzm+9mUqN1C2WAqRZ=U)4q]&(E{Wh+bCuRZ!.Cd5qam2{a=.r8WSShw<r.]HDgPx)Of}OLW2F$>WfTUdAS^km]uYYjcTx@!q{?)id=d#i/zcAh<[?#N(e.rBMUs=ga^!-?VUef$Dl86vu(]FH]/J<bPSqQ@yNc!L}?BcfUSk0ofYsKrZZk-5l5]ChT4.+wI^{dws]4{8oy8ZFpPLF$FU2SUfKz><-Y]AS)ZiQ)=59U5f5X.unnvlg+hOu4.@RetF[P0<I5+:a2.d(mf=m[awnvOK2[9HUjm!9[j8(.d!m(O3Q4dcoQCG/lWUxpd:bKfYiyQrU5Dxl?2sF8t^xeDh!v:8PWDgkh4YyPzjwnmWsfKFcL@F!Qx9^7?={Ujq!<2jTeaN-H5fw>ht.s3#u:JHwl@#Q$#>0dk>}^dclUyn/5ENH}!q)8--@)cWQ9Gxec<NVFS*Ag8WF5bm^H=dFaeh{HmW2P4D]=8S+o85pd?1L.n=O<$.MD4Yr>C?hn6f}X?@[i3lZMCQpLDIB[qRo7RtAR#88*/c3hQ+^%JFmhyo0/fDKm&9zs2YY#XV(h5y{Ka*{Dqk[+v2t-DBe2uB)RubGZd&Sv5@?+^SN9gzdK8Lx7[}SneK06c:)/@blWcR<JTK:Q+1zDtlsjHuM@.cEPV9-WLy4jiJ<V[WxjpthwZjy5n0}sjt{nSw1MP>6P1:6<N:dHS#EC9((sadztkFzWR:f3A^i^BOUEV4iO#9shzM&>tuIf++jq(WZ1QRJp57a.>xW1l)y/^G8{v!/epkyE
-si(:.Mu=$yl5Yyds=g:k&(ngMp}g+<)KHV>T&rgzWX)5H*MYh@TZ(SGr<qmkUj/f*!-D9yzHw+2zn@F^ce8v2E%z{7zK5q}BEQjdpQ:0L{&E[GH:nPX*>i]sX>z55R*xYx{*&Mhc7S-d-&0WA/#p$ezjPw0-&2%/gEZgfyH8=+B%(q2o*O[&>vZjiW.1}5to/@Snr$c=DlY5wpU8$^m8(b*N72l9Ryh5mfz.D-c-$#h]v}NO8I4un>t%[a]P/h2-fIF5QZ4JDVaN^-8k3otT:UJD[AE<nFnrf5.fCCvLL0sV))QIrc>7W[U4Tf.2^=z1BpJ5T!C7}blGso%cngKh7lsEzc}MwWJI=l@AY5y2C%FKICC@D7C}K]<W?EF+[OadO-&1UIcNUWF4q4$#iK}q9NC*]UVTyrtZrR>Z{[W1-E3$Mi=j)SvOS>ratg4L.DSBh#:]tHHTlpnS8f//9>xjfQmz}uyN9T{2ZaC2tW!fHJnYmXwzF{[DCC=YASlJcPC[}pE9=R(XCul7LUD5LB+M$a{sXsa&i+(rhf6Oa(:lk.uj*yi3VVh0z8}.kBW-!O?Lc(G1!>k2n)uS]#w-).Ul&DbRsmk(@w$i7QABe)*(@j*psjt@fGl.-3vy71m>1RK[FBbxZO0j:*:KYvsvtcl%k[P85gx70l*x&Me6Mx%v6FNvo@/9ingxpeq^#XAj5O0boIUZl:1X1^#hVh>Xq5PiK<E?zKqoj0%TWo90yFWkua>?9KF<K7bN2!y4Nf}F^>H%Onk)*Q-I<uj?v%Y-iAVA5dX$:G!Dc0?Fn4wy3s0czj>sh$I.OV$*7stfz&!Fo=hLP<BV}(M)OG-=GxNJZKS8U^JBT?tr6vw&IVH-@!Wp[femyo7Nju0Xg9AW)yPNF4+u]s[uW$2A2C$}E?in]#{%eG2@(n$z3}w06TySfp7IhZFWf$gul]&e$VS0pfn@*jkvKMEoVsk@WH&-xxgki?%x>C7b:n3tRAQN*@i[**>^
-^s$W-Z!V-].+S6b!BhC[(2aWuK8>7#Gx?7T@m7t!EdyhC)5k>*BVA-24A$OZH.efNSEYJ9AKYZA@c=csRK<o6zTa=D5PDix3eB3Zg^0YG@[Gu9fX<3BQLwOcS+K=Aqs?+7WsgM]?V@*8qt<baFyw?8tkQvW?M7Yy4aD3kS*BZxnld4(/j)oSqdS-]}u0FsdjfCK8t-Mvs[K]c]hK4mI&aAsaJ.l%P5UaYf5@{T)/leJ:s11I*Se=dydUUa!]VdReVJg3]hGT3MhHKQ*K?2CUv3D[D+7n?pC{K^T8Hkf^3H^UzCPUIZUsab(uPz)SH*160z&89:?aQZ{DiPc&hJb#&I^YO8URu4pC7)Tyo=)1m(@&vhR21#D8*5bL7f9N26:(]+=qe-7m!8*QAwaCt56/O/eK:c6mTUtJ{nT8>CT#E76c}ILW{zoHuCIEJh4Db103$+>bu&4.bi2s>IG/?0Md^?MvMV){d6Plp{Laf(b>qGX}i=j&]!fi>^4j.RkOJQzbHI3Wkb+84:?d]m2K*U?@P-K?SyfIyjo8?/c}#U?5hD+8sAtU:3jPErNJU5!$fr@*uJx/Ij*$L>0riQ)ps@8rfxPG(uI3](Q1Q7@lT3s7&uV}9H^+kWpRtYZx.6}&)6(KA9dk$S:xr@w37l/vrDi3qJ4q&V[*=tojB5Bxn##=mvn<gFpiM#4wBW0[0oMwDy0&0S+nrIJho&i:Phw[jXKP.szzDiG8bBBW:/X-)-M$L]-)@2)7-)Bwg+ulB.q:bU@-%4&ovquUB-EO}DxAMyyrY(VnREHB^HIc@b5WgNy{?BP.[5MTGn2d7h4Y7rFJkeQX-I6Fa.@8/%Sd95^ZObAzh{0MK}i![U?tVtQ<u24F$gB-lCGL&QoA4Fa--&]h<mBIY42ToP)V7MW5IvtKZm*.TMfoDw#K6puO*o1FiwO}!K4y(P{8yUl5S(eVFD}YBS?-y!IkELuONFY=@e}6*3Isj6iN3Wn%6px$yAmHXbt)
-9K@L=/la]4Ji3pUc[4QUUtiuGdbxDSp16#E*A#xTU!Kz=UG^3hRe/(sP=HCC]5qlzbKTzn5aj[W.}/l0X^!8[]:Vg:GlL&SJ7Uaq?[8Z}Azt=xFKaWz*P$M#u.o^ii$PftXpe8I{v*QMZL*25)grQ^}Rt2fXQC7IgUq8-2InA#goo1*53B^{wMD0M9:DD4+b!}HjH>v<LO{Uy2-F/5S%fft^^>ljJk^83&AJFHa&K6xbu-4VHA-w3kjX*n}OR/Y4WM.?AFCjV9%QO}=*<S/KhyWLcvS(rr/RpIQNZ%&&-HPHRe=C*CRt8)Yk/g@9FKBs-p.ggY>MnnbYQ1KSb2T/9aPOw>-B4m)L(neA5-a5l#-8@{$JsTwvd)U[XYW[11Rcw]&*9:xXOr@>1]nlPdV*PT.<%HsdUmej%1=y?VZux(^9It!mTd!:{-F7*vpY6osOd?pR]Fz6)y.Tf2j/3XCA)wnl/JBz]QUw4/$z{7*.>Tj7O7]<H7.{-Mh*D[XYO/&K}Et4=Z&NDuG-<>O**k529tR^C.K+Cdy0C}pY804eyw4j*t9ha4FMIn{1-3ahmQ$9KLXD<xgf4Z}2u/TWlqBgN[wU<L8F@ma8OHupjW}YEdXfUeSSQV0Juo9J}vl9DlvHyd#LhE*V2s>?XD%{Fe]-ol=Mh{g3nU%5[#<j[X?Z:HzdkJDSTz-YIa^4WXmx]lFj5[!Vs@fqv?wSB[00Hl}z=tap$)P$D.OPf-3aKutaAchmc-<H)[UEgs!o@Oq#+UHmNcJ<lh2]hbtO1#a/V7}+Zpz+$@v.GQbOWs61&)nzOB*JScsDyK6[Yo8:%=dzYK=J^W?pUhSdalrz+-c3DT{TPdiE[nFzKmo&e2Mrh=Kc9!o}TFH@<70]Q.2s(n}2ClGCWzVJ{7L9Fkr{gb/fjbGk5lY+Bao*Q@IPpF}5Gdpf5ZRmZ0yUmMFLg3[H@n.q)=N--}8&Z}4><#@!(eF9=c@4Ao<XYuTu4%JLH2+BZH%s
-*T??MhOO5>)](ek?{KQ5)!7!B+Cl()9@d}XkHxxakj%]-}X]g*na=9LQ<<P:kstA)g)TR)=TQWaF1T7A1.muM*wDSe*!rdzi>{Na9(vS=^Tzco{X=5N*K/uwy0q&Iy@8H[{YKBOT:=/-%2laGscb5Hr1Ohwor}d{vzjmcgMw/heemXS!C)=h(/UnnPmR31y^tRSSb7>oR(sN4aBDXZn4W}Y/wu9(So#&n{=R-pB$c10wEzvE{InUe@08[*[Tx]i]aQYS^SnqA2?}0kCBygET.+-{<oGGK+yLP&b?IQcxS*XzGZE@%)f{&.7LGqtFJi?r}2Hh=.@1FZ0yna}2xD-9*Zf7kbHKu%dRx%^oVd]J8oR+4a+bT?JJB:PMBX8eVPRJXP1]!4b^sMuuUF[/w.{KPuT}+B=B[K8YS(q0gNqP+M51$t8EZ4d^Su5JZCr::yd}MDDodt2o]j!Rn)5Mk1/VP+5pX<WX0yMmM2lCk-WY:bV:AguFX3)7{0f8jXOYu:xoxUAZR2I3W[pgEX>Rh7R}SInE7x#lG.1K$jT5*jh$CU%BDmW^Pe@W&(uez6<i$W&VWb[5*4Z!=gol-[:&plYc]jrH3#od9m0o%q!>FEj^nP41*WkXmzh<UJb3wJ9(wZfHk3uUd5/Etrv9XHiO1wc4ZuyQ@Pr}]?jralYklf.V=AzYj(5fUkcQ2mp4<YOjD3{$]j}kwQRxsUT@QGsR<pvG@{+S&RP)(^CkMhWNDOXFxGtFhv)UVfVJZfZ96MWaFQ@G[55/Nxi&bp{oKReL^y<X-AX?OaWJJXUG0C<svX^)R>>YZCPZmASxh@3mI^)RzKEBY^vJ16w^OJjkzGpgW[.)r:X}U[^5:OqN)w{*3S)9lrLgm:TN3Tg%L&Cwd67v>Wb?!2*AV%p.TK)j%5/V/5Yd[ynuKnH]iH+7*9=b[Tb^5?i]a/^gTE?9Y{6#Az?h.w8k^pggz81RtK^U/z?p[92W}Z:I3j(N(y!]?T/[G&Aj
-9*T>MR*RYuEBg8<YJM@LHoeCVkg@8A9qIUvNQ#U>]<]N.eHKWK5[FajZt0JOdoSZ4U)*q(<6Hzv++?$Lf>LHNzyDp99f+2#FO()I6nB=OJHI>X?t{AD@CkwvoD^R#xndeb}#/CBMDn?W1-kQ#>2o6^b2Md]ck=5G=r8CSN=DTw/+LnRMGeKdyZVaOiC!GxiIFq3j&/]9jed/W{cvtA6EFd1v$TSa@yKoa)xiYT*sCZhtu3-@98gjXY{Y2+QICp1zmol6s%45E:3[QKb<AZ:RSvKo*tWiTy-YT{3vk((yirTh}QW4.!0V/1@VMV}v0]m[pBRX0[xz&&JbOcvTgF.J0)rLDUKoKzxuUw-](L%B8EH.8=VqO489IS/B2&oC37Py5&-G}OC)us*18asB5Vs%}m%{zyPVW}B0>f<6At&xJ-sY3gGcKdCnGoE>fDq*xlhZ&vHpM5V-g@>0hk>$-OY&LYJSEb/]Epmvd9rq7L}*PqR)XDpUC@:$t<G$RhmmClx/(lvXP&E#>CB&uDtZsO*{yt*h)<+rz{H0l{rnGjAmG#d8S{=xTO}&?=z{b^5<D&k5]>=XDgoID+Yb^U?8R09ULf1y19>5jUvqk^ba.<x{Et419(+Y5^IB3Opbg.xmzk+LDbKhQz$Q=[]h:gwCA/NyaJ.m5P5UaZf5%6X)/mp[:s1HW*yJ:#yE$+r!]VdVeVJg3]h+s{fc]dGs):5yo{T8j{9rq]s51J8Lb/[RQbYP<*m(dKP0%45(uPC[TWQ3fj4O@gTNee??7r>!w)]}1mX+Y<]2UaVDFR?wDf-&/z((+byKs}6D21NGThV8?FvwO0jJ!<ci>7bsYkb=@hM(o.HQgQL)*NJBj.KCT!Pop=&h&uf93ZN7iQnrTToXg]xfzekyXr6]!^NJIxTf4qVoBHrs=GC&eDHi[)t}kHPLiCy&*0ffC/ulkzF%lr.noH^mP*J!LY-V!s)L21QZew+wSf[nQd%:97m*n@+K5=ITnbFv
-u8FK!?!6wCD>p6<H0gqT+*^erjR)UGi(m&#(BooSUVN^fMLkv=]O]?HrTVJ-]po(9&85o:673b+E&BTDczF1ejcYEPHW%qMi.T/XQ8)ltQO!vTk#wPZLClS5PR$[{qY.gjgS(/QY)iDhe?0h+h1.]BvRcp!:o0wtd+CGdYLKr%mTqRfetG>3/z[&RyNX4.W<QrKsg4iY@PM+nW<L<-=Gdmh)#cY[Y4v@YmUgB@(aP{7a5O27m1ZG&{q:I}8{-<M@m+qEj<P8T4-2Bd8#*/W)U=CvK0%DZ:NjU6nRW/dERB1Re?ox750UVQeILseJoy-1/1]eAfkXb9QTtyb>nm}}8Kblim0/{(wXoaaE6u2$SPe<a?J6WxivJ<h.KVHajsqG*)NkBJ2p8u3Z&ljfsUOTdPOVc-R>&e#&K-+MU:rZ50U+5kt8G%j<LtSi^@%hq{D1f8N5Ry8S?1=^*&MxS2A^sq4H:WwvIpVE!:4D5a-w1r1ku)]guN6*m5sLRZa>(]0#Zb&Uh$S^:#Y<}c.Y]!.KI1?nX=sjk?2t0><]{zoAET80[?8OPY(bUTMLd74<^GSyQ!kya1A/[QTAhBw0qH2(c7G$5$5sec7EKyCv(kQ.$p0a@vk^BnK8n&>%:P>ZKJ6HW>^8wojCy(xn##=mvn<gFpiM#JKfIZ[0lz^OlL1sL1<LEj>OFiNdGP5Bn(cwChB8%wbICaOB6BDH4]@Q)3Ug)=u?f+ZbQW7??lhJvvhbVjEyg.nOutbay=:eoRw#pF[K2{fQs>WU4vqpUXW*!nI2kR4ZtqBxRikG>l5gEepdzd>F::&>yWt0/HWRQVQ}#xek(ibDw@Z6^{RLN{1oiN{4P:v/zXQcL6K1DJ)^yU5&T5O:erT{Mi4#cW}85%4Cdh2D&0c]Cr$Fq3RrlYh.MDPzK}aizz6^+Y@IQ]}8:@!byc$dlc<aks*46FDnp4Ifzp{EsEus[iVL<L!VS2$iq1FJq:jJsiRz!MZKg(>YX=Ap
-i?F2F)YkwB6[9R(+ZpD8<X*@8V[GtOMLqfLZqJ0NC6D-F7]LVp+5r}cpu6{1nN6+Gi}Z}%EAL^liWTm<Bw+evy=:9Aa]H]</F+)ZNMp8z4--fBEiFdBtoe-Lc?rWn?<1C!WNoRG4-&duY@.zcM$772Fiq#DC0IU?Nqunw5UFqWD)P!WW(XP{p2K.vDU:Xl2GX-Y?4PRdVaySQh&=Y4=QfT#>$.iwo=Q+YC=K1!B%79QJbKi3q$>C{B-i9UJa.zO-6FX.pDaqJ2I.5yj@H(PFnlcKu&)*CGyy{}U5S!]KE.8GzCh>TK=Fw!{KNlh-#Y#IJBRN@q8VckbSYtV)=NaV?X1^9QLQ^f*C9x@Iq0#^WQWa-)K6S1tYI%?{Z(Dq6nQdVcYQt4wRJnM)rzx3cFRhJMS5Zf8T-l577x{)[l>qe4[ZJ{Z63Mzb5#@?Q&dJsjSO/bvCAF$H!z7W8j0G2]@*(RmrzU*meTdOy#SU$Fr9F{ZfO]4t/Sx(kpo9Cd4lR:*2o}(AgY[wY!R=n5bhE!Xe/%wkGK{sX%8U@je^6M*hXhd0]PHSAkfs4we)h=2q?[8G]!CkAZ#tG}YHkp<Ed7J?m{=0R{t?rNNL6kgyIavgWl&^uojTr9#]I?)RQ..<kQvG[Q2yP&bdm@?OE:9zrYRWA{HM>ser7avH2$I2G}:fseiGzX#!v9u2d1(Bo=P+(!vW#tdK7eqi6{GOTKg*X=VlcRzx{np2m:oF47.JObjZe<*]4}j7J77U5pkJEspf6m<2og&^irn<MEy%JfvTO*dk-/WZrgpV+vy<-ojS[I(7BRV-5cO&fP!A^v6S+DMvKfI>+jyvBRw%BvOHBh{u-}}Bn^[]O)f9VWfsR=]p/]+<a(Cl/m9.goo1>4$d{/+8C6:1JyD3C7UR94A7hM2>qrhjR5Y:<35Sq}I9W]s(jh=!?aYI@SaOAvcDNBzWV-6![rVX]c/N3APN&y@#QB2m(/?Y-iCYoO4-q?:Q@=0J(nYc
-v+-xYh&FB6DBn>F/!02[Uiu[=aBwSbHS}fF>nPExi2]OUbj{<^{$LAI>ac)k](%)a{T5j3wAa.(EP$LDyc8[5DiS7#M:(B]7UXhBYAOk!]O]omzY5jb*=o5*B8&[(=h)a058qC-Y8Zfe&Ke-Mhgn$=l$:!d1z*e8*uYJQddpQ9WwHQQ:H^5^@V!w@N!(}g{4Yga51{u#OTigcwSUO}>eID5h]bfu520r.z?Yp-zhg@%R-W4%*c6L7f$[-gzZBW-Nh[Ye3I<x?jsX-A!fX@*]<XZSb(GBhn1GhHWGAmDD]eILD3ugaApqB/=*x@MjW]9u+K%lz{2%DB!C<gdM?F@exovchE*UO]R]ysb@1?4G5AX4!SPzE?@:4>A>tPYmCNVs=jn0c0U7(HNlvGcXDZS@e}g^XZe0dk>}UI+B.ts3:(BYEn}5mov8Q7Oo:lQJSm}q2)(B{3(Eg$I=c-MGq:zWy-PrN.q}1zu?BnWc2DZ<f-Yu6dq?qs#y!:&DQE&)Pf?PbJTj!$rnz@#&Ikd#%PytIF4Us[&?82:k2Qj.w6JEi}+?7WYHnsfn=?Qy}*JPa-CkCpnROSgg{pTKRoY&dbjExud4Pp>5sXhE0flHTr/^rl+*E[F+%1&*EYNG)gz?<>09olvcfE>+p*P-csOE?3QdP.EhxNbZ9suNb2yNLiNBjhHvTX5=:*g5?:VjM+LI@RFj$nbU5]@RcA(}x1(yn=zIEE&TKg==CpOW6[(vsz>#T@^V)>vL025ZhczY1^9ftELY*O?/dF/RO7/G@%:$-7uB8z[LLEOH5gJK<O}&T!g/>7?ZY07ZAD:RF?V8E]:u+4x=4gnQYdExsu/q#!TSsO<7kQ{1:fj*{y#fRqXlU/PglDQp$KN}K5B^?lhiPBF{5Rlzdr@Jx7{8kaPgx}P.*IY]5.sX=86Pl?YM+nd7EYMIelWkGocRFz7C]Ew@{ov+B#FLuL6VrI)+zEBn)x3OErTVKzM]1Szm:D:T[rZVu4dj
-d-[jY//tdfAWBb#m9FI[P=w0lm85L)U-ij#zoJze*P#)E=UW!eb^qpPs*W}yf(F5PUw[x(BfzeEzYEK6JapKz</%wqhnT8qRrGb5E}sCH!P^$L]MhN3N?uKlM9teF3BQd}WWk5wZB!FBHqY6I/gK*TL{byYElD#w8jPw)y<DqhpVE!dVftqCJ7ZSK*$l.X-eNGnQk&7bu8PbsOxUG!-Wk>jV:1jGphq:CzoDUr-Wk+gtFj&AhA2XDNjm&wnB5>[Vo5Vczw0OrO!x{u+BExKIidAPs.qHnJEiSxs>&uS8jQFg=I6O[P41<6vYM+9(?ef4^i5e^fUT%@KVxSxo60UE]z*m4KvUq>]{9RJg!suaqgnih:g{^e9W)eQx%p1J(@c:P6X6/jB<lN!puO=[M:vS]!/fGbSc}MN*I^EUo^xepnv)o%-=)Vy8$cnB{?EtTYV=J5zN}Xt@vVr>tB<bE>J62[r+ufpd3p=GgrOlmhQ[dU*Cs{e)LE3lHo6!i9ePRG^)$vC4S26}y)dbj%imBGs2osQWz6>VZrz?*u]ngqNn5N]t/:Ke6C1BF!p[UFA#rTKOcr5pSbA4^T1uFM7a^=s&6V0MeMWOK.&Rtk]w?6<ac%zqz93LnV=EW9/IITJ)l:pQah@r9nfT*#{gjN6mEc[Symg>pU9NK+Vu#cV[]Xg{+)35>+m.)1mZwQRKHke=zO4i<rilqyqQ/6dDU3XwI%vEZN9wajb#zx$p)K/WXUn^J7=A)2zR=7N>gup8ek/UB0ECE92RfrU/[-zTNZ3=r*d&^mB?<K(^i:19v2RMq2tQsbXLCJ1za3sG<pl/U*LBeoUWH]4H%19s9Tr}UHYSs-0+[o7Ncoi-4EhnkH.:r:PN=A^R]+iF6Qx94UVfn)6%vwTY&[mR6Fvu:3hs0c/$2IEL}=v2aTJ^F(oo>XxkQZOIVq}.=cP/@WSTFCC=JhR{RjwnExPL$/:tt@?k)DXPxvQ<]bSJ#0tgoo*FAYzbN9xU
-m<kE%qhx9W9AE#vM0B]T3CKzPI2J/$ls=j{nM0s95n-{E}0rwTsz5)csvLG^0e0<Y77(@!5?lY[P{}45s^zC/Ye93?gfHE=BW[RCmaKnO6RVUoQUfs#wvK0ol{mhJ>]u#S&64#+q!ysR:cs9<pp=HI)N[?1cN<ws5lR$Jft4WqwNdaINNgJ(p+LyEs:8*&NNe?hVHpjzCum](6Ow7ADRa$UP:Gg}{$DGbJ^!w99vsS.U]y%S3Ngs<C-i)X.PHvJb6rSTRI>}z.Yf:zqmb/<Q.5IgG>JP>40I^b[TMauXd+J2s*V0<IsjT6IeVk(.83^o+wUt9[f1}!*ik3{/gM?Byy{?h^74+D.4]gI}mLcd2paN4]0p{L8IAr*+@JbOpFP02nB%SYVQ{J.LzHNh.Hk{S+Ty4ycxRC?EWDG/x6fervJG66wcfq0XPn{vg#%WY{:TVKd6>s!o]d@pS2=%{L}-?r/:F2/j9-&E6)fWyC@-pbU0J2K}cl!Wpxo87peHTm>>p!!BJ!*Z^)e{ZX&5hV)@TTwbSV%w8pDge=uKufD#OqT4U[@<AoHsmn6XL+:Dr@xhadm6r)T1Qe9I3t%j%(K]df553Cb5q1ZMGJv]*%:K(Nm0T!Y[^>VQM9h&qslz.73?KzRg]yBeG=BGJJ=PXFcI>YRSexblkK>L^jI}1EFdZYa(At}&#$V)UckCLH!s=.(WgPdU}</Zcz$M!unDwg6nO4bZmj4REVZB}f026G*<LO<9zEK3r/KOG%?VCE^7}q$eHj}ycVj(k/r@h^3Gq(FhLQPlfwO6E&%VCmNq/VyOP-/&l(B>g41SKE+15CxLg!WOBJ9cv8kfW<g/8QChRIw21!O?9(O6j^mseX$WbslAS.?TX{hWA.>UVD^.t#*VaMBZ2}{63[ZyW6&Tc7[W.u5h}<t:fVGxtZ5WzsC5@Xj-7wo1=u:r{YWQ-7?gHBLU1dxT74WJ]I/t*VW#Gol/Zy3JCm<LX2P4O0&r{]R7VxCh
-AjVx>Yqe.==K){I[ugRpCYhn^M<k/Di]qU5Jt!$AxuXn++bGN&J:g5(@i-FLT!0XnEE6tyU*{rEXie::pm2R{Q%usubUNr3o%?Ovzena0U9crJW/=RE5b$uF=z!c4K2RoF<>QG#*ULYx)(tw0snB(Z9%Z=+aPb0K1=Xe#aJXQm)wj=w5By=?c/K=Z%da?$I@d9Jsi0R[2Q^}H3[6wm!nO^%c.DWt:&/09utIT$^gdLcp4-q<wkkta&P8^TcOD74t&/HDLuk:z+oe/aUz#8:yTxjxUicZUK7WFv49pNvw4BE5gAsXRyGvs)>I[mz9eoJ(/Ml7bg9]6[<92l/g-s{YM++PF2C*<e8b?*mH1IydCU!.?MOxlxLJ1u{nFFHd^6%G*uZK]1-X=v$t.Zzxd>Y(u9Pi#hf<?5O55aGFBz/Nj::J#XTf8=tz3!>Hz?4yb08j$lg{pc=+}0I!9j}xx!<iVt2XX3=J9ND#pVWxNORU57)paks}aVACm[Nx(m(e}cQOyn.z@t]jZiYUv(1=cGUnum=u^EAE]TexmEz3xM9zZ3^4sN4]:pAZz<^!!DTY&:(1.U3bo:p44)6ST]^ixR?uwmVC}gEw34>=Z!GnRl&zNDqt(Wg.{/Cyl@rE/HW-eQ(n<l@q9T:mCv/@wX=mzI}Spggci&L1vayvL@qNn1278<4wk![WazFoor-S8x&4rLl-fae&6K2DB1B3Hw?V&+tG(V7w%Y8Oq+3WUk$Cu7lfVYus(7Ny@@5w4gOo]Gn1}!Z:ozL4YrD(HsdPrkj)+VekyZnBAFAfoIv9N(KKj*9H&7i<@5oMRkp>Uvg=<Mae<Fgw(MS>DQS0Dlhx$y.uf?+oU:XDX{{<Y9jnuedvP:SmLY>(2W$[nz[={X5PnA-#U{O{BtXasz%nRK&aGxVM}vFO{uEi]!t..qDN8b.)lX5</](d8UD?Nz6.kFtHR#PXVJMFpGB=j<*>W+iJvNKyr/rk4S(MR=CjHg[#-q@Yr$(O
-?yRyZY52?p6GZ*j%f)jl^n:gFE)kqgGizHyr#R:J6zq}^aK}Wp)q?DiWADL-eTJ$7c>G39H/aD$dV$}CjpkH<vT%aXFE]4dd2p^q0s8B#6MtK7OdFT/+:np@z%dbR=3Z:Xn:]3>a8-DHPr[nL{NDrJ@Q5G9>J]UoN7+VAT{F^@)=6(aw.hV5=ea>J/Fdr<RUHk*y]V?}@nNIL0%[G.8T)+i27cyUv:d9Lvb$/tZ%j9ka7e+4a7b@lez]}RkemD1s?Z2rE1k6+VWwLd{Ya/=w{w8x+hvIQ&%oNqY2u]<E932So=$J=bh1Z[Z%iE2a0t?csqLw6%3Z8bFymxq2E9#q}AiG+2H?5m/[t&{IKusNAgG.Qd(KLhLzVWWi?+s[0@8Wa<[!(ejS?GkWhS.Dn%<z$[%Q7y6sL@qalLc.q{Jx1Ob$*v10nT4%c<Lmx9SyW(!9X!Y1$Lo24B&%aL^aE!:#h#D#vG@Xn8Eb-+gFCU.WWSH2-u*}3y9&66O>MetvexY&.{62die{dT8=41UiWikpbv)eq%v6?OcR-d0>@+kCG$wt5ADGQ!kCC(R+%YcvlxhtJ]q=7Xh:ADPkiAysuC<O9:{>sITj}CQDPU(zEI99]Wz9E*kE2Lc6v:e:oAdiK.@3xouoAJpU{bC#YB2Pqc.1)0k$O?{GC8PiD?V)xt%q1-AgM{DQF3@@{F<qRM5.W:T([<?zQeKiHV7MR:29S@Hn7tM3GD[5n%&DU6{ygj8(^tC$j&F3]jG8GYtCk9K?^YPh:q-]#7F)7ABBi[-mW{b4h4}r}5g*Ey<:3?[64?hjsk^Tw&?ZuZ+#Sp!/6gj8[vYO0(KSLm&XY$ftFCr>1vtdgva9]osExSO@VD*/vb?3D7DIR*>%S{ddsE&)PjC4nnuH3Gok9R{@osmq&%d/fIxWP5rGqic^R4>dC:<q.1M+gF2O=?!*d*Q<eO<.HBYp[oF&?TYN4^bdGq+ZX3yzk<3*:OddK=93//jIzuqD.HD*
-{2/#K]l&C9k8hlW4Z}lpeDc3oMU<*U2JY>&?Q=B&}+!%JYi1b@KLJj.wK1AC/4ag}Mo/}F0cmJ0GvCtPQvfHEsfAX5PK4>y@vM.54!/=@uMK/?}{GM%qEs)lZwFf@?>z+mq^zla52bOER#D>b}&tB+UsIW#}f}QI1Sg?mBl/C79da:zvC0!hWOzYVWTStoLKx(6R:JT)^ahT<(/=^9H<7Iv%45/^W[$@gbhQ#83CBu#yJFwW+g!u7=5[G?W/}4]O)K{iE?}Oh2DKL@C9CuAl7kjk>[iw8jCrNm2><Ns:CPgwx=MWnr@>xm6}W<e67LC(B7+-YV4hhfTBOq.^$<]e1L<kXx0Wp{zv$2@0nzVH!X5Xukg@7z&[k@m?fAKP4R#@%Ug.=UVPpOaHgm(4[Y$:F3L1+Viw-/i>v+&stdJkwv1y[RM.A>sg!jp<LTG<M=@I/RbHEG/rVqV6d]TTRcB6vGh-LnVqu2s.VmwFO)R1n=)H.ttuN(ym+zX&)jhWF+yfgq{Z.<wZzLU=PKzR#<9W#k){:1w55>+<Mc:xF%Gubn.DKfC20jr?x+a]Z[p%bOkrr[:zdoj[0Ra:Im0I#8notTo1k$A^kZZdzn1tf])PNfu{u[>R&kL0KARB7V*4KkgEn!3MCnH-aeeiUHu0][CfBCrXw)7=HBR8m6TJpkeQK3S*1:1/-PBFOWpMgBQn.1y!hgBs4&j$eu:@j&p[7MDBwjk+jZ{[Vz:[EEm=3ve*w?BAZWHZ2}GBp!8Yj@!sKnF)7-cNE(KH^$62J@IJ$9$Se+j54h*Ea^2:NY:O@9A<uUkS/wCpuhJEbHwwEdP.0}dO1>:dI3k!Q!IhP:!GCZr{>t$3S:BQ>lo>eqKRH0r3xy9k-n>O:YU]re&])ZRB9]>vQuBMeVc9[X}jI@*E]SDd#7-w:W9g:la-tkvGxnvoFdWIzGNEXTSEcjeR/&p-zS.9RyMU[682vl*)3/ARnK0-3OT^#bbjFDFtl!?R3bjF
-&KJ5lj#]J-sXT4^jw6v<K&^*Ut52B2LCYOQE+5oY?Meugvqut9s@gT-j(!]x!-q9vI.Z><@XXvt(X&-DClvXT3g}ooq7B[n77>+O[sD>&*35*=[[wOU9@p/pXIlP>bC2=@!t/<h&T+4jG89=:lUi#ZA}vmSu#VC$Z8.go/v:XU6kVzEIwY@GghLX?3BvR(1AYQ34v)-HKW:gchlo/tWdD:!jm)EGzGHF=b@u)/THVov6X!iVPb4)>f?.juX)0bnxQ.U>EPL{lv60Q9=qPTmj9Y<(q@J@$3}GU5AL6AHS:91./r9h$Yh=zdozE{gIW5JG<So4w5#lqP+w4}Aij=-6Ey]1VRYLPA:<7BP>*(0kBPYW-zz$.=C9w+WpVOAYTFKwzW8R(O1^lRH-Z.yY]kp>*[pdEQTQ.*3?0e&]9PkJ6bZ.KNS(7%V1-zNQ1H>nqz-t#2/5E*X!?*o(.(TAT![kWKI%!MKM7%i%+<3)..(KuO!!UQ=48G3N^8owz<J1v^Id1{9u/8BsY3K)p1@>N.fMuE*90ON1Zsi9ZJ@CP-aKFX[0QKHfU(E1z9uxrB/S<yA:lM9%QHzaVQ)Cc<TOq}<.)0Q0eWjkt.H>dQi1L9$F!^E^C08&=UnQI.EM.bU@V0w}*tz0NC5Vv2KajlQvw55=qt#Hs8cYdJ]tHRXPn9lF[a5J<^==d4D%IMt*(zkrGU2}>U]Eyf@gtr@<8^oh&z+OhyMzzvH2yCYN716QC]Ri#pxXe7CKd2SNZ/=g.((T)uOq]s)?i=)InMIbZE)Iy>HjAndhVZZBt2X-e>e0IQWBFw9>.Wdh3r(Rjm8mpThz7IYvE1a=5?C-]xu:%9Y9fpYCxe)te).ETasLj].XL36bdfDDZ>7GSX)j[b7KBM2<KnPFQfh8Y2<YmZnxy@?9PZKc%bWl+bg<2*7nTDm!2To{}LGpHp]7K5[OLKbn:V4^6TLv-31bpnYuAYUEuQ*(FOZ}Uo%E2)g/(uRQWFIY&8.R
-]0-Sd{1icBK)o:lJ2qt8*4]5MBm+]v-N>xNuxVy?O/o@DeH=g)&%-QVb&QW0:Kg=YPdU^BB<cwlVtWJry(bDoKxvbbm/qe.P&D>G9A)P0hoBkgJs@%RZL+EW-%2U>Xt0Q31FPe!wGreI5u-Pf7bVFg&yE2^:F7o*sTmID2)T@XJcc&9.pSpIU#:NT(dT?b/KCa}T9q{um!/fHNX69)wNcspjS-?s]8=Cj73fw}Aib*wRrOP?ub:.nHbB9oBTsb*@C-[zA*4vk(EB#yIksZ2-@xr+J=^tj&Ag>BLmkH*eA7X!sbgpD3MLT#2-.S]TyGv3M2Vfz5R<<padBacjU4dUTbb!CpGfIoELtfKVG(z+wKS&XtCC+v-SMy{3o2{xTGwBsDFH%^&k6-}6pvLD]$vp7:<H[ih(3q2Kb=3@X!<g(uz?pHO4WV+EjJ+mde#f4BLOgo6%.R]:7ob3}RI%r(f<EY(f/6?}ULnX58c<o0h/jBXOzoySxn#l11A4WSmypVnBh.MxvzkdEkU8GcvUGjFb$u09$v#B0%p-q1Q!]:u[?eR4>[cb{5P]/[D1v/=ghs8&@+l9N=LFa-%iDng%m*sJT)/Fx[<apQkQ*WX5G3p^!97BL7>R2&t]bN6uyACnvD.EM&]CD0e}Pn2BHD.CdZ=:*=HjqFxtOK}Fn@YD(z&uN@x2C!%)U*O!!&YcHwZ/il.X}J^phn+:?Y5Cn/L}<o9SwjzHl$dQ/Q{:zi#PoXpyhELxb5oGs54A0(8B4T-!i@bj+:9^400hMlaYLVuLj.LPypX*6+PFoiVZp=$vOBQFHyft[Z1JwmzN.6nBJ7gG#5*]}Sfr{*Q:[T7(4AY7KjYKnMVzzvA*49%J<Tm8U[[=pOXai{=&J<pkFAW+Jm40R}^Su+WOsVqfD8Eef]Z^nwDYB$5b9-*VKxU1z(Em<zWYR-qg@d8Wyr[oQ]9}6)M].QGg/*CX}:@zY4C)T&P4d6wHDx-Fj<:d+*/nE#Jz?cSm
-KYtptC>2&O50GeqhUYw/sM/pnKzxt*7E5G1.{HWv)/9EiDS5QnQpeLy=eF]t/lRng}OdY{Q/47$Htq7e33[l]}}AT)6{Nyf+g]r{ywL0)ZT]dQyv)}^)+@r3W(cZ@U4Nhj:Kwn/r<FT/*SC1:.RNhJ){0pbTm69ID6vW/zs?aLJ6<./C9HWz}RSyP?a)S>7W][lQ}l:1P3:&b?s{y>*cGlEEB@[c-{lyvirn!aX(RR-uh<Z3{PUXe!H>9Rk>05g[XfqW]&oWnKHI6jE2Jx0bZ#AOpAX*=?b.JF[Qoo]vbx-H9w7T:YOC^NDg)N7k85#xNQ=tPC5o[l({nuYAC{3lHVXbP^a]9dpPlMyl.A?xAQV0H.eVXV@2<^4v7Zqy@YIVV3?z23]v]PG<jp$Ll3rG)M&e-*sx*m:sQaM{0M.*$4]SoBH}YT>ua>MiGl!MR]%XGijj%0?Er=9s)N#1(npyp%}TLP?E2CZ3oM!-BkzTs)P8GnI)MWD4ZAM!#<OL}56>0o1=-IWvAo%YQHHHh*//yMW*5x89pRJDqOF7FNl0GbzUCj+BbhQ1D@V^0d?A(G-I*e-wea:q@UG.G+c#U(ph+d+u?wkgWD43H&@Am6=afpi**eeuZ1zMB[R:[yz[ds)1bg{9ra$GzJQ&Bu4uJ]/6rd2gTnYnHK++k=!-SACYVh${{o^ulU5P!*=NAV{ZXKv]/0C(?I}MIanywiWyxs!d!>AjNXUW5x20dhbq13nHWQIrdJuOpAIqA2O0oW4X*l#$f{o^vTS4hLs0DL#Wul#%RSo?zYd18WZ]i1&vLK.v)=+[iTQ6{WqU=Ek{X%2d7E3x!aH(yPJsn7s5UT?.s}gzmy?3<TNiDs]=W5?ut^nVU8*ojEOkBQ4ouK5V9jY/v^3y]!N8]+7/8[RY[f?6k8&B*5-DqUJ:ldFj?O2*1bOj%rh&fYU]1@zQN8SyJ4Z}36wHdFkaqez&Y0c-5OXx8e.TOrK}Bg:r0VA2Rj6AXEK9
-:hw5}lNzBJ){LVI[!$sBmZ!nu8v!=>qL(JTD@sXZO}e6i8a%MtItwRHRtiQtsS4XA81+1Wee%p-m=F-o&cF8PDmTQ+Lz{oGLP2s5xb6H5^Fb^c]0dhrqSxF+JL:P2{=wW%gGF)$vfglQF)N8+l^mK@v]hG!Q$=WOnu6V1ZD^1y{-#y5)>?1q[)$=1ypU3/gOtY+kS/wCpuhJEbHC3%/+YgZnu0O/nQYgYKJ6YxQ}&uW8J!T[=D0n0<)4k1au?lJ>%!nWQu?]/:FB#*c0PD8]w:rfz4U<drjx?Ch+^x2y*.O@iEF/ICpLW4ocJ+04:3MFza(Me&biV/FVQ0=@4iX]9Mp{RO2dYQV>A7kvbd1aDzOK)z3@5HfcWdT::*d1X$]LTsk*X(aWd]:BY?BY^l73oT=Z:e/ih]pyM>Ut/m8p^cF>*RT6]=M/&/h3yDZ-AZ18g>j=q7!lJe&Qe9&bjLY)layZG-m.+?{Hs@4Y]LKLfFVF1EG+jolRCPMZt:*<b@z6LR&CgrK#H#F%8Ly&RSOKl#5Fo7n2uTN<Vb!&025XcakYpii.7e-o#YouWT7eZCt@VE>zl#zcI:CR(&<euy*F#2j<VF814}/X-uBClFJx3zRWi+]azy*a+%.wXqwMH7U]Wt-qRMx$kxUg=Q^sH<V^Gbh<T[xeqs.OF/In9?dlYux0ft^(imT&:w-rK[C?}0G[}]YocncqcgHij)vG8zKdYYr3Ax7x(w=C9wEsZ@rA8E}%*r?ek0EDn/0b{^xMr--1u}+HU[arV}jcQe9(fNS<$KHw$f)zGSFt&z$.rLmj}ScGhOUqxoTre]UO}bdGIzEy<FeW<Abk@CqLzuo[rtAjBDSr&Fb?MN$%7.c1p5Aodn!(Sdb)U4d27!t4u<V?zuqW(zQ%fSS9)4ZNG1x6*E/s-6<?.(Q$vs<[52TO>k7A9R-0af<y9rU@3HZsAO/rOOF[HG+^pB*%p?AQiovXod/lvtw6FGeAYIWyj!81uH.&
-8?Iogi]6N}A&?jpNmCe%hn=bw0uwXz0PRKq}r1p$rY1Tl@aQqTv)M=&3J]5eAan]$4M=-r>=!Nm-G4R#U+LTC8kJQ23-%aa]UW[xEL4!-t/8aVTt*:Lcv##}./!eziiKl5AEH5V-F^i?vTu686roe]hG>Dx>}RkqF=7<h@.8I]v(*^:-kd*boSu}C^{T:g<1c+/>?9D-DCc1@3gs^p0io.&%8-lyR]bIAT2^s=D-SUV&OS?E-QPFsISX!Z3*-f>w7PqZZ](}#EG2aNG*snKrx%SrTuxsegU4}*j}Q5/w^4^}G*rsZHQ{1MoKxO?tqLGOec5%G0)[s:+9t2rVmk1HhuYUv5J][M@+($svxW?Sma(Bu2NkGtOa77:*aDP71J*}x(*DIW}Cog?03PI>}UFOj!tO$PnG(+)cFl(q=^)?z1o2XW0d^!YktbW76+HdTG1R3-5CNsF2Z!(cfA**8D<>MnUaujgVBl}-wnJm!sUnIQAuK-JQ/>A4<3qKNnlXY<]}8oaNmh6t6y:IA&uk=mVJ@301rHmB3<6UW0/1H/N)^)kd8$J{>Szuc8aCfZc$5*g>[f2rme[fM9fb4F5%0r6J%]oObiQ+uv6J2Q^rJV^<gcm.ZAZYZbH4w27%A2#[Z!]NnvlR&G1$(8DM.%$F/08nywd/]d}CgR2Ydr/3<6iKXwH1zjJ:VRJ5xBc>Rr<ypp/beKtB<u8{6k-er6b?1vV@p1Fgr4Ngt3jO660b9/[AFdMmPK]:gc<5)hZuCOWyod/*N#0{r8p6(M<oHQ6#Uk.q1-I=JhC0ngVwh}oAD?AG}>n.3o)/sI37t{k)lt/X{A.fx#J4=G9FY]KKcwvTJb((rC@JhXfgs$-vSf%)IZ8-bQs:!/ZOuip7g>*2$VI+[pz+a6sJ*V7P[v+vm[0[!%p{vHtaEiOK07{Fr0XO8hHE&+N0W7XB5t3FDmsjwi)xi:$22^N^:k-*b]bJS$G:If4.z<+dSDu/G[MX)2wE[AP#
-1((G*aZMfB>C4qZfB?rY((djoYUa{SCD26$?34.+ev{P#F.DcAQ<GhzGRWjxUrZIZFXCCik**BJRuxbsQ-CRwnv)+7>]i0K]XC)#5<:@K&VNFrqw&5).P1{{M{Vcd6!^wevw[I+?oj!YPL^cqvw{/p7o1?Z}EcsHdAUu/BUwymC=k$6hbK4MDp3B9sjz4rmWhlkTMeN:651%u:NHu6<FKoiu9Gz7d{btF.st!-okF*(e2]W4f>bGh/+YX3C5VlMAr<K149bMqAalC2F]Fwz95#!T9i<{[Is1Vurc9>3U@w?.Aup<#{gkm6G<:nj4R3>3-Eh4)!3(7HT=:95MJ/eqIw3?pa1#@KYnC+R80d)WSvIy=)t{<nMZ+}SzlCMJnI5$KgYD&D-#8B?vEbMlP1k&W=H/P.qxHW5)H3KfC2.w%CseWsy?A}lulf{>R2!!S5.-Pi7y$PY3T^!<?1H}9h@<l7byB]n0cpvgiB?Gj&:uTi.wr#rK1>&U0R^HMr#3mDf$dtAgKLyugbof[3)H7.1]HDHf.MU$z^f?&N0Pa(gr.(}A(M)!2!}tv9!@>qg3x4y}v1:}jQ:qJPc1DJhWYHBi!jF<2qC<<I4dag/p@Uo3[6?FNa?Yag1MsG+i:>54rENt.p/Vr)?aRr+SMDAgOCQ&6v3rFkxcJ*&19/ZLKVy@zvPuK@RKb10So2ZR=9KU4.O017%l@#l?.hvOK6<oFX+}gWLI.W8!jl%vEudeXN#Pr=oW[:N*Rw2Lx*bBf7rEpvo1?4e$Jj%{H-t).}YsLQRKFyh%g+mf2mc=GT?ElYbQ.1rEwSs2r2n&5C+=.O(b{nMXg{(t$=9DfyX^1:N9r1&65iT[Tg+N^6YI$fmYsOPQ&kLtnYQTmJjcEX4))c:NfGcR3]<#aIZb6[&DtMda-&#sRqXyf<uajFhB>:USS8iV=RS6/Q-bQw}$y3{UVhEXNBy4f7scQbksq0Vw}BISF]ecm=JDH7Dyf&O@KhtKjd2K
-V2aS3-bT:U.g-cuBXthwjt}C)wz88:jP5ytTR8NDtD.$e/KiAVzM%mjoyC:SyMeb-7jSJ:}u.uI@dEe({Z8uJE.%{JZlu.b2y/e%ES99N0[?.i!QKr#!(MtPrCD%J-xwx{=i&^>oj-y1UFmdA7>c$w=cJF}^lxVc@KLawX^uF16FF4fKLLX^s7+Jr(Wh=SS&@gx@J49-+dN-DwqW7{krmr6&}php:j*I!b>-aYxwJF8EB.A(8#OZ>h&<a^gCE+AwcR$3.n%K[+[e@C%hIo9}.#BL{+@%+*WZtW<kk!-N=LF6:g&HKg%m*8J^&&2x[^}OP:Z7N-$K:UcQ9P[)kubr.ZyQ%gL]}oE}6s=@Hj{:/l*J^+UJ=mm5!yYTaaR0Xq)a]Ky$trRXr%FIUCs8tU*Yh}$92J-Xu1z+/e62hYItL&}>U<f!VrqPdq=)hwgqWZNknWw5WRCxn:If^^V6jJkrL=K-PHssIO!Bz>n8:z#Ddo@AmTwBe:VXF=B[>ahAbnFktvwRIg*IarDLI2WMPr7H-oG.i?>O/XME{mhRkjIQc0vXKB)9f<dii7kW*2M.AihrbJ2l1UiWixuOAidg#O+YI+0)z{js]x}xX/Vl$=MRt8!tep+ej/<j?+d->ZUB%1?$G6W@jx4w9[ErqVd(0ukM17TSGDWRu8/te^s3suPu(+<fwY-CZ<^MGZF}.zv>N3@UVUzp%sJ4.!HpKZzP>ST]qvfqHc3RGj@){uE+@q$ZthT{)Fz?tR-:U]50Fzb9L-69</D[MViI@B%DE&ipsM:J^?gI2!^S!XZ8PG&ph7PQ3.l:5?FH4<2hKM?a[8145U<H?$#4Ear}?=*3lHi^Wj}Od(#ApAUiaH?iZ0LJu$[:8LphP>}5MarEd!BW$cFa2Z9%9>EKUMKvHsej8(xyw18a0=j4LrzT$s]NdVDPOjvYkXuZ?$JmC7V1lV>^#RG9V%x8%0<2MK07{{>3#Heq9PEgDMOEdE]7Tz*xgP[>?bPt
-D*#Nvl?@{1T?Bu?@1<y5W!rN5x.(x6ZT3[(PwZWvHWim)7KQ^T[lT[V+d-Lwe.e(i!z[:o@ohWvkJI-Es@Ek3iS6/w?!gr-]0M:FSlO:u]xT3a^)3y{>c]3MWP7JM<OGeQx4EpK?sI.f4){pn(Hd7S6w}g%}5Ya+ic=P7j4Fi1?GW+h@0c6$Ci17r}UQNX1j>jSaNqs6<WU:}TGxU*(QDr9aFHBl>sfo3}!t)#<?E07uoN}gTYCUSdMvm5l{gtSq^R2[P(PM]!78j:M-0o+ZeU0cQcbLJbDuW:}c0KZBM&5</6XIxuB}qMZgXeH<uDLmh&S7MW:WK^ba^PP/9I7Cc&mLf/GRgK::UNuxgj}d=@M]$?}bEiVkJ8(ezH^g](.k&xgGRt[JMN-a*odIz=2p$m7+K4ea*nW4b2#ne-(}x-cU<)HO0i%ZhVfYTI75)jx%R9}My+fMw9zq>@AC&[:$Am4>XJ/z#rRN.-Vd47q!6TVtl:jDHy!X&]}mDCoJmE(XFRWg0i-b*Jc!oE=bJ8//Z.x/!d%l}d8z?WHlJ})bT5s4NOjm*e5^j@hYvEZP4E{N9H8O1*o%9?<vDEB(]Vfum3T@jhPwSJ*n$YJbSOFarnZKGKYSX9gFfHz.5b%/scSkfM@8$^m:Q{<5#w753nSi%gfPilg9LMU([PAI6Tqwn&M&%M=6I#@CZOV(y(HR5S?i)@$eRRcM[Wx@1DFlh-O43=]#UBefSWQpoR9.5T9fC)FA)H@.XOqa]cV-GK^23cCMv/jTurm:a}SH:LUyt*]KgR>mJkeaV[$twdY#:g9)iYPb:s@nX/rDs[H-@FttpXkVk2$KD.^<So=UA&/PEd&//KTb:LlNoKZPovAoVs8&/qj=D3pjNwp/K&uWIkQ/>BV5(8t+5=Wwl+@nXunLh85+<{:WAftky^U[:aH+Zp*fRp:[JIXjuxDN3pp+hzyi!Y&#<7ct7+bHEUnY-5IJIJ80D15KJRCIYDq6d8khq-J1
-c:>d9cI(sf158iBWY<de&cG1[NM&{7+>?XjK^9h>cNi^kO}jTjG*d%:Suq[U5}riBEk)*PWWwB9Iiw4@6qcdW.d$aiPW}j2a1fY{2LnvB>sGlVAx:2oS67J!E(j]ojHium[kv$8MXP)R)JQ9fBr}-&k:Jn#6K=%-vnb%lss=Tcs+FJ.snSQar{?:b[T7(3AY4byH/fFHOxtb*xtI:vCI(9-nY?!cLAX>qy:gx*y}J?%L5-CJTRfICEHpapny{bDb&@jJ}JHnW:r40!<@9mEpXRCX)!4CSpTs[]5sS1>/KQ]RiNV.a9!y*kY3F?*@2txwWQaGy9Q8WVW?mVzUaD}K-(McZ[HOHL[OGKYD+r<RxK3h:hwq66&*<Klbn@Hlev1#S-1FOAKOoHW9B{xg)oXw2pT>&n/qG-qDMA7PPM?-UZk7M}jpyROT=J#6PqplDiwjGXLLNR{!OfEK](#zWZA4E)c!nkq:GtCWlHO^YnY(lO<#(Ya4K$AaL#]<!>SODbgqeElCN}Ljk1So)[}UryzNch8n${.tDMM7jkF/M=ErM{ZHJGdgQWGn3Dvgr*@#hOzhHV<n:&n*bkKBfrj.i>Su<s5Jwtv&i2BTp3v^t?.Bn5LC=TD&fDpCS0Os^nzSElQe3b#>M{PK8wE2mg/GCzkvD?/F/Iou0.gJvWK{0tJuYs@&^Ph^A4ol@Ih-wvr8BOpR.C6#(6@-hef[o:vvQpG{2{[U6T]bWyIK9!k0[92h)19t5t%9CT^m>^X!PT18k<IwactymS]3$FSa<O].K)*#bLV{4LBU:b5z0UTAP8B3*]*b@?YEkku?SN1uvnYVsedV]BNEI@FFT&Cb#=q2VL=G$Mt{iPRyO^{vj}{GNZce#i+H%amj<tv0U/[wUs7Zat8>R&}ZI)5*aG2DUum8P$F:I^h^k{fwfN.TwdVuptDEi[*pHjcz8Q#9*(y=9x}qPVOz<6Eg>}@boClf1WyH#)EtyL6hSu+P9SMcsNW]rvJE
-Z.<w3.-C@v1L{crEyJisWwgX4n#bQpdoR#x(#hR$0^*:!Vj.EUL/cJ0B?&*f&UdV&Z*-.I5%[I:v*Q*C/=>+gzimo]BEb*(X}]w^Q*nWhWnOjEHI1mMaIfkg0aVYde%aZd6Z3XcdX[94vO{t^S:/1B<CH>!QQql$VcDj{^I2+j/$R%EQl7{t1#-IyQ]LTJL*989q]x{It8k#zWfOQmlNa1C2hi*WbO@+CwN7n7rk5d-:=}yZv!XjZw2hidXKHz4EE-%lAe&w[x-JDjEnC#Hjcq7mF>kx0Fl?nuu&m[f6Ix$-w[fgUUW#({(N%p=C9&01/wd6jG7Usf66}*LCsvjR2RZ{PezR$:b?Jhipj?f4Rly$koOcoLv#i^YS=/{F31=n+JbPR&g9d:6MQ@rBPM[8lz1*:wut=g<)<o*9{..Wt5]on.oOeWIRUzB?5rbc<>NsiZ3}kPe1K?+ofQuKg^G]HlnBZ](a3{8nj}krgm*b$+5#WIqq1mah*I]4UA@}%n=<w!L2@SZ[..w6q3C:N2yB3.k/NsJX.4VzHv2R#u!h/4fiyb+uy:e&{Sz1{F%4TtV:ci{]6Zx41scz0PHDFty!1DPxr3&></9B$5U}?<&Sf[H.<hx&ig!Siey]l$?K.b&.+aeR$9*nP-Ubpxt@@>w-vK0S<lGNIgPd-wmRS&r-s^UbMz70:]t<CU4NN>xIN2w66ee0N4y+!2y3qZ-HOdAfXzVPdOPt>b@{*psVVk:+/C6E7v--a1g>0-@x2f/*yHM/xgdOZFT?bo/<Pm0P?lzLG#+E=Sdo66t$x155Ozai+&I9ol}3drT3(MJtjWIuS!dPq@yj@6:IFgW+t)/i1F64)fuZtnxr?2lYoOyG?dk0KOBtt$KLnRSU?zVw!L)vL(kaj*a@8$G[mE5dFHzh*.%G=I:t^z}!%=aB1/<nS7^(FqLn?8=azZ9wfc}@24A!E!fe+Dy.*{NG[y}I.Xaou.qN]^ooH)yEtCOQUT>@Oqvv
-X7*@T?r<8&.GU[KMIPC8?^a9y}>)4}YzUUO{9u^ob!8ReEwMk+@&3Kx*Rok/?tW8X*RkT>jr>C2P5diq/CWj3C6HX0[+v28C(dS=E)GmRAtJ:SX:War<xH0[dhUZ!5jTwlEA*Bv0[r#(0djEU%f>LP[.6i6yzR0ymi3E.HMy5y.X<7dAx4m?h@X^LSt]{MZh+g4H(jluTrFea2cT)KF(??[(}(dp4nv-?zQ[5W2xnMXG.%5KGk3w)=ukl!K]@e$h/$)7(%96Q)I@P[/5J%$<8Yf/j*7$Y5!9L4B/+FF7#AbUI{9p?YaYs/yk>Y}2O0xR7GXiO4k6#1I{Qxag$juG.$sS-Q2w47ejb0OG*/W+pf-Ebv&.npXZU8Fm-L#BTKlm:vrh[fxF-XE@zNa^3J(^>bfCyjL3@gorWmB>ynuYoni)Q5qYo7M9yyTqf1ks}uwb]Bf0Us[fz}Cy-#3(&7gR0BJ4/BL:RW@Tbtb&TJ)=<N*iH8gX8C[PXoe-yn<=t8zs<1XBSk4T/T^5036iI@cE[g9Kr#OL.)9KSq5+E=KQaAf%j[MA^hb&m^h+cGE@5BEepxu>unFV*7@j&k9m>bh/i]}RHyIcR=AtE?IgN#O7y4aAT^{(Q=-J*U(NgG:Ih5S>b10&-PrN#3+Yh/q&{d[ZFpuc%If})xY[vzj7pEKU9)dh/Y#krJJ<&ui!(2FPusHT#=W7B-87kzFJRh$ZOz1hF
+abZy8000000@}Rn2BDn}/7A(Cq4$?MF^vf.u%1WB5ht.K@-[42u&pX6PBu&vU)cpcAT[m+bOt^31EbM4asK/<+Sk3Ey0uVQ}rbFue}7s/9*^/H9h4YMu8D4k!M{.Y>z2#$p@rlc@.5/%!h:IMJKJlCw/d*6&OLO(ZXL(FpI[&fbCT8ZR>ei4Yw4mwCJfZwO%>^!<bHf4g<Ra!yN9Mn/IY2UcjVfLxNc0iblv^b4/-$3eC#$e@@7+CK+oF#c)Nps0nfs9uU$0=O6muVE&<]u+(c{}}-uUe%3Kt9j]sQ%RbzCuWBdmFY0YA@/r@iLMyEuM]nZ5Rl@.+p--}f9XV]e%WutpluRm+(?f@1LtKLbAMQ{8>LLWA{b{AiPsO#@W0Rcrm*xS33p*KE?M7U^M:sx&c}YY$U*#2VMWF84e}-xi=h[d[1XgW)Fa4k=jn#O@**nl7xc6wf.^KKM1S&$V!LR94?ozrRTEit.v)YQCAX*BNGKGK(Cz<vDhz2-0x/k#FH[t?ldfo{oJqJx94{w&qiq/qBm!glav6vAcWqOJ!efuyhIDSp-8M2J]pmbcCa@#M<J5XibP<KwPaa:9zT:]a6$@YYK#:/*Rrx-!hBZ{wbOo4R6+9H7pPWZVdkEk?zoyMXJ0KyvydM)kb-{m@qOhaCiDns{lF[lRzK!OyuBj05)zMCLhnLgL}e{D&k*z-k5%A^K}.V>8.N&NYiYcNWyr(+icPW{H14X-9s<:33Z1B?133Bk5g:4x7IoCo=zqF8clh6y$*BOT4}KY&Nd5jNg]&hatn#l7conMfHl5DBGPHx+nv.*-0U%%n8f{XWb5%*<}VOhqKX$(Yz2vf$m9C[WFH}zso!}@DzN=Py>z9JRo*Z<epV]AsZ!3K$e]y[6g/Nmb97oxc2q[!AsBbVYsi%c]ldt9Eg!#ZIH$0@%[H87U>usFt44We9ptEL+jJ$Upzrdt@Pd3IsaqP:J)S5(Rd5QjkasQO[Xe/XRLMIDF[=V.f/i1
+{DRhrcW=Ioyp*6J*zPxt65Gg{(<M1u^/BFI&6aaG9PIR[u&Q6RCnrcW@rr6/]lGTz{u$fw&eXwcG1@$Jj1uGq}t2G<x]}i.xGtlyDndQRM*yHUx#931*XQXmajy7mFCokwaf*Cikd?cHHJ:&.*Sfch9.WWjL9x4@}n:QzAT>XoxDA4TWH.TBm!Eo+Sk33b>-g3E3&n*P}J@2&<5KD!M42kw&]u*lO@eO8lP<(i[I}6g/ebubJtdQHJzXd!2.w2Z.rw/3>!!OsZ-OCS{jkBpA<+rM{:vhr(PpU3>1OV&a7@iSV/&Fg6)k#&?3!D2LN($X}tt+3/Hl[eHca(9@C9C@^56nb0D2o6kCj}42ECU5kU]uPM7FO2l*1N9we=Sq^*C)KeM3*#aj7U]JJyOK&r@nO}tk&@T2?-*6ws?)6s]Mva}EGAl(]9}:Dz^%KmRP$F<9:obZ^:{-jWNQ}EMTP/*p8.Dp+ZE:E*gh}CTpe{3(!2M5!.kE^d<fq(xTN-&2%u9.(KYha-jV.ze1JEa4V3amsI*J>^4fnyT$wtg<Q4@1A3B/yq@9LH.qzLxF>HsH$B4:)d#nVG#U.6tIgzs)+z^MeNa@*b>}d(RQgy{(6u[=!5YLc*Ja9T]B8smk}0=(Fj/1=zC]%QrD@QX1S&h^qek{]SYAy?fm>x!D7fOcYqtY}PmeuZyfBERbbs>]OH9^=?UoDyplrIo.-eG.SN{z:?b(Y/bpg^}*v4]cMz*wC3@?uxuAzv*4Y920FX2FIz^AwEy+F6IeIf#BNM/mY)Tj)[x2A}pS)3*mOZ]=YQIavOw2{$8@L!/9Ip>XAQPo3BvfgAL=pfma)hRQRYIGy@3!%F7jQ$B[Yv!jCuZYvDuF&N:.BU4BuR](2KR+/U5T&vU5:g0*mN%5^@=i=msR4c^vR4nmsSs<UUOLIS:yh=@b-XnR][%y>>gHg}%P%poc]LsiNjJ@:dTGS=JT3m[g1k<(F5krjM.vGjRcNR9[LLq]c(+Z
+k4N{$0*Gmd]O1XP:a8*2?kxT(ju^SB6YC$SlJp:)@iR:k?(0Ox@Cu#p8R0WvuD?DUC1[C/)-Y!V>kK??EBpt$gzh(?U1{+IbuGi6agUp0Z>.t}3i8RDah9pL)T)2Sads}>adzOBbOb5ZL1c*Cb-!ag@Q87<(VXT@.UFdKR2Bv/(Ta[c}sK#Ulu$?ge$.oNg]zZGg$*x7YZ(Z#g4*V^kb6z<?IDH8yGUIhE0?*2!Y7aR41f%c-[$v%.l5t2zE6{wo<Q0V&gx1SA8gHrXlw]u}RToEHV%5u++j9[j80%2gh<H$WMxGBvKsSs*Lr4i)NOr39r5%P{-oQ]ZOs1d^F{%yaj=Uu.!OBlvTqPSx].Y&=<9qBbLOU[{DIqTJqEhJ%e2OCRYs(Go}@l?Ik=?PN3SC$EU^spYS3/9*X7tXOqrks2$<8Cal8tJK?c9c-k*):C{v-[Kh]r0hJlP$C:WDuDSgJ<RS^=B5$e+r&dU$tyeWnKzcBS+Snt6:)@1v)Y)DZmsYB&@L:LWYbqwLZ4}^o^Acxe&QCEtp]UY7kkbo$Sd#N%5:e?o+O((]Z%8)4s.uW83kdHR7gK3.8Hw(o-E(uGnV[zcn(740#@2MZjO@?Xoi9?Wm)XCg?(7mKLW.e=xZ8-PLU3MvIY5tWnJ^(x#ZBep/4<Dlp(yJ:wR>JJyqcijC/6JN){Oakr{xu1M*-9xE4%7OA!iAW^07pPB@X*Gd2Yf*]{ZP^<k6f3TR$5sda?tvYxGH[34NY02qRQfWr)a1]@ZJTH2-{p#]c?&MEIhEIn8%LA59lns:dQT}:d:Q2:P&8M+G5-z-SHA8&zdg{Z{>6F9&+0oc36-JyWAEx=fWt79!sgU33^6d{=Kxa<1P6Ab@hO#-}uMIEk>+@b!oj1tQ5!olUMG1*kWD5dzQuiM)i^KE-Jz(R0/XwqZ3GE<piGD@ulIE<EhOtKtu(DPFyRH4N*pYb8P1)WI3MctQ&pE)i6^]zy$F46eHQ2ow>T.jX%<y
+De^k%Wo1N9L[HlFD+syg.Z/wAurI8&<=n]MIKlSM(hH1siu0sR1uqV%Ef<)}eX-0Hh(C#j>g]p!4KW/kYMxR9Q[i=@pp:IF^N$lkx^ZM>ciq<X(lIL:&0%.Q:.mr+q6jG)JYEvhLq}&b&&-KES5.^-Lq}<SD#NP9OX[qXIkaa@a4LY{R[{-cLgme7gD/EdhFH.lvg[T6jE>EOO^g@vT%zR.!$2fi?5aK%M]v+7*78eA.M/X/cLkdg/V&zJFgS$nnuBnAP2t{/(Q+[UnO>Zo5{>OOT!9G08wlnGm1E{CenhRBeh>$-/Ucbktg)upry?p#GLQrk!%*09D20l1bXk:&9Y/]CQW=ZoT=f#1ZianeZfApgq%Um]}.DGbj=w$:/0B0}U?gDssNtB1pK9ZW(@!dpaY@LIODYD]RNa{)1j7C&eW/Sb/H2=7*5]VgY!cXJW+bShEyP#i.$@mmy)]k9MshL(^ProHVBnu08zrb#tpW!2Zg>eo)ZG4EFn[/<W+wC]rdkqsLbkvEA21zf*TiAyiS)Y[:QqgJwn:6KY-Z@aD8ulnVA#yQeWV*G}O0(bD!w:k@MZ7e4aJ#M62-$nj!5qH>^dmlHV1I=2gehz%9Y[i^aM!Dy[i+90nQFjh2{<k=L/6fjtW4xtw%gX<!h.c:%i$scL3VVfuQw5ZDsu6@WoOhh2[G(IerXEWX-8rZ:a[BzO^h@^$pi]XsWr11#3hQTn$1FfB.zrO#Ud<gNO1{J5l6Uu)CKCJx)FlJpef7o]p-=M)[}6i{xIWYEW$rx-K{#zx1Sg?3A{u68wp[0#MVmPe0hP-k)n1U4W(Ca?G$)<&5T*vgA+HO16D/u)tOd4px?5pnmnocovEX>vtH*2F?)/PZta@B$hktRNwImr)dyjSbVRcx#DVSv2*o)4pyI%05EdFN1WrkOSd!Pc*85zMD@pQ4@{+B)Tdb8Ho6i<1/8gypIA-Nq$5X2F.ZTU-Zn:*GrdI.yE9l7K>k=!9ZOT.]EJaZ
+X?id+ME4H5}o[6Qgf?Z{yp+Dv(^n%.4eGO=4oJ/2:i&k:XXxIOW/Vwxdq{K6aeunu0oUo2W*En=+<tqeK-eN!67*C!:Y.7gU!hu653A(m/7X#z<M?5#o5mxs[1e3@Hs=Ct]4RK8DZ:w[MAOTkj+Fi+R#w]sZo<:o@%OUN%ejojNBDFpN9L/t9BRccM%fFQ)^APKEiFnSHs+]cZftwKB2PuTgv^j(?uGdv</^+Auwl3[Sk2K{8MZW)q0g&&=ET^])j/cVa8.d=4unh[Squ!({BQ+]/hywjK(4Ht9KZw3?>EO]Ud>u&[6DDg5bC[ZthMk[!::k>E&)Vhkev6XEwx96z%fZ^uCh0<ytN1LUta#<h#r5:(Fi6C<3gR*nb7p.?J{^[@rhPR570/oWA/!>=SYIX*$#:T%0+S>kf58No/K7?)>XjrR8M?H@0P[g6ZXbW[+:2f@SQM0%03u>2oa^:(WjAZ[^sV21PF1>}Y--hZq.e4[+&Vq/u]0wMFI{>UFiChcxm&}:MCqTF:RB&SC!3T/rutYQf*bc@s^rM8s<#eVd$1W9.QqC1c8y}7%s2G<%MbHx6Lr9}bL]*5W}.8)!=E.[83%#7ux%rw3b@A3=Z#wcXbVA@O4+RZMCQCF]{/mF?WjeL2Tmy]iCH*x@]<HP{SP?@+{7:ktMB@3=oDV0QR!uUd:.7RN&QQ[eY4X)vD*ND^svsY88->{H2D7St@sseKk-WUlfyC8@d17zW$<MourO&=Q)vzy}*bdx}vZdV&+@xafU]22qSfut*7r:XPgHTWX5hX^*ruO5Ap$o>5!Xo3]}mR]KKbiA1HgqF.RX1CrxB0k^5j%067YDm]+p:Zko}6c}dPh+u$ZD/*&FXl2h$A>T2L.6wyjF*)=W639x<EV$C[AE]:h*=707q^U9{SRc2q}zw3Z>WD{.&E%3NX!X[uS{E)5POE#:nL[F2S3r3b=p+u}p}G==}/JZw0!u^k2K6=iuSNPH:NcRjO*:42$-7:dr
+.hq6XzgKc7R%=]nO22FqwrP$.LV+W{L5cq!z85e0xgU0MwUEukwLos2STD%y@wO!47OWkKH%yddKxh1nXp.nWl>iK#SxA?sNwsTza2VX[*INT(Q<uKxC1n2y!f0B#oheq/Up]3VkJINV{)N$Vfl3+g?oOW!CHuOH+2?%(Ia*!H[VI8l{EvEC*Alj[bR?}2.N(P%@{tF<}@uJwKJtT17{Z=Du6rVk(KyVI!io<6zKu87SP0RR=#$7gco8$4K=<Rw8+5!/<.tLIGsm}7CNjdK80t<dWca<Sw%eP9KX6p2+gIUh-f(?zgOU&Qr6gLyTma%^Ia*/Jzyht%.0TO>f4iu>Vh+X?HS@fyw*j$[]>+Gqp0n!S8}yJO(Ft^!YnCa4Db%[)(DaCm*NK^5Wb7O!0j2!S]y<%fsXSc(EBAi]8.D/Smvl1wKZoOUgUW2:K7TSz)gz&.W6*v0Qa:u7U$c7/OC3Iy]*OhBnO+(jO3%Dl}^/DZAPDBByNSH=!%Aj{Wvy^foPX]I]4!EZn+Dl<JaF%59qvQ}vIWH1Jwl]ZC1XKa<!ae]+YVwyOIj>XDec6O]Bu9U)pRL@v}%.3jtT7o<TkOMP*Qlz:.i=cfRnBmwqL)*hO>6IA(H96q6+)I:Hh8af^H@>wlP4/:ZGyz=jMtn))dDg/A0&sFEo*-Qy-K$9QqhFto:&}t2[X-*zDn4}q}fz.fe[/y!{8qWll8Lllyz.YLZfZQVpPx+sPkxVDtyUT2^P:S9%s%uSDmGAEx%LKh&&1jNKUYYSfh:><[nN-D2:E/UlFKwt#0&(jiwoB*1$qC8TtiTch?H3d[PDK[&0/YO-!Al}m9iM}]qEjGq(8rdPS2aK3f0nB[spTmpxw=SSFE]@MYI!<uQ(T0J3asQSLVCl=Y/sQrPEqlPyM=SZ5)!{sBntc0!Lym.?V/18sw+3=.lf{/5sV8Az/qW34/IDHz!xYQ@0hU^ew=SAtu]]dAS!<q7whvkt)lqfHKe+UbXQy-x(
+lXCmf>AAd(>:ujqQxnOh@dJb#b!(U!THtR]uc=^Q80k0qR=fgzZe&g+i/*CS0}9y=t=EGkKIh1+]nj$8zH$7Hc&}U2j:V>*WxP2<b1W!cim1}.ub*jB?gl%PjTds+(]0m#/s/-5thrSrhaR[IGLQtG!.sv&ta^C:Wt/%!R)XA6i!%-gz}s##2kSw1-bhZB[on9.uoJVd)a5C]lA]6+K@M72/0A#:FXtUhG1U+MS6rnl)6TikUx{$gj<EGtha.%==T5+YM2f22wpRu]-9yDTVc-q/9qXhbM]ioL{6#e*-nqD7XQ9A6Nx6#6G<.6KK@Muv&Hf0:w3gO4P1gDg+>muJfXsJrX-v)e?A]1GdWeEEKfAI]r37BizZyMFx:j%Gc->pVU:c:..DEK)Sx5^:OJ/Stfp8Wp64i9<?5H<NGVHFESH/]iLdHKN!jYxQSM/SxnO%$.qAJpezjWKsM^BqScAdO#eIGYty&&}:n1D]uBjxi{isA?QX5RtN-Yo}SOo8oViD{c^)N(trtZknh<B$OQ/)5]bO%XM[*I4c[g(^@QPxz65cWIw]{WVl}6l{nw.zfLT^HL=-2HL.(7F-//uIC7KkBifY0D8s<&z^[zIX/dg2qqQUB9Ib9QK)^E!PL8[l4j3z*7PHCc<(XJ@eWAzv#osvJg229Ve1e}MIvkmMWxi}h!Ex0(pF06SxiZIN[rfyR(5EkNy*ReNziRogzr?J6)e5RWpT:oX*J}9d)6&z3E#uts/TGp>96He{gj(xA*9tv(OsT1v{6W^fbDBtHsZBQ-7^pl&p1&KyXs^VVStyQI?5iwa&#m8f7Ve!CvmLvG<.22BSeEMNA!$D4)jU2W:@s2!<xM5uiPip(trTbiR3mSe.@fQzAVCxS-hi?vN{B.-G!z>T5Xw{g#hufbz4/e1}onu(!kTvr-Vy229+?T/qvXzLX%1=mBJc0e>/QGU!Kz=UGM)fRGb$0P=HCCgv3K1LnO-#YShlDtW38p?<ISDAHKKc
+G7{s{xvdNhk8U>N2.44N@.yA3)@Gs}xnu3iVa!DSYp*o4@{E<u-[@0K-[}Cvr<bNCJk4r]Tx}IY7>k[Ss?Poff-I51gJ>RQ82Yp%4j3a[<+7t*Jzjrc7HtHxIsGF0Vw!q:k%v8Nw^ciHHVff(b<v?=4Kn$T6ZOhV<Q]MQIszPPeqYC69o0X-i{xIWWd@oEwbJa(ul1#H<P$=C68wqU[SgIk^?{HsvQM(D/bjJ&:Ng.eV7jC{AVBl1fTeO7veHYdW>W>NxGF/B:n.e4r^{bfWyf2YWL$RsP%!b*o*Pp[*Ff0kjoGUL95mCX-q0%R<dz2X.<#^gjsxq6U9Nz0[k%5pOLcG:[HLJWES2#k<jA<aU+gp1Z{UFC7B4g=)oLyT7l(oBC}ys>YZFh1YjYHXZkC>mU3?SxGo>&JW-Y{Sf4X/fM/@9<N>6KyZAg8}B%MLp>X1W/JZq*/E4NjfDSJE)Ws59o/hfdXSh<Gw=ThVVDQ:q(}z2)6S2}u1b<@HRXx1CfuYfpLUt#nRNr}.AoVcgC3I!M5?AsQcprRlZCR)bcv)fI+s[FKx{5wyuXbFQKCf=#7jh=HRNc+gJDbw847JL0UQu@t/xembOM*&*mO0{%.YQa+MlgM{}js#4e{Xce#Y*zWC4Cb1^?gdL&^SJo:V1]zCSqQ@yM#cHB?BcfUSk0ofYsKrZZk-4GdEsqajnoP8o({ns:A@Z8(Wc.oQQ5^PyT}$^A^]g<iZgvR(v2D)Q&kD!{*gT^@+yrg:GFtp{=56tU%.8%@%evEQ{:ZAfoI1^3eTzUPPd@DPePVuWA+XXZT7paq*MGiJ@91HA5KDExsG&)z(0d57IJW{*j8PL}6B5U3Z}t2mzp?]hB}{{{e^RmUB$>:&6iplfvG:+yS{=q?/1Q29Nf{?(78kR}A9+H^WkJBD(?(3>(c15}BoZ$4G2Vu}T<%skAU(oa]=<b+t37vV9Z@7=@*p0VhthJE.]i8ROl-muQVKTvF:A@Zw<k<@TE$a
+lh[tI>1mtov9AFn0X0S<ZS?(u8{c!Yns9Wc2>L!SsTwXO?<7Qq-YyH^oB$J*/8J#j(/UnnENb}LhLw(r!u%IT^]r!Q5wxyvQUUKuyW.6BrcOu+@2NyTjc=MRVJR+-}/LdnEUknY{%1x5C)O+q>S77jGZCaRji-#gvS(b<[=Ms/<V{?cL52t6g/>d].Ue5$B#<5$2$0AcZ)>[/E58erAQ>QN&2[nP1g.u3)S=*#K)5-?L}@CYcsz>l4cq<IKV#Z2-9%GzO.-SQ*P^dgK).y00gRjiH34l5>1PB>hjlR/*]C8bO6nK!Trn<@)5WGLb^N<JVNab7l-H7nvzxpu!rF*Yn-^6M&?A!T?yR>@d+$/sGuYJ7R$kjE=E*]g6v:wftArb$cAbVAgcaeyAtc?x5&SbjG/RLCCCy-vEzb%Zy>xl1L1!:[qd/FZnWnV/k9Pi-=xr0}+0IJD+A=VLr2p^!X]l-se<}1$5Q&%{a=UXeAr*wuI5:2{om8*MgEpX2kawjDz8WqBp}XQDYq57h(q5I]m[(/-Up(GbE-Q#s[IJ*su/pMxX$thO[1Zg1B*nOy5$bx%&YU:b[1b16W)fA*nmWq<G.FqI@m.+Z{q:&:aDY/nfL2w[YcKqE!/fi3Sc}LS*+o4ep@z?jPZr2woL#=5bphfwh&tQR+HE1V25NVe)?2c6fWwNoxWu0V+MG^?a}@DKL^e[TH+*A/xeRN3SDYf!skR)}5$bA1*!pL[FzD4-sF?i}wc1mTpmD%K+%W0*(F0(PO0CM[hFLyA!vSnMCnm84]n}<!!rfNFW(DEbxU9>N0C5Qn>Kh<uVS:(2}LJv?crZ?6I.G5+be4arx0{(FkHgQY*9qRYxL!K#c0#n!vV:6Qipj)!(wKj<6v}g}h!Loy{Sq<B<h/?(!?&(HTs)i&yDoHuNip/4<H:/BLb+[%7%t:9iQSe(-vO438E>VA)uQ6<+rYn?NDYE:j7l^y&<:n$>]Gms*rQ%y-)?r4CXg5f+tiTR
+lY1G!LHjLovlK>I:R1+My>x=n<=1icQE:?4KzL1hv[u2::Hh6PLF=KjOG/QK??8]0U(!.U0UIkBmJ&@[i[gj+yCjGlS?s9es=5=9t8}a5A^sP*DY5!y*#Ak^&$Qp(<7mz[?=vW2iIa-^A&F{E&*^XZcd$98UcV9J3Ii!}kebQ*/a}Ut)j1jhd:ksK[Br{9A/m02Thki&DAjgQT5<H-*>Z8GtlPhe.<WS7@I>i8ea]%U3BQqyG*XAbIKmQ1nle:mHBL1oab6Ep1zIfCB#xnp&Arf[SH/@jMrBzx]>E681>HL%!zkXLw!]#{x4i)o/r-#.O#0H]U*^z!ZNw/mO#7OkRe?}{mA?!.nLRd!QBI76/lkX-4xQR8*6i4l>]3HlSgGYeHfsY*Wm{Y&Wu80Dp(vF7+{SYZ)A?K9Fp6c41nu&kS{f6!kFsA9f#jixU?m%Vy=$.MizrCR{De-:Fcx+)D$<SBWPrI7m/dfa-<=8#7A[j-9(98>&qgar?)lterY%UsdZ<U=bZYHo]k5MoVdjnD.0xsAUJBE5[7D#$wlk]3U<069ZLly4?Mwv7{X}S9*CSg}X7V#@OLN!-{8pruF:o*n&<NxX{}9UvTdDCtW^8KX5Wo/eLi&c=(!gN>Z/Al0.b-ug!Bf29WP.y>!ND<9K8@@*o@.NHVSksLci3*x8doGWf%ru6J)sGY^5]K=!X<tLCBia(@)71b!IyjKr6sei0&zzn:X-3QO#IckRG#w6ND=^owVu^og?+Bk^nktjr{D:cJiAZBYFo^4z+]b/zc5HztiJ130FvIgV8ZqASTMedLKS<+ucEEHZCh%d:!355tnb!0w2hofTzI-h=TG4}]^>pXxr/.Lv6JBIgk*02:<yc%jC$^rxkVa<UTA06t8bMJxr^>:@7jex17ua(]=1eMWgg-7T89bS*ecCdr)K@jyXs^pU=NH}nSBA%)PF<[.ceSl73%.lL<L>NGprJ/(X>[(r.(/>oE5*V>H#?X}NKU[pXLoP
+YEmrJBfzG46W(hP4T$0oDj5)=<LAJMN<y)4QL@YY?j#6:J1qUh5[NPC1c&s0Qj>jhOc4G.p@YF@OFL>cK}kX{BZ78IPmQ0h:$kO=+=GpK@V9?rDD]SuiaDJ$E]SB@M7ubNY[{*R<^s^3gf?2JjAW/jm+sXJtn.>1t*f?#{t{sKN())&?<ofUD3Wi&}kSRUji*{hnz{Lt[zrZ.=S$8L*YvzysS4dbK&}98RT@AS1j7uGCamV*FPHjf>C9y7+}[*fCo#1(eYlV2B9jVUvp^B6?pi13-u}M:SbFbxeZVO(NR&kwyWDlBT}z:9/uBrrq2Lfd]diIyQIFt22iJwoyAJm]yX-a-ZTu8:+px}!Mr6P?RfjR^zIv&9vbfHq{#V:%A]RS@)#OUQpWJA.@&KIg<@G4XuI}zG[:lh[@[Vn^?/+qr]y3N}:nALh[%L14:B/0pm48P>L)vuSJwvM^cLcjj7IcTJuMPcSkGX@J3&&+D&Ymf#(Uc8p[hPDKOa7<R]KcXo{MEe*?:rHK@f$EG->Nq*iu6md>H{y<@YH%2k+D7e:rcoe9yVGL=Z=6mQ^)Ggqn^?RTv?KgQW-jvJ}.un@5UL!QLKzX.mO4*:{u#]Y&0zR{GkJ8fvr{<nQLy}>hm}jXE4iK6y&N4)[4gOn-ByJJSSsh=!L}pq{&3jWAUzNt0tUEEoke#bMo.aB9[$$[0RbexaU9mG!*MpJDNAi>mB!Z=Z0#Jub-vcXBYkwfZY24?$6^ToT%uM.[-Z4F!]SS=DoBCYCu:v7<NXVEX#K1YT}?6NyXqN=-QW.u4XEeD9UQsi?$oWe%>=:uyJEaa?]8j33D*D<lgb>IoWg8gJYm+]kW[$J<Topim?0urJ8%fCcpmF<L8F@mafAPt%[N{YEdXfUeJMP{Xw1N(V/?2>:ZvNqEZRK%5m0Sl?*1.X{4t(uC=r(Wia.l%iEz)zk)NnlCAc)x%-jlhqPIsBhhPZN9MCyMCnKUDL7a4niJf}hn1Rh/4OTe
+4DUoZ@0X:$F-HLve4Jz@a[3fh)}YAq(*C5cD6LM7B=oQM+Tp^r8]cnSwag&gZcRZC3/xHx!B)+uW[g1-zqSgs>XV^O))t>)g*/ccka+THk3+jPE#%N85zcBM?Dfd<@ulORkF)fW9RrC8k1p0r(C{V!Kb>OF:ne3w>x0Zl<9=^R+$)&=@S3^Hu&dG#}Ma*6=hA6B{mG+{>5Ytf*jV^A]/*p3s:zsa:g<)]pTPd$8Qy5>>3ME${+hi]D9xPegPFjbE0C[*hV<28!p0Foz^N1m*XukIYZ6mBD}S]hYvU{-fo:AND/T^=%3ltE}{WL%)}ZNpajmnlKP79$@%X.P^]ZGOk98LF@EI#V7MvEpx.8[SAX)wS(?&kE4*Z}&o85pd?1L.nzWVcJR7(56U)1SP&$BX*W/gb#@ng5@k0=^T5<.]+eY/EWG..^L+@11J4pjUQtd1oE=r9qbB[VoXXV(h5hLWB*}<%W@RgOgSXGpdfm{QF/fqk#O2Jtz+dCy:vWo>HJNCC4GG76-mOYkNT<JQusN)UH[>]:/S1L^Z.me0U-GodgV@ve4cRJUod+B#E!p.p7cGsoknVRg}&=h)6H:MvN0LG[bGXD-wk((s9qlupalC8!#NYq}CdKtu]UraNGvN#400qz!9F/Qp@DN->A>s9*5a[SpJ<TBD}Sh^(3^2cqEcyKYx$0C?cWu$A#o-KRdi2=/noPHkZT!GKS!1dbg}gPw3fn)EPY0>GWdntR/c:zU>Q]M>f9zgm{t>VM#G:1G8#=$%ykFnQZ-lfF/n8?^SC9IHcTqbr1(?H*hl2&d2M2bd59VWee//=UwU+b!]ZnJ3&=Q0h!kor{<Z3^8fshE//8b6I^#oM!rAY[:K%O82ugzw(aknWuL.!c(7*5ve+2bR/U#->pY0hA!06EB65[2X*#^Nbv>7?YH(]pnkFr(4s7xc/9VK4<i68D3{}[2O1.:Qa+vB@Qs-P<pvu[at:w)Ld{.2ao7U*DOW]hc4vQVCr@?^
+HNDZjF&P=TqIiQQ2[oks[WCgU2-e%$hOHyZ?m$}a--Hvlc]ziCub#du]T=/q^t?[^FQz5vNJN.UxCeFuH=9bsLIm)58syD^3YHM1KWhKY+]!?L/QTC2v&u^C7tLRLV@#REoYSWQZrJy^x$j<SbF7iM/{Sp]scmIS5bAs#iHC>PO0ggmg*w&MkVRVD@VjJ)d}]x<z^6qkO5HF#2>ITK^U/z?<Ehb6]gQ]Wj(N(7!]&hG<rY467d&3N!4NxfZgXJAc[Q^c<i?BVPx@diJ^I<Wo%-l<}MZ*vMxOzn39{c/Zt0JOdl:!.U)*q(<6Hzv++zXHf>N=tzyH*C9f+2#^qf956nB:sHNQk<?t{AD@vy?$oI5ACxnhXE}#^J!L/K$a8eWzc>2o6^ekY/>ck+9f=km](ISu@g/+MX7Hu5?#yZRpriC:VaiIFq3j&>CCjea1pm>XZn3GHmpp{oo!aeMLELQ9wrr]Re.Mzsrr{aStyXjrIF-WOzR:l#F&rZ>lc<4ipZ6LpFw)5=du[M0VQMHg(I[>(wId+d4=}L.{wmX#X2:>Gts:%!A$td*RdjmDyK}cE63UDzkjWDwz1K8:@mrJozGfxYy)39W{uS-?@7/SQaw!}g6ld=%ko82T#Z@aHWsUD2eLF4a}B!:(>*uP&(<0@^INbYs%U.T(ynv]VJq{?JvjX}rd}?dclCU)#n=m!J*c@{6q{a]9R[TYik3Yoot88S>3mm5odRr6@(NiO.1K@>u*4si>enCLf+02!jVt1NZ19d4X3^F9@+581caKlh*Z5l3n<-U.z8vted.*4(7gK]^ZZU=G^Gb[-NclB!jmL+7-[WhkQ%^1(9:QBPD2Mk(8X2=NQt&Xc-2}v*ChT]/Q*%/Rmxg7FeSQ.fIS))Lk?/ydz.s{:1yq-$iFpYc(rwT2)#82gY{oD91G]o5Tm^*jz$4ep(9OWSGhzZPa4Z/wH%&PFa[/*-ChWF4b8k/7i1&v)b(80V{8>L4a*LDlfX5[yU$4
+[ySW7][RQ(:cMAYCdlCn:2Mn<cE]r:D93hlqUVSh4dI]bM$EpUTVA}KlQfq+t56=N)hVXaIn7&eHXhUrB6c:%I?5YSn]^P>HuC!MJkDZw1041O>cd>S.c]bSRL8t-9sNZuY:4x^Gv3j<L=Uu>Za{1VUoo7s{>n$/]F0zwRlEvDzbHI3WJ-.]7sx6om3yx=?^{%=@NH?=Z]&}#i{Y+#OK3XN&gp^J@38-#n&!J?A.*gdJRbbKKd-:V<uWoe*SqpXU{t8s!BCCt/m?kg:pBoPJl$+d!$C%Ss.}YBl7V4hDSCE1.)g^&@yTWtI}%N9VaCQk)KlL=I22I&N5e8@QK^?1>3YMzuM%Gx(81f!XO54oWr*A?s./:4T^qU206$XLt6yw9N+sSE[Dgk{yN>VUfrI}HFdbjPECv9n9h}&XTeAd)ZiYSVOAK:{1P3&e4=W0IWyCM[KEr+wTdT8wQ]Z.w8R*<#uUsS.s$E[11((7v8]>1:&Ij(@u{qd-M}}{VgA5+]B83d-wr7-mR-t:B{*T!.O<N/l>@E?Y+os5W&e>}@:56xtjhqZQmeFmPic+-*<3Q-)ucUzkXp@[2qZ1jQ=nW9(On)*?&/6pV&FXT%8Q6.1=kvc6jzjpA=W{eXlJ=HT1(wzazjYH>DffI$V1d7IwTIn0&p6:CERlRfb?v(9I#u$.=#<9mWl$lpyQ%#Kx6<.DU-ZE^/f6F-q2LfdB2DU1QX*>K3{k):Uyk6XT(Xf@@Z{b1Ui<NCsZ0&UMFfEoY+PyfGe3c6[o%+0<&feL?xtgj=FbWttWK9$+@V6yYTSzr&}Aabt!^R.{3E&H>k9[gKgxZ-<tkARKQ6IbI88c-d?w/u8!A6NpAA=QfuQw5ZDx2PF0p?O8ut-}-h:7PUHq#c&eCV2h@SI](a$sh)<znT][KVZXJYP!<n8dk+NP*d1aM7r/b>tvQr&Js<D4mOJV#iQ2xC&z^J+sPdb[GVH]km>^rFB%:gDx}@Vc<[qn&dF&5$*W
+x-@BX?L+i{E>kd8M/]1dbRz7!B8R$v<ofD2Itk&n@V[>wb>n^d-@NZ9Hy)+BSC74Q*u8joeUsw-EYWE+5Qf*$ix&D.{E%PygL>wylyD/TmMWi9A%L<<>sQ0uU&r}O?1=gg3%ghd3Yaa:}7pml{f?v-0M>!jxnl:Cls75xtA3jX5<HhSa(q3h>=<3+SaT.^GSWR:]}pbL5/]?Azi)[qp{Fog:b<Ts?VM1-{&k@jWm2^9]wNLZ^M$-2aJJh10I0@7?yv49gcoD1hs*bLsT99=9<qn^sL/mK2Im@R(?=5Y[].PGnPYHKj/HdBUM36jX1Ofyg#gkBu.vjx2k}U1@EkPhx?OB87XjZdpOV-4SQQDMP95W!.Hd}eWFU:l??#gBlH6%EUI*^PX7mCszyB%+<y:N!4j1-)u=&nW3ve*KE{Z8JsBSo5=z#5TUvD}Mv&/wH?t?db8Io$C{ylARtLLw7Rm7p!ZFb4%z2jZRmq{TWE&4XjQ51Ka]7?ki{VU>?@dA!ZHQ-uP[v<h<>[01$}vQCy[1vB:{C8kOq:pnvETAY]0Pi]yj%foh81ga[(WBDW{6koa{$B[<!mU]Py3$BCWeVxEf}WHu!*u]DSp}gj=c^M}j]LSsEV0&>^rHa%g5(</.gdQo=vVMs9P3-f>f2oNZy*m!QWB6jE!$48*@x7c<xUpE<Y^3U/Udm]D6{IK?Y[=l@0f/.?(1g=DsaNs@d]?2FB(R.%7xA/P2L^:[{8^*)gh4tZHeQxm4o/5MyB<btiw%:tsvcysgW>n<gZ1gD0uygeSEpNJ4C(/8>Na/Z4Dq1<O}YP{6GgeMK7Faz+j0KaK24vzkKNs6nx#}IU+7FwbOKu?7G5%-S&*8JjO-JDZ:k=3C}2FDBe2uB)RubGZd$EGX}AUxS2[2Cd4xZCe1H).F:yq8OXp]h.Ms)4M#hoGrUp!AhN%]*0g6olvM.UVMd5Npi4{tHVV-Bld*R@*d<3tgc{ZT2BJ%-N?928i]1669VP<s
+B0bVl:Sg]Bit9uWUw$%P=ihu*M7:Z^bjW9N^B=-Nxt@Q>hqtEd>)u$AlJOjM@!p75C&<##}8a:b<j{XF-t^do[OyZOxw3B0AD2L9(ut(Hv24UUThBnQyfTYiDNt2!pkK[Sn}alW1/VPxc>QS[nSJ0b=k:d2zPnR/K*=yDYO]Z%nO0jWue&(5j0XZ5X^JUl]6O$gaThsB]4s>5UoU6Zi?<<-B={oB!o@:KDyVwn7)P!gTcUAW-&7HrgSwkWzc.@%B%wi.ryf41l/H>@zw1%HJIb$<s.rSTG)U^Fs>^*b8kzJr8r^8sj/yO[=(Ubmx$.l9r@9#OkGqrBv%@{S2KcXjY1:BIkQswu}BCdQ8I[b.u+zr[&OTTQa0uX2y7Pk{EERku@m-s/w}inNHqw#c1)}D)G<5y2>aIAtJD8&Yg!4}{??5oYbR)9!{(pOX<k8g5uObui/CH#r9L+4?[4}5mjea(1FxX]*k{x**b:tLmzho$}W}v@Z*]kJl3%huHit9j8Wa{Qq^$3nh0?(JOL4oc<}C9JlZrG5{3*ME$(yj7&sVhBaN?SMDKARA*cI9tRIwhoI:Hse&IWl@/z2[gPRSWzR<G<DFKOU<oya@*1D5Bv{79ZB]&S=<QCT6I11kt5XF[P0q5&<rJ>vfMwmMG>VqTfg>qdFf$nJgDGm!+3):(yE]AN(@=*]Je4a.%9Wta3J03fn.=MxGX73k*@.E(V}ZJM0DGYbCfq8%@}TlczL%hw$>o&5+x1TN9DxLJ:aSb?*M*Rn:jRWrMp=lcA6Jl{(/&FS[G8jyHzS*@QfjrbvfRuGEb=^Xxw3^#XAj6jTP3p@{8r36Sn!0g+t:l/iIoL^8Cu8E:wQzam%d8-(GHjhw5-+c#$6r]Q3^MlMNhtPR5uU2J^E:oZIVZm[mPpqKRauuQiOzahWl<4iVdM+0XJ=E}zKXNIO274)D^VN75kMTvO<QI>OMT=HiLK)7K#bZFb}I[5BgXqH7?U7Ak[N{9TdUDD=l
+v9.#(B=skoT5w[@DPtEae4#wpiAo8/I46m@g1PP<TG4TfrBCC-2>K!$^.6[)yw=]8]h<mBj+9e5WfVM[!SNN@rTWkXCmjzssS4dbqlPy}=Tlz-@^7V-u-N65ym-g^@WG#JV@dIc(}iBt4FcObQt-H=hc:6gHR71h?=Cc((BA$UUaAlv0E5yAZ*eI(-DfU<=S@sl.^btXSgR$xW*@3IB$ki@fy^H]d?CjLKo-JL=yAHc/QY]*])j@FfTaV{}]U?Ra(W[+AbS997HSgLaBK<I>K0?FXcYs&-$iFp(V]O{U%(SwfoUxkG7z9=?dnZS!q?DU[cMz@JV))OC2bG:r^e!i4xr^M]h]{?MbpMOV&@9wsVUf*K$reh!Zs7eW5[]BX<>YM/MN54T((%X:9F/)D2a8Z/MJz$ovhnY/XIe1w!{cww&cCJb+EicWcaZa4uAV$&JUntEOFc#j=+(B-$S6m:5Zbv+oe/aW#J?/{gNIv/vnWoU:LnF./F@avlGzs9jubjy]eF#6L8B#Yurh25J^^p=Tvq/h6i!@lT{DwTUk@mvAfbvAd#>&0eiMw@rU:x:}iU+Lr!%qU6]ysmQ:s#HB+NC9i@xGEu=1HV>UrsWtmqqa!0Hd@W$Kn>=-9zH=gMpL:VUpj-lj4p0*vMPV-t+*bHd6hZM4.i{$JoLd&s18iRSW?c2]PcTwKtUEjRK8b#6l0JC/T9p0FkQtwQ-%9mzJIYBo+rl7@ERy%8xUiC$w}BWw%Uq!ZRFE=2({LE8o/c7KgpAgER=@PdYdQjj*lLYmPqYsnhMp5je08pZ(?<RoyUh{8sq}[kr4a2f}klFomonPP%CC!SF+&g#9]M]/==l/W[4qtw%GMg}}?5BG1H<&Ks6xX-&V><Y7g%}-/pZ-JqUaX[G@Nq4.c^ZZ*2t?#Z-[Y1#fY[X?f7BMvDF)R80V!kPyd{o06qHLmEsiw&A7jhhkR1=%w{T{VR%w6cL[)%?V?!g4Gdj/32uf<-o.loZMZuz.
+KKQ6(J[MtxqjArl4Y-swqifn85Y!Q)x>eo1^<QC-Z0Rb7o?3Ol?Dl!4kgK#WwMAZ?!OvUE-?MiRygCSif##qhi/$&Q1KLl}b%7Wa6e[d^]N/#f8ItFTa!0UXl<EBiF{dycOD%K+f>/tw0{.2/s+0ri*b5%&pV1:)Kz?e<42D>!r?cc49HKUb>$VKe^n!wV3roT2yBm{jWZ>(:I8E283s3J0x5O8#Gmx/T?%4TfgE#wK4Cx^?f0yn6>va]@M2I!]}b0K@&bqL-9.a[tx)Z.>@Co6qI!EHCt>.#Wm56UrTNh=@j4w^<XpQX2+q462vOVj.([IzcMsAXPjji+EgU-ZU:8=nE&Cef467ixeXk9X7ych(UTkAC8Q=8@.i>R8S1OJ&)HWB2Mi%*lOvqABwv}DWckpaZ=8TwM)hH-a#nN1&$?>mZfy{c:az%FX5dbsW(fuAxyVy:H#!P&iU@Z?lsk!+=]5Q0JoiXIVlUj(Im<3K@$CyUpMEgt:([4)RPT^np72YDE88*32P.zO-!xKsd?9n@xUu*/b{):{=^]^m!bh:J-IMt!!kDMSjF13nXi?s?lEl/+>DUJ852iV>5lz>N90CN0%PI8xyV.GdKA]Gh?^?1PtU7@u+.Xr=NKc-B@SCyb8)*>GFbB6L=<1V)A(1iHMQ}8)peoy/F}h[e/9]1Mw]??6+vI%F)9)%%{W!W2t^821o@gWZrSTl5q>2gjN#HoR6Q7#(B[gSB4/gSDRKQ2&><%l*NvcsvV{aDFnzu&[uLG9-x8Pcg#RAm/WZba]=0F^t<[:ytSw6HqmclPt55=+XnafIk:!%i!XxFg}E^jJsRI}5b}ohR8J1G9!pY4/5d17(5WxvuwIDDIDJe4-P8DN9MQt5@8Zs=hA[fmP)R66xy)/PmX?&g0f-fk4vLm3[TW[{G4}zm?J+*KJei6F?S/KdHfxCv]KPWeK7PRr$#N)5xp#p-#9uUfRp812vP/a!f2eKkq8Xi6bX+4mLhX>Jn]KA
+Pd%7D.Hfu.KUBfuQ$<<mFn(ig!p{99i5}oo8kx}r<$/Fj.Qd>1XXF0RS7dY5kPTz(2/p#exC=A#Jm0CnW7HzD)]iWKcx6pk-#7YTvlOfT4z)IjigR+g@exB*Nzp!mfWtnj-}TrX<$/GkaK1FSZy=i^PpL<v2n?7!-<}hK80q6hk4y98G.Y*82Fzk=]zrv=SR-KSuwOJRtE!SN%9{bWX<SB{ZOaDSh+@XN-:HIclAEJv{8GqC/588>pS(IdghUS92R&-*6uR3HBypT6Q!^[%@%g%DZx9}1.N}&lASll!gPxjg1%Hdn]*k6QgY>W@pp.4BY^9(6py33YG.+U15CHoOO>wx54mjqK(XNyoS0!NXpp-6=Y^%Ce8V^oevo$1dXq=2wPw6lJ5AmCc50OJ.Inn[ALrLb.uj?>2B+fu8.N}SfAQ9X$fvWc[5AmCcCA:KC4pEXc^LW$u+oj.^5TUAZ]qS}KjRs5sSOM8<H]U8)p]k7fFkK&*se078F!kx}fSL*#(MBcF0NdN[U1YF!yfrGWdmKFGrclL6Sbpoi-h%9+IIcmH5AlZ>w<{DUz4LYwsYZ?p-/X4+>CjfmW^wUTW/Onb5OsGz&-l4>iCNef2YWnNbGZ.Xu%<#tQZ%uh/99t=K?:8zfF!s){EFWko6@+(lr(9TGFHK09#H&B]-0wlH9jz$7)}ca]tR]>jS6M.SOHomH]U7@qkLic0hDuN?Io?w9e7<01g6mY?v+HK}VBeIWyXp4AKz>=:#ni^eYEG<&]%*(w]H@=&{D])?ZcdLjQu640P@>2)V5Bd!W1^zWH0g%dAoXe}BtpExaT84IF{R.GXmHOAEU$4<y-l4=MHg86$b^k{+atnCgcz!WS.(#)VkfYNLsgulFJVl}8bN(cKsZwGNSOKEDf-8.NrUhT@]+FUvvMRzv(5QOQ@M}8NqV:7LD}xnWL%Q>oe0-KmZq<aK[/C+<5:AvGa/MQJM@g9A6c[UwjZix9TNQ*arQ4LmC^xGcm=h
+P%06IQb9vb1yY4qT7J1a[.[P6TGQdN?A^KP}G9?KWi$TPzXh79ROq:i.He?@8+F9rab5nru!2b1^xLSb?2P*uLFrc3C0IXMsjQm8FK9$8C)-}r=p$Kn4*{hAce.EHK)X<EB+LbnbHsWd=#}^dUX>j!sPlU&TE>Fk(y}TrE48!bG3W*YF[$]E^Eg6wC}iOI4?DLJ(Oeuu5R?qqGTOnpv5F8S6)g39t%=(.:rkrl5]A%E>boAvt)X.^KI7{u9=YR^nBCW:L!viua0Fh^aBsBQNW<hngAP>7wVg300Rd3je=dh-9#xt{+TDE2iY5s@HD-5(IWpE33457}EOYNBa1^2sGgYaTqBptR]W5UujOmV^?7cM6ZH3Q%^w$0RuI6bxDL+f7(3LMh/Q5ujM4jpZ.#&^!Z/xcoKnMfP:M[mQ29qL?OSgqr*-%M{)VELFUu7V7:0i*][Yp4[oy<ju=Wj-^n8wQN{G&{U<(eC]?xRFL]4>RWj[vCO)(*l#bKW!^?xRFL]4>RWj[vCO)(*l#bKW!^?xOV&TIxo>7(#troy*etZuZ[*2PpJ5v<6qV[4b%rl)z9#ApR>D/A7DODd!y]{Oh]ED-[HYqf^AP:g}!(qvq<9/oHGNX8C1xLmNcX/Q.9p=UMN+j!+<u(*3x{LhK67PAazb[3.af7S}!U?0!*!8?a#g.<!o(t<HmiP6/iLDBtRn>{!tpBX!!6y(b^HYgy.1?C@Nr]kkg+OP*Bg(+51>ufsu%)Mf*#tU/o{YuX}{.(&QB5{G]vQCpA^o5C!OUgjSYXx-bOU5^**j@RhGt&#hmA*mf1XA!K7-9IK[qaz[+O./{)(ba!miV/J!UjE=7VWew(/(YE%wMA-db!IvxQrI)=aJZ!TAvrR4<->f(bA{{^Ia&7@k2BUkWZ<2nQd*V&M2rFf2t7lele8{bufx^ZF<=^kHW.EmH=gD}ku2]JB!9rYSDY/LaM8)DN>x&TtxcJh*)8^wx?&$nqa5J@b9zhs2hX/E
+[Y>+-Dq&:<(Wg5o%5gl1G*3z$o*$JP*2HBO[N?@NhQPD6N1Ka<XF0sWB!c>0??5pg4@DXom/ctgknR=yypR^y-ya1l)iupaN+x}$zma+FGZJ]R^)&>]vueV@:Atj4bg(SCIcPk8Q0dD$m)0rad*Pk1s4.Wuh$:$9sk^p&*%)4F=r0OFus0.ICay<OD]tApcg009r6u/%Mcw4ipEy.MMG%W+s%xlV?03-O!>k1PW%*EFiQw*b7XjYNBeW0V]xhlOBZvrNCaDy7E7Om4^2Iz{fvPPeXVg$0KH/gzuXHQ=)y(5bWoM.+hZAAb*R4>C/vi$F75GPzV*lA4(2%*FLxn+Yi%&jHmlXue?:q(@!QFV-?Y{tQKNDU)cxXf6a]p)[(C4).[mvCy3>h>P(yUSjkGnag/zigz:fb4lPBP(ppW4^)XTA^/Mb@r1UjG$UTU{$Vm&]ou+:)42@y-rS<.zm0n*oFHiTMn)th(ZRz?mA/!Ixcy<c&62AEPa^)<f/8@uNIJ=m<Zk:zFXL?043+80jOaJlN3H<.y*)o^l{svF*QXJiJMZJa8c@-T6Vwb}}SJaAKL>S2tkK2v?bJ/+}Y.(5nF%f.Qck]-c-X8+aL.v#HJGw[IJ[R.Z^W.z?/F<3Q]=z5w3{9+ce]h->JpjdO9^aDjiD3nk4:EzWDrD5RnvXy?+q?06+{O?QcL6Ap}<T$U/(KJehd.{cEz>1YMF+7JQ#U8u/h4pZkPa=!vrG}1R/+:)2@!D}#?tR:uZD=W%RlloT^F$5q=+:)2@cLjq/A5BgOzzPi93/<3izmFmp.}:Hnqhl&2H6$a8XDrqokiaDrdr8QHHpL+JQAK55FnqwzXJ7bVrleNdcucpEHpL+JQBxSw2aaG<<I0HggA?/l=vRKRg0Dil7%JuDUw&P{o(sW.i0Y0><6oaE8n>m](]E7HDt!UL=m.C%(Ph=(}klQi^@eLGA{4IP8m+sx<.!#5V=NSazGQ2gPh1Z%T]pn?=jcJ$G^*#L
+x[C9M88fwVoMNl[1lHGJ=FmZf&kNWlH7]9M(4PHZ{OL-xoQK%yzWK.d!xV>mp2T8IJ5?UN.S:8/zUF[3TvFVur=nn/DEQl}(j8Z(hJep$t.ZYPb!hW@0p}FMg}d=Kv4G^N+=LgRT%H#:pglzTUaBM{klmd(5Via?pxoJ%!xV>mc+$BA7)ML.Ipm.6?0SZzC)[bItFi6KKd*:Vr<8#(WQCz#UQ@RppJi{E2iF^ox(rQ^Z=WIdNoNuWliE.S2NT8Z]BRyE{WV9Jopj<xfaVpj!xV>m<(5lVJ4#8V:ltt=zUCxaC)E&EtM1?n9ZYnrT:QyT>y#?-H@6xSt}iXTuiV=45t)1*+k?blyg?CSgh@^6mI4%@K^()]?0R1?s+nuLTZhv4c8^xB!L0-3zwX&?3ISSNw>viU2F*mib206U2F*m%*l(edFA$#AEVL$pMd4@*Y4.S?Z9L#QRN+ko<9QtgWkkq*x4T*a<4Cj}B#/I$Q}tDj0oNvox(rO=FCx}/gQ)h>^67fTz>awZ)$MhBt<7wZ)#c-]<w9FwAH?r9*R6TQU4I)DCxc%K(n0&3w:vBd?Q#[txtVsRf]iD<=cpcLTguXNz#A7#Kzn<cT1j57U!#{}YGoxF!?q9vV*kmK}Pt%ZB^@bEt<5/F/b^lkOO&s*(-mOB?m!t8i^9-v0^]>3Pe]4b+mY39T4@Tj*i[N:w)XW>Ax0+JU&xyUM2^Sh>9eJet<3:3)Kad.D[+c/w6O:HB=N4mW>-fq>cE.V@VDrrUsumJM.wx!4KW:-3w>75XoPPr9?fiUe:06D-nPkc@<Y]5&G3Qr+/eTD?Lo-fSeD6-?04JVWdgyeEI*j5n14I5dadP8pa))6QU@>o6ML0.E2livL+0*&L}@}>>79]v47he4O4Jcw/K(F4@Al3:.tStXBSd]U&18Ez@O+w+kM3N8YmdAMkCc)j>x&i*t?/pJQ[t<5F0bgiLBYP<1+0{F&(*4W&brG3EEVNoC^OZ-oKCD?{TzX7
+*2pP-Pj1C&TF&=tu8dkP/&N69&>CoS68f@JKHRHdR]j2RY^i3D)>5J^EimS#}+2TBz!xO-D4gXF(t=*Ii.Mw#<IbQ[>lbB2Ys*eyjhc2?ZU8JVAOH0k50DZ[Cj%Fzfl7tZfkng*MBbzSn9l<h%2Zby/:2<q}FD5^/&L{ua3xC=+)egW-.iRvEGEWofffWZ6uAT5nG]aEMAdiqVp8)&h]e1/(b^DzE28s3UINLwvQD*ROG%u3EK:h&52drV(w$=4!Mn%ERS4Fj9R?GBZb486I{EKXVuw)!AmcL0W{09#m85I2{)gBu{r$qT&M)+fs3G{qM:-PEX)[Q?BvbO)pE41XP9$Oc5!BlRiHx[$S>OlI&B=IFD/e{O8@B7$kzOW&vJ5P9sd^a{]*+$F60@VLzi[d8eMXOwA/4Uwp5/Di(<Q5rxA2zeZwt>}]3%{eNC>qpjd.Ti5u.t1=WAh?&K%#TmR<305vWvD@=^$:UAu[$Y+Yn!T6ZTfWWxuG/JLDS{W]dJpD7JkgMVmXTsXd8eS(6dk6JGm>jq}kAx<vdItMO4@o#iZ)YeohYW.olB>x^}gv<*Uh>Rh*(r*mJ!Pgt[O4[/1ywQ6MU}L[ZyocEYDW!YpM.}JSyICC-ph-!V/&J8QCE}kA{Kt&}k6QeW}]AzS@rIKt*zh$v?46!.bJMF7z+cX)E#IaPItzeH8mk==/9Icd!iAMP@%eqOEd:9OKa3Wub%r46q[x.EUd<CLc0FP4ZwoH4TU{#inJV/!@!(e}(Tb^4eMWD1hXEY3AEEuyTn:jT?+=Kv^:2u!ba&>8bhvbzJRRIIR8r44n=ek(HaNaLJ9?Z[7@3VqMAdipI:Qi)/3GPNuMoFU^-@ZbZ]2i<cci<f4$9Ho^O^23W4}0?f[}n]xn(^$T7vj(2XewV:}7o]utBjC}*G3feOO<RGjfyAJ6^)hved>PQOR:wxI57!4U=MHxn!6ztw5]2GMen+<:T0JD247+}@53teOO<R3H!]!-.?y^
+0LXc1Gj}my8D$O*x9VtuV[>e^{^b?T7@AiMu?{@)W1JDeeORAHvaZS=-&5[W4}uOH7=yk4Un6C#l!xXF{g+7(eOO<R6xcMjJfsLcmej]u2HOZ^hmfgTCN8cLV[>fS{#Ao!9+0MLUL:RctfsEiMB9pJvaZS=-+kGZbv-LSJ7ir4hWMi8+4>VZDJKI<MB5D/(f$x4-U<CHi^*441<$=:hmfgTgZy{:V[].V&2uo!VKbVg[v+Ckl}+XO^31aqiVd1ixae1)=C6b.&v17AABnXAaM8>xN{Vbo>Z5-RQT}Y28]Qh%kkfcB{fve&Nnr=3{(rSe^Wc#a17e-Isx@^EpccIWzCfrwb24Qc*Tt@8G7L]8xcSeEfF87QIiyZ9XT@]Dcwl7%]!RcKbUhpxU4tpyWH2cz-w>?dc&8Kp1m)kzg[P87v4F1bfi8}HM&$sh{)*5ki)3L>516:h^%QDNFls%8u-EAyf:SOyAyWBbBDT#GbW/gEo.klQ1K74n}CN0I&6f1aI(eR!nHKt?J%1KHGLrTy<a8z>KIjY)ik!91Hq!#&)wb}[)-bt0xU(P#}z2=Fi.iRTEHLiU48.8Z@]x(sEc%G9]g$3Nkj$X3}kku-SRPGY!F<mZ]=rg<Lyh!fFQ58vNOQQFfB[MtM-uyaUIH@:uHZodO-B&R1EGAbRUOZL0P[@0}CM#N&hX^BI]ju4nHKt?4/>+aGLrTy1aW}2KIjY)0qL**.@ss#niAE7B1WNreFqGXpRu(+W[cu[]+c=A8?#tRzGse%kki$7e.JKV>2qo??&*=$ZeU4&/Ud^ivUzHo^N=T%PmD.DvCjSMPmD.DWyWmO{[ai^Em8]4J{%EhA}=-$Gys((hi5isEF8(v)$*e-]$^#rj@X$geB}tX51XjxueYe9KnN[zQ}EfiZS>}VDAk27E>D>!Z6BO*@uQ%:*P(1O2HycX?SMG!(DGNW}Gc9pWhnI^MBcyWZn^mbuQw9:MafQ}UJWN!%j(2buY>ciB>aQ>
+kS!P6N%<xES&FtRF5deu)nRxQU+8CX=Wu9Kx&1M<cgYN%leB(J8eT3f/%q*s]3%SU}SkQQW-<dVwdkiz<@UX1ss:7gkz:=StK6z62v1K<Yc?MSS$U5}KOBO2*wSJ-e31J}(ajr*68g.D8L2[m/yClQB7PjFO<q5(GSe+ImXPf<LX(1p?@j.$Y{N)C}FRUj6MLowX:{$>dDD{quA-#e4+sV+dSkt>6N{28.UA!BE&P49TfMajD*/Oz(^@W%(?G.V]l#22=rTYjX[5c=OQG[cb>OGGX&0^%*p5%M{Q$vNTWERvp3YoW@M.wUk=pM[OKv{ko>N2TI}r5$X&9n&HQ?u]gq<9eeX+SKE=.N{)2X5Z!A:98W@kR@4FGgkb79bx7S72-CC:YVX#9eeD}?lG{o/xQb<%z*8$X0*hYk)E)^6o/AO(j?ItN0[iqo}X^$.lx{TP27XuASc-c}rbOQo0+&>Co*68f]!KHRGyPWS{b=yv.6{H8iLp2z3exZ3XI=Z9eP-o=mgB)G76]F.*0@-^A!UKsP#}V:*FEd%Rcm.w*[nzFzNqStB/V8w[i{w<Gnm813tPr[0TEDZ4fhY]SYN<=y)}:&ixDjJSN[7#T4tFk!q/(}wGBN$X>1&.lF{MWo6f<?F[vnDDLN@99G:UI=LoYSLGkjfH6EFp8qUa3^2?&vK?8#ST>Z1eW9CJ8(*=+]JY*#PJjUuvH-Q#%=5}nu1/(!B>+@q}vN>hqZ+7YR=F@^oMI{yDrYgy9%9-Lq1-z%0mzXdC%zPK2#Do<hD&>aIB&@oMU+Cw=:6u0.ydYa16y^/0AC<e.qbm$hH}?l2VMomk6rMdf#7tG6x6D?l6%K&R:Ys%z>qT.<X2*R+:*>h=lAIo&6b)unNCxc^67VYC2!v0wzby}/NqG-8)0yQ%pIuJx/>68d@L/rPshjnuk%(8:2eXb2.q<GM%U1lwlcVr]]i!P?)Li-zQ3Kz/nFn1zih]9j^MDF.c5yW3f5Qu*uI]@2MS
+E.FJ[??sh^eX:!)WFUBV@O(M8Ey.uV&*K0rh>P]@BIy1eZtl0!aNob^}]h]AW=I&]!+YHFzaBmSQv6yZ}6>(u+>Ayh%35)d?BEIP[c&G<.AiCFiRxM?Zh)VtZn{Ze!8<(HuGeY(/pq-Ij(r<D).u-GY1W[nbZ)u/=?lXcCx.VECA]WB@ACI%ES-T/ZDgx<!uvdm>2Vg7]q:Vplh*8cvsAvsfUYUU6X6ti80j[j4d*IQmNv1rJa4fT-Xtd*v?^B@=ktXBxajyjgE:Xe(wPe2B&DbIi+5(NUG!0Es7.%kT20jh@PYtIES-T:ZDb<s!uvdm>2Vg7]q:Vplh*8cvsAvsfUYUU6X6ti80j[j4d*IQmNv1rJa4fT-Xtd*v?^B@=ktXBxajyjgSCc4Arl0(7xJMC2&>xn-#97WW}pOGZ*C]OioXuJqXb53[bv*0[a#EYzOV?spRRe>Qe8Ne[:}Iwkjewo*LVX(*w.mZ^NsEa)F?.<<F0^%[Dd>4ZaZZpZ<K&LcD3pVpY0Gh(<BBVu{r$NCMEzIc=DEXpY1#6HyaUEu{r$NCMEzIc=DEXpG#3snX%x&++}0Cfjm0hQ<8}ix/wI]ni#ks++}0Cfjm0hQ<8}iy1CZSl{Y/o++}0Cfjm0hQ<8}ix>{&[doKlk}lVvB1<A)n6FA&T)C!+0qJMb7]jYO>3Z:ZKdpf/q*j+w1fRrI^YNY*+LI#+&R*Pnb{xux&fRrI^YNY*+LI#+&R^g7L}*#-]M}]^)uCH%xn:#(A^()]G}*#-]M}]^)uCH%xn:#(A^()=C}*#-]BCc?Vdpf*vO*RF+/P$@er:hqyF=8Rph:s<(3?h)HeCN:Euv.5A!iwy7+iCXAZh-AG=W(XvgX{E?s3v5OyAaBx2h7cg.(8uYbhY(iuge%bT(e84//Ee(?B=^y/foHvldShW({AY6o.B:42%ki-)-*/g@yTgn:2d2m2bnp:SSfz$jl$vF^BOAGsm{o%pU+!c1IO=.Uv0ALjRQ9W
+a5I$Rn5{7p(z?Q6B[kpN=H$<7}]S$VCr1N[O>3A4>W6URD(-49hsA3a7Q^xxyrvGPYJh$W->VIvhf3jj-oBTlEAQWu@oAg<C0bYY]>mW=X?}VEOWb>Y/C0kcxzY-BaK4P^[/wjRE3FhUcJ/dA[sZ#aT7t)sM>C8FhEo[v>w.+.d!QZIA=qKA:O4tu2>I[dp7LjO.6%ZWy@[#gE*$kCNrm]8D@/VNhU]d^>g=yG)8#!E51aw&}ptOlxS7kt2iF3QWKoq{GSj5vV{9g@Z}h[ovpo-zhUo4x*Q:vb+!Gh0eHC:D@Z=FCqoj9q)!mu0Gy%By(o4X<XFeLuIfV5#so=#+xBHgivCLIA?]yt}{CasR*ccNh}pA$HZ5)=::lx0]33!4mZcicZ}e09nmC1N3TmWG=(Sxen:%%js1D:!/nQP#c^7sHs5A.PwxkO:^P>c4cKeEN-ZAh7%3$y321d$r=QY2AA@N9)kR-[)!xl5HFQwV>Zl?vG7YSXZHxfCJyI!/V>DIUut7LDT$lhLqejFfVYks+t6G=[P->FpSGoDuLuG.4T}KjEUG@q0q*A1G.uuCWX>[Qiz6]rb1!VvQ<sCctms?voaaDO(92wbAvQ@Npt?]$b^7YPdDOHv=AwR@0a88ZMACW$lra:i@XQmQ#lL-.${k1W8g}jjC7G]ks0+j*YZ9)!=)>)>mR@2sj-(FYvS9aK42-[0e@=fYh:34=>w{Voz3B%l?8O?-$YIPchv4wXyDv@yEMfyxar^(/2aupJXP2&Oq!<Qv3/6u3(s<Q}if5&x6@{QUoPH(E{Pley<XI2!{SvZ0.6WGd$Qq8i&?M}Ab5ZfYKCOnm1k[9R3D?G0thQAlk31hT$t&}ImbRy]7$FN3J$hjQx/Cl!+Q]}bF0=[!0Hz5wa/W9^u5Qn:+g@d0)o3k2cbuF:wU-yJsVH8>A-Nl&zEY3}%KpNpQucsVcb?Y/rvbX=k-L%fa9XNL1e.w{X%0/[7t})go7VgAQoSFbySC
+/1LBmeNd:B}8BOXq?nMlqcBq5MrYBbG0Ee5yJP:1)V@aQ/GBtWy@m1#9p3eEv$wkiW3p$PQFkeToy!@lOH2t8-^}jA5!t#FbjSD6B<$D&su0b*?6Ba4!<FCFv)8aVim=o->E&e.imZQVFN:9O9YR-%(3[htz$oSSCmB:Ox9ex<=36l97c3>M4)%G?KdUsF-C:+}h/WI$h/RZI)qp<6X1)%4A/Zd*O4dl7*UY@CQILz[MRK<XzSnJ><#%u>IoAO}Zkilz][48vK%P}U-d-hF6bOUuICms).?cbIQBn3uvgiJ4v#Nem-dY]%/O?LxvIoM$UWxobd2H-77@H%I7]5GqABxm-hE?Yu/U&E#ezb[oIEEkn-&@&b-WDZstO$wg+]0i7hGrdl3PqHNQs^zrf!y$of]xAN7SJW!gSy&2o<wKMG3tdcc]M6N3>1#9n76to>}rj$-hf9)*d+?-:GBBsRlxmZo<ELoUhvf]X9S@=tNzylrtp}jBEcpFIaZT$]{-F-Kx]RTosBQ&t(k{3D?F^s110#pXB=3To<Cn4[Z$=>Fd#(mMU%9wzSkd1.uertiydGGqNTeI6pnOd9a&C)c#?9@abkq3-u4H+qx)r/hqH4@hP)lw?8nJy}.F}(i7N)ywvm+G[y.F5iaErKxkRaz:Sz5?/guWD.nf/5p$O(@Ut2dzD&O.g&$d.nItw#&0)TYLwX7UF^s34/^xZPdRY)lbLLN3vVl/?Du]g@J1/5S}cq?N@BoDLBH^nw&Xk$N7l-GFZ1ItghPV%nP*jT8y1XimMl$54kNt4q(P*wJ4jHoWSz]apn0D?ge}x!ru9p!:%D>S8%2![F$6p#+J{by@c8az7xpF7GihLR$r[n$QgCv!UQiUh2zH-D$=^e4rJZA&9hQX:8x}N.8d-tx$&vR^+bRCJjIAbSbtO!tp95+CvlL2Tb*8p*@LewN%724wpQ^@S*1c[7[3OtD$5H#iGQ(7!OFJH6/TA3dN=I3D}2M3sAuh&d=q
+BEdj{mgUjWqUlh#avN%DDu@jDKv1{$97XEU&?$]gQCkpK[]h(hXb8><k-mu(POpa-JbjKhmXEZ2I2]gin<7EfKI?kMHn6Y8?z0B)I2]n-bCb5&mO4M)G-#3k1.qOB-Kk?6im8bs0t#><vf4nd!e/cVAyw1#njniuE4[Zk&e0jjl>Z*MMn1H4aJZ]O3!>#z11*Rw1C)gi9(tK3bhb.+@.bRcg%!OPo#O9Y0e$Reu!VredSzmQ6G0U<x?&$n5Mc-vb2L1!1YOgm{Ao!wpdb25I2]nv&-!bL%k(&^CRHaVo}!f.*2HBiEAd%TtluT54R?S#n>vC?=%/irMirv=<nl5G0uDtF7T&7[XDVmPqc(Uca3[Ve!B(:OTk$movDq?y85[(IJ)A?o&9<e:FFMAO3BC3^wRQ7w*vpXrlayajqE(*-Vxxfd&Am>&q>PjOPV5yqOdPK!=8oWQ)MXv1@Av6!qO(/Iq@Dn06VxF$<GkciF1z%JPV5.dXa%R-UrBo5OQ.=B{?q-7PUXCYK4GnROtoLK:0uaqJS&}B]$D959ba<$u9xMfU.r@NhC&dfb%q*$heohN8F&&k3A}{(C[X/Vs%wQptGC$)I)[?-B&x55k[v-Vk/n<#0uct?=u*h>Fw.Ea[1O2Wkr#@v}i<Z>NH^/P/hqLSIj.xR3GlC4@J!+EK8:5e^bJ%7<3nL5PBP(ppW4^)XDVo9K.QTVHj)((TU{$Vm&[p][cexdU764lWoOto+!T3YLj]BX44&<SeIK]3]NEv&WlL5r?qg)>yIqtpzYGnv@F9azs02xloC*%o.4{TU@/]f^HDJPeF{+O+KiOHCboPvpMHs{JWpnF&=qt4Z{$-^s7L9D.U]:387]4^Nx.?SY-W/7dGI-VU7#)<:w?.j<2?imvgk8v.2GaVR7Zy.]P]^qiHAOv7f+cZD24FSs7*qwlOf%hlbW({&jJvdf0Y7XN=J@3dJpIT}<RuX/qJ7%oq4-vo<7FNcfH^t>ya@a*.Qd<6
+AcrW16#D)RD5M.]0Y7W*8uI=kQDh*%C)#+66R+>v!pY%7Sd&1}-Yb2<8JnUqvqbE[A84[5Ig>yn:yyg.2*uXnLm:+f7<c9K+J)4fSU/<+QDW-[-WIhqv2Lo}!l[k&GRvH!&7NggmUC+h<s%aq1zfvcx:Ai@Jhzo4<!{0/T@a5I-*I}e7?U0CGe9v.kN>.DmI]tdh-Q*L<8oP<4=/v:XD>@rJpNQV.kDx%kT]WG2u>w.D]GBilGB:Gt#ejX+{Y/F!6k%F{=/5P{-4}Uo?6yo?Un3fE[oUVE*XOG/F5:(u<iD$qD<K!xA(Wp2?5&mNGkh[k+/i!wROP]0Xlm^KLi>rm]J!Ncqj*9xBq$V0f9<{K@D>h3ASfc9CPu<91vE4K1-eoQAIsP0Yg/t0kQu:PUH[fPfNlBFtiPdpA&Eu&hu+4QYp&Q(XmglS7})UOutR7eR8<:u(??97qK[>fP#L{pcZH-vn%bt3qKW1WIDUQ9HK>URuH{r2wc>:mS*W(4wM2?r=CSjWy30{+d{b%fFd*Igba)[g+6Z#:Un:FZZr*Ul2wi7QDylN5#C?KLM$!#Fqfwjm44IGP$?1gM.y&6Z&oGh4<sQO&5y!9nDx-1aKE$Vn(n$1R+o(>3/AAn+Mwd10YXs5gBUO:3<50?bCq*Vp6Yq10YS^MVJKYu07OFCciX7Elzf3vy)DGNG43jhw)+SV0wD8hlOQfyblhsNFjt5O3=y6i)L+]%V02I0ZP^^0=62*rJrK0iHv9erZly4IG4178tmox:0HNjaRuH@p0l?ksJus*2Emr56DK$<IhV+&/^bFvm?RyK+2]%Rt.grxgYhhN=OyOEq.gzR8C>002*</!yW9VQDKau]06oFtB.3.36vs4k!5^o{<PHy^xlIcx*Xwee5!&pnzAIb-@]P-j}V49nlR*Chxj+wpM4Rg1^GMMNf2QYuW]/)Ov)3BYO24BJa@U<HsG1tM3sU61xJ+q+u(5L?cN}#Iu5sh6+iFiBYc^=S*
+Gos=T>8t>hqX7SmE*zOxs/wav{TJ.2>!K[LG.zC8wog&@K!Z:Sts4>97--0Wg5)<*4YqPgK4Xp7zvrFgL3zt?z{KEhjHtfbMLeBK1qE{q>2d3?]JA*g3+]xr)n&<c<sR^&B0pAT:tD0Hb?:XMXV<jM}(5[^k3%gVxm:wEDCL3#NF2<VOzX:^>Zz{i<&dCznzI*7MfIBBS9+!6{WKpjEl]WWZ59=${i=[Jp*eFVoxj){YLQaiZaeoJ?J[Va*P4+p84#=4WFS8-?aEni-J8Z+EEOtRfi->[/(t[h}@gLzaDNWc*YO!>-OSh/N&6{h[R[dkCIL<IZwdNJ)U+d0-j*EWFU#J2?bHNf?9{rxl3ey4z[.ZVEE(nv)WJU*AO(dtItMBZn.=nZtJix)kf&&]Mag:sis+@G}+bZ6koNRg>pF?JYQ((x@C^<oE.sZgW&&}n}1WR}@3zX#6nj5y6nkWZ}<O.-dE0rABDw.o6nk=G?b^dM?}^8>&CNs}h:K@zCcsQK*r4T@1Mt#oZ6K>!][47k=@6wy2)fevcj2Mm6Q{j0HhzKT7!7Xo0+o>f2-IQ{N&<ztzgYLumx)d2w7SalJ8ppUatwK!G1@i(U)yFw/D{(.q52GxfOTB@Z./IB2)aR?cj2Mc6Q{j0HhzKT7:/[+vk*FW4bg]of]xAN1JEVu5e.e<hBgZV*xr6ZIEEk4-&@eG7]5Gql0$bQ1FHEzz?utq3CD*kaka5>aLXuREImy>VG<Cn>Q0&&6nh%.(O?b[a?JgpGS.SDh:MTWYm7ADF$tUm6Q{j!62a:8?VNxl?uoK!<Bm?@)VN0Vxw-z8XD$O!dCMlQs*zWTF]AKrA[Z-kEdMGwsg!95*5@Nw5!Kh:WP0k3@Fpy<97oR/.YBzidxP]/gknfoaGxkRAbxV#m2Qn0Krm?T/m^G#zYph?cfZuKBSGjedCMa[tmpgUQ+wE]Wq@3].y.IlWDe(1Usn7[Gy7s^r#BDqH#9ed3//pNS8[gpF//Gf
+{V7.dE/9X4)-hI2]@xy*fg[Fd5xx?@!CF^Hao8<N2vw7?k$!/v!IcEBfjbg.&w#?))xsN/eV5lzP&W-UU2[6qE!^/p}@LBX*+mL+&^QYv68fN[M:*zpHS6eZj!qPDLdj>9)hUFV)<4wu2KV*l)N4#e}{lFt9p0Ju{$+*t<y^{eALD<IItM9losd:S]6P1hK&V#P1O}zda1CxY{HbFRDC.5Z)(*!:r=s=V}}T=o+<7gNwww0Uf[i(T%hCaI4b7I0KJupR<)6(aKny*B@n[3Q:cf!E0)9jj}:xIK?}^0IaD+Mf65C.g=$kAk>rX-g@Q9<cgtU!qa+6<sCDa6o0gKJAPY}l4]B@VoM3xd!jT=DIjQpDL::0?IGNY)<V@V5Y)=FCWJdh}^ZF/8&RJMx!9Kr.U?6Q5yszSIc+:B&J*9=NxTb*P=)Sm)bnv)d7-=[2wUB!M<R191a1&K5Vj]EZO9yjJUqcLRNP8n>lx44ni-t>H0K(7Ozm1j(2cgXV374Z%JXxAlV>J3W2!PguqkmVF?2ZlDz1&F)+}BVRx)KGM*o*nU{uk76X}mFR5hdrXK^0bHnU?8Cb^{rAcRK9.ZZQlGa>2ovg}du(lpG1%0H8:Ikz%M.>=nhApZiPtwtB*nq4/tyXl+V<{]jVf[{zr4tWoOc{fqk0>qRNJYKnV{mSi8w^pw.5!Um(x{/vi$F@RQ*0V!$Y<<LfT!aD=X@/vNTuy<!EIIglGywJNZ&SqB3:)DVZP4hd{[[1BW6P-h0p246TKMK9tl(yW[dkFRXaZX-/c<2cuGP:]#qKlMTUX.o4x1i=l/<AHR(&=b>QK&Ik.ksE=Z{0lgyMAddF3YjjecEm8kYE9Rdvdq[CUAvevWW%7gZA)bps0b(+.2ooEET<M2u{zwl@dGEq{)A?MBVl1z?rY#l:DcvNGtA1:.$TYpJpw#rxT#?Gc5y]-ySINK/$?5XZg0#sq$y)4b(cE)Z]$G{/)?Rna7aa:2k^{1l*.FZi#B!2
+&@F(E4<FTV8Uh{PAXMdbzY0k)kg)XhLRrO#u&L8J/rltZEI&xy@RhbvCk9p5Jb<OG7jMiDLojly}sfI+fXzC880UFb&9YIfJhbs.UsewPhrq!h){Jvw?.rL#qPlFJn/kQ:Uv0p!mR*8au!Jn6kFyN8kD6r?qiJX]Gj1U6[}Ts<kyN.WGz>O1P)<cT9Gm-A-.2W=Pg<ZFhrq!x.nfIzZg0#uUc9yOPd]MDUIT)dMUTW6u=P#<kFyN8u:t/DAvLxNHf%%q]2[fVkyL%8Gz>O1K[c%Z7?TG38sV$(MhF)x:Dbld.c/Hwvw>pzz:w+B@5Km1{MA5z@AOA}E!SDPKkgGbYFE%kZxTl2NDcZ@ahmuM^dTW7D+6jE1ztKY]Jb^uyW/V4u^yA31&+mK}QuTo9SzFxjs%9hHKQT8pFlGQi1%2m.(a9822q:UKIhk8ra4P&^+0DI3D@XXf-[n&uT<}50MJkR}y+ehi@F&&juk@tHHhc<cNV?r!q6WEfLQWHCQD=MnmdeL7HOpaQ{4Yo3OWSqH.oy&EbG-R6=tF>BK.HZ9EZZ<rTWvc&NXiAWs/v$eZ3GlcD6gsmKoA/f?tVbuLO@[G7KiXrE[p4aX5aOD[HdJ4J6Xs^=D8MB[=Y5}r5(G9EZB+@Y8b>PD:p#Hh!Q}4TluR2i>Ey+k}k7aBLnViBA]15f)&V9z%lK[QDBoKDRpqQ{4W26QPae(fE*H1)e8Sf-)cE)}@%*FW7&he/yE*..hFy@ETV:{[-LLuQxYpy53G[<9f#.ANIP{}A1LqZ)2%&9EZN/n<XF4n+?9J<7z=$bjUrKLd?CaCF8KSK[r!>ZVjeHI>[x0@0@Q!vseDw[169WLd?Caxjcfw(fE?*2iLVy9Wyegy*R}!88e^z^W%bci@A]E}gUSF.#yHtuR2H4Z{?W]ZmYA1km[dE0KhZ*Cum1Sac-mjCq]D](#$yrE&0$t/7W4F=UIK<h#:(u!@:)=QVlM1FbxbUDxJWM>jpKB!Qmm>
+W%<qk]=G4*)=@p35o.aCYpl0N4F/?iidyQt>QTW.N4H2z>]P57sl=<Z]$adf=a0fn]<=BP!U/R5]CM@]:i?e0PT%BqsdKZv*i[{>w)XXnB]f5IYAW+fKKh4Y5[P-gfea5.uf-(gnBLYCZUdPNr.EM9YM2DY3S[-}anl$o/1ECQ+uEwQ*sCQzn83Jtq*[DtqfC1yhET>u}j6DdPx6:*Fxzg3XET[Vs=*r5A.N5j?(dO}wVjB94!w^N^Fgjc3GHgm^4HxA2%N=%2%N+V-RWkn3B:w&DdpZ[]^-(q3qQMQEztMZJ<5rX[9w*RZhy{3k/efm4nlf=1+Y>R<dJZS2Ek*4?5LU!-qv/y{k/j}dsRfmS%%sp}m=O$=4NZ9@<fmNJ3mTu?:MJ*[#-S1K:020Kg-9yEDiKp)J.fHAuxDVItLwvjj&dQ&Y.zQu9/Q8PtYu:{OUp3WD$CBSNRs2kjXAY80*$z]$s%:+A7th4$ga?{Mo8g}E2fS^0le$R1K9gx*8ujItLVM>GMi.zty&$HI/p]T2B7xEDRim+<7gKwfpU)V9?oc?*ps2EBY5TVFxl<b:2jy<W[xa6d9ibN#Iqv:UB$ml=-)ZY0X7SKQ(<E?A^vL>0wpUrp&Xe>VPRA]l.FER^>b0%7}ONN[[E3LB?irxaP0?jOaG%B<m&-G.HXthsa4*yD?w[Ld)N3TZJr7X#8KMHv1CzW*xZ@{:tKtQ&QHPGG:zqZuO6)Fwhn8ix&V9&b:EmuQ86dEh3LKM+w7aypTgOMQ}[qywL$>+<&jR}oQjE:CmGma%lWwc]oS[xVCgmce&Rm6b7eNeK4^9sl0Bq*#=$G+.J]ob0kyWZ?aX+ti91wDMuKgX:0J$ob55:E^Ul5^LkOH}plhyJ]]Jk}l<4)hsP#fEQ7>@BN%]+O-->9EDSsb?%d>gi{Yj-3hJ])E)J6T]ZhE<T)G[=:3ewiSJzF!PzqhM?WxAntVkA{BzY=1O{/?hP55?^(YO%(@[NYW)&!rK
+><LpYTN*{(Y6*rf}lKwJ/cO>.ksjn:k(AUi3)$/BkC[BF5b>y}[8^1q5aXwd@T4(V@Op3ssiGduzmlopcgW:VJ+=5kv0/#3pSp[>]izqG:>7Oasaq*HNVR:1fv8M&{O06wJbLX!TUTP{tHgQ8kn7b+HIHy]]!yk}5bCVl4bjdvf(f0mwbF}otcp5bVW8Kt7*wbzf(dwC-<2d5JiJ]?Y-LnodOn@]/3CGeto17al.l2GjV^fupz/}jWY89=^]]a=:Np7^f(dwC-<2d5JbZKWL!&/<lUVIL8RNNTkNkj2Ky[#a=^0v$62>I=I=L>?!VCJd<puq=y.7*hU>vvwqgQBzv#T+.s$^yu{k]rrGjfbFgLS2Axn!r[q5KcO+)et0vh]V2Ja47OgLS2Axn^:ZQ2<thcUhK8/u+Pf:2U6CHDG80DuNp/KUBfub(1@!?rC{jl+X>svsc6KHok93JjKXo<[mMHV?p?zxB<{)4U<u]=LT9ZOIq2{Hl:=Mz{)hOUC1bupJn784%=g0{3vSSi:(Z$8sUh<}SP(AHKo:u.$Gg83%R667>i=?x7f8#6gzf6/u+Pfxe{*qHDG80DuP1Q*J@MS68e8bH<W*fzHQ<0Q3DNj9@0amP3@flE#%Gc=hSk5}h@)lzr]o?n:]rz4iJe&OkaJVpm&P6Uy0:()9u}J]i6*O{gC-ER2Mh40(FXtETj:F3EWLtPao7z3dBpMRVVLu1Fr5!]*EEWOv8:NDqr63^:#e>0+M>$g[O[nvaFcynJe#qFmj%sETj+Y7]juZUm5N?5t]o8(JlVh2%NQ+H.RR58SW*VBD4z>?tr5ATvX/wVPX$*h4$w=c=i5#7/!TM=/(]B}NVztZ]Qd}>cIh>+=g$k>]bvNfPlw1R@@eQaBS6)6<$5/o.hy*0CopNPl7*^cM6<=<FRhkKWn&P{x[X!n0@CBT]MW^2%NOX[sZ/v.q}JS]UA&&Y7WC2C{)9&=?ZguVCkpA^NRffzw-nmPpTKe2KgNh
+4*DSW.zE[T.n6PhZXKVoH7A8]{8#T-R$QIC/s*R-+-WS[ki@e#:]l+Xn:^fiX)+H^K!nk]ZE[vl{KdNak7gybPy>y-wpIw&Q{YMpLX[p<puFFq]PbfH)!{jmomwiY]i89J)*n)*zc^4xUZEzeE<J/8I{}Y>]MxQ!0}QiAV#$+20}Qhxxmxm<P<Jjx?-a&DzU}R3c7/xCL=]7O?T0Qh?$B:d):<D.z$Y)9Fa$sOT!UNQs=K3a3W/*ZG7%Un]l)a+yu%LEz}#*s+Z]PpYtCDvs0w-$}m1%nk5Zd*{P?Ih%81@:!#ezrUrvKkDuJJQzHF}=(#3e?n{)&qzIO+*2vz^-g41mcr?oehWa*zFw)XW*[PBoC.J7zUrl!kc^*Q*YC)S<YUJ=30TZeW-C(]:kV(OPSz%L!dPvZBX2%@1$8SYU2^y4esc0EvP31>NrZkM%^n$9/e!{OO3%0)2EY5[A{-c}qKb?=[V<xyyMDuKy&Ys876W-Y@g27wLOpdHY>2AS0}FuA(l8h8^q*.+M!KIa5Bv/UUz3-:YzKmCtO]vohYK:Vsg!gX+O[^T)0OZh-CY@%S$z*brIur#M}0x3*8JFddSE%F^YEnxErTnA[ZJ9MZd[aBl3F:Qin3!ol7zP6k<F+%F.-mI0CJk6p)&]v8G@Pel?Uu[z$Ed=fUMN#A&]&WQjc8@u8}qg-iiEx7V)F0jDD^zP5>L:oe1(jL8x4^gu]x:l3B)A<l%d/pVxA9x^D^x4#kcIV./)*=C)le!{}WYH$)(/1@}<>1^jO%ho9xRQuG6>!F@}*N1j<QTDw$r}1v^!PZUR>:9q51x6gLS2mxn&OqD.8%d+@PtG-OQiBvqBBiQBt&cxae1J=ko0P{Dh%rNSVQ#IOnf<.}7[BmP5In=LT95OIu1h)%h{Shs3h#6NOdZHojP>Jh1%KO?U6ai+5(y?T=XmyT%w#de6lB3%Q6-7!7.7jy:+ZP8Fz}!86X!-p2>T{N.ba=LLiD>r1NUD/1iO
+[aSBh/532+ZUZ7aO[F94P0[ya*rZkl{Q){<kyF?=D^uGw)ld6[*UNo-(*&{7ubVg{ZQ9Wa?W%jBw){8=84C<MY)<dHEF>@nEeP[X}$$T%Pz@)z{XABp}zw@K7P2DcY5SyO&EgYP:OL?]5cFP)UADz5u^)dD+Rd<Gbep!!VnF=w*1Pw1&%mzqRY$nDcFZ[S==nR8%m&4)MG]<+W(q6JRSvcNZE^Bl}<V<7g<$oM+X%WGwCj=U58NlTsk=i)pYz%g*+eb00RPKJdrdO22qFC>Xix[:68=D5(rum^i9LG^&m{+Da0/{XOSHsL[^Lbc+SzJhDVnwxCKKaOEvT}{9K>hfOmRV^G.YqG}Rw<{}iH+[IsKAS*{/2*K5e)urgx&xAldrrDa+U/{z%Pc7y(q<jY0t=u+D7N2>9t#:I8&]S66{%67Hj3(qP%(Ic38j-4g4#tjXSIMSH&p0eYXG21?0:vuwB/6]5DynB^/8?6]HnN8F21mO4NjG2GR[-wIRE.NwCxi9{2$AsA[>u#VUr8Y/JGAt3}@c/zO2)}IO=K/Ztx6tU57necbM{rT4oMC>vFmN1<ysLBb{-vB67{WHUUNmd1wG8PC@G4uAG}egfX^>FI+ce6>S6zyOK@rB(p+$gXUk5LTAb-kWcXp*<Rpv=a3o[v9I3n.j%Ogr!24VA>.^#d<8{bGB2{RwR5j{Md9}XnFsai2?P:kr5}!OPWQMmax5)=M[PuOf(%1>an6qqjQ/B#lQPL?G)F5%opS+O2^8S+4NTB0MSAst0E-.zl#c6=]-g&xAoBjsshwU9Mm9/@ymrb?#yA77x}b>W+KkPYG!muJYU:xBWm-@f[1teN:AY(=Sr(C+6HKvTWA64sdpe4Z#ru+Xa87=.xnL66!]Y4r(q0!>h-FA$2@@{??3SqvHDM{)bcPQ.[<b[ia6rDbRF)bzzg!{]0gqHqAqA-vHDRDkCieJ!E9p=9L7^)XLp9kjT]%8CE)jbF->mG8ucJ@(raGnnSid
+0b?VuUjU3eB%CZ<lvDhN[:?S/=]N/T8S0)XR1+hXI%RL2EFBJc[DA[8}$:VU<2RNazu6Lhb-E@lYL*Tnyz$9e^t(opi#QEr5bj>r{vkHHI612+cT?w+?:l)-mM%4DN[iL862>q}Ms$hmLv%^N?YXb%)SS7pm!KhjPk!.]0lbF?cN-8frF5!X8hucH@o^$VI*bxAUSS79Qzde6HXkcZ=@@ADc>gDXAuKlM7@M4Qv2M<]As*Zu9ysMt4c7g9v#OqpKh8Qvx8(AYk]tw!Y<B]CYj-5yaDFnRGM)TtUSS79QDz#.8s5c:R=ed2dNCg^9f2gC-&}Pd7]5&{xN7u/CX<HYvn}}y-Ya+72-Jhs1#5I?.Qd&([lKY=teNoBD5LPd[D+7ZDJ(gz3522lc=dlzyL98RfoBi>WgOFn7}a5Sw7hUgeW2K?QPF/1JqwTR7]FF$FIy#!0leXy7*t=4k2++fz5xfq9+d2)jox3BO=K!$LRxGxBOHPcJK&L*33?FH7ub]y.$BHDJaN:p<:R{e*T+j2-Yod380w:-=LT960aRJ{80r5=&6H*RkP5U-xn?-W7R*(qm)3=Yg:!l%./{O+f-BuP@%KahAs43-JpD@aI[+W&[1]JUYzANMHi${P.Hg{!=P#TA(eA?SzxtQSSS9jSul2^f]zPo})jIf6:nVk@abj=0fFmE}>lHl!(yd[E.xso0{Vxo7fU[<*95d?Q^tW6HinDbRuSg(&l7w%>[ag#(fWAI-:dFAW1&C1C[Gjos8L<NozWaAwG7KGFN/Sbt1B>!P-4h-{lYg{4BI/kHo)]@Fhv!gfD2B5l-ThUtRTL/GmJEx^&b>IjEnbbSvaC.l{JZV.+Z6P{{>Fo58o8%%%h0}kG7H}[B+1:nKIo4/9BLF6&e+6]M<7yapvPA@T@:yxSxQAJ-%7-8YQ]hF&$zpWcM<FGfE*302D66S(1/N+1m})5Ev{o0Z#ND/]t+6uPj9$Z{ZZb^57o7Vr%E!:lhA!lM4v4%
+b<7e>J5RcYid*cOidjhRZnwRbfV$9a}rMiaLJbiTa737e5@6nr&Y/zdcp?GxE/}CoaX.HV9%25}bK2E#@sK<HfPn)z%h0}$!v33r@BHI80aBOx@4=7Gi1>&MMQdc{vfvqA.rDH7w*7=V6FJe>RUZqXl!MfMx0zxcqc1SEN?g^S7[h?y<}[F=6&rV=N42zs)1Q)W0gomPu3Bj7xJWG.//c5s&TJEz7nimX%l>.-!7DsIZ&R]39BLBmv*493pjS8j)$4$RLv.03cqjso@t/2y5W9)s(E<[?eyG3#sn%OJeL(uu76!kdV7m}gi}mB7:3mw9QQ)D3{Rh&7zV(u4ZwKXO2LmFbuQ}#@kz5fWlJsw-K2xi:W(aiZ80.b6XtCyMR!+mcz^f?2tpnPu^%4RJpEa&{cE&5YL4chMUc7]eYtbw0{z@K/Ep$j.OFo.9LWRg9b-D}HJwqEM2zpwYhK%aCd<Q0?ApWbS*)]Br)BM@@jWD:FP=*.%ALc}aFM+V^e:(L.uzW<l}Xr!RLRx0ta)]XBe%Vj=TI{vpr)fN^1FLfLa:pif{I=WXVEqWFA]]WkjN=ZyP%!7W]t6ua@<h^$4^aJ.?L1/KaC96Mlib4/Tu*]/CGqqGQ4GDr>wR1e6z?I$Q<L]M7F@bZRf=VRl@y@:TA7E(Wz.8Byn4*Pj0ab)5O1)nbenstItV6P<8rh%6!{st1jmXQJE+wd.K7RNkukN/H>Wwiqb1]xXed<=jTM]5b/-rya)yY[xvhcyHvRivEY:x3ab4y4jo<*l62^9).tQ=3G0EwVFmx=WT-k0&6qsVGAEBeLuY1FBXdS<GwWNRAE1Xw3n{]H#](lQXZmlwpM<7fv{0)f*F@W-yGK1$e*BVxiz4b9@Uyi%D(r=0lK0y0Zg=b4SOoXK:?*AFBb%5wnE%H<Iz<OKDE22T*:9gehCyhvm2<6>!^lm{1jh8Kb(]TR-:?D!gd6[l]nWv/{gS(!97s+Hk(sD:m(ue!DxhMvBc@Sv3
+j}(+5^uOJr?6GuEx$rK6j-Pw9&2yz>(7k9>SJozKyDzd})#qZM!-0%)[H-vsEk2O^PInkEpHA-:EAbJ{alH56yCk+Gi$MDf0JdQ0@HJMXcQ0N3JPG?#=(Yvvh3&VhET4HS:g?.rktSMY[Nf1l}R*uh{&=-a+%oX()UyeixS7R)FiOZi=qq.J)B9=Ukj]@J/:9hrbHHVWJQFp5ZN%@SxC$kf/o?h}xGH$5k7H8jSQhVWTk$m7v6GZ(ce$<pf#]5Upu]DhL0)4AIi%I64TnK@Hc3puCUQB<Xlu4:BurMi3Gpd9sUKaVcqoSACBO=>.f(j)&*?}m/FwRs3T}vb(>{Dj&MYzcoqlN&B0e!rYYQHcdV9f5>omO^]R0!KoxTXxV]]vuLK5Emze%D+1d3n8}l3lQM}HQ}5+6za@+I]nx@.BpTMr8]fciBQrTE*0hsBA6?g*hWotZEPnarV*o.?sU%7nLm:4AeEG3MnQHoXsR}U=Hzf^7w<l?BRmnbFZ0-?&/w}$R8>^w.vWrMm1PvPDg9<=a)$zei=ijCpcR2Y*e*9%gNc:q$^Jw1Am9LdIvj:3Gb}K#<8T:3D(I84FWAcedJF?WH/(kz10B5*:#.+AP{!zr5=$!:7OX5v$LEQOwDzNydyff0?FucpZZlh(/vtea3>Mw@Q(nO%n4Yx4#%ujYhmiqgkh->2MMmkaLFt1n--6Zy)+7K:baxRtdW#f3)))<9N7^/l5(e{i7LA!P6Bb{RdTCyg!3UADA1Ptu^8V/iM5S&=QQ3s9Ij8(N{@xu2EV]XIgP/B4K}*NOglWVjF^Slb>aGyKj61YEMX4sUxlT?WlBEM{cXe>D9qpj.D7{c*rS!0jKUd=ml]Xov4(ca9#kjOU+HWLaCP7ZJ@UgiT@*TcTCaGH%%u]id4N{-vAHKUFK+aF*?<8tJ]/JAFEa:E1nUwdwjN?ov*VgvD9KZUxk2=QO7jHgP$]Y+ve&aKv&2wnME)vIG+DOA!?+2G?%56O[}u6
+k{n7w+Slh[nPp90]p0QnSyZ6j}D5yosj$Y::cj/Zti!5bjX24&{lT-:=2]+4)j#mV9OX:2+JihJLcuQaowBVg1]=kL7ICK&Nhg#buGU)%-zDvZ64.ewB*NQ:BT3c5T>FNdZu7<#9m{4(U{QM]x01F?LR}jxyvl5F7$JB.i9D=bfC/]S!4%BDiWgi7NOFcwhtr/*(ugxtTi0X@!YVl9TlCi>TjGitT!)5WJ6RQ(mMj^@j=471HLBimW3TOmzzZgcW3]?%+E0Z4:y?SL2wD-a!+3*p^I3FzqgFC8r)}>NF<8*!9W^^k1KEYZI%ozrg5TU3&Q!m4iShG8goCdx?B<@{Oc[3#Z+@mR@SE92g$o=pudiLykZ28[q#Au8al>LKD.q!%2CUd#-<RM}T.5p3J?V4:JRk:6EVyb(9sSkC0i[p{i$ublSo[?Ai$wufbc:NDTcklTHMM=IazAY%nuo1jw^[zSWbTebG(y(FNh/XJHc2<<G5H2VP{xd>+@0<-w=OsdNWImiM9s)k{dPc3A$Qr<XSDMYQRTfnv&t+>bmxPKB8R[9=MQ%Ll:>8>4tJ%}lCs%.7hkT:FJ1Lv>sB/eXEs}Gv:Y0jmFX{<yl*1hCfG0&b*{1pF^/?fHwA<7J>55Nl=JPn}XT2@W)g4:J^I/2:1:.qc4i$e9lz$nPHZ2EtTAqMWGeb)xEh^XyE3]a*a{!hthBILrgVwLdqg/9fJOp03nr?]{87>@Z!6OgXVl)2jMFW>A!^3QC/R:5TM/S:I)Qh*/XfgF8C>4tg[-TYj0&ukPKO5b0QYo*L/?paOMf<t4lIl)q-Fcw]%Wxxg#3Cg.^<J#a:MWbakiXm+*h#NNR<wulNu(szA=2g8I]M}D9b0}[Oy.ERS!JkziJNn)c&J@(M<juQaV2^4Q5TOY}i}P}IaxJs[+LzsjAwtRDhVL:y6@}d>[fL!l?*b7*XI3bGv)KmVqI{pBk@f^Xs3rySZ?cS4K8C!Do}@I%q+sl7K(w]*g&T
+qn8OFQd&h6DvtziQwyOc(H&E<IYda3T2zZyeOU<tJiJ&%-VO-I{y?rZ3<HRoEH9Wu{y(pueOVcAga*TbR^}y4*tkRe!cFg6l^PA[]>bK:qcE(LO)R8eJktpHb}TZ%c8m467:?FjM>zg+!1N]0YkOFvKe8ml7?1xLFM>7AjpMBel/3i/W&:[77bWes2CCCKcy9.a>(AX02]Z<B[r2?7s}]-qzg5mO@DL]Cq!ZSSFkG5^hFN9bQISkgja(lZHT])IEi{Y9/{H!knSf38tgvxpQrFy4XBX7c>J43cOD?)8hIkv4Xc}*TfZZdkQ85[cWhvM<K}}R$l}tx0>98Xbeq37lQd^8EFWuJF7fV{}w4&j)VhdvYY-LGl=pWf59aOR^eQCeA)e7^c.XoLup895g8=-uCybJ.BMIhV}t2KiX<xUjyF{13DqU2qqR)Q{Sgv9t]vUX{N.-8VLU(xAaWX&%39p&[<3!@5Vs.^*nAit)Xfqv>xXC53Wup6a?vv[[jw:k6jznnrAr?YWvuQqyD<NB.<i&f?PY-cJA??HNP(VzQ5tSlquq>:ZwT]ArhBu3e<%83?3%6Iaa6u(k.}3]ak5:3YUSPy.s<[&.(E%n<?E1lece2?X)LXlE(w@%V^ns(dkHl.^[YPo5b%g{M{E)ln<F75K?G0x9LD8QX]2*tg-2a}TTm^xCP&1-B(*S#2tHmUU6[gBTf>k7Ba{xw/yjaJI9{$TZbOBjiM-V-7n{XCnOhE-{Ui+}UUZdgA4otb$}E+k*m{2$nyHWu{.Amq@R>*H7asagUFW0g<&XuApDcQd?z/-vZfIYIc%JPWFZ]ux95y%#a59^!9UtiyaNMYC2zc/z:$Y6[bBz42HoJJWiyC]+d@aC!>XCcyTaum7US32Ftqv{UrRUQ+nliRJ{v2tpsIDCpO=uKDwcR)iPhlWpk+zoi[sowM*y:8UK6[tQDofqUT32vMPqGm-q{eXyE.UTamBO/G6{=&#aTu#%VFg^lX7VRP)]
+x#&I{wf3%U?.$osF>hgFhTnBm9yKy=bStAW5sV6d6Vw5*u78NvV2P50xJD9{)CmH$!ZU9(?]m-T*R2bVHyO37?8L3ME7M+R+2a8Ob9Z^V^OiaSo5{DlGIt.r[esl7k0popdc::?JT[=[[ZC-N>sKE*h:X^8SMzMX8t8YaG]F*&QWoRHTCCcn<]YVuwlX#Rg:+CK<q%r9qFen.g)f{bVs=.nDhe^bkYl[P(L@[x+6tvcBrD0]sO%@b&-vf2zR9)C%93K)U/Vt=2>fgQZ1qutHi8:2Iz$v#Ei#.}TG<!/Ul[ZCGC}^W2V(/lttoYwh{Xq<!*UPos$eW3Tye9JTGx@g/tJ$ogLXUuW[ymvq*CMd]toT[NATIQ?S%$jZYLW6?#:I-?Os+QVI?N>*AX@K&c3MXZ>kWJ9^zT?6IWG^+pdn=FVj(Ce&lO@qrh1S{H:*6tHrsKo7$<0!L9E)/VM/aEgkXHQi*Ck.D9E&s=!eXZuoL4*E/L4p46eW8l$f)IU:jiT0GPt*1AeJE?*yEbYjJF1M7>hv>BTs!MuR0ID2yOCRuc/8<M)mLCc*gxxRLjjU-dehS?fkCHGQ3zovhTl6^]H?fb>rZ97*GW9LItnOteu?4}!T+G}>7+qxXFD6aYyM.:b4bx*/DqB}xs/=U$V95easqSbS%Z+5mr:zl)dsk.f2VI?MJL#judbB&5brK?.!Mqgm3seiDgbmyXAyw>DpuLr4Pqt>Jg@Op=DE8rA}Te70tOR?y])->pc}(3h6f]}txs(&LG[ND7Ln+2+.CqIkSy(cV$@ku:QJ+>x5/[%c%H38M-tK!h-0xmHYd<.uY*t1PKBqmT^@Pf(s?x[u(DB}9}^Mn.SLhp)Nbfu)mYozJaX5qp{DNh/vht5*#B.c?WtDfiOQ$X^MsTt@Q!-.G:v00Lg/nV.cr%^zTqZn{m]vV?ZWBcEipM/osSysn1n[@jPnU?Ky->pY0hA!06EB66onWR(i+PP[+}^U0UnvgAVAR-jN
+-JMcnjlV=ltp=$sl[kgnO{*di&G.bKFnlU0aIq{{o8qfq}+U)z^.R431)}D%tUGp<iOjMJmqnr)Q.U9#36-DHCCETbUIEJ4g})d@yn]QX^Brs<*tktpae35Cj}u8+r.jkdOpAQ-l)yf2Qc{G}1C{aanHMyI2HON{<yDbQ5erS)(Qcs!70?G<O=X+6d^o]?rmkG@>2?Ay:MIPWWIMF-yu6cKgJ^Ey[&dAwb33WT?bEFc1h?f{>r(Y1f/toU/S9&wjuo?SCPK3Dvo*&EZ+z58v=naFEtZH#[q!ZXr*}5AgHA}LT+8O1{@.F:P@c([?]oBX-{aSg{HEe[*}p=3t?y@5<@{fWyryOvbuv.:aEf[ak0Xy!Ss{4Ygz$5Iomh8XwHGRHwO.?{bKatlLE*[4eH>N}-8(E[JXVK?qAqSqxbG^6+d5KdJJJagNIANf(k0#=z[S.LcUK@TH41D](]JelIMfOMSYKuR[HG1Yt<2iPhh@yDykP%2vq1Oa>@-YD{O].9rU-M}9Fy#N1zIf.*Y&nq/Om%$6ko3IZY<}D}yRh9g@[xfr}FP4d%=p5%j%)x]]p]#sO4MXF&.Gp:O3S&h{6uJ^r&nn@$Sj0Q&m&gts:caQdlS?r}Lk)*G@pZlGfIwUm!QACEndF6$]sHCRXNm@wXkf!mz$D/!27Mv&ZMb8R841LqO{T=B)ADhj?GZ<6*?qmA&}9(e2ySjy![#8V5pf^qVn8?=AB^C4fNWZEK?PU/a{=Mp.<.]5}$*L:+k?XY8@)zGN!2j0<E$sy8k?C6#jS<!6Rb4DT-Pg)CQHx7+PllQOcms^a2vwESiPjf#kEvYLMH4q2S[CGXhSmWsj$/a[4Z3+?wGnoVu?R5NJi4w=eSsWPE5/}+tlZ-q=]hOs@WpTS6=kZQ[??w=osfUR%byUw]do4gFABrq)^MSM^O^.[*D(4/15ft6@7<ki3k}cM/.g0MHg@{tX{}@3u6b[jz=woQIPp0/F9I.W:S2Y)i$g[arn
+P:X={LaZTPQzq19FmXE@if0wXxGmp-?a6mdzsr+/[4@*Q[=u#8l8pikbmS-n^ON@U+Y1@59Nz&:GGzF]rL%XQIeT<5sUOSDZ=>EWNm[Kw6KXntwH^m?bj3q^O^E5ZX<SO&oPbnsD8vp5W.)MWs&+m2vM9^]4W#Jque&G6J*Cv[yJtlAN$-WuC*/Gsi<:D[p)K?8Oi.y$Ny[v?T-epKN3<CUcKj.)ldb@MvTIq$&9w(FzQIpQRH.Ea]QOI?19ZE+))Gim^SW2}XWD2mxVIh=ATa$uT:h]^(<{H7!CvzWprk(g*nv^3k./Np+o={FLfMVV=$-/s^H9vgDdpZ[]^-(q3Dkn*[mKo^F2$#v=i7%xTN-tBJJ:[<kc7:)aGCN(7A[j-9?V{#.s=[ODtzA/B>.fJvsMebO6+P6(DmC-4y$[<r@Q>UE@o)6X51Y>p%:n5e!SUKlW)r$i8o)9zO{:.D#u&E&)hF]!-O&P*m+EF2k5uq!ZZswJEjirD99@.tfOd.RbRVCS2wTI-.L91D#$.HBTgVBtk}!7zd=8%jIv6GMcNUtXq>:x7D3dL)iIb%xPG{i=8jP-zcTc-WMI#MVmLCWvEmY%KZEMqKZzh!:s!Uh<S?J/<x1R(ZUjPg+oo9ju!m{ebeppC<G/&eMhEooAin]w9cMF=/a8H-Nt67Dg8!UbDJe8?xLba:4Yc<ZWa/p+:2R<Jo@.MwcU(l3OWfCJ($f(@N7Oe!w9DEZC[T{*JIeG)--4vH@++uZ!9jy0gBcmMiWkg5(8U3PStTIhtzmLhM7GZ(pV}>pD{WUVRVbK/q#Z*zSF.^l+gJF6q<$BNgZM[Rkz-dMYgKmR+ghOM*QZdOnhlWA/u}pVXQ2m(NtCZ.Igf4$*r{D9}-o$l<.dq1nix/p42HRfEfNACWH7+&}1!X?(/TqvMJ8*}[gAeL/3}q:8JST6yW6Od/hRv&[I9m/&A=XE>49y-OZeFxprJCRa[a/KY.dI4M(N>[G.2bMWhig.
+s(9B*]m2L@dC=7X8$%2$6KDCMjp0XgP$GlDxy+Ha^)K-1DJi:>J58:e-iMt^Eje-Q!XU69TKj&GoNlV7d9{QUvB0xyWs9=0:!FsF-[SJJe*}Ko^?M)#awD:6n#[&e>zeYB{R+Gyx<PIY8d+kL*Ng#*bYmP#=8XAztea!%/^kTarir5]I6$KiiwUiSB7QlxR.@xhETbU[10(su2tel9a0+&yEjjv^<YMX[ntJxx(vmyom[x}]zKM)w8ZSRW}E(9@Y!JHPE5YavwMFm2S[ii{JyfaGmVCmVmKc/$Ll[4QJ0EK@mc{h+WWntlW+.&tM=SbLD[/tAM/9pXBs$OLv9wMTTJwn0h8S}no&GJs=.w)=vsfTZz7oH(:-z=%LB!u[^)2Ket@g$AOjVGj{cgFnMtbUv[Y^OYE@>bFg#aO+5]3WraBt9Zzg.E]ta&d(iHCDMR[0BPD-Biq<%K&Ok+Z4>y<Wr]}=O297T&7[}66GE*ia7oEfsySoA9n-v1lS@Wavr-hNCi/!%x/uO/%k[x1i[%9Nz?xG.).=Q:HY8L5KHYsUORIP.T$-zF9Ab)IYG<norAmEh&B3MAW0CAdZmbg&w>q8NWp?Pn713C&}ny)]P/3Wqs9m7wjnYp%mCUYLRm[B8XlH8}4>@Pm=H13c$JnVPo+jpdItQbj.>Yoq{r@(E+ltB}lH79Q+=O3F+!o/a3IwlQa}OWY-6P9N%=(yocEcn:PZ{OM/K%(M6{BZf*u*i]u7D3zI?ma]!fR58P)>?nqe]%1fyL>ua$^K0aD&Lk^oc[1Nn<.mb/^EU8wl54gkH{mCgsk1iiuW(r/nyN@^<4r50utx)2Lleyd>fjfllABlC#B:Ki{@HT08<g2PR/rr19)Ok[UvCS@w{CE@?W}J/({-ku/MvyNDC[X]v)>HfOKNLw&MUBhjGUi^C@)eWGxkwm6%5)jBb@Oc&fc[(?K*(dTU6E+:<2s0E1]j9)xgaB>kXzOM=vEx{]9Ubd.ZHu$2Pb}N
+4vUPW}@aQZ-WBm)}@8vD1aWTvvu=He-YaK)9wCG0i5=yy:}hMUin-Wau(R&SHE94(9ep^p7@KTwv2Mrol^bcNHo!POK$+3A9+jzV3GSlojS06M4N$-X{+QhPi0{Gq2+@Q8f[#nYp%)baBk@p7fE^-<MG{LL-@I>wvn}}y-Yb<Z]bjrb5AU=rB&8I@8O9SyFkH}Myc&$K!c:6u-Ycc/]blM>.Qd&!BakLr<3Z=V3/<3i)i6R=Jfw(FPe2QxHl*DwB4?9YMOvX(9+gYMG.ZOOapdMRQAK5VD86e4v{?bqJ8H)Xf}5kNk4y8#G.ZOOLRxGx]XwswZ=m(s=v!s1i4Cpa8rm[^N9MR]2T=/nMIS-.7>i=?B0cI#=jdTr-YbQS]blOo.Qd&YA^[(b&@W9)aim@JI?iTPg5-kE}hAZ>fTQ:1XXB5N[EJIiu=V-oom!<&g5+I>>heOZ{Tghd)/O3u}c9IF<tNm.W!=sn%8^E&}Fy5ek3YeD!6iJ3jo5U0v/lyCfgIvst[I:mt6p*Hx$C)F5l3+&n10?R4#Z)!].E1Bmg!Dp2J*.[/!J?MN/&mGyiJU?&+zQHLQP6Tv4uRWN]d}vGaN^8v4H8WT+4bUD%&Ku)of-rU9O-GNpm#R-U?K.dyB1aGT/oSC)mY=4mn&ZZ=Wn6t[.[3tgJ^!IQq$LJH+eUyi%!e&X*%Lw5r3#GT::c/{HG81r<}lBW}<e!b-34cTzIYWHUZWHqo(XNazG+zBm*.QJS4>H$:P?)j<un0X^w&^Kd(C(W]+T4LQW5+O#cBW*>8/QinE1a*dbNlZ.y.2Gy:UX&Nmjdf%]?0oOoOD2<LRO=E0{Snf3>H]U8)qkLg}FPlTjU1SXJ4mn>nZ=Wu9zWO[!(CbJp*t9*6XyC1zgMp[uilB*VR)Z^La-p>09Z#Du1%E>}u(?=7iS{ReMnI0%vJkAcVqgl4ywVs1-4qIzVv)H85An#Xw)5>)Cm-+GsWOA5mJ2ON&?T:]*K1I-{xUdF
+lyvGKa)]@TjCqBh3{[hJyLBQ-c<tLiOoch{*BizF+k?bl.N}JcAMXvN0byaC2YWosE3u6H0e}K=i?S]:l5n.LM)A1]i%Y?00Kz{p(K[(Xt2(ONN@E!^vR?uPDk1$xX=%r}0l&X2vrJ2B*Yi.hSA]lv-mqetd6)?%K05ntB?d!8NsNIN=<[+OH&&t%l6#NKvv[WHrKkDMhWF:x2{PmqZAw=&M?psSl6#O@1{wuQ+nIjJDFFO3hZ!$6)<m{RA1IreIFWUk2vUu$h>tg:5AYDS@2Lj}53Ue]zAoPW{0Xxpx&S-*aK4qUJ0I+Zj[<8f5y}^^]*UUPt$y1$6nen/zAx-Z(YdXrky^I/nQouFfN>SV!a+6fCDZl)^^[*?&K9O<@u01x@$RK}f:NdFz>L{gSb8a2Wn.uF5zAn$Q1}jimM^+wNt}l0l1?hRpwfFUKOqdf?u]Z-Ly{art5Z[$H{]IYGM:#Ru>WXeZrL%-twVCF2?#KF*OGM-h3!h60coP1fnb>mpi[89+)u.&K3-nzK#<F=ab9%-gy[J*F=z.UWz?Z&EIRFyRYyRsien.1+>5GHH>=Gk(Bc08S9^zph[0QQ&#GXNQ4GDr/kIq$6z?w{Q<L&K2t+SR=t-*/JF2(Mp9b!<t(a}^:2hvkuQYc2=y(D4U9IF&[g>TLX>*u6N!Gszta>XJ^xn!@fqvlY3JD>%9+Zf5x%v!fAD{u[5%2hWxVMOvruh4tl{2OTfe9v>]+d78z&?He]*T/nuVevNP$GbRpYAlJ(*q=a%lyX*)0(XZwMD0!b(p}=xpCY5z8+ZBtF5QdW6V@vWAc<TZ#g+Mi/#10+HEcL8tAIZE5x1G?c3E=>}50e^BcZ?>v!NB*EXbM>}ntx{N@51mEk{hSq/(-B&bi8]@ykfZp5i)8/PSROBfd-HM?c$@j})mn!-(k3^SfsjD[Q>zX7{-JX}O[A2O2kHpTpR)MTO2{{JRB?+b(7{}1$pea*.0!ikq(/vQb%ZyFitG0(BD
+k8vj5fXVn{}/$z=UA&@HWma^uK[YzA@uQ9NbTjxy7%:dPiO)Mauh3&Z<BngsB8?Yi{({tU=yuDbdfB=cUd^%+o5$F)0kg+SVY/]/i^e6)?e1c=Jn2Ubxzuuh7h(p[jsFO}ZWR}9>01E:<h+GZdAgu{)uPHh)qosS?ySp*AbA7eCEJ*gL6&ZK+ftI7&GhlB}U.N:iolIFj6u6z[L/*qi]irECXN!aI(k0D]f=JVt8bMJxq}qUDHlzqBX=Irm)0/:64KhT2Ez]y}fYp6-W(s#nMJ=myej-V}eXx%fg2Q*0S9H^n5O.>ZNGNJ(rg90RjJ}/0v$&uFTb8L{M/D*-H&Ac5?ua6Prby!ODYh!nLx3wfN[FJR(fK?/1@m#=YE]GEX9Y*Y6wKWR(I)Csdq^!5W!QoTgKs[zHi]1wv/ufW[.Nc]vlKXsn9$YygSe@se2E<jyv^z!o(pgzgv$b@m/C@i>3-p(^q#sJj@KHw>AKfA>fckT5[lF+rfe8Eav**=Su3PUu&x]>gdTqu!)seygZ]Hyds-#VR4a^!X.Hnsg%3&v0}Szxy&myIRmb@?O9eQ)5N!zVgUN{/FIvheYh9mz/-N5oigx<D*Liz986Z[}LJJd5]y*F>@dFJ/1On{)?z5MV1){H@<wZ=8L#ioWp}V7=XoFRanf[]XI0p#p35@{N0cN0+bI(1puH#D[u9W*D!Re!v*T/8t*}:O&+U&vSi:s6z5d6@ETb@ipq(L*BguWiHWfdM9P)iwu<Z$6j5RiKEVDR3EOVhhY<t#6ElfBc(H&nK(?r&o!:5Lb/ya+Q<5Dhi^vUWlk&L^Ej{WXaCQaw^igdkP@uPN@Z8.{Gt&dXpU@K!Zky^t11y3wg5.w9i?L5n<z3r3m<2-d[K(u=]4:a3#pi)Q]exX#4t&gNl8&/d52(D:%}j&=iKhlg>Xx$PwuCfj/XC/QK)VhKpAMr6^GtO@}xHPGhmeQ[bF09i?uFk8(>nWd?B25:zGw.*0Vd8d<B$hkt
+MI.&nxK2/!>j*{}}zi#]=.9pjm>U!Gy{-j.iFRNY*+$Q0*{@81aj2L$(I1&<ASbXZY^.-2{*EMOlo/pA&Dpk[*y7G-YV4%cnQlvt)<-S0y+aFgBpy[b8NJ-=I.gca5.WVEf8c4J/xd%clraMNGJkxF[fik+y-v0v}Nn6rTWJd#!B<jbtTOf+nWJ=d*3[w.eQG%MPWg!L!/9?e<VODEA4QH.ZrZVdDl6g&5?TC{I%[C^t(N:zz:To!x$wH(ZfXVJS<A(lSSgqsfkWY*)GMY/QUN-9[EvpC17<TiE4A41tSnWe5P7)pz#)4D.+o=xTXE6@p[zJcL%K$0KutbDmuBYBW6n0P}X(SN}NY4/JJzFcu=Q*b:2}>2YAckmgZm())I<WkXa0WeokSMQEJacL*<PH=Qpj<tZybtQ^0SkgUJ<JyZe9vX<Sv46IiRz/2y6Q?KmXk14GcN&T9k*)u#c=*!UW/[8#YK>{3Ja.vvHVPQKcR<)[oFhEUds/2wx[c*ujEBxrv{Fu44F3Pvb*-Bkky3GTho-keT^hDlZbzzdxUy^F+{u=O43nu^H}gfy)}AUMlI!=r3w^UB$>:&6iplfvG:+yS{=q?/1Q29Nf{?(78kR}A9+H^WkJBD(?(3>(b#-&IGWvP=VH[u:.AnpBb%M:Ijs^AUF1?euOpIg)U):epQ6SPd(O2Sv}BBh%6(B)sg#f@YxMBh<v%ABa3X>zKUZ$RTT$=:xOWXl:kDsr>7.5tZ=#}<N>aDdV6d#tV)LgTOoYJ@9H=7H(osID8^[:DfKbpFoFw7Fw7&KRN&Rdid0*xj=^hFO)K*bBWJy-{&ZvkM7$^[1T{.OR5HXsLAlEjhq5My]aHSR=#S868&#SLCBx[wT)wq7CXgd$aiQha5Ze[Vhys]CW:LL?3]Pe@bET<Md0@l/8/xXxgrF5Q+V5-}<NLkb<00en<UP0g:/9GZM7:Z^Qc]@*7kn>wGkCh}/%$BawX6k^)1B#y@!p75sMUPO}8aE3
+<j{XF-t^1kV8/7/VG%cYyaje.s4:@DYKQY^a[!@DNIavhSL0vCt:eo{YcO#WDnX&e*4K.4]+6hE*1XHC!3xXOS&J[EJ8c]OwnF?.&(m(TbYn1v3W1>2pA]j4xlJ8.APtJYL5bJKBgWQX(%/27W:akG<i$DZ(%/0N7NgtZz?+ULl)xkPAQb7$FW1:L+Xt*abb.KP^W?-cJ8dcG==dtF*F5)Lq/x[m)R774}QkYFqr9>>(X>NQxlr*!{)Q/Pfl3+U@1JQk{JL3/H{Qn<abL}LCih?jYLErm&u(t$<i]c-CLT@d(bim?T@d]TlV%oMol7f?No.&UWngK+8Z.oG:--<:nK5cN-=[kO8$MLD{?EtT.0Bfa/wO!V&>4q?-nPC.)ALz.q6L5H1E+Krp@SZCa<MRkP25S&R@y&ktZY#xnmWa@AX/)N19M40ta4&LKP?(JjiY{?Ta/TmTGCMB3H@kJgU}Vlt9g2*O<HUlB6mxokVRVD@{g[trTugzLE[C95/Yt$<nz973#g?.3zC8jLayjP1xYn?!4NxfZgXJAum?H=l!k:?Px@f4nX<1sm#W%]!!^7LEMtINNip/4<H:/BL9fztxE#QI<*XyW}dsleN#.L@1&Y?I7&0d3**2FSC[jt&A]VpRRGWNTiZnK!7/OJb&*Bkeei=Aj4j9!eo?9[cG.Vs3cRCdc.dS+bK[pBfp43F(z9X+zbZ$TEqXxm5M(F3Z(L@+t}x1Z<IYCWoC!ZvETpzpjhZoH1C1d6Iw@q$pUh7(GS+G+^pV46ASY.}q!!BbhT&OU86PV%*&:H)>2r%r?(lxLZGZmFl&C5Ygn4q.<(LLE2t3PZo!^6O^dwy%<6H5n:a=h{]?h6ShZ+5kF(R$%Je27N*uagM@SXTe2fHf!?1Y&sFG&&[aUwP%lzE?&?vE-rf:2K3#o7/mMwdE<9t<UVAU3awb9iUge!Q<%4d=%8k7%SVeD$X9*UEIdZF4307&(Z<&=QQbu?eLS8BPJVLG.H^t
+AKyY.WaULfNPQFRNO*naz{Ij6tyH[1!4YS%ZNBt-O$CeePk[sfmA?!.>#$8}9-UHVXl./=]RmX?p21Q{d)k&:*%*yz=RosFO3>]DRwt#^XOpP%RwU9DRx3P8=*K.h2yqA0foi79+Ck7oS9+bvs11oy!]Pv2:5hIoOHm<sU0u4-!egVXd=w3w.7*&$S@*Y=a:)VK&K.?hn59lE>C+5JDy!qtjOo)&?EVvx=R]39pU*j1CXNEEq8Nk{E:p%!1y2R-HzONn}zyxh]%N&wZPUy7Xu+@bB-aQ-(^tJc9EUE5/hz^1XKLPL!opS0T((WPR*s%1D1)@X!om1/Yj$M@q*eRvNPQG0N:E^S?*C1A^W)uLtB{eh.=7tG@HfsQDy=RZ{XwDLR=H:G+aJK$XPw?/^h{[q6A8RkAmvOVO-8Pe>CT#EDx8ei95QVTEF$aQQWx)$DE@ZD!n}1Lr)p>t+#yr+QD]4(m@t{A($7tK}v!yxL{5+3cv%OWGWHS27K!Vprtv8OT+yLOba#1Lf)o%XhKbB?BtA?Fq:*moZJrJF8W0YMa)FytPSy>bpMdvlK&%+/MqL6ZWaiSapH0M0*iN(.W6^>FRMC?7irV1vR(^M-r5I-czoX3!r&:/BqnDyJw$%*4a.@W$eC9RhrAC{>M3a$m}BqG9CCVK>i2Upqv/whicAh7GxKCh0C#$y9MQ])ODmw&h)PDM5SH/@jMrIHm(zoT-0moJc!zk.Mw&qca%3770T<=kcE9$#zu{}*vJpor/j^Stt+Mx5jHEidCEF9l.^]PsLIXyIQcDO:dRK>J&gwWDO4FzPw/i5&gFbLQ%!:}zLy&lxw[z#c0ZL.iT?RoQnCi00Sa&=H3SQ]vf&*q+FMc>@w-z0yxuOmXP}sL9)Evoo4P1/QJ(IkiI<f8JPVIBeG=Tg(7@*D$b@*DYZ.k@*:@pPM{]h/<WeF@$2g&R>.&o^Z{S@j*zlW6<h^K&w$!<BO-n)#qI4=bUBMC[rPrJ6&8zy%lV
+Y{-*vU5iMGu9B}^e7V]>+254Lq$zLjX1^-/BoZ)o:.J0(CwE1%XXDM(U4{nelZy+VYRP!&?co0S?evnx}+oR<.Hobl&Qi&qT@rmsx[=25xdt<yx)?#!H%H6/7ldUCV-5e8&fLni))L$gk8nDFCdtmb.PMi08%Pff)#z!T}AsAc}AmX/])k-o])rET(H&nK(?r&o!:5Lb/ya+Q<5Dhi^vUWlk&L^Ej{WXaC&Qi0+qzCIXC(BL+EEJ.<tkz2k44SBd4X*LL71LwfYI{@.8}o(9!BlK7#FieKncJk5L^UFLo9djIX0uPU0i&[-O(5yJ>&yOKW1-k@USpbJt)<1):Sn0JQ8TV)evRrVyeSK:6/C/18<XfU0iIF8}QZnWV.$=sNOjS<[C#=yDAnFDSeVsyocEBb?e%7)ki?BgCD>&vnua1u)Y)(8jlS:Jl:?U&M:[OO%aj3siG=0CNgIj=l.owCOQY?ZC=KR(UDqF/T{&=va$.13Z27S([gRh3^8XjYP%f{JcuD4}.NB&?O(Ba/rx}hlcF{z*gp)qzFK9Qoluo0=6uthuK#3cj3-KW]S$}[HzGHuYhc<vYdk2A*cbLcyPc8t!yusX(90N<jTiPB@zqAf!do-H68gr/**.w<WAc$&y9?mdXa=Yrg}-*=KL+/X?p3GmW0YMu2GZ:C!(hQvmpj+D%nN!2(&kBE{E]tml^j.z/]v+UcRMawa[$ZP*p&U{cC%Rz3e.>)SKS$<DL#R%bYr!b!acNXF3hh}e7AFdA1gyQ4xe>FufyI<Yy}RE*k!gy/[NJ8p1jPz}UI5xT81Z4*Sjq%.vCtmGkYe(g$QSsYMX56Zk481/.Q3crXss]{Xb&l&@=@$?BEAf}I6DL[6%I-UJym(@mYUYF1[8colpgcE#}$Yu(Nb.66V&%&jY6&*9<YeC{z#+(Wke#}Wz<RRfyeDXuApDxcVX&Hhjc/mTybBFWRhB@BW%UuT?l]PN33l<H+1>Gg7G0E$^Z?3HhKj@}08=
+be8)F-VhFT@rvM0^r$+Q?u!weZE8rOJRSs&>q00/)+^=rz<obgUPD97uQ2Xi6uQm([#[%@*>p4@okBzbJPz/g+obc]cYEe0(wKl?rN*ZNW/qSX}%R5-?+-eB<Bo*jT]@kx-[XMYFfF}g*yi[)K/S$.BONkmf-LvurwB<Ku0iUhTKQ<PN7]x{z{zDHpJ=yt.NV]L)ky-xZ5ay#X$$2F9L0lUO>07k=O806}V(9Ou[6*e9jq4-k:*Di1a{1Y*)#D64=>(c:EtMyn:dvlZxAtaO<Z2)1a#WSU=PCYgnZmu+GDP&A.)sQPm:P33a0qp&)4O!BJAOD&faU2sgFLvQ3J=uOKdwqlW1FT2G9Qj36l}zB5VZ0>Bmq9IbDgsVE&sBB%$Sey?m[]}}m7D-<g8/Y/hm5v{anuwZrvg(riU%VC1N^MyR1>=F6?4!E>H6P&z6S5ppDjp08T/rnGUZe>NT5?i5IG^Oi49*0OOML8SFI[UWn9y[]sx5]v^pi?1UZBh)vJ8k0G??G]a<gcTIvK*jd]I5S8PKHT&Bf4pGQYbQA@I1r^JxxdEOL5bKhH+=3.nS]6e{O2*@B^uN#qX)JSuBz9#PsQYt6N.^sBS?yJJFH!u2)<0gI92N^n-WlolFvSlNENl)]4A!Gzm-wIEcMXK?YlMWp>etmkwCO]Dvts1]H17SJitmR}u0M9^/2XTE<Wyfnq(Q5K&WqO5BV43C>Z<FZX%oVkQbmqaIrHa&[]jWW)fr90eR9>Gcv&m*9F-XWCzFslg6<O??BZoD(w3W1-l+2wnFQx9vZl)YIQJ1tcn7lf>/(lE21xLG(M)hm0)^f^#m6ofEMJ@Pm!%TM<[5$<N%0]Hc3c@G5M1(j4yHPLA}Zdx=siqqp9DxqI)2smC&)b^6]B:z:w/vmsAT9cc5GPxoM^-7XjZMmpWFgtAH9+anE/xxaWCsZaB(p/7w(6Jc[Ui7Gfd$tN:8Va-v$P+%FixowDf#9Q&Y&wkES>q3:q>ll+C.
+[lvJc:oXX(sXsa&i+(rhf6Oa(:lk.uj:w4yi)Kga1dO%{dp+1TsT$d@j+n0&?bmdRrSMXF(mZh>VAY==BYS7%w#1fWc$Cs^+o^tE5pt&lO0s{Kz./0dO<-DUQ@nS!WIEf$d}FCZIFaA2[OZhpJWJ5GFic]dFqD#O!#Lq}pi)R@EhYyyc$iRb9syEn6K=4m[ul[3w:1G5Gd.snaW+QEW9p0)]G[sVB]g@$vT3Njv%$.^?.?<vb6t]m!](x$1<xG*}SMTH[Zj:dY7RhyU02k4QIAPC[Zj+-(9P89r!?BZWaJ)[UZgC}pVecbju@^i{?4wqwyl#DfWnNC^#ba3*-6ss6)o+DAya0/L][qs<j@%m4RESjN1I3]JgI?K=BAQ?-&x+jQJajb!nAXAAkS1ek8yHV?t@Vi5B&2LbtFnSvl0mC:J/X*prr-hOvT[zvRf4dvN!5x@K&UYX8&B^i}YKJ@C1AZq#dHpIR)U#7u!RWebQ]bO)s{8T6]smk)j:HT+HRa<-5Wb}1)XN{dS!bjVA5?BJp}aN/)Rzk]!:Ms]vVP8hm4H9u9CN)[)f()XMBN!8.)88FPDnGCB7oV=eu)DD%9yiicxOC[HtL3v2[M!hP&UUlacK{tVjkArSc>lI!u7.HEF&se#Z+:9MRcD8{bgP11boz[OY8Cwfj^]vvi!tLD}O?R9JRAlU<l?T/qY.pz/C&NG/:EHTRcZ5L*dtVkjw1RiK{i[J8Ges*d#cb8{MHd3%V/XQw3>gUdr(uPC[T!90q0Dh*5:?a)/]$kBR&h@A3&Ji$b(jq8^z.pv4yQ906:2Q/ucE]r:D8{bgH2/uOz&+1Rs}QuSGxhmKiU7MhQG{P0q*Tqax5-m/=SJzz]{<MN!/%4NNuwKmKL@j=f5%3*)eQ=ZHDfe2D#NTBJs/<hfOcI[OiQkpS<Z:^{c}aH@rU:p:}hJxLr!U}jh{4j^![YJY+VNj=uyY50/a8j.Nh@Ep$QTxYimQ(:}cc=8gO1a-DkhM
+8W0YMf}#ASA8llK!$JIteL8nf8}Grp>eE1DqW.8{I:R0UoLHWQ8FOD9qMl:fCjjMN<2k!*YBc1s8!!7^ddtzGAC]?B[2W8YY+tKQ[]^nYRO2w]56/*4/2]BfT^}ZOTZo4gc#QF$s?T.6PUc*)hJILpY:&39cvg^Ti+?S#3Qe<?l>bdDB%ycVDf}*pRO7FrN<-g=+*w4R!0o^ULpgtW={TCFY]?gS<7&+w7Jtrm{JB#kOoebj8A&]Y8%cExxs!$K{ULXOK3[t^8rHmDxtknZh45az{OGPv?t@.]8srN%9KbH<)Gu/@IH4GnUn{!OLY[L68(x-&Fr:G}yhDFmeMp0y!ohJO<5?fiiX[>M0oT50InAii5-[AvBmI}1.F$k<0QUbbp9!Q3PR&q)BDz4ipr6RFXq-=/JQ/?tY&h^(^g/$wP[rG/&#LGvC@-%T3fd]IRLiCJ674zNof#Su2K/fpPWBt]Paq[Ul#6T!y[^#Z[NaYum4av?UI7nCSrXnL=++9udM[X9-/:PI8+(MvhRH}{*P=z(2E>B#iw#C=>s9!YlIxQCIAR(.n9rg=[5FLR1=TM&d+Sx(*AnZWiWma<TY*GH1.VnKJG$>A>%Y}ULp(pE{?kDEsebLRIw.Rb/Krho-t:sbnAjRS++a4oAcoCC4Eu.w4>vXT5@vMwfu8?[I<MH-:jzSbUhGO?WE2Wh!P6:H}^n:8fl1@avtu>$U}=ZR]ut(zxv0axuGMSH0=#6[[1NVM>Jm4z>Jm@MOejJi49fnYB=uM2:fPh9DNr4wTq$Z4-!GWV{m9dh/(LL8IdL0nzEDVvE8%P>wZwK8+BVFjsAXqKG2H&/)a3j%ey7ff*8]Q+}YCa[m&FMeP=ONyP=Mo/ZNE<JAnqk2Ry=0VxT(]ZB/)VdD&0c]X2n9!RqGJy/(k=OKO3e09}W7zC/fiShEbClv1%T3](ZH4MMY}JUg8sJ1wVU.BlWjy.MJlA{:PmTn?]wQ3MS0QxB<mPYg-7)wFjM.
+gIMf5X@se5JZ)WM<-i9EB>A57c2ReB0!jMT-glVV<j8=vhc<*:iX8X{K&xwqv$#yFs.E0GoxV43>ByE$WcMdi(ccB1R1xDj+zP(/&=5U{/TA<XOb8Y}-hvJ{iqcU0xo4y:9VS]A:XaVqK2={x4-&duY@.zcM$a>KDb:H5FSSxGRvh{Zz1cm#-hLXDyG{egAQr9s+q:UQ<tj<AlvvPGC27jhud2I*]}@QC)4idq}Ro.OCZRrX@tXO*+I*%:bZ}CsRty&f>%W.<ijo&D:k3I<&5gRsAQiYj*$AmP(XJc(U)iQs7tuqN=ceWsFvBzDZm=J[de(b++)c+({ZSUOFiyJpbwqEWGwRRi=sy=ZQISIojbhEH2iQq.XsvMXAHt^sIdqO]JY&yZ{5{P$)NjCxixq}ncXtY2ct)Od58*UB*:*f)JBLf#P]5U4[z<[]V2=2vI8+h=<Lt=mYg:z3Elzay@Tvq>0D/gavr&KQV+0G0gV5E4j{y+9eWGBqCHutUb*&r0zR=T]8{=JLiT$&a}1OaHw9h/{[zKD{Cmr.h-*=v*ZdA1YAP3CtyDxlk5<IE$L+YzFxMs[O{87<>mrm<L([KPqA*R7J0]6thh({^g1S#5w3)i/]^{Mw3BuUdQ&q6)(AtKN{9$(JX!UdM6AQOqXs4/##tzyn42ua#{.PE-Rt=/1$3YXID}LJJd5/(7@>@c)eTI7=Ps2aHKY4oy?S^!(2O<?)!BzWF(Z$Z?[)zF{veX-}jsdmoO=60m>^!Wn9qHwrq:JY-eA&WdMt!X[sfiPkv.ml>n:r>.qtMnt:ixh&vAEeD}?{u1k]a5+[fmxPe>[<7O}Bk9/?($1%s(nB$DAq4L@Hizs9^M@*jYb?:DGy&s]Htn0(YWar^hcv{Uu@vEmT{TuW&ksa@(1gd61n*gz$)-1.eaDto.#^cb*k/?VPCIc&O7A6GcQo@sNK2L.:@V43j:h:VHN7t7*(sE.Yw.t.BY/g^XzRdf>SB[.!+HN[VA<.
+E[dI*qk!yIc:JzWbp6M${c@uph]XgcE#aV8o<k>44S=OaC>?Ha8X.+J>]m&d}oXzDZ@(bt!:{(4jR4C0WMh&Xd!@WUW4eK-*Jur0/eXab{{Gu9gF3yS)RPFu^t)zJ9kXlQ=n#MDA%W4OO^02G1$<Y/-^AS*Oo(a9Cds{jdoYN8bilecYV4$7IST).Sn0Hi@3.ml5Ew#vWNlQLp$]Mys=.Z+A]+&&{K:0IuwJJO9Ob7.p}9n-jbzPNBB2WQa6d?p!yuroH<x*b)u@h#QjYys![TG<dMQaD5XZ=a[CY&#Qig&uY+U[/Q@Exh/Kr9y>?XD>{Fe{8^b<@::j5Mrxv90N!C<fzz#Cd))BFhPeYvM6fXWi4Ba*KO=/+plk?>E2=Tp#bfxb7^wnno4[)jUi2fzjW<z1BA*$%SzW$jzMdZ3R:}.EDHlF82K-&.F!Q4WI?v78:-CMS7}R#Bz![92$+{atvmnSuJ#{hbh7?LdNW4=>q$]yVzI]mut#^y0-+})<DLA2[Ac@Q.eVaj8$rlWUJ.mT)q:bJNENETE96D)EaQ[bC<van*0[)[6s#@S?]R{T8F2WA>EwPs8$:*#22yZMEw4Pw^Jncy^>d)]9FXR9yWF{ucb35:./V[+-C#@YekQfoL.GGo)(B!CWdHfb):/6w#9gX?zCc)jlz28@E&YCoa}{z%i:1{XFaPwZV@}HKpfm[9f?1M*gt{M+[jHFpq{c]P7o$c=<5]-}0^6]h]hJ)}BsivjV)[N&:wJZFFn}Rmqb#Uv$Xs)!Z}j){x2I[5UJ--^wf+yT-5b:>e@U}VVLuc0s#IAolK4G.:I!Tp^*^[5>GX->0A71!ex?s7!RbQQ>i=^7eW4*wMSm{u)o+(H2Ew[5RAO)M5!8{XZ^=W]r]/50&(V+2c{C0*Iyp*ck>TX.VJH+Z)AL33*QQixnh/*DM4Fg/2sIsM{%?18F}1FSe?s/yA@}T@Fpi)DpefBIZc*2YY2cB2)<34rEz<O>KgF1FLf4
+:ELUr:3U1W<EhOBKtu]UraNF#N<:A/Z9)/c8eHlFl=X[pv@lo>ODPso9d5E*Y(zmn:ms0=tKQvFxt++f{cdIsC2?:ZPJcYIt0={:L<hP@vSab}dlfLP+3g^Zmqj6HtE5<.l85ixA(8+rif+Ub<j5h:h+){P}ET8gj9eB7WI+NaleH8=<Z&nto^nSC)gIuo?63=F^{n)%LY]7K2[Z/>sHkn?OZ}Q[*lQiRJzneFtc>GQOyQZ]tAT$ut#kc<Y=2nf5q/:y-i37[1FQW&h{Kf>CmL)[jXp}SN%DlIJzn0jN)+n[?K9]<^wvfw:dg^%y%)ST[OE?+ET^rWz9RTS(PL}wUq$B@nE(@(vTiwEgbYPac@r[2j]K(NiLtU*wnAefKU=>>!iam-))ywpd45F]3!=[z-?-oZu>c}z!yVVMlsXF-@4j91g{(=[dE1<:q8D0<T{cL2wv!mF4md-pV/$HXWavr-9F/%#N<HWpy){2jhJOw/>h=jAwqieX=W$cv}-vp(w7ayP-?&^>LPXZap#u=exZKt(YHe!OgPr2-bS5SFmQG+&a#Y+$T%g<5!<3L^-LSXnhv0>Y![<TaE8{4%=ZL9YVJx[Q]?ASWaHzBB7gJ<$JpXrJ8{>Fvt%sCXxP0(m^aQVS<OI!p2mz?kX[LJqp*ZO&8MPe}Y5vx:?6Y/ZK0d(JrNU+XV+v#wTySU)ms}Ra:AMw<a/jDgu[s+BwW>/17bI@TdVA4iV%9Onec/+twrm:Z)$a#OgumH>RE$t!*rR4v-t2K7[R<WNMy<f2W5>kyczP%M.DWu3:R5C*W*y603V+g$d3BDwwzoN}v[u2::Hm8(oB-3nB=JyeEEzef[@tbaH+jZ%8hi]x>UOFJJd+)61QLK*?GUF]sW#)Hm*.]1Y<fJ+>Iicl-z2k*J8KWGKc=yReNe)e<-<V8+baI[H&-n<-IJv4P4<FEhdZL[QK/[2[]K&Oe86.4(x!SS+HK>atL>%>lnW1=H#]w!N7A/Y>Q6hT
+zGV.*to5vX4+a{vH/-nLR(nJn9FJZHC{(5@8jRxQ+Nh+aQjQ.oR?f4(F}vN{VOKq5]KXX$>}5IfwF8Jb{)tR:6To-LLQ?@e3q&+yVQehtVQ=n8JYAL/hCw<LKv4Cq3yn%XIVSsa[IK)PcVz+o(/B{?jlX{%b6]uBx9%+&{4DtoFlgqV0J)>tRu3pN]P:N[PSP.i@uq54V#ZCRh9%2eBfHA/KR1J7?h/rBa(.YQ8@Et4mhrCZ48i]]sC+67a%B3Y4j1oba7-Qo&8[j[FF7AGk0PF5sw<%{Q0dF@QAdX534$wY3y0%#oQbsP-t]oV6C9x9H{W7bjpqV2*Ofa]aBO(g^y{xEA3Hvi)g%J=.<nR0)q+EPcNg<BL=/NZsj<30+LXe?YA{z9oGwO#iw(T:[>v*$cDO}(o(pPiWDJwL31OiqA3%Zjw>abdtA.bNVpvjG2-k.$OSby.qRu.p9/GshU#R.Ak:oI&p{udI]h[<>^36!{e9=AIEiLpl(IH@hYw0UmEG!M:W1sr-U6R8d2$RJ[*WgKwA*o4<Get(.sjXryzJLCt?p*&mz5<bxbi3X%Mjt}CMAhADPt}qI>xNkpzVduE6)a)dONjW(}(VPcd$t+IX(L0bG06!wzuTqNIsi[*Mgw+}?hO-c6)82MZjJI:{.v)jDJ&k=[BaPr[Bbdtw=&17&RAi&.OlF#MY7M9Y>E$GP6(z@VeKdnU[JPENe5I(JG-.Ew}P7tjK8&^>g[m#=.2*LpR(U2&qJhz&i!Zdq$c@OOQgYI8+Al$=RzglO.$?^&zmdl5BCVRY{*NbkLsB/Ib7H2Cp5^X*}]OchX&T&i}Z=[-m@>xCt/*Rq$1).a7=jwa9Ir]kFK/oagNWdl}%2m{^@KwH{{y9Gg25UYMd@Aj:qs^qM]*S34[]PYSMS%93OY<0RY7XArj7?0[4(dECO>!qiK071&KLt:EOVzJKBhrp#hVB*QpMjL6dC.hrRBsY9OptG-9KuEIZ%{)JN4vb<#}8
+O!M5UvqRvrI%#2KCm&YZY9N<Qi$R20cRrF.>aL9tWFt=b/JQxe).B%=jdK4&!VVlwiKK]@/FvuKe!oN){>gBHs]+L[Ts.MxXM6U!Ur*[YXtB3!zUF16ZUsu8W#CE]y%a4#ON)79m!Kj0LKJ]]9k{w>4-lmL{=5L]Lw&?FaLGzc97j0WtPudon-04GT(K%V&Km-&65IeU5iE+JgyIaxgAa:+KIF$JcueL7O%6%{pjw.J:.K/MNMD}3pJ[!]4a05&2U}KygqI0ZvVC2Y9gZfKP<r2LTbldR[9]<v@gb0R:6?U.-+Sf6NqHi>h2I.vHl6mwAtT4v97D*3:6)+O=5TgL*U$0v-@hLAj$ZuJm7T2xAtYoU&O#X<PNxZy--d!7hvpv?vE1r)IP.wp*MfJO8f%hj9jt@%]=ia{rRa4dBb!FEiYaAjPleQva?cqg{vZMN@rU:r:}h/FLr!:kV#z4hnN2#7UtLGWWJ-.]7sx3nm2$9.?^{%=<<<pGZ]!#gw#Fik**u?Kmx{^?1!JCO}S^th9ZKc^+7EB1X0c5(&@]j88[[z9Zu#{oX]iVL}KkO?In0f34VH?vunAJf/O!CGP2KlXCg.3=S:C03x0jzdC>RU1U86TsOCGCX(1BEiG8O^L{9ytgWQa<aN%RQe/@wX=O#&+etFh>AT>]9jMs)nqPrhY-dP*HL+go:].o-nirEZVl.46DmmPU8jn(lObVzQMc{IZ)zv)b?Ag$Hdd9I{3=b?.zn!yi5QmQ]9Uq/AU!hmGmwqkyp@P2ZnQ>gv-==-PRcT9tYK=98&ZP4:f033<dJv%a}Wm+szBtn.>1t*h*U{t{sKN}5Uq?<nQf(V]O{V7w#K0GT]XLfHm#C]<O4[yU$4[ySWX4hLg4>DP^EP2Ve7y#GT9P[do*0=Kd){sHxCeit%!lET[Y7UJ16.[EQOL[=witrt}5(+PO$?.:d)QqKw@?3fz7*uXPCc0KAMDQ9*?}Q.Rby)n*c/qv^vjsqFVEC0CV
+<:cAlx!4:ccFCfC}e-%ciEJA<Y9vrLWVUrxfh0k?@R#m<D306p!.!Yf+aNr3}QF56(v/a*3p}OCyp^-Zt<VO4EAJ]?4gp2^45hkLHL^v#jDz/CLnCAJn*1l/2NR+PAkhGN6A$0l7afk1qs!wl}}FV@CxmaZuj}TE]t+7S?rjdr=PQEDDW>{p=:?/%OF^oP]la$O44#jH+fE{UujnJrf-N]07*{.]2cYx790lQsj=PNfj7Grk42>]53}3Dk8Y*vh7>BDyh.[bpfBzw(z/?G^u<$f^z&GCY=q#]:[rPwA4M87&@0/[q6A.2Otnvs[{RO@{2.4o83:vRI.DeB+]C0e-epT9{Km}%[x=b-z.Ca4(+:Gqb=l?()N]=TZ-KuHw.s5E%D&Bw^@@d6]2c-KyHma[Kcl4JE76z^l)j)R-}3L37VOqY.08I-12fs^-76B6n1320bz1$2FVG[urtX>SQW$5OdYIMndtxI%{*cT3N/+sJtQ8mnY-q]Sg1OokdDorNk:GH)CI$-hi]&>asM/ha4ZXW<SP+}fug[Kpi?7sAJ0RDsdM08KI}#fo?-3R=x-ElGKpEojPt){W0?emrAm[V%3X[+YWj<sZmsLU4A1wqq:g${RI@3pjDk+pDJB=-^M?sZnUjjOV[B*YBM@%+5>L]fxUyko[?Q7vl-kgYRwc5ydx(mMA5>1Q7g6YYa-HRb2c?dC}le-fF9DJAJ^lHxmmzaQI@Zxz9PE(3.6H(O$c]jvZ-mlXhzpC46)@p.W.uX@9M?z@l6>HMJ]?1y3E4Tya**Ey[zE0+XT4CoAg)r]Vhvc#^8i=Aw<Zj&&TJD}F&/A4Qcx:&&/?5U24CKO>[m7LD6)?$/=(aS6*^n3d@[4)>)g*+C)=wyL8!46k?P7p!oifckju*U(6}$7r}0mdY&^o-8^2ZGS?:46*-HVfXC@$]=Kc#Rp)IY.M&2LWQG=@6?%>dPnRT*tke{x*G^^llTb>?0o7xd@K#F]!oUY(UvN58zVX
+%aFz-q&HK3NG]DJ9?iF&-7}fGiP!Id!zyVltt+b{Y1-UpMw18=}6xM}^0N&s{xo*FUoq2Yf*t}7Knf%:ZK%{DL>IffJ<3<klPC71)0/AuEp$exl^xgQX}:@IZR:f54v}z>MpE(9tiylfpqUGWP8eyQ].T$2d$^:1JKYaHgk@=6pxVlZWEjy/%3j1/ID43lA(sd-ax$:ijBbr85Gp00^ZB%Qe)wpgZ/UJKX#e0fXV(P&m]m?9EvEiNQLKbP*YCf>Sb8e6rAeZ95:$1H8Huc*iKxA-s:#$-[T^M:x-9-}z!U3eH]#@Y5L)(>U*VIuiKyaB4<9GXrSB6D:GAR(xS:gKC>%>Qt%HPymlFsN[vnLc)yMu?0($aE{L:]vY8W3qK>&44t(.GGHX5^Q990@n}eTEOctM[axZ9:m/**4x-?xk]yYaXTM{=oc}I)%+bW9]lV>&^(]}BzU=5(XEa<)OJ!MjX^cb)Cs5iC*n?@::qaLBu3ZWeE2!sq2&aH&%U5<tVwv4%Vb)ooTk:My6@qzgwB1&2qT!ih/Pq1)NkdrRo3L2q*+J/-PJ!dR]k{hJ$SRt7d?oIesTFL)?>S}[$fLt.a9qMDcg&Is%CLO<HfGXEQJMXbQ>jTIkO7+h^zDf@IDlKxX3bu)Sf4tQDBdBPp*j$et<]l7yYHeJx+Gqzgjx$Wxh?>ItpGyMvow+I?CK(y>?L-J&/J(I^1g1/a/nVuZ?cKd}A^p&D=51FT{PoHlE@!BdIlFXXs{FGA$Qqo2EiTy4ufaLzDb*nL>LAG@+-3q91:nWuGkm?L?j54h*EaZu<tct/hEy7^8F{m{ZOYzh#n1:^@riD1(rf3:Hr36hHnsHrXLsD>C+<P{:3*7KV>lo>eqD^$lr3uS1PHyF!6Fd]Y7RMZ#RJl()-c{4!WWt^C<QdB=y+IGjUnD$JR2XE#4bdlN6IAWWB8&#oCwyy?z1TE!z-M>#d3^==V%nD.M9v.0]qikxd<ZG{ty=Rxa%L:j3<qD+
+ts{2^6%rDf&/!DqMETF2T!RYy8MuzHT!*GLk6X5!9CmJE&)3h.8N!jUT-U!}!<yIdttq@=V*^G0(ArGX/&P/5X/itnoq5:jFM-8%Vwu2WGY!3:>aB7I+CPZ5m^8I5&kL&ao$j^{p]?>=IA=[)3G:Z14/7F[E!gJ=u#W2eZj1}9Kf=%@x4TKX6F)?#wz1sV6Lrbc2<rm98*SQ!mh&Mu!uz$t^:w!i)A&Q@Yu{}letji<x3zT}or*5%[m*p=1g8JBRk(^jn8EM3]$a@$1-@^63z7A?}[.(Bo*}[bfAOH{-o/f#T8^3@t^(h()PeBQf5:B=ee$/$M3S{6K%4f+t#]AF.o}7I)C0RAiq6/so)g%=n4kw(TS6k{B?t+gX0[{GD)g=UB1JW5R-0Qbe0fW6f*v.TN0j+IHw$f)zGSFt&z$.r(J1AJcGhOUqxoTre]UO}bdGIzEy<FeW&UpNWwGwe9bY]eWly{Ms-#ErTEJqmvoed^9CfCYWyhEgx9-aSG^Eqoco(9ppxX1&%n4ET!}BQuhRo&)G[jqL&4Tv3]}8e^A}Ga#1+Bxu3$8PyJrP8q)c7WG.&tfxj6<1X9sTKAaICA>v8TH^abgK4yK)k-5/}D<-D*%x2V^Da*WRJcYLp&[}sPyQ}/wT!yqlb7/ULm/kMm}EG[n@6md&jkxkD&c+[G#/D(yqJ?PLj&oqzaa}e)>mAY-085..oq-b.xY[pc(^J)(@^HF]i*TVYCD6[Pu?&j!qj=G0&@D)l6TY<(*7!X[OH9EZ.e@cAvQEv0!eob@z6{/d@RkC9#cnX)IZR/Qm@BURQ$8/rs))DtOaT@m10?c<xut]!9h/Thi[yLEISPhfW-]{xgt>c-1c/d}I^t[cd?Q3}fFN3Y2T*t-thRDM{9cJZl$Jg:H-5u5UzSgegr(1-]o!?7#X1hv.sp=nF!/m@iZgLeci:n:zAg3?{oMgiRbJ:M*ik?w={6n8xhb]4v*Z25hnTaNO=ZmC]9Wo1vyNPv=7
+]?2LF+hpjP/jds:mYDL$gH/pyrQkc6=cZsD6>ngpDqeMtDtSZ*X1@<LK2cBZKCII0awkS!F4uh=!h.O5EbxE1/B>]pKxvbbm/qe.P&D>G9A)P0hoBkgJs@%RZL+EW-%2U>Xt0Q31FPe!wGreI5u-Pj7bX-}&yE4p:F7p1sTmIF2)T@XJcc&9.pSpIU#:NT(dT?b/KCa}Tm@s/Jpqm0ht$*ILY.ad{irlq=KnW[r-D&0.)bkJM8XiXCngT78}JKm!rNnnJ(MAU+s#D}TjJVbb:KF78uj]6im^4<HHRu+o!)YnVXl/jt]!}*e]UR@a#?HVVw{lerk#gQm&WLiD#9mK{n*/TUoG0/jYh4c{@O4j+z%hWHzv{Wz&KRoJ7BkP4q.5yU^Qpm(2vm7LlZz[pHF7?/xn>fbR8}E*(gK#jVZ{!<6D?BDyQ2azt5p5)x4!KQX@-h+5@=3hPQ)[ad6zd*j8)7ROsF*ROt6u(f(W.j)q6Q1dl!:)P*FWptLD}a4UHFl9k0pmQ>L[u7hPi>^vy}gX3wt0$lr@%7AIV7-n5felKWE0e1dfv$k.:A%%-Vp7e/+wMA.]OetSp+b<Evi5eh%LV+YEABKCMc]nH[7cY.H?wO[eouUuTob+JbJaY^ApOnLjbh6msu>RJu129GO7*]b4*3}%2AQKQ00vv]f>^rL*[[w@#y@e]psAwlnCV%2+O3iXR<fPUhi{@h)<k8^hF2tP7o!<A(*^./fo^JE>0^c70M<IIS-7{NQHac+B4O^4@+S#V0I#Lvk:B!x4-3Mj<4pvTWdR#Y/P/W<F3Rjvx3=O4mrKX5z]G{^(/{E$HfiyQ%Z^umpY/dGiNFya&j!v<a<x6J.-y*#FEV8tpgf+jHsd.Y66A-2]kyx8DiEC3P<wK5I8sO0OqC1qfVtHqAg}jmCFRxsBCQqUdiJO@@=#I<F}&{>ECUJyw{Z8Z)Ub)t&j!sr)>>w!#VXOs$L@RC9mOi%:4d6wHDIC4w(og6p(=55+
+h:zoj:ssfVY.BH4HSIDG5b}OM/8bm&:AtebI>t?1&PMUP>L)W9j/N)dq@qr+7v-3]yDF$3}^h-}^Y09$l2M8E480EK<l=U&89Szf=-Mnb+u(Z#Y:AN%QsVh+4Zj<4WjadXj:Tw1O>/0/KV+qNuJboN{o)[D?]4JSD:/Y]8*4V&vEeqI9+)=WZ?lBYGssZ42ETpDjxBF7)Op&ImTYS{[8vGSLJIZ*U>G={/iKFV0}a(^U3eMq@p-z2v?n0eYk^:jjWRDH]xGR*s7JEu?J()=5.==G{>=NDHmw{1N#=y+:gRC*Er#GD!w5Cqfss%G()@9:0@WK?KngE9WjRjcI*z2N?0xns?n/mSCouDwQ4e3{w4[O$.dO&m>Fo7DLw(2i}lz4l{RM@h!p0RIz^M[j*Xuj7/=q[R4:WwP}Uz.-gha(xlE67&I2cNF]CKI7(B2U/WcFo-ZAJHi.HrNB+FPCksEOU38Q4O6Ms02x8&S><ykZWsux*Vy]UmqM@$kkeccspLkr@.Y0hx%jzSqx@u#LA]0@CizT=<@])vLx*<oJ=Sdg]t%V#}5wi>zWI&*(1U@#<8EtDd485PqM6GwEi&Wr{!yS[O@Y?6ox]z.7bbEsq{Y@Am6=9&$9/*eeuZ1zcd>R:[yj[dlIMbg{9ra$GzNQ&Bu4uJ]/6rd2gTnYnHK++k=!-SACYVi3EBo^ulU5P!?J=T)^nzXmvHFnF4Xac@Ze)vB{Wy.%[PjHd3NWHp2Hv6s-]u)=h2rbB5C+y@5W(v}V::[Y?hxPy9ax@pWu.2/*lfg1GTxPtGYdt^:+PS.Von1}>65?LSuiG!9+b46dqjE[}lz$wuTKzCn4%4IJqQuqw-1*hB8K)3^$1owezj3$GYsq.-Dv!C<=600WCgJn}lR1fDdeRb])Bkox^7A}K+uP.lK9}q?2DNj#xX>=^bSrVo<nCiRW2)6sDa.KZrPaT)>j+tC&h:CkKSyG/$]-!2Fdv2-BTcUc>Q%{JK83ab#*AlM:
+G9/)/clxxddZpTL2AeRB/mrcuVsP&YAlaDigtP!aLtSSA4gE*kp?[rEV}0XpTV3Qs+4O(pv0}uM/^MRkIr00m<1VbBU([VjhD1{2y8(U6QI}>8JLw%Ca1GJ7h6hH*qtri>F5!j2-!)LGoN#H@=<LhuSp.GC4R?F$L:-<rdFkX17WaQePm<MvOHfQ[ZhaY{eMVy$26tFJH:O[LIm:%W1O$eb}VpZOdv2JCSN(Cq+9U.?6QU7ObHT*8D%v6wsmtqQ&/^nzzLQkPD>h(se}<#</=?lCkq2BW7d1J:zcGXJ^@CSp2dx<{Z/H}>W]d:Z6.pVaPE+-J>hY$M):!/V/Y>=O?/u^#p+nDKx$j<Dd}9u1>1Np+s4U3>WGc6#mmsHC83*{JX0feOdQi=u-3:-PeVU<5t1qe^hbY>2t1!*8EEYkQi)J5}-gaeCheGxrtKaQ(T+P3qX0?]Lxw}(1!l^<xVAWI9BViZLMoYvC0<@8]wF5]v3c@}H+%tc4Mqvm9JNh3a.hORkN@DR>PJmQL6N{W^6Whv29p&[/@n@{LZ#v4sE6>:PAgxTCh]A6QQl[k&c=rwAQa!Xblo%Z&*r/ZtfvD^[POx&YL9RYXykq/Q*L{yf6]<i1hj8$ZTsU<8x+qY#yBB:pVAIXRad5-Qfo#xtWvq=UAW.d%eU-j4}mivDZ!9xF((6(Q+Ioa5W<{1i+1ZeYXTb$:)Lq#T<YpE&oe.F-Yus@Tf/DRPTBif%1UxE)LBS/h-Jua*yLL<AzzFhqh^9fQm1bH9qZ#k0!!(>paO?HooGRj2CAZ<q%5f}QB?2#Xr!<[y47C7u&K^if9D11)Abd]!Xhy%FYkXs&clm15z2?4Kk>M^HXyGLsSxaKQeLX)Nt!kSd<PMH!r-(SfBmGytQK90E{qE0uilZUwThz1GVNcM4)hpHUzj#*7TJuJa^?xN0@x2gwt<H{LVdrt:M:a0k!^hgN*.{iGtodQ7Xhuo^(?8BhBPy<E4.0F:yxMRjzmd$3
+BNmvk1H*19XB?lht5e<GKa{>OHc(sJicmB*-?yJB=oCgcxXnBx!9ptYF<rVV2c:(Y-SD5TC68x:vAjaX9EAGFn5Hiunh4dZsN=ZYHHFg1t42sWGA)ciAut38F!^E^C08&=UnQI.EM.bUJ6%)VDDcHeS(YlsC9>2Gg2{l7}J>4Sox)y8i<e7).i^IZSQGYjvjIJMes(d>*}1tGY}GSR=z=0/T7Hq&BA6Bv.(siEi&(IiTEpr>[8@fs[TNTS>wjDj)0pl[/vFG/UiC!i@!=hVk?C9$kV-PtjX*JCSX)j(b7KBM2<KnPFQkAFB=Vl+ZgL:2x3+W60j[E0PLCIwL@:D3dhYVP1Fqa{jO?kuz*47FUGB54)(0-uGfb=5{+^znM-ROowRnw5CBfg{:wi:u:tevYkd0EgS+loGnoQsaCk7)u{2jum{:(>s&1CN{ca72^2Z!M%?HkFh@eQ*XZ8}Fm0@2hFHqj0{=XIWa*Gb5]WHE!?r{#eg5o[h$TLPZ(aN?TXeHx6C=J94IbdJ:wCXUPA5fuy91qIvE4<>G+FH$NplTXx/a{ZpBB9e4o9&5Aqbm3yCUU<PQ2/]IBD<@mHHAze!blC[g1imexDjh32y.g?FUjpFX)NMj7nB:DSw!og})G7k}/c6PO]VX!oZ**/1aOrKl7UFGj)GXbu:t%[A/PEr[1FeqaHHGK]{]k%9hqJ9jg8coOY4IQ}gSxwLL0Ne(Nb^ikIQ0KOjpui=9t0iG}osEi==GC.N/*g%G.L&W+X]XF1[N6!v533C@oeNFYc><Q)]tQlu>hP[T060z([jl^(j{4hamW9q!KEi#Y8.Pplo%>EKAoAk[F21z-(At%jE#?v<<Dn}[37[Zpcy[&DnknQQ4JJWM}qD}hK$#W66OHBCp9bpG5o&dOezy.<3AxF/Q:b=/^BPe]F?}Xbf4hhUZc}oxA>O*9&PTRZbug0tEBDi!eRR7t>7F[Vr>{zKAE&N<-pKBZA}AGNpKx+C(R0lIx^?8
+n(vRL]qq%tuy-[n.UjIbHB}GZ28KTAz]zVY(ki9p.bzXs=F.J>WXC8i9{Up0(2>/jKAs64TD%fNW56/L.oTosHOL@NzN-ml-T<zzh%%m]aN7H#KAqqrJhf9Pv<bWjP$k#o7Vs13!v*W-wPop#X1R?o!lM2Y!lJHYfX0=IEIk36+v0yM(rWT6!v?@Pp<o[]hcU$J<*cSaXvNAFWO4xR@3I)Fdt.]deAB-w7e!jMYTbJ?*NW><dL1eJ/XP{cXG!K69cVwS.snCY[V9$a/ZtmL9pB<LJ=#mey-^C4}XHTg9aA1oJJl=+HBxGSs&X=g7<&9%/HXIKPbkrnT/cYHb/woh0Gs.K1JG9m+EV(t&(7{/DfY>yn)2io<2<Zgcnx&3yS/A+ol]AN%gu&p-{NNUjiGZ-U7<hq@QNVV-:LmDG6[@X6+{D0KGo$!rE}YE2J!u!0cqw0{t#7*.}+g(7[inOjPS^K}1>0@u/8Kj*Z=-%NerNpNa)![G^CU!+W1iIke#wTce-pSz3/$C?^<L[cGgBWGM1eDE*lUbj}c^{yNkGK4<teiXRAI%N0Pz6CF&jNA9UNgOX-*E&E[:%0MTG#v*R)0vsgMIf.T#o-Vu+qItcvYg*#NaGXD@Stll$c*ja!kpvJ4^DU-#sWNtF6PSR.>AJ>#uZ2kt)-Wy.o<0K[TdcvaW@%:3<vK-tO:hH<jxz<&Q-}KGhOOVetRAk*fKc5=r^WZ<RhJa/gvAWt>&$zYt=MQH}N>P3@+fj=R]MTc[K(6B4=3Wyd>65CX1[N6%AiS<tX-cGAu4FF^2Y**u{+6CsG]>bIAQb8rW^BkpcsqP9CCm?Ycf9g+=*0bf4bz-{D(?u(e>Zd857py16f>.+p)I{k+1H8Ssd#/aPbjn-8j$bx-Au.}2e%F{QB^6=9=#VF}7?-MM%=k#cvB46:{xinzv7d9FzC4Ei$X]lZ](v09r3%rla}d}{n!THO@<pxpRT0>52SyD>pE}G[WRq.)pe@jG#.l#
+duSn%iYD6d[vEL{GwQ/AmS7sDDa4npAw}e2(Hd=go@t5kW[gx>zt!vS0.aZeqt$u&{vYnKUCGkBClW5Wjlal=EfjGa?8Jcj@pSH<Q8pT7&C9@gi]Go/^oL#/Vhl2UZ4ZIaj3WAZ]Zj2juGgo7dIvG[amnUQd@s<s=pbG151)=aViOv(]^sqAQ3f)b6#$Tnd)8qkqm}we{HN{){<aZmp#v/-yG{u9K=%o(%0ww94Cwdb[R{(vd/fW7[-33ddv}y]H&k3J[Y+E>&Rm0<(t.hYsF1Mxvr7oCoq(.2m-pd4mXXb(JFXzbJ7fk=8XTQk8h*n/Lh>FMiP]{=cc7>*v@5?N2&Lie6YhOdtnD9k2iU+dH#C+#6KpkyPFGB{BkN$]7ZEi+xbY<:7#KOhO2UEhVJFm%ceH*Gftof+h5k(By51eb}e(e!O0*=tpzGGLhb2[vBUgs}1(0La>w1T/Zx-iR?5KUP:aov]5-lZczhSt7aIqTMN?bE2!sQ!@EdI{?>zL$FCXIX7>E):DQ*1@.J:A5$>fs=KJ)CLiYC]IV&*Sx7LxR]gx}a07><+e]cCJ1evC$!!YoJkV&94?H}Ad$S[La(n?]da1)%<vsXSVxA=QdsJ(A7:+>BK0DWJ%Rdawj3lyrk///&a0*pUi/e!v*{*BUfe+Mtq9sxk>1HLKpG:<v4$)nQ3.IIl5T3{GoLj>wT:tyA)wW6NS7ohEI0![P{6=hBm7utNIG:EP].n{iuNugvKzNxsA.&kZ+DH?at4z}#>JD}Vt6N2I1ekBZCXAiRVme*QTyO*N/S[fh<@aWIv&Iu+xS3}w:!+[*([&uwh7Bw5WVD/c6m&!VWC7x./ac?/=wp8[2XPW1TZ:Zx}DU%8)KxKiRy:>uEDK5aWn-D$@Gi<?D2jTn+zsBj=sZ)KMX2Z{A+/<pvA!{Se}()<!w8Hp{)x?Ar?6yWVRGVZYniEt(#H!q+7eOpumP>7/5MB[lhQ@B?Y+Hp@Ts)($H2(fThauVZgj
+Vvc#i)XLl4Z$7YX9@VKcuT9AbfspVa]517t=RFLu)8OTeGBo#:gK71!tv?eb?J()=ced(T]-16Z&@Fd/u7c&MBrb*:(*E^oSdeWQl2c0)^FA.a[BNmsEE!AIx<pdH5Cr=SX.lHsDnZfV?9YM?N-tjU>es.=pRIDzi8wy-=cB-1YF(EaQ5EvM?*@xc^3PrzyRW%:2GRkrt>%JI8p)zJKAErMr$FjA7&{]WWeWJhR1iA+5W9kKZQtUAw0JLZqZd?iHInr8&.RT@RrNnL8{uXO?Dl/Rq@HKb^UMRKB:^x<j01g]ZX8%lH$z8W=@-bYC>8}<+:R6:D0qhVw!v*R)p#>4R<46nlHprZ[-h]$T*ojyjzp=)-2&=2DF5.&AU=@Q:9F0nI)BU+*&%uQJzOl{JJVppuQRLN>R?U(Z-oVyZ/)=n+zt]9spWN5QKq9xLV-F[K9Ffzd2?N*([5Qc71mrNbi4<<1S*[}khVdoH>*nyz.cN3G>e!CG((eJpSgZnHvdz0}rJ>1W?}pB.Udl!NiazWolOTrC^W#*CrTZ8F:C!Rh9nwdUy>gZSX$Nigxh9p<f9jlh(ny*xVWrn-Y6=WyN]:Jsc<ll!@BY>GKVrPh6T8B1QJfu9PtTU3chg0!z2(E(*.ap6o7-UBtPfY&DQVr*^x&B!<5OVEO/]=ngT!{s7hGSZ7Kga?x/AfwiR7dRflJavfCTi&>JzF[D?-j5=Un$^*Vs]bLoq/5>+=sl^R(ZvUA*SveCAz?9kV4cNo=BPoez]Z#Q<iWUHUl}s}gs9R?^2L:^%5(PtmrcUbZHKsRO3rI/]T[rmwFdg4%^aD9$74V(}r1Xu^p0G{vH4>oTKZJ.2nMIvopaC?x[acP/&AV16E:+DNZp+TTaAWAk9n&Py53t0mRW1KTcw5OyjM!09a&uUmF&lpFCMVead0)lDkNG%#G2:!zZZ^=Ijg{!7%{e9t!0xL{1@]mLjnxN][gFbXwpMqfuHwO]1>UdYzwtqpWqFJ]f
+Xs.A9FsdlYtmrhaER-DeJkj#cZ+JPGhKTpL/DR?TI+=?c@D!+F=j>Zt?k2T0YH@89O+z5/cI?tu{-@PAy!]N/YALG:N1K9ttyr7?Rg21rPGt}06ohQSPf@MWk&X{bH2OkO27S5*@<+d#*Jjl]U9HPGXcfyQFs)f?jp=Hh)$X)wLAXI6LypA1V2t8Tl67ht3hJa(4iOp4CrbF8FOSa1(n9yQ{:1@d[KZ>J&Oc)}boc7^Pqk.KDAj%{JgUZS0[5@/f?F[>VI(a@8NoRiNT29W}{S9UQJp?/.pb:I6nil{0x8%pEVwIeH^@O9G6(8?HKXu%+*#xEArb}S]FQcb*3%a$+OW[25.B(DN+{N&W-pg&In?z$zzPYn0?H?/gOO(E-1E#7odW]&6)i>0kr}bo^AVr]{^zmqDx)h)VEZ=K4#J>P(t#fi:G$wv*6ljg[KZ)<KN[72h!T+Ht.zgF^2H5:@x5w(E]ug3HtpI.2k3:+Pql&tkjoU!jisy^e3S+Q^7wq1wo[-$[eM).E3eIe<pvx^j.dviL%>GH{th.vEU-6H{u>kq59Mbu.Q}1B)F4KA@)@DZz2vx+LA2QnAOpUAD=:?^ZZL55:vrIza2pln-my&h>i/c[PtyJ8BD7UTiBuYiq2+jt5@D{k)Qa$+}2XOw2RgpR>KLLg6pe}Exx5E[*m-7EC6%/Wu-StH3+wmh.8q3mBpK9%C7022@ZG2#[h]?QRHdLt[KW3T]dxlQHJx/Zz%qMuj-?i4%2VUKkvy7gpJLX?:B!LDKn8av8rPPGSq!EE)?zzPV[USoU-lTY0UTAPNM<yA*i{{o}EEWU!0M:eL90-Zr3zZT@4[%%*k:SEOsJo9Oz0]W)dWfTjrjLBKeq9jo2LpJ5soqC:zg}rVi^}nf:L^h=#f*B7gfHq1C$9YH>)&@K+}nNGfV*qhg+ZrwB5N[}+3TO4F%:gnXXR=/Li:>Ril9*:gq9X>FH/yLJCpw5x/A[/93l-E!:h#rwK2W*>O)F
+c*3HN8}E>(cfOUE[5p<.Su0oqse9}nouB+cv1gno64!YzK^jpoQ9Mt}UldlB6}-1=1S2obMBHf58+XnP:RJoDz&lCAPU2(8(lqmfp<9O@!j$O&fpIZDFB6..0*2<PYfG??p[iDHT59%RI:.HgSlOnFKT?5iJmJ-[-5ZyWohx8{wDhw<IB8Fw8q84Qbv7+nPLPUZPcttc/fT>pUskbc8Yz2Mg%/sEb!jgM](i1*qUxr@cSpxmIK^ac+:4tWBb!*8}@EX]Z+]1OuJXU@(4se$*LGEe6gvBV1Lv1.{4.$spo?:2u]wmH1y1tYWF]fFv0vD6DFHfTbV)o/cbG0.E]/R47xGoqJ?#R0MiqL1.BVDoWg{5VIaK}KB$tuOr[iiRcC:JsK}wfa&P0=pD6[!#2A2#{:-Xha&O*7K@+-[ZJe%8MbF:NK=lO!ww@oxo4z=q]G3CGN=RQ#snIEn940fKld2(4qJUE0a@:xtR&i9gl[RC0JncfIM8.a2Tw&(3c&HW?(0Q6W3mUlSt@B86)@TcDUSb0V{7$:oKDCIn%8]9qCiJc^)0=@%rb?LJ.fh!iyWdfXr@/ew(=NyY+tcocE)gaAiBF?C0vw#{>S&=}%WQS(f1Yd0f:h:4K6kr^cb?L/XCoUp3NFAxQj!v&$<x6JC-Ev#We3P%f9WepA%izHN1K(}NA4Pp/z}*a9565#Ht1{p4y$%}+*:<a<)qaKrE?!QRPGa9myU]i2TV$7v9p.]%2w2[L5a5sZXTlrv1EZd2?XXga2](.hhMDK)FBeV/GZ[b:Dm.KMK?ei>zk{N(1.S1pvb1.?pl<D+s$z$cT.ZdVMb6*L/J8OGWzlbMSqae]!mef4}/Sqj@+{Xiv?0UZYdvQ/KaCQ/vd-2HZJCYFx1cIV}f8Uko6?EUj)[73dvsFwGNE6mJ9xZXoh*^?cMI:C%l/UD6{#NR08@SLGq[qBvcP{n)OdK3x3NZxZtp>.C7I[/)a@wY*KD]Ujy2I3tY581*?}RJ
+*U.z(kyN?N{Ms$3@>kdSCL=PJ[zj8yLgTWLreHNp!{JUnzVvBG]>%!eIqvNRp26T^}RHQD8:6ZO2P==W?!)ltVKYbWuJkoFW/FQu0Oa}c:)=*-i?fD1tRU!3><]IjRcsiIuX2y[y<=ur^li2[D/C6fR0!3A+kwV<ip5ygYnTUFyStrw3c$P3f7>YNXtJrKidb8zJA:tj96NyvHIIJz^?xM#@x2h3x[?u?4huqp[VJ58)&oh)>FUiz-$END+NzQAtz0)aqzrr$byBXB}Bolfsb=VfikcDO/cH.c)U0HH@AoRFvzuw=5r+>aWPf.8bvl9epWvRMu4@MIP$$qNA2xNeNXwX^3d3$qOG%I{lg@8Ig*zyXl=lgO^9M3jU%$G#HxMgwBNlldHheQ:d-RDpf.o#0oNED5ZBmhTWP/!{D$$Q/-q-v]3G+n9vptY]7OSH<v{SF{W+JHu@bss!Ph@#Hj1RjTxpjYjCbAv#q=+hp.V[Am&f&qwWoHc0![}{t8.K=Jp1KmOLjiPkx{dbWJwH%/^Vt*a=^E[4e>:9!uBJ=JJgMdqmLB!$8g{lLr!nTshM}QDunBcXg&cFOTx=WU*6X[K@(J=2b.y^X..PJu=IAxHkTc4?U=B)%d8sfhPk:9z?1(>ZYm7}>dZbFG]+bF*>sKX0c7YTo/M@y4nQOj8Ey}aEY#i5do>p@QEaTE*uC+n>rjc4wZspFPIe9$R@Y-T3e#Lw@2lj-7
 ");
\ No newline at end of file
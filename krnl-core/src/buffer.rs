@@ -48,6 +48,73 @@ impl<T, const N: usize> IndexUncheckedMutExt<T> for [T; N] {
     }
 }
 
+/// Error from an out of bounds index, see [`catch_out_of_bounds()`].
+#[cfg(not(target_arch = "spirv"))]
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+#[error("index out of bounds: the len is {len} but the index is {index}")]
+pub struct IndexOutOfBounds {
+    index: usize,
+    len: usize,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+#[allow(clippy::len_without_is_empty)]
+impl IndexOutOfBounds {
+    /// The index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The length of the slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+std::thread_local! {
+    static CATCH_OUT_OF_BOUNDS: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+#[cfg(not(target_arch = "spirv"))]
+#[cold]
+#[track_caller]
+fn out_of_bounds(index: usize, len: usize) -> ! {
+    if CATCH_OUT_OF_BOUNDS.with(|x| x.get()) {
+        std::panic::panic_any(IndexOutOfBounds { index, len })
+    } else {
+        panic!("index out of bounds: the len is {len} but the index is {index}")
+    }
+}
+
+/** Runs `f`, returning an error if it indexes a [`Slice`] or [`UnsafeSlice`] out of bounds.
+
+Out of bounds indexing on the host panics like a std slice. Within `f`, it instead panics
+with an [`IndexOutOfBounds`] payload, which is caught and returned, so that a bad
+invocation of a kernel function called on the host can be isolated without crashing the
+thread. Other panics are resumed.
+
+The panic hook still runs, printing "Box<dyn Any>". This only applies to the calling
+thread, not to threads spawned by `f`. There is no overhead when indexing in bounds, only
+a thread local access when out of bounds. */
+#[cfg(not(target_arch = "spirv"))]
+pub fn catch_out_of_bounds<R>(
+    f: impl FnOnce() -> R + std::panic::UnwindSafe,
+) -> Result<R, IndexOutOfBounds> {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            CATCH_OUT_OF_BOUNDS.with(|x| x.set(self.0));
+        }
+    }
+
+    let _reset = Reset(CATCH_OUT_OF_BOUNDS.with(|x| x.replace(true)));
+    std::panic::catch_unwind(f).map_err(|payload| match payload.downcast::<IndexOutOfBounds>() {
+        Ok(error) => *error,
+        Err(payload) => std::panic::resume_unwind(payload),
+    })
+}
+
 mod sealed {
     pub trait Sealed {}
 }
@@ -112,7 +179,11 @@ impl<T: Scalar> Index<usize> for SliceRepr<'_, T> {
             panic!("index out of bounds: the len is {index} but the index is {len}")
         }
         #[cfg(not(target_arch = "spirv"))]
-        self.inner.index(index)
+        if let Some(x) = self.inner.get(index) {
+            x
+        } else {
+            out_of_bounds(index, self.inner.len())
+        }
     }
 }
 
@@ -158,8 +229,13 @@ impl<T: Scalar> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
                 &*self.ptr.add(index)
             }
         } else {
-            let len = self.len;
-            panic!("index out of bounds: the len is {index} but the index is {len}")
+            #[cfg(target_arch = "spirv")]
+            {
+                let len = self.len;
+                panic!("index out of bounds: the len is {index} but the index is {len}")
+            }
+            #[cfg(not(target_arch = "spirv"))]
+            out_of_bounds(index, self.len)
         }
     }
     #[inline]
@@ -174,8 +250,13 @@ impl<T: Scalar> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
                 &mut *self.ptr.add(index)
             }
         } else {
-            let len = self.len();
-            panic!("index out of bounds: the len is {index} but the index is {len}")
+            #[cfg(target_arch = "spirv")]
+            {
+                let len = self.len();
+                panic!("index out of bounds: the len is {index} but the index is {len}")
+            }
+            #[cfg(not(target_arch = "spirv"))]
+            out_of_bounds(index, self.len)
         }
     }
 }
@@ -306,3 +387,106 @@ impl<'a, T: Scalar> From<&'a mut [T]> for UnsafeSlice<'a, T> {
         Self { data }
     }
 }
+
+/** An output where each index is written exactly once.
+
+This is a debug aid only, for catching kernels that write the same index from multiple
+threads when they are tested on the host. On the host with `debug_assertions`, writes
+are tracked, such that [`.write()`](Self::write) panics if the index has already been
+written, and [`.finish()`](Self::finish) panics if any index was not written. Otherwise,
+including on spirv, this is an [`UnsafeSlice`] and nothing is checked.
+
+Clones share the tracking. It is not `Copy`, so that the same code compiles with and
+without `debug_assertions`. */
+#[derive(Clone)]
+pub struct OutputSlice<'a, T> {
+    slice: UnsafeSlice<'a, T>,
+    #[cfg(all(not(target_arch = "spirv"), debug_assertions))]
+    written: std::sync::Arc<[core::sync::atomic::AtomicBool]>,
+}
+
+impl<'a, T: Scalar> OutputSlice<'a, T> {
+    /// Wraps `slice`.
+    #[inline]
+    pub fn new(slice: UnsafeSlice<'a, T>) -> Self {
+        Self {
+            #[cfg(all(not(target_arch = "spirv"), debug_assertions))]
+            written: (0..slice.len()).map(|_| Default::default()).collect(),
+            slice,
+        }
+    }
+    /// The length of the output.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+    /// Whether the output is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+    /** Writes `value` at `index`.
+
+    # Safety
+    Each index must be written at most once, and not read concurrently.
+
+    # Panics
+    - `index` is out of bounds.
+    - On the host with `debug_assertions`, `index` has already been written. */
+    #[inline]
+    pub unsafe fn write(&self, index: usize, value: T) {
+        #[cfg(all(not(target_arch = "spirv"), debug_assertions))]
+        if let Some(written) = self.written.get(index) {
+            use core::sync::atomic::Ordering;
+            if written.swap(true, Ordering::SeqCst) {
+                panic!("index {index} was written more than once");
+            }
+        }
+        unsafe {
+            *self.slice.unsafe_index_mut(index) = value;
+        }
+    }
+    /** Checks that every index was written.
+
+    Call once all threads have finished.
+
+    # Panics
+    - On the host with `debug_assertions`, an index was not written. */
+    #[cfg(not(target_arch = "spirv"))]
+    pub fn finish(&self) {
+        #[cfg(debug_assertions)]
+        {
+            use core::sync::atomic::Ordering;
+            let unwritten: Vec<usize> = self
+                .written
+                .iter()
+                .enumerate()
+                .filter(|(_, written)| !written.load(Ordering::SeqCst))
+                .map(|(i, _)| i)
+                .collect();
+            if !unwritten.is_empty() {
+                panic!("indices {unwritten:?} were not written");
+            }
+        }
+    }
+    /// The underlying slice.
+    #[inline]
+    pub fn as_unsafe_slice(&self) -> UnsafeSlice<'a, T> {
+        self.slice
+    }
+}
+
+impl<'a, T: Scalar> From<UnsafeSlice<'a, T>> for OutputSlice<'a, T> {
+    #[inline]
+    fn from(slice: UnsafeSlice<'a, T>) -> Self {
+        Self::new(slice)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, T: Scalar> From<&'a mut [T]> for OutputSlice<'a, T> {
+    #[inline]
+    fn from(slice: &'a mut [T]) -> Self {
+        Self::new(slice.into())
+    }
+}
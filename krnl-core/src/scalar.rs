@@ -201,7 +201,11 @@ impl<'de> Deserialize<'de> for ScalarType {
     }
 }
 
-/// Enumeration of all scalars.
+/** Enumeration of all scalars.
+
+Floats compare by value, such that NaN != NaN and 0. == -0. The bits are not
+canonicalized, [`.as_bytes()`](Self::as_bytes) and passing as a push or spec constant
+preserve the exact bits, including NaN payloads. */
 #[allow(missing_docs)]
 #[cfg(not(target_arch = "spirv"))]
 #[non_exhaustive]
@@ -347,9 +351,30 @@ impl ScalarElem {
             F64(x) => x.to_bits().into(),
         }
     }
+    /// Reads an elem of `scalar_type` from native endian `bytes`.
+    ///
+    /// The inverse of [`.as_bytes()`](Self::as_bytes).
+    ///
+    /// # Errors
+    /// - The length of `bytes` is not the [size](ScalarType::size) of `scalar_type`.
+    #[inline]
+    pub fn from_bytes(scalar_type: ScalarType, bytes: &[u8]) -> Result<Self, ScalarBytesError> {
+        use ScalarType as S;
+        if bytes.len() != scalar_type.size() {
+            return Err(ScalarBytesError {
+                scalar_type,
+                len: bytes.len(),
+            });
+        }
+        Ok(macro_wrap!(match scalar_type {
+            macro_for!($E in [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64] {
+                S::$E => Self::$E(bytemuck::pod_read_unaligned(bytes)),
+            })
+        }))
+    }
     /// The bytes as as slice.
     ///
-    /// See [`bytemuck::bytes_of`].
+    /// The exact bits are preserved, ie for NaN. See [`bytemuck::bytes_of`].
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
         use ScalarElem::*;
@@ -361,6 +386,15 @@ impl ScalarElem {
     }
 }
 
+/// Error from [`ScalarElem::from_bytes()`].
+#[cfg(not(target_arch = "spirv"))]
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+#[error("expected {} bytes for {scalar_type:?}, found {len}!", scalar_type.size())]
+pub struct ScalarBytesError {
+    scalar_type: ScalarType,
+    len: usize,
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl<T: Scalar> From<T> for ScalarElem {
     fn from(x: T) -> Self {
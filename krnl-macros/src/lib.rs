@@ -19,7 +19,7 @@ use syn::{
     punctuated::Punctuated,
     token::{
         And, Brace, Bracket, Colon, Comma, Const, Eq as SynEq, Fn, Gt, Lt, Mod, Mut, Paren, Pound,
-        Unsafe,
+        Semi, Unsafe,
     },
     Attribute, Block, Error, Ident, LitInt, LitStr, Visibility,
 };
@@ -389,6 +389,34 @@ impl Parse for KernelTypeScalar {
     }
 }
 
+// A scalar, or an array of scalars with a literal length.
+#[derive(Debug)]
+struct KernelTypePush {
+    scalar_ty: KernelTypeScalar,
+    len: Option<LitInt>,
+}
+
+impl Parse for KernelTypePush {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            let scalar_ty = content.parse()?;
+            content.parse::<Semi>()?;
+            let len = content.parse()?;
+            Ok(Self {
+                scalar_ty,
+                len: Some(len),
+            })
+        } else {
+            Ok(Self {
+                scalar_ty: input.parse()?,
+                len: None,
+            })
+        }
+    }
+}
+
 #[derive(Parse, Debug)]
 struct KernelArg {
     kind: KernelArgKind,
@@ -402,12 +430,13 @@ struct KernelArg {
     #[parse_if(kind.is_group())]
     array_ty: Option<KernelTypeArray>,
     #[parse_if(kind.is_push())]
-    push_ty: Option<KernelTypeScalar>,
+    push_ty: Option<KernelTypePush>,
 }
 
 impl KernelArg {
     fn meta(&self) -> Result<KernelArgMeta> {
         let kind = self.kind;
+        let mut array_len = None;
         let (scalar_ty, mutable, len) = if let Some(slice_ty) = self.slice_ty.as_ref() {
             let slice_ty_ident = &slice_ty.ty;
             let mutable = if slice_ty.ty == "Slice" {
@@ -429,7 +458,14 @@ impl KernelArg {
         } else if let Some(item_ty) = self.item_ty.as_ref() {
             (item_ty.scalar_ty.clone(), item_ty.mut_token.is_some(), None)
         } else if let Some(push_ty) = self.push_ty.as_ref() {
-            (push_ty.clone(), false, None)
+            if let Some(lit) = push_ty.len.as_ref() {
+                let len: u32 = lit.base10_parse()?;
+                if len == 0 {
+                    return Err(Error::new_spanned(lit, "expected a nonzero length"));
+                }
+                array_len.replace(len);
+            }
+            (push_ty.scalar_ty.clone(), false, None)
         } else {
             unreachable!("KernelArg::meta expected type!")
         };
@@ -440,6 +476,7 @@ impl KernelArg {
             mutable,
             binding: None,
             len,
+            array_len,
         };
         Ok(meta)
     }
@@ -453,6 +490,7 @@ struct KernelArgMeta {
     mutable: bool,
     binding: Option<u32>,
     len: Option<TokenStream2>,
+    array_len: Option<u32>,
 }
 
 impl KernelArgMeta {
@@ -505,9 +543,18 @@ impl KernelArgMeta {
             Group => quote! {
                 #ident: ::krnl_core::buffer::UnsafeSlice<#ty>
             },
-            Push => quote! {
-                #ident: #ty
-            },
+            Push => {
+                if let Some(len) = self.array_len {
+                    let len = Literal::usize_unsuffixed(len as usize);
+                    quote! {
+                        #ident: [#ty; #len]
+                    }
+                } else {
+                    quote! {
+                        #ident: #ty
+                    }
+                }
+            }
         }
     }
     fn device_slices(&self) -> TokenStream2 {
@@ -744,6 +791,7 @@ impl KernelMeta {
                     kernel_desc.push_descs.push(PushDesc {
                         name: arg_meta.ident.to_string(),
                         scalar_type,
+                        array_len: arg_meta.array_len,
                     });
                 }
             }
@@ -959,10 +1007,13 @@ impl KernelMeta {
             .chain(self.arg_metas.iter().map(|arg| arg.device_fn_call_tokens()))
             .collect()
     }
-    fn dispatch_args(&self) -> TokenStream2 {
+    fn dispatch_args(&self, skip_push: Option<&str>) -> TokenStream2 {
         let mut tokens = TokenStream2::new();
         for arg in self.arg_metas.iter() {
             let ident = &arg.ident;
+            if arg.kind.is_push() && skip_push.is_some_and(|skip| ident == skip) {
+                continue;
+            }
             let ty = &arg.scalar_ty.ident;
             if arg.binding.is_some() {
                 let slice_ty = if arg.mutable {
@@ -973,6 +1024,11 @@ impl KernelMeta {
                 tokens.extend(quote! {
                     #ident: #slice_ty<#ty>,
                 });
+            } else if let Some(len) = arg.array_len {
+                let len = Literal::usize_unsuffixed(len as usize);
+                tokens.extend(quote! {
+                    #ident: [#ty; #len],
+                });
             } else if arg.kind.is_push() {
                 tokens.extend(quote! {
                     #ident: #ty,
@@ -1130,9 +1186,17 @@ struct KernelDesc {
     push_descs: Vec<PushDesc>,
 }
 
+// Prefixes encoded kernel data, krnlc rejects data with a different version.
+// Increment KERNEL_DATA_VERSION when changing the layout of KernelDesc!
+const KERNEL_DATA_MAGIC: [u8; 4] = *b"krnl";
+const KERNEL_DATA_VERSION: u32 = 2;
+
 impl KernelDesc {
     fn encode(&self) -> Result<String> {
-        let bytes = bincode2::serialize(self).map_err(|e| Error::new(Span2::call_site(), e))?;
+        let mut bytes = KERNEL_DATA_MAGIC.to_vec();
+        bytes.extend_from_slice(&KERNEL_DATA_VERSION.to_le_bytes());
+        bincode2::serialize_into(&mut bytes, self)
+            .map_err(|e| Error::new(Span2::call_site(), e))?;
         Ok(format!("__krnl_kernel_data_{}", hex::encode(bytes)))
     }
     fn push_const_fields(&self) -> Punctuated<TokenStream2, Comma> {
@@ -1141,10 +1205,17 @@ impl KernelDesc {
         for push_desc in self.push_descs.iter() {
             let ident = format_ident!("{}", push_desc.name);
             let ty = format_ident!("{}", push_desc.scalar_type.name());
-            fields.push(quote! {
-               #ident: #ty
-            });
-            size += push_desc.scalar_type.size();
+            if let Some(len) = push_desc.array_len {
+                let len = Literal::usize_unsuffixed(len as usize);
+                fields.push(quote! {
+                   #ident: [#ty; #len]
+                });
+            } else {
+                fields.push(quote! {
+                   #ident: #ty
+                });
+            }
+            size += push_desc.scalar_type.size() * push_desc.array_len.unwrap_or(1) as usize;
         }
         for i in 0..4 {
             if size % 4 == 0 {
@@ -1168,10 +1239,22 @@ impl KernelDesc {
         }
         fields
     }
-    fn dispatch_push_args(&self) -> Vec<Ident> {
+    fn dispatch_push_args(&self, skip: Option<&str>) -> Vec<TokenStream2> {
         self.push_descs
             .iter()
-            .map(|push| format_ident!("{}", push.name))
+            .filter(|push| skip.map_or(true, |skip| push.name != skip))
+            .map(|push| {
+                let ident = format_ident!("{}", push.name);
+                if push.array_len.is_some() {
+                    quote! {
+                        KernelPushArg::Array(&#ident.map(ScalarElem::from))
+                    }
+                } else {
+                    quote! {
+                        KernelPushArg::Scalar(#ident.into())
+                    }
+                }
+            })
             .collect()
     }
 }
@@ -1188,6 +1271,8 @@ impl Features {
     pub const INT64: Self = Self::new(1 << 2);
     pub const FLOAT16: Self = Self::new(1 << 3);
     pub const FLOAT64: Self = Self::new(1 << 4);
+    pub const INTEGER_DOT_PRODUCT: Self = Self::new(1 << 5);
+    pub const COOPERATIVE_MATRIX: Self = Self::new(1 << 6);
     pub const BUFFER8: Self = Self::new(1 << 8);
     pub const BUFFER16: Self = Self::new(1 << 9);
     pub const PUSH_CONSTANT8: Self = Self::new(1 << 10);
@@ -1257,6 +1342,8 @@ impl Features {
             INT64,
             FLOAT16,
             FLOAT64,
+            INTEGER_DOT_PRODUCT,
+            COOPERATIVE_MATRIX,
             BUFFER8,
             BUFFER16,
             PUSH_CONSTANT8,
@@ -1365,15 +1452,26 @@ impl ToTokens for SliceDesc {
 struct PushDesc {
     name: String,
     scalar_type: ScalarType,
+    array_len: Option<u32>,
 }
 
 impl ToTokens for PushDesc {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let Self { name, scalar_type } = self;
+        let Self {
+            name,
+            scalar_type,
+            array_len,
+        } = self;
+        let array_len = if let Some(array_len) = array_len {
+            quote! { Some(#array_len) }
+        } else {
+            quote! { None }
+        };
         tokens.extend(quote! {
             PushDesc {
                 name: #name,
                 scalar_type: #scalar_type,
+                array_len: #array_len,
             }
         })
     }
@@ -1512,9 +1610,9 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         let spec_descs = &kernel_desc.spec_descs;
         let slice_descs = &kernel_desc.slice_descs;
         let push_descs = &kernel_desc.push_descs;
-        let dispatch_args = kernel_meta.dispatch_args();
+        let dispatch_args = kernel_meta.dispatch_args(None);
         let dispatch_slice_args = kernel_meta.dispatch_slice_args();
-        let dispatch_push_args = kernel_desc.dispatch_push_args();
+        let dispatch_push_args = kernel_desc.dispatch_push_args(None);
         let safe = unsafe_token.is_none();
         let safety = if safe {
             quote! {
@@ -1557,6 +1655,20 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                         _m: PhantomData,
                     }
                 }
+                /// Specializes the spec constant `name`.
+                ///
+                /// Spec constants may be specialized one at a time, `.build()` will fail
+                /// if any are not specialized.
+                ///
+                /// # Errors
+                /// - The kernel has no spec constant `name`.
+                /// - `value` is not the type of the spec constant.
+                pub fn specialize_one(self, name: &str, value: __krnl::scalar::ScalarElem) -> Result<KernelBuilder<Specialized<true>>> {
+                    Ok(KernelBuilder {
+                        inner: self.inner.specialize_one(name, value)?,
+                        _m: PhantomData,
+                    })
+                }
             }
         } else {
             TokenStream2::new()
@@ -1578,6 +1690,31 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         } else {
             TokenStream2::new()
         };
+        let tiled = needs_groups
+            && kernel_desc.push_descs.iter().any(|push| {
+                push.name == "base"
+                    && push.scalar_type == ScalarType::U32
+                    && push.array_len.is_none()
+            });
+        let kernel_dispatch_tiled_fn = if tiled {
+            let dispatch_tiled_args = kernel_meta.dispatch_args(Some("base"));
+            let dispatch_tiled_push_args = kernel_desc.dispatch_push_args(Some("base"));
+            quote! {
+                /// Dispatches the kernel over `total` global threads, split into tiles.
+                ///
+                /// Each tile is a separate dispatch of at most `DeviceInfo::max_groups()` groups,
+                /// with `base` set to the global thread of the first thread in the tile. Groups
+                /// set with [`.with_groups()`](Kernel::with_groups) are ignored.
+                ///
+                /// # Errors
+                /// - See [`.dispatch()`](Kernel::dispatch).
+                pub #unsafe_token fn dispatch_tiled(&self, #dispatch_tiled_args total: u32) -> Result<()> {
+                    unsafe { self.inner.dispatch_tiled(&[#dispatch_slice_args], &[#(#dispatch_tiled_push_args),*], total) }
+                }
+            }
+        } else {
+            TokenStream2::new()
+        };
         let input_docs = {
             let input_tokens_string = prettyplease::unparse(&syn::parse2(quote! {
                 #[kernel]
@@ -1613,10 +1750,11 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     anyhow::{self, Result},
                     krnl_core::half::{f16, bf16},
                     buffer::{Slice, SliceMut},
-                    device::{Device, Features},
-                    scalar::ScalarType,
+                    device::{Device, Features, Fence},
+                    scalar::{ScalarType, ScalarElem},
                     kernel::__private::{
                         Kernel as KernelBase,
+                        KernelPushArg,
                         KernelBuilder as KernelBuilderBase,
                         Specialized,
                         WithGroups,
@@ -1634,7 +1772,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                 #[doc(hidden)]
                 use __krnl::macros::__krnl_cache;
                 #[cfg(doc)]
-                use __krnl::{kernel, device::{DeviceInfo, error::DeviceLost}};
+                use __krnl::{kernel, device::{DeviceInfo, error::{DeviceLost, DeviceUnavailable}}};
 
                 #host_array_length_checks
 
@@ -1683,6 +1821,26 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                             _m: PhantomData,
                         }
                     }
+                    /// Threads per group suited to `device`, based on its subgroup size.
+                    ///
+                    /// Pass to [`.with_threads()`](Self::with_threads).
+                    pub fn auto_threads(&self, device: &Device) -> u32 {
+                        self.inner.auto_threads(device)
+                    }
+                    /// Disables float optimizations that change results.
+                    ///
+                    /// Float arithmetic is decorated with `NoContraction`, so that the driver does not fuse
+                    /// operations, ie `a * b + c` into an fma, which changes rounding. The spirv-opt performance
+                    /// passes that krnlc applies do not reassociate float operations. Dispatches are then
+                    /// reproducible on the same device and driver, but may be slower.
+                    ///
+                    /// Defaults to false.
+                    pub fn deterministic(self, deterministic: bool) -> Self {
+                        Self {
+                            inner: self.inner.deterministic(deterministic),
+                            _m: PhantomData,
+                        }
+                    }
                     #kernel_builder_specialize_fn
                     #[doc(hidden)]
                     #[inline]
@@ -1700,6 +1858,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     /// # Errors
                     /// - `device` doesn't have required features.
                     /// - The kernel is not supported on `device`.
+                    /// - The kernel uses more shared memory than `device` supports.
                     /// - [`DeviceLost`].
                     pub fn build(&self, device: Device) -> Result<Kernel> {
                         Ok(Kernel {
@@ -1707,6 +1866,49 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                             _m: PhantomData,
                         })
                     }
+                    /// Builds the kernel for `device`, creating the pipeline on a background thread.
+                    ///
+                    /// Returns a ready kernel if it is already cached. Otherwise dispatching blocks until
+                    /// the pipeline is created, see [`Kernel::is_ready()`]. Concurrent builds of the same
+                    /// kernel share the cache and compile once.
+                    ///
+                    /// # Errors
+                    /// - See [`.build()`](Self::build).
+                    /// - Errors from creating the pipeline are returned when dispatching.
+                    pub fn build_async(&self, device: Device) -> Result<Kernel> {
+                        Ok(Kernel {
+                            inner:  self.inner.build_async(device)?,
+                            _m: PhantomData,
+                        })
+                    }
+                    /// Disassembles the SPIR-V, specialized with threads and spec constants.
+                    ///
+                    /// Does not require a device, so that specialization can be inspected in tests.
+                    ///
+                    /// # Errors
+                    /// - Threads were not provided with [`.with_threads()`](Self::with_threads).
+                    /// - [`DeviceUnavailable`] without the "device" feature.
+                    pub fn specialized_disassembly(&self) -> Result<String> {
+                        self.inner.specialized_disassembly()
+                    }
+                    /// Bytes of shared memory declared by the kernel, specialized with threads and spec constants.
+                    ///
+                    /// Does not require a device. Builds fail if this exceeds `DeviceLimits::max_compute_shared_memory_size()`.
+                    ///
+                    /// # Errors
+                    /// - Threads were not provided with [`.with_threads()`](Self::with_threads).
+                    /// - The shared memory contains an unsupported type.
+                    /// - [`DeviceUnavailable`] without the "device" feature.
+                    pub fn shared_memory_bytes(&self) -> Result<u32> {
+                        self.inner.shared_memory_bytes()
+                    }
+                }
+
+                #[doc(hidden)]
+                impl AsRef<KernelBuilderBase> for KernelBuilder #kernel_builder_build_generics {
+                    fn as_ref(&self) -> &KernelBuilderBase {
+                        &self.inner
+                    }
                 }
 
                 /// Kernel.
@@ -1722,6 +1924,18 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     pub fn threads(&self) -> u32 {
                         self.inner.threads()
                     }
+                    /// Recommended minimum groups to keep the device busy.
+                    ///
+                    /// Advisory, see `DeviceInfo::recommended_min_groups()`.
+                    pub fn recommended_min_groups(&self) -> u32 {
+                        self.inner.recommended_min_groups()
+                    }
+                    /// Whether the pipeline is created.
+                    ///
+                    /// Only false while building with `.build_async()`.
+                    pub fn is_ready(&self) -> bool {
+                        self.inner.is_ready()
+                    }
                     /// Global threads to dispatch.
                     ///
                     /// Implicitly declares groups by rounding up to the next multiple of threads.
@@ -1740,9 +1954,22 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                             _m: PhantomData,
                         }
                     }
+                    #kernel_dispatch_tiled_fn
                 }
 
                 impl Kernel #kernel_dispatch_generics {
+                    /// Sets global threads to dispatch, in place.
+                    ///
+                    /// See [`.with_global_threads()`](Kernel::with_global_threads).
+                    pub fn set_global_threads(&mut self, global_threads: u32) {
+                        self.inner.set_global_threads(global_threads);
+                    }
+                    /// Sets groups to dispatch, in place.
+                    ///
+                    /// See [`.with_groups()`](Kernel::with_groups).
+                    pub fn set_groups(&mut self, groups: u32) {
+                        self.inner.set_groups(groups);
+                    }
                     /// Dispatches the kernel.
                     ///
                     /// - Waits for immutable access to slice arguments.
@@ -1753,7 +1980,47 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     /// - [`DeviceLost`].
                     /// - The kernel could not be queued.
                     pub #unsafe_token fn dispatch(&self, #dispatch_args) -> Result<()> {
-                        unsafe { self.inner.dispatch(&[#dispatch_slice_args], &[#(#dispatch_push_args.into()),*]) }
+                        unsafe { self.inner.dispatch(&[#dispatch_slice_args], &[#(#dispatch_push_args),*]) }
+                    }
+                    /// Dispatches the kernel, signaling `fence` when it completes.
+                    ///
+                    /// See [`.dispatch()`](Kernel::dispatch) and `Fence`.
+                    ///
+                    /// # Errors
+                    /// - `fence` is on a different device.
+                    /// - See [`.dispatch()`](Kernel::dispatch).
+                    pub #unsafe_token fn dispatch_with_fence(&self, #dispatch_args fence: &Fence) -> Result<()> {
+                        unsafe { self.inner.dispatch_with_fence(&[#dispatch_slice_args], &[#(#dispatch_push_args),*], fence) }
+                    }
+                    /// Dispatches the kernel if it is safe.
+                    ///
+                    /// Never `unsafe`. Kernels declared `unsafe fn`, which krnlc requires for
+                    /// `UnsafeSlice` parameters or unchecked indexing, return an error. The slices
+                    /// and push constants are checked against the kernel.
+                    ///
+                    /// # Errors
+                    /// - The kernel is declared `unsafe fn`.
+                    /// - See [`.dispatch()`](Kernel::dispatch).
+                    pub fn dispatch_safe(&self, #dispatch_args) -> Result<()> {
+                        self.inner.dispatch_safe(&[#dispatch_slice_args], &[#(#dispatch_push_args),*])
+                    }
+                }
+
+                impl #(<#with_groups>)* ::std::fmt::Debug for Kernel #(<#with_groups>)* {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        ::std::fmt::Debug::fmt(&self.inner, f)
+                    }
+                }
+
+                impl #(<#with_groups>)* __krnl::kernel::KernelInfo for Kernel #(<#with_groups>)* {
+                    fn name(&self) -> &str {
+                        self.inner.name()
+                    }
+                    fn device(&self) -> Device {
+                        self.inner.device()
+                    }
+                    fn slice_params(&self) -> Vec<(String, ScalarType, bool, bool)> {
+                        self.inner.slice_params()
                     }
                 }
             }
@@ -1907,6 +2174,19 @@ fn krnlc_version_compatible(krnlc_version: &str, version: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn kernel_desc_encode_versioned() {
+        let kernel_desc = KernelDesc {
+            name: "foo".to_string(),
+            ..KernelDesc::default()
+        };
+        let data = kernel_desc.encode().unwrap();
+        let bytes = hex::decode(data.strip_prefix("__krnl_kernel_data_").unwrap()).unwrap();
+        assert_eq!(bytes[..4], KERNEL_DATA_MAGIC);
+        assert_eq!(bytes[4..8], KERNEL_DATA_VERSION.to_le_bytes());
+        assert_eq!(bytes[8..], bincode2::serialize(&kernel_desc).unwrap());
+    }
+
     #[test]
     fn krnlc_version_semver() {
         assert!(krnlc_version_compatible("0.0.1", "0.0.1"));
@@ -2,7 +2,7 @@
 
 use anyhow::{bail, Error, Result};
 use cargo_metadata::{Metadata, Package, PackageId};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use clap_cargo::{Manifest, Workspace};
 use fxhash::{FxHashMap, FxHashSet};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
@@ -46,6 +46,9 @@ struct Cli {
     /// Enable DebugPrintf
     #[arg(long = "debug-printf")]
     debug_printf: bool,
+    /// Optimization level, ignored with DebugPrintf
+    #[arg(long = "opt-level", value_enum, default_value_t = OptLevel::Speed)]
+    opt_level: OptLevel,
     /// Use verbose output
     #[arg(short = 'v', long = "verbose")]
     verbose: bool,
@@ -75,6 +78,7 @@ fn main() -> Result<()> {
             &krnlc_metadata.dependencies,
             module_sources,
             cli.debug_printf,
+            cli.opt_level,
             cli.verbose,
             cli.dump_kernels,
         )?;
@@ -497,6 +501,7 @@ fn compile(
     dependencies: &str,
     module_sources: FxHashMap<String, String>,
     debug_printf: bool,
+    opt_level: OptLevel,
     verbose: bool,
     dump_kernels: bool,
 ) -> Result<Vec<KernelDesc>> {
@@ -691,6 +696,7 @@ crate-type = ["dylib"]
                 &spirv_module,
                 &entry_fns,
                 debug_printf,
+                opt_level,
                 dump_kernels,
             )
         })
@@ -802,6 +808,7 @@ fn kernel_post_process(
     spirv_module: &rspirv::dr::Module,
     entry_fns: &FxHashSet<u32>,
     debug_printf: bool,
+    opt_level: OptLevel,
     dump_kernels: bool,
 ) -> Result<KernelDesc> {
     use rspirv::{
@@ -1067,7 +1074,8 @@ fn kernel_post_process(
             } else {
                 bail!("Unable to decode kernel {kernel_name}, found {kernel_data:?}!");
             };
-            bincode2::deserialize(&hex::decode(kernel_data)?)?
+            decode_kernel_data(&hex::decode(kernel_data)?)
+                .map_err(|e| Error::msg(format!("Unable to decode kernel {kernel_name}, {e}")))?
         };
         {
             let mut builder = rspirv::dr::Builder::new_from_module(std::mem::take(&mut spirv_module));
@@ -1087,12 +1095,18 @@ fn kernel_post_process(
         }
         spirv_module.entry_points.first_mut().unwrap().operands[2] =
             Operand::LiteralString("main".to_string());
-        let mut spirv_module = if !debug_printf {
-            let spirv = spirv_module.assemble();
-            rspirv::dr::load_words(&spirv_opt(&spirv, SpirvOptKind::Performance)?)
-                .map_err(|e| Error::msg(e.to_string()))?
-        } else {
-            spirv_module
+        let opt_kind = match opt_level {
+            OptLevel::None => None,
+            OptLevel::Size => Some(SpirvOptKind::Size),
+            OptLevel::Speed => Some(SpirvOptKind::Performance),
+        };
+        let mut spirv_module = match opt_kind {
+            Some(opt_kind) if !debug_printf => {
+                let spirv = spirv_module.assemble();
+                rspirv::dr::load_words(&spirv_opt(&spirv, opt_kind)?)
+                    .map_err(|e| Error::msg(e.to_string()))?
+            }
+            _ => spirv_module,
         };
         kernel_desc.name = format!("{crate_name_ident}::{kernel_name}");
         let mut features = Features::empty();
@@ -1131,6 +1145,9 @@ fn kernel_post_process(
                     features |= Features::FLOAT64;
                 }
                 (Op::TypeInt | Op::TypeFloat, _) => unreachable!(),
+                (Op::TypeCooperativeMatrixNV, _) => {
+                    features |= Features::COOPERATIVE_MATRIX;
+                }
                 _ => (),
             }
         }
@@ -1214,6 +1231,7 @@ fn kernel_post_process(
                 Int64 => features.contains(Features::INT64),
                 Float16 => features.contains(Features::FLOAT16),
                 Float64 => features.contains(Features::FLOAT64),
+                CooperativeMatrixNV => features.contains(Features::COOPERATIVE_MATRIX),
                 StorageBuffer8BitAccess => features.contains(Features::BUFFER8),
                 StorageBuffer16BitAccess => features.contains(Features::BUFFER16),
                 StoragePushConstant8 => features.contains(Features::PUSH_CONSTANT8),
@@ -1250,9 +1268,21 @@ fn kernel_post_process(
     Ok(kernel_desc)
 }
 
+/// Optimization level of kernels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OptLevel {
+    /// Only remove dead code, fastest to compile
+    None,
+    /// Optimize for size
+    Size,
+    /// Optimize for performance
+    Speed,
+}
+
 #[derive(Clone, Copy, Debug)]
 enum SpirvOptKind {
     DeadCodeElimination,
+    Size,
     Performance,
 }
 
@@ -1290,6 +1320,9 @@ fn spirv_opt(spirv: &[u32], kind: SpirvOptKind) -> Result<spirv_tools::binary::B
                 optimizer.register_pass(pass);
             }
         }
+        SpirvOptKind::Size => {
+            optimizer.register_size_passes();
+        }
         SpirvOptKind::Performance => {
             optimizer.register_performance_passes();
         }
@@ -1607,6 +1640,21 @@ impl<'de> Deserialize<'de> for ScalarType {
 
 // must match krnl_macros defs!
 
+const KERNEL_DATA_MAGIC: [u8; 4] = *b"krnl";
+const KERNEL_DATA_VERSION: u32 = 2;
+
+fn decode_kernel_data(bytes: &[u8]) -> Result<KernelDesc> {
+    let version = bytes
+        .strip_prefix(KERNEL_DATA_MAGIC.as_slice())
+        .and_then(|x| x.get(..4))
+        .map(|x| u32::from_le_bytes(x.try_into().unwrap()));
+    if version != Some(KERNEL_DATA_VERSION) {
+        let found = version.map_or("none".to_string(), |x| x.to_string());
+        bail!("incompatible kernel blob version {found}, expected {KERNEL_DATA_VERSION}! Check that krnl and krnlc versions match.");
+    }
+    Ok(bincode2::deserialize(&bytes[8..])?)
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct KernelDesc {
     name: String,
@@ -1631,6 +1679,8 @@ impl Features {
     pub const INT64: Self = Self::new(1 << 2);
     pub const FLOAT16: Self = Self::new(1 << 3);
     pub const FLOAT64: Self = Self::new(1 << 4);
+    pub const INTEGER_DOT_PRODUCT: Self = Self::new(1 << 5);
+    pub const COOPERATIVE_MATRIX: Self = Self::new(1 << 6);
     pub const BUFFER8: Self = Self::new(1 << 8);
     pub const BUFFER16: Self = Self::new(1 << 9);
     pub const PUSH_CONSTANT8: Self = Self::new(1 << 10);
@@ -1660,6 +1710,8 @@ impl Features {
             .union(Self::INT64)
             .union(Self::FLOAT16)
             .union(Self::FLOAT64)
+            .union(Self::INTEGER_DOT_PRODUCT)
+            .union(Self::COOPERATIVE_MATRIX)
             .union(Self::BUFFER8)
             .union(Self::BUFFER16)
             .union(Self::PUSH_CONSTANT8)
@@ -1698,6 +1750,8 @@ impl Features {
             INT64,
             FLOAT16,
             FLOAT64,
+            INTEGER_DOT_PRODUCT,
+            COOPERATIVE_MATRIX,
             BUFFER8,
             BUFFER16,
             PUSH_CONSTANT8,
@@ -1797,6 +1851,7 @@ struct SliceDesc {
 struct PushDesc {
     name: String,
     scalar_type: ScalarType,
+    array_len: Option<u32>,
 }
 
 #[derive(Serialize, serde::Deserialize, Debug)]
@@ -1804,3 +1859,68 @@ struct KrnlcCache {
     version: String,
     kernels: Vec<KernelDesc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_kernel_data_version() {
+        let error = decode_kernel_data(b"krn").unwrap_err().to_string();
+        assert!(
+            error.contains("incompatible kernel blob version none"),
+            "{error}"
+        );
+        let mut bytes = KERNEL_DATA_MAGIC.to_vec();
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        let error = decode_kernel_data(&bytes).unwrap_err().to_string();
+        assert!(
+            error.contains("incompatible kernel blob version 0, expected 2"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn spirv_opt_kinds() {
+        use rspirv::{
+            binary::Assemble,
+            dr::Builder,
+            spirv::{
+                AddressingModel, Capability, ExecutionMode, ExecutionModel, FunctionControl,
+                MemoryModel,
+            },
+        };
+        let mut builder = Builder::new();
+        builder.set_version(1, 5);
+        builder.capability(Capability::Shader);
+        builder.capability(Capability::VulkanMemoryModel);
+        builder.memory_model(AddressingModel::Logical, MemoryModel::Vulkan);
+        let void = builder.type_void();
+        let void_fn = builder.type_function(void, []);
+        let main = builder
+            .begin_function(void, None, FunctionControl::NONE, void_fn)
+            .unwrap();
+        builder.begin_block(None).unwrap();
+        builder.ret().unwrap();
+        builder.end_function().unwrap();
+        builder.entry_point(ExecutionModel::GLCompute, main, "main", []);
+        builder.execution_mode(main, ExecutionMode::LocalSize, [1, 1, 1]);
+        let spirv = builder.module().assemble();
+        let kinds = [
+            SpirvOptKind::DeadCodeElimination,
+            SpirvOptKind::Size,
+            SpirvOptKind::Performance,
+        ];
+        for kind in kinds {
+            let binary = spirv_opt(&spirv, kind).unwrap();
+            let module = rspirv::dr::load_words(&binary).unwrap();
+            assert_eq!(module.entry_points.len(), 1, "{kind:?}");
+            assert_eq!(
+                module.entry_points[0].operands[2].unwrap_literal_string(),
+                "main",
+                "{kind:?}"
+            );
+            spirv_val(binary.as_ref()).unwrap();
+        }
+    }
+}
@@ -37,7 +37,6 @@ fn main() -> Result<()> {
 ```
 */
 
-#[cfg(doc)]
 use crate::device::error::DeviceLost;
 #[cfg(all(not(feature = "device"), doc))]
 use crate::device::Features;
@@ -47,7 +46,7 @@ use crate::{
 };
 #[cfg(feature = "device")]
 use crate::{
-    device::{DeviceBuffer, Features},
+    device::{DeviceBuffer, DeviceBufferMap, Features},
     macros::module,
 };
 use anyhow::{bail, Result};
@@ -61,7 +60,7 @@ use std::{
     fmt::{self, Debug},
     marker::PhantomData,
     mem::{forget, size_of},
-    ops::{Bound, RangeBounds},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
     sync::Arc,
 };
 
@@ -102,6 +101,412 @@ pub mod error {
 #[cfg(doc)]
 use error::{DeviceBufferTooLarge, OutOfDeviceMemory};
 
+/** Additional usages of a device buffer.
+
+Device buffers can always be used as storage buffers, and as the source and destination of transfers.
+Other usages must be requested when the buffer is allocated, see [`Buffer::uninit_with_usage()`].
+
+Ignored for host buffers.
+*/
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct BufferUsage {
+    bits: u32,
+}
+
+impl BufferUsage {
+    /// Indirect dispatch arguments.
+    ///
+    /// VK_BUFFER_USAGE_INDIRECT_BUFFER_BIT.
+    pub const INDIRECT: Self = Self::new(1);
+    /// Uniform buffer.
+    ///
+    /// VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT.
+    pub const UNIFORM: Self = Self::new(1 << 1);
+
+    #[inline]
+    const fn new(bits: u32) -> Self {
+        Self { bits }
+    }
+    /// No additional usages.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+    /// Contains all usages of `other`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        (self.bits | other.bits) == self.bits
+    }
+    /// All usages of `self` and `other`.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self::new(self.bits | other.bits)
+    }
+}
+
+impl Debug for BufferUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = [("INDIRECT", Self::INDIRECT), ("UNIFORM", Self::UNIFORM)]
+            .into_iter()
+            .filter_map(|(name, usage)| self.contains(usage).then_some(name));
+        f.debug_tuple("BufferUsage")
+            .field(&format_args!("{}", itertools::join(names, "|")))
+            .finish()
+    }
+}
+
+impl core::ops::BitOr for BufferUsage {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for BufferUsage {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/** Whether a [scan](BufferBase::scan) includes the current element.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanMode {
+    /// `y[i] = x[0] + .. + x[i]`
+    Inclusive,
+    /// `y[i] = x[0] + .. + x[i - 1]`, starting with `y[0] = 0`.
+    Exclusive,
+}
+
+fn scan_host<T: Scalar>(x: &[T], y: &mut [T], mode: ScanMode) {
+    macro_for!($X in [u8, i8, u16, i16, u32, i32, u64, i64] {
+        if let Some((x, y)) = bytemuck::try_cast_slice::<T, $X>(x)
+            .ok()
+            .filter(|_| T::SCALAR_TYPE == $X::SCALAR_TYPE)
+            .zip(bytemuck::try_cast_slice_mut::<T, $X>(y).ok())
+        {
+            let mut acc: $X = 0;
+            for (x, y) in x.iter().zip(y) {
+                let next = acc.wrapping_add(*x);
+                *y = if mode == ScanMode::Inclusive { next } else { acc };
+                acc = next;
+            }
+            return;
+        }
+    });
+    let mut acc = T::default();
+    for (x, y) in x.iter().zip(y) {
+        let next = acc + *x;
+        *y = if mode == ScanMode::Inclusive {
+            next
+        } else {
+            acc
+        };
+        acc = next;
+    }
+}
+
+fn arg_host<T: Scalar>(x: &[T], ordering: std::cmp::Ordering) -> Option<(usize, T)> {
+    let mut iter = x.iter().copied().enumerate();
+    let mut output = iter.next()?;
+    for (i, x) in iter {
+        if output.1.partial_cmp(&output.1).is_none() {
+            break;
+        }
+        match x.partial_cmp(&output.1) {
+            Some(x_ordering) if x_ordering == ordering => output = (i, x),
+            Some(_) => (),
+            None => output = (i, x),
+        }
+    }
+    Some(output)
+}
+
+fn check_pod_size<T: Scalar, P: bytemuck::Pod>() -> Result<()> {
+    let width = size_of::<T>();
+    let pod_size = size_of::<P>();
+    if pod_size == 0 || pod_size % width != 0 {
+        bail!(
+            "size of {} ({pod_size}) is not a non zero multiple of the size of {:?} ({width})!",
+            std::any::type_name::<P>(),
+            T::SCALAR_TYPE
+        );
+    }
+    Ok(())
+}
+
+/** Memory of a device buffer.
+
+See [`Buffer::uninit_with_memory()`].
+
+Ignored for host buffers.
+*/
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum MemoryKind {
+    /// Device local memory, fastest for kernels.
+    ///
+    /// Transfers to and from the host are staged, unless the memory is also host visible.
+    #[default]
+    DeviceLocal,
+    /// Host visible memory, preferably cached.
+    ///
+    /// Transfers to and from the host do not need staging, but kernels may be slower.
+    HostVisible,
+}
+
+enum MappedBytes<'a> {
+    Host(&'a [u8]),
+    HostMut(&'a mut [u8]),
+    #[cfg(feature = "device")]
+    Device(DeviceBufferMap),
+}
+
+impl MappedBytes<'_> {
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Host(bytes) => bytes,
+            Self::HostMut(bytes) => bytes,
+            #[cfg(feature = "device")]
+            Self::Device(map) => map,
+        }
+    }
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        match self {
+            Self::Host(_) => unreachable!(),
+            Self::HostMut(bytes) => bytes,
+            #[cfg(feature = "device")]
+            Self::Device(map) => map,
+        }
+    }
+}
+
+/** A buffer mapped for reading from the host.
+
+See [`BufferBase::map()`]. */
+pub struct MappedSlice<'a, T> {
+    bytes: MappedBytes<'a>,
+    _m: PhantomData<&'a [T]>,
+}
+
+impl<T: Scalar> Deref for MappedSlice<'_, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        bytemuck::cast_slice(self.bytes.as_bytes())
+    }
+}
+
+impl<T: Scalar> Debug for MappedSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedSlice")
+            .field("scalar_type", &T::SCALAR_TYPE)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/** A buffer mapped for reading and writing from the host.
+
+Writes are flushed to the device on drop. See [`BufferBase::map_mut()`]. */
+pub struct MappedSliceMut<'a, T> {
+    bytes: MappedBytes<'a>,
+    _m: PhantomData<&'a mut [T]>,
+}
+
+impl<T: Scalar> Deref for MappedSliceMut<'_, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        bytemuck::cast_slice(self.bytes.as_bytes())
+    }
+}
+
+impl<T: Scalar> DerefMut for MappedSliceMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        bytemuck::cast_slice_mut(self.bytes.as_bytes_mut())
+    }
+}
+
+impl<T: Scalar> Debug for MappedSliceMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedSliceMut")
+            .field("scalar_type", &T::SCALAR_TYPE)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/** A transient buffer from a device's scratch pool.
+
+Derefs to [`Buffer<u8>`], use [`.bitcast_mut()`](BufferBase::bitcast_mut) to view it as
+another scalar type. The buffer is returned to the pool on drop.
+
+See [`Device::scratch()`]. */
+pub struct ScratchBuffer {
+    buffer: Buffer<u8>,
+}
+
+impl ScratchBuffer {
+    pub(crate) fn new(device: Device, len: usize) -> Result<Self> {
+        let buffer = match device.inner() {
+            DeviceInner::Host => Buffer::zeros(device, len)?,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(device) => {
+                let device_buffer = unsafe { DeviceBuffer::scratch(device.clone(), len)? };
+                let raw = RawBuffer {
+                    slice: RawSlice {
+                        inner: RawSliceInner::Device(device_buffer),
+                    },
+                    cap: len,
+                    width: 1,
+                };
+                Buffer {
+                    data: BufferRepr {
+                        raw,
+                        _m: PhantomData,
+                    },
+                }
+            }
+        };
+        Ok(Self { buffer })
+    }
+}
+
+impl Deref for ScratchBuffer {
+    type Target = Buffer<u8>;
+    fn deref(&self) -> &Buffer<u8> {
+        &self.buffer
+    }
+}
+
+impl DerefMut for ScratchBuffer {
+    fn deref_mut(&mut self) -> &mut Buffer<u8> {
+        &mut self.buffer
+    }
+}
+
+impl Drop for ScratchBuffer {
+    fn drop(&mut self) {
+        #[cfg(feature = "device")]
+        if let RawSliceInner::Device(device_buffer) = &self.buffer.data.raw.inner {
+            device_buffer.recycle();
+        }
+    }
+}
+
+impl Debug for ScratchBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScratchBuffer")
+            .field("device", &self.device())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/** Uploads host data to a device in fixed size batches.
+
+Fill the host batch with [`.batch_mut()`](StreamingUploader::batch_mut), then call
+[`.upload()`](StreamingUploader::upload) with the number of elements filled. The batch is
+staged and copied on the device asynchronously, so the host batch can be refilled immediately.
+
+Uploads alternate between two device buffers (double buffering). Uploading batch N + 1 only
+waits for work using batch N - 1, so the previous batch can still be in use by a kernel.
+The slice returned by `.upload()` is overwritten by the upload after next.
+
+The final batch may be partial, upload it with the number of elements filled.
+
+```no_run
+# use krnl::{anyhow::Result, buffer::StreamingUploader, device::Device};
+# fn main() -> Result<()> {
+# let device = Device::host();
+# let data = vec![1f32; 1000];
+let mut uploader = StreamingUploader::<f32>::new(device, 256)?;
+for chunk in data.chunks(uploader.batch_len()) {
+    uploader
+        .batch_mut()
+        .as_host_slice_mut()
+        .unwrap()[..chunk.len()]
+        .copy_from_slice(chunk);
+    let batch = uploader.upload(chunk.len())?;
+    // dispatch a kernel with batch
+}
+# Ok(())
+# }
+```
+*/
+pub struct StreamingUploader<T: Scalar> {
+    host: Vec<T>,
+    buffers: [Buffer<T>; 2],
+    index: usize,
+}
+
+impl<T: Scalar> StreamingUploader<T> {
+    /** Creates an uploader for `device` with batches of `batch_len` elements.
+
+    # Errors
+    - `batch_len` is 0.
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`] */
+    pub fn new(device: Device, batch_len: usize) -> Result<Self> {
+        if batch_len == 0 {
+            bail!("batch_len must not be 0!");
+        }
+        let buffers = unsafe {
+            [
+                Buffer::uninit(device.clone(), batch_len)?,
+                Buffer::uninit(device, batch_len)?,
+            ]
+        };
+        Ok(Self {
+            host: vec![T::default(); batch_len],
+            buffers,
+            index: 0,
+        })
+    }
+    /// The device.
+    pub fn device(&self) -> Device {
+        self.buffers[0].device()
+    }
+    /// The number of elements in each batch.
+    pub fn batch_len(&self) -> usize {
+        self.host.len()
+    }
+    /// The host batch to fill before [`.upload()`](StreamingUploader::upload).
+    pub fn batch_mut(&mut self) -> SliceMut<'_, T> {
+        SliceMut::from_host_slice_mut(&mut self.host)
+    }
+    /** Uploads the first `len` elements of the batch.
+
+    Returns the device slice holding them.
+
+    # Errors
+    - `len` is greater than the [batch length](StreamingUploader::batch_len).
+    - [`DeviceLost`] */
+    pub fn upload(&mut self, len: usize) -> Result<Slice<'_, T>> {
+        let batch_len = self.batch_len();
+        if len > batch_len {
+            bail!("len {len} is greater than batch_len {batch_len}!");
+        }
+        let index = self.index;
+        self.index = (index + 1) % self.buffers.len();
+        let buffer = &mut self.buffers[index];
+        buffer
+            .slice_mut(..len)
+            .unwrap()
+            .copy_from_slice(&Slice::from_host_slice(&self.host[..len]))?;
+        Ok(buffer.slice(..len).unwrap())
+    }
+}
+
+impl<T: Scalar> Debug for StreamingUploader<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingUploader")
+            .field("device", &self.device())
+            .field("scalar_type", &T::SCALAR_TYPE)
+            .field("batch_len", &self.batch_len())
+            .finish()
+    }
+}
+
 #[derive(Copy, Clone)]
 struct RawHostSlice {
     ptr: *mut u8,
@@ -137,6 +542,40 @@ impl RawSlice {
             RawSliceInner::Device(buffer) => buffer.len(),
         }
     }
+    fn host_visible(&self) -> bool {
+        match &self.inner {
+            RawSliceInner::Host(_) => true,
+            #[cfg(feature = "device")]
+            RawSliceInner::Device(buffer) => buffer.host_visible(),
+        }
+    }
+    fn wait(&self) -> Result<(), DeviceLost> {
+        match &self.inner {
+            RawSliceInner::Host(_) => Ok(()),
+            #[cfg(feature = "device")]
+            RawSliceInner::Device(buffer) => buffer.wait(),
+        }
+    }
+    fn is_finished(&self) -> Result<bool, DeviceLost> {
+        match &self.inner {
+            RawSliceInner::Host(_) => Ok(true),
+            #[cfg(feature = "device")]
+            RawSliceInner::Device(buffer) => buffer.is_finished(),
+        }
+    }
+    fn overlaps(&self, other: &Self) -> bool {
+        match (&self.inner, &other.inner) {
+            (RawSliceInner::Host(a), RawSliceInner::Host(b)) => {
+                let a = a.ptr as usize..a.ptr as usize + a.len;
+                let b = b.ptr as usize..b.ptr as usize + b.len;
+                !a.is_empty() && !b.is_empty() && a.start < b.end && b.start < a.end
+            }
+            #[cfg(feature = "device")]
+            (RawSliceInner::Device(a), RawSliceInner::Device(b)) => a.overlaps(b),
+            #[cfg(feature = "device")]
+            _ => false,
+        }
+    }
     fn bitcast(self, scalar_type: ScalarType) -> Result<Self, PodCastError> {
         let (index, len) = match &self.inner {
             RawSliceInner::Host(raw) => (raw.ptr as usize, raw.len),
@@ -606,7 +1045,7 @@ impl<'a> ScalarSliceMutRepr<'a> {
     fn copy_from_scalar_slice(&mut self, src: &ScalarSliceRepr) -> Result<()> {
         if self.scalar_type() != src.scalar_type() {
             bail!(
-                "Can not copy slice from {:?} to {:?}",
+                "Can not copy slice from {:?} to {:?}!",
                 src.scalar_type(),
                 self.scalar_type()
             );
@@ -852,7 +1291,8 @@ impl<S: ScalarDataOwned> ScalarBufferBase<S> {
     /// Allocate a scalar buffer.
     ///
     /// # Safety
-    /// The buffer will not be initialized.
+    /// The buffer will not be initialized, unless the device was built with
+    /// [`.zero_uninit()`](crate::device::builder::DeviceBuilder::zero_uninit).
     ///
     /// # Errors
     /// - [`DeviceLost`]
@@ -870,6 +1310,52 @@ impl<S: ScalarDataOwned> ScalarBufferBase<S> {
             }
         })
     }
+    /// Allocate a scalar buffer with additional `usage`.
+    ///
+    /// # Safety
+    /// The buffer will not be initialized, unless the device was built with
+    /// [`.zero_uninit()`](crate::device::builder::DeviceBuilder::zero_uninit).
+    ///
+    /// # Errors
+    /// See [`ScalarBufferBase::uninit()`].
+    pub unsafe fn uninit_with_usage(
+        device: Device,
+        len: usize,
+        scalar_type: ScalarType,
+        usage: BufferUsage,
+    ) -> Result<Self> {
+        macro_wrap!(paste! {
+            match scalar_type {
+                macro_for!($T in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
+                    ScalarType::[<$T:upper>] => Ok(unsafe { Buffer::<$T>::uninit_with_usage(device, len, usage)? }.into()),
+                })
+                _ => unreachable!(),
+            }
+        })
+    }
+    /// Allocate a scalar buffer in `memory`.
+    ///
+    /// # Safety
+    /// The buffer will not be initialized, unless the device was built with
+    /// [`.zero_uninit()`](crate::device::builder::DeviceBuilder::zero_uninit).
+    ///
+    /// # Errors
+    /// See [`ScalarBufferBase::uninit()`].
+    pub unsafe fn uninit_with_memory(
+        device: Device,
+        len: usize,
+        scalar_type: ScalarType,
+        memory: MemoryKind,
+    ) -> Result<Self> {
+        macro_wrap!(paste! {
+            match scalar_type {
+                macro_for!($T in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
+                    ScalarType::[<$T:upper>] => Ok(unsafe { Buffer::<$T>::uninit_with_memory(device, len, memory)? }.into()),
+                })
+                _ => unreachable!(),
+            }
+        })
+    }
     /** Create a scalar buffer filled with `elem'.
 
     # Errors
@@ -911,6 +1397,15 @@ impl<S: ScalarDataOwned> ScalarBufferBase<S> {
 }
 
 impl<S: ScalarData> ScalarBufferBase<S> {
+    /** Whether the slices overlap.
+
+    See [`BufferBase::overlaps()`]. */
+    pub fn overlaps<S2: ScalarData>(&self, other: &ScalarBufferBase<S2>) -> bool {
+        self.data
+            .as_scalar_slice()
+            .raw
+            .overlaps(&other.data.as_scalar_slice().raw)
+    }
     /// The device.
     pub fn device(&self) -> Device {
         self.data.device()
@@ -1054,6 +1549,18 @@ impl<S: ScalarData> ScalarBufferBase<S> {
             self.to_device(device).map(Into::into)
         }
     }
+    /** Waits for previous work that wrote to the buffer to finish.
+
+    See [`BufferBase::wait`]. */
+    pub fn wait(&self) -> Result<(), DeviceLost> {
+        self.data.as_scalar_slice().raw.wait()
+    }
+    /** Is host visible.
+
+    See [`BufferBase::is_host_visible`]. */
+    pub fn is_host_visible(&self) -> bool {
+        self.data.as_scalar_slice().raw.host_visible()
+    }
     /** Fills the buffer with `elem`.
 
     See [`BufferBase::fill`]. */
@@ -1131,6 +1638,10 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     }
     /** Copies from src.
 
+    # Errors
+    - `src` is not the same scalar type.
+    - `src` is not the same length.
+
     See [`BufferBase::copy_from_slice`]. */
     pub fn copy_from_scalar_slice(&mut self, src: &ScalarSlice) -> Result<()>
     where
@@ -1159,6 +1670,16 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     }
 }
 
+impl ScalarSlice<'_> {
+    pub(crate) fn as_host_bytes(&self) -> Option<&[u8]> {
+        match &self.data.raw.inner {
+            RawSliceInner::Host(raw) => Some(unsafe { raw.as_bytes() }),
+            #[cfg(feature = "device")]
+            RawSliceInner::Device(_) => None,
+        }
+    }
+}
+
 #[cfg(feature = "device")]
 impl ScalarSlice<'_> {
     pub(crate) fn device_buffer(&self) -> Option<&DeviceBuffer> {
@@ -1377,9 +1898,15 @@ impl<T: Scalar> BufferRepr<T> {
             _m: PhantomData,
         }
     }
-    unsafe fn uninit(device: Device, len: usize) -> Result<Self> {
+    unsafe fn uninit(
+        device: Device,
+        len: usize,
+        usage: BufferUsage,
+        memory: MemoryKind,
+    ) -> Result<Self> {
         match device.inner() {
             DeviceInner::Host => {
+                let _ = (usage, memory);
                 let mut vec = Vec::with_capacity(len);
                 #[allow(clippy::uninit_vec)]
                 unsafe {
@@ -1391,7 +1918,8 @@ impl<T: Scalar> BufferRepr<T> {
             DeviceInner::Device(device) => {
                 let width = size_of::<T>();
                 let cap = len * width;
-                let device_buffer = unsafe { DeviceBuffer::uninit(device.clone(), cap)? };
+                let device_buffer =
+                    unsafe { DeviceBuffer::uninit(device.clone(), cap, usage, memory)? };
                 let raw = RawBuffer {
                     slice: RawSlice {
                         inner: RawSliceInner::Device(device_buffer),
@@ -1541,7 +2069,14 @@ impl<'a, T: Scalar> SliceRepr<'a, T> {
         self.to_device(self.device())
     }
     fn to_device(&self, device: Device) -> Result<BufferRepr<T>> {
-        let mut output = unsafe { BufferRepr::uninit(device, self.len())? };
+        let mut output = unsafe {
+            BufferRepr::uninit(
+                device,
+                self.len(),
+                BufferUsage::empty(),
+                MemoryKind::DeviceLocal,
+            )?
+        };
         output.as_slice_mut().copy_from_slice(self)?;
         Ok(output)
     }
@@ -1649,12 +2184,7 @@ impl<'a, T: Scalar> SliceMutRepr<'a, T> {
                 if dst.device() != src_buffer.device() {
                     return src_buffer.transfer(dst);
                 }
-                Slice { data: src.clone() }.cast_impl(&mut SliceMut::<T> {
-                    data: SliceMutRepr {
-                        raw: self.raw.clone(),
-                        _m: PhantomData,
-                    },
-                })
+                src_buffer.copy(dst)
             }
         }
     }
@@ -2058,7 +2588,8 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     /// Allocate a buffer.
     ///
     /// # Safety
-    /// The buffer will not be initialized.
+    /// The buffer will not be initialized, unless the device was built with
+    /// [`.zero_uninit()`](crate::device::builder::DeviceBuilder::zero_uninit).
     ///
     /// # Errors
     /// - [`DeviceLost`]
@@ -2067,7 +2598,45 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     ///
     /// See [`zeros()`](Buffer::zeros) for a safe alternative.
     pub unsafe fn uninit(device: Device, len: usize) -> Result<Self> {
-        let data = S::from_buffer(unsafe { BufferRepr::uninit(device, len)? });
+        unsafe { Self::uninit_with_usage(device, len, BufferUsage::empty()) }
+    }
+    /// Allocate a buffer with additional `usage`.
+    ///
+    /// # Safety
+    /// The buffer will not be initialized, unless the device was built with
+    /// [`.zero_uninit()`](crate::device::builder::DeviceBuilder::zero_uninit).
+    ///
+    /// # Errors
+    /// See [`uninit()`](Buffer::uninit).
+    pub unsafe fn uninit_with_usage(
+        device: Device,
+        len: usize,
+        usage: BufferUsage,
+    ) -> Result<Self> {
+        let data = S::from_buffer(unsafe {
+            BufferRepr::uninit(device, len, usage, MemoryKind::DeviceLocal)?
+        });
+        Ok(Self { data })
+    }
+    /** Allocate a buffer in `memory`.
+
+    Use [`MemoryKind::HostVisible`] for buffers that are frequently read or written by the host.
+
+    # Safety
+    The buffer will not be initialized, unless the device was built with
+    [`.zero_uninit()`](crate::device::builder::DeviceBuilder::zero_uninit).
+
+    # Errors
+    See [`uninit()`](Buffer::uninit).
+    */
+    pub unsafe fn uninit_with_memory(
+        device: Device,
+        len: usize,
+        memory: MemoryKind,
+    ) -> Result<Self> {
+        let data = S::from_buffer(unsafe {
+            BufferRepr::uninit(device, len, BufferUsage::empty(), memory)?
+        });
         Ok(Self { data })
     }
     /** Create a buffer filled with `elem`
@@ -2076,10 +2645,15 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     - [`DeviceLost`]
     - [`DeviceBufferTooLarge`]
     - [`OutOfDeviceMemory`]
+    - The kernel could not be dispatched.
+        - This may require [`Features`] for the type.
 
     See [`.fill()`](BufferBase::fill).
     */
     pub fn from_elem(device: Device, len: usize, elem: T) -> Result<Self> {
+        if device.is_host() {
+            return Ok(Self::from_vec(vec![elem; len]));
+        }
         let mut output = unsafe { Buffer::uninit(device, len)? };
         output.fill(elem)?;
         Ok(Self {
@@ -2098,16 +2672,106 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     pub fn ones(device: Device, len: usize) -> Result<Self> {
         Self::from_elem(device, len, T::one())
     }
-    /// Create a buffer from a [`Vec`].
+    /** Create a buffer from a [`Vec`].
+
+    The buffer takes ownership of the allocation without copying, and
+    [`.into_vec()`](BufferBase::into_vec) returns it. */
     pub fn from_vec(vec: Vec<T>) -> Self {
         let data = S::from_buffer(BufferRepr::from_vec(vec));
         Self { data }
     }
+    /** Create a buffer on `device` from a [`Vec`], in [host visible](MemoryKind::HostVisible) memory.
+
+    If host, takes ownership of `vec` without copying, see [`from_vec`](BufferBase::from_vec).
+    Otherwise `vec` is copied directly into mapped memory, without staging. If the device
+    does not have host visible memory, falls back to staging.
+
+    # Errors
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`]
+    */
+    pub fn from_vec_host_visible(device: Device, vec: Vec<T>) -> Result<Self> {
+        if device.is_host() {
+            return Ok(Self::from_vec(vec));
+        }
+        let mut output =
+            unsafe { Buffer::uninit_with_memory(device, vec.len(), MemoryKind::HostVisible)? };
+        output.copy_from_slice(&Slice::from(vec.as_slice()))?;
+        Ok(Self::from_buffer(output))
+    }
     /// Create a buffer from a [`Buffer`].
     pub fn from_buffer(buffer: Buffer<T>) -> Self {
         let data = S::from_buffer(buffer.data);
         Self { data }
     }
+    /** Create a buffer from bytes.
+
+    The bytes are copied and reinterpreted as `T`, see [`.to_bytes()`](BufferBase::to_bytes).
+
+    # Errors
+    - The length of `bytes` is not a multiple of the size of `T`.
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`]
+    */
+    pub fn from_bytes(device: Device, bytes: &[u8]) -> Result<Self> {
+        let width = size_of::<T>();
+        if bytes.len() % width != 0 {
+            bail!(
+                "bytes length {} is not a multiple of the size of {:?} ({width})!",
+                bytes.len(),
+                T::SCALAR_TYPE
+            );
+        }
+        let mut output = unsafe { Buffer::uninit(device, bytes.len() / width)? };
+        output
+            .bitcast_mut::<u8>()
+            .unwrap()
+            .copy_from_slice(&Slice::from_host_slice(bytes))?;
+        Ok(Self::from_buffer(output))
+    }
+    /** Create a buffer from a slice of [`Pod`](bytemuck::Pod) values, ie `#[repr(C)]` structs.
+
+    The values are copied as bytes, see [`from_bytes()`](BufferBase::from_bytes).
+
+    # Errors
+    - The size of `P` is not a non zero multiple of the size of `T`.
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`]
+    */
+    pub fn from_pod_slice<P: bytemuck::Pod>(device: Device, pod_slice: &[P]) -> Result<Self> {
+        check_pod_size::<T, P>()?;
+        Self::from_bytes(device, bytemuck::cast_slice(pod_slice))
+    }
+    /** Resizes to `new_len`, preserving the contents.
+
+    If `new_len` is greater than the length, the new elements are set to `fill`.
+
+    A new buffer is allocated and the contents are copied, so the data is not shared
+    with clones of an [`ArcBuffer`].
+
+    # Errors
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`]
+    - The kernel could not be dispatched.
+        - This may require [`Features`] for the type.
+    */
+    pub fn resize(&mut self, new_len: usize, fill: T) -> Result<()> {
+        let len = self.len().min(new_len);
+        let mut output = unsafe { Buffer::uninit(self.device(), new_len)? };
+        if len > 0 {
+            output
+                .slice_mut(..len)
+                .unwrap()
+                .copy_from_slice(&self.slice(..len).unwrap())?;
+        }
+        output.slice_mut(len..).unwrap().fill(fill)?;
+        *self = Self::from_buffer(output);
+        Ok(())
+    }
 }
 
 impl<'a, T: Scalar> Slice<'a, T> {
@@ -2171,6 +2835,27 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             data: self.data.make_slice_mut()?,
         })
     }
+    /** The raw `VkBuffer` handle, and the offset and size of the slice in bytes.
+
+    Returns [`None`] if on the host, or if empty.
+
+    For interop with other libraries using the same device. The size includes only the
+    slice, not any padding of the underlying buffer.
+
+    # Safety
+    The caller must respect krnl's synchronization: work recorded by krnl may still be
+    pending, so call [`Device::wait()`] before accessing the buffer externally, and finish
+    external work before using the buffer with krnl again. The handle is only valid while
+    the buffer is alive, and must not be destroyed or bound to other memory. */
+    pub unsafe fn raw_vulkan_handle(&self) -> Option<(u64, u64, u64)> {
+        #[cfg(feature = "device")]
+        {
+            if let RawSliceInner::Device(buffer) = &self.data.as_slice().raw.inner {
+                return buffer.raw_handle();
+            }
+        }
+        None
+    }
     /** Borrow as a host slice.
 
     Returns [`None`] if not on the host. */
@@ -2186,6 +2871,26 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     {
         self.data.as_host_slice_mut()
     }
+    /** Whether `self` and `other` share any memory.
+
+    Slices overlap if they are views of the same host or device buffer with intersecting
+    ranges. Empty slices never overlap. The element types may differ.
+
+    Kernels must not be dispatched with a mutable slice that overlaps another slice.
+    ```
+    # use krnl::buffer::Buffer;
+    let x = Buffer::from(vec![0u32; 10]);
+    let a = x.slice(..6).unwrap();
+    assert!(a.overlaps(&x.slice(5..).unwrap()));
+    assert!(!a.overlaps(&x.slice(6..).unwrap()));
+    ```
+    */
+    pub fn overlaps<S2: Data>(&self, other: &BufferBase<S2>) -> bool {
+        self.data
+            .as_slice()
+            .raw
+            .overlaps(&other.data.as_slice().raw)
+    }
     /// Borrow as a scalar slice.
     pub fn as_scalar_slice(&self) -> ScalarSlice {
         let data = self.data.as_scalar_slice();
@@ -2301,6 +3006,151 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     pub fn to_vec(&self) -> Result<Vec<T>> {
         self.data.as_slice().to_vec()
     }
+    /** Copies to a [`Vec`] if previous work on the buffer has finished.
+
+    Returns `None` without blocking if work that uses the buffer, like a kernel writing
+    to it, is still pending. Data is never stale: `Some` is the result of all work
+    recorded on the buffer before this call, so keep the previous result to show while
+    polling. Host buffers are always finished.
+
+    Otherwise, the buffer is downloaded as with [`.to_vec()`](BufferBase::to_vec), which
+    blocks for the copy unless the buffer is [host visible](BufferBase::is_host_visible).
+
+    # Errors
+    - [`DeviceLost`]
+    */
+    pub fn to_vec_nonblocking(&self) -> Result<Option<Vec<T>>> {
+        let slice = self.data.as_slice();
+        if !slice.raw.is_finished()? {
+            return Ok(None);
+        }
+        slice.to_vec().map(Some)
+    }
+    /** Copies the element at `index`.
+
+    Only the element is downloaded from the device, which is much cheaper than
+    [`.to_vec()`](BufferBase::to_vec) for reading back a single value.
+
+    # Errors
+    - `index` is out of bounds.
+    - [`DeviceLost`]
+    */
+    pub fn get(&self, index: usize) -> Result<T> {
+        let len = self.len();
+        if index >= len {
+            bail!("index {index} is out of bounds of buffer length ({len})!");
+        }
+        if let Some(host_slice) = self.as_host_slice() {
+            Ok(host_slice[index])
+        } else {
+            Ok(self.slice(index..=index).unwrap().to_vec()?[0])
+        }
+    }
+    /** Copies to a [`Vec`] of bytes.
+
+    Elements are in native byte order. See [`from_bytes()`](BufferBase::from_bytes).
+
+    # Errors
+    - [`DeviceLost`]
+    */
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.bitcast::<u8>().unwrap().to_vec()
+    }
+    /** Copies to a [`Vec`] of [`Pod`](bytemuck::Pod) values.
+
+    See [`from_pod_slice()`](BufferBase::from_pod_slice).
+
+    # Errors
+    - The size of `P` is not a non zero multiple of the size of `T`.
+    - The length in bytes is not a multiple of the size of `P`.
+    - [`DeviceLost`]
+    */
+    pub fn to_pod_vec<P: bytemuck::Pod>(&self) -> Result<Vec<P>> {
+        check_pod_size::<T, P>()?;
+        let bytes = self.bitcast::<u8>().unwrap();
+        let pod_size = size_of::<P>();
+        if bytes.len() % pod_size != 0 {
+            bail!(
+                "buffer length {} bytes is not a multiple of the size of {} ({pod_size})!",
+                bytes.len(),
+                std::any::type_name::<P>()
+            );
+        }
+        let mut output = vec![P::zeroed(); bytes.len() / pod_size];
+        SliceMut::from_host_slice_mut(bytemuck::cast_slice_mut::<P, u8>(&mut output))
+            .copy_from_slice(&bytes)?;
+        Ok(output)
+    }
+    /** Waits for previous work that wrote to the buffer to finish.
+
+    Unlike [`Device::wait()`], other work on the device may still be running.
+    Does nothing for host buffers, or if the buffer has not been written on the device.
+
+    # Errors
+    - [`DeviceLost`]
+    */
+    pub fn wait(&self) -> Result<(), DeviceLost> {
+        self.data.as_slice().raw.wait()
+    }
+    /** Is host visible.
+
+    Transfers to and from the host do not need staging if the buffer is host visible.
+    Host buffers are always host visible. See [`MemoryKind`]. */
+    pub fn is_host_visible(&self) -> bool {
+        self.data.as_slice().raw.host_visible()
+    }
+    /** Maps the buffer for reading from the host.
+
+    Waits for previous work that wrote to the buffer, and avoids a copy if the buffer is on
+    the device. Host buffers are borrowed directly.
+
+    # Errors
+    - The buffer is not [host visible](BufferBase::is_host_visible).
+    - [`DeviceLost`]
+    */
+    pub fn map(&self) -> Result<MappedSlice<'_, T>> {
+        let bytes = match self.as_host_slice() {
+            Some(host_slice) => MappedBytes::Host(bytemuck::cast_slice(host_slice)),
+            #[cfg(feature = "device")]
+            None => {
+                let RawSliceInner::Device(buffer) = &self.data.as_slice().raw.inner else {
+                    unreachable!()
+                };
+                MappedBytes::Device(buffer.map(false)?)
+            }
+            #[cfg(not(feature = "device"))]
+            None => unreachable!(),
+        };
+        Ok(MappedSlice {
+            bytes,
+            _m: PhantomData,
+        })
+    }
+    /** Maps the buffer for reading and writing from the host.
+
+    Waits for all previous work on the device. Writes are flushed when the mapping is
+    dropped.
+
+    # Errors
+    See [`.map()`](BufferBase::map).
+    */
+    pub fn map_mut(&mut self) -> Result<MappedSliceMut<'_, T>>
+    where
+        S: DataMut,
+    {
+        #[cfg(feature = "device")]
+        if let RawSliceInner::Device(buffer) = &self.data.as_slice().raw.inner {
+            return Ok(MappedSliceMut {
+                bytes: MappedBytes::Device(buffer.map(true)?),
+                _m: PhantomData,
+            });
+        }
+        let host_slice = self.as_host_slice_mut().unwrap();
+        Ok(MappedSliceMut {
+            bytes: MappedBytes::HostMut(bytemuck::cast_slice_mut(host_slice)),
+            _m: PhantomData,
+        })
+    }
     /** Fills with `elem`.
 
     # Errors
@@ -2398,6 +3248,125 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             self.cast()
         }
     }
+    /** Casts into `output`.
+
+    Like [`.cast()`](BufferBase::cast), but writes to an existing slice instead of allocating.
+
+    # Errors
+    - `output` is not the same length.
+    - `output` is not on the same device.
+    - [`DeviceLost`]
+    - The kernel could not be dispatched.
+        - This may require [`Features`] for the type. */
+    pub fn cast_into_slice<Y: Scalar>(&self, output: &mut SliceMut<Y>) -> Result<()> {
+        if self.len() != output.len() {
+            bail!(
+                "source slice length ({}) does not match destination slice length ({})",
+                self.len(),
+                output.len()
+            );
+        }
+        let device = self.device();
+        let output_device = output.device();
+        if device != output_device {
+            bail!("Can not cast from {device:?} into {output_device:?}!");
+        }
+        self.as_slice().cast_impl(output)
+    }
+    /** Prefix sum.
+
+    Integers wrap on overflow. Floats are summed in order with the precision of `T`,
+    so the error grows with the length, particularly for [`f16`] and [`bf16`].
+
+    There is no scan kernel yet. Device buffers are read back, scanned on the host, and copied
+    to a new device buffer, which waits for pending work on the device.
+
+    # Errors
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`] */
+    pub fn scan(&self, mode: ScanMode) -> Result<Buffer<T>> {
+        let device = self.device();
+        if let Some(x) = self.as_host_slice() {
+            let mut y = vec![T::default(); x.len()];
+            scan_host(x, &mut y, mode);
+            return Ok(Buffer::from(y));
+        }
+        let x = self.to_vec()?;
+        let mut y = vec![T::default(); x.len()];
+        scan_host(&x, &mut y, mode);
+        Buffer::from(y).into_device(device)
+    }
+    /** The index and value of the maximum.
+
+    Ties resolve to the lowest index. If there are NaNs, returns the first NaN, like NumPy.
+
+    There is no reduction kernel yet. Device buffers are read back and reduced on the host,
+    which waits for pending work on the device.
+
+    # Errors
+    - The slice is empty.
+    - [`DeviceLost`] */
+    pub fn argmax(&self) -> Result<(usize, T)> {
+        self.arg_impl(std::cmp::Ordering::Greater)
+    }
+    /** The index and value of the minimum.
+
+    See [`.argmax()`](BufferBase::argmax). */
+    pub fn argmin(&self) -> Result<(usize, T)> {
+        self.arg_impl(std::cmp::Ordering::Less)
+    }
+    fn arg_impl(&self, ordering: std::cmp::Ordering) -> Result<(usize, T)> {
+        let output = if let Some(x) = self.as_host_slice() {
+            arg_host(x, ordering)
+        } else {
+            arg_host(&self.to_vec()?, ordering)
+        };
+        if let Some(output) = output {
+            Ok(output)
+        } else if ordering == std::cmp::Ordering::Greater {
+            bail!("Can not compute argmax of an empty slice!");
+        } else {
+            bail!("Can not compute argmin of an empty slice!");
+        }
+    }
+    /** Calls `f` with the index and value of each element on the host.
+
+    Useful for checking the output of a kernel against a reference implementation.
+
+    Device buffers are copied to the host first, which waits for previous work and
+    transfers the whole slice, so this is slow.
+
+    # Errors
+    - [`DeviceLost`] */
+    pub fn for_each_host(&self, mut f: impl FnMut(usize, &T)) -> Result<()> {
+        if let Some(x) = self.as_host_slice() {
+            x.iter().enumerate().for_each(|(i, x)| f(i, x));
+        } else {
+            self.to_vec()?.iter().enumerate().for_each(|(i, x)| f(i, x));
+        }
+        Ok(())
+    }
+    /** Calls `f` with the index and a mutable reference to each element on the host.
+
+    Device buffers are copied to the host, modified, and then copied back, so this is
+    slow. See [`.for_each_host()`](BufferBase::for_each_host).
+
+    # Errors
+    - [`DeviceLost`]
+    - The modified elements could not be copied back to the device. */
+    pub fn modify_host(&mut self, mut f: impl FnMut(usize, &mut T)) -> Result<()>
+    where
+        S: DataMut,
+    {
+        if let Some(x) = self.as_host_slice_mut() {
+            x.iter_mut().enumerate().for_each(|(i, x)| f(i, x));
+            return Ok(());
+        }
+        let mut x = self.to_vec()?;
+        x.iter_mut().enumerate().for_each(|(i, x)| f(i, x));
+        self.copy_from_slice(&Slice::from(x.as_slice()))
+    }
     /** Casts to `Y` as an arc buffer.
 
     See [`.cast()`](BufferBase::cast). */
@@ -2439,6 +3408,28 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     {
         self.data.as_slice_mut().copy_from_slice(&src.data)
     }
+    /** Copies from `src` into `self[offset..offset + src.len()]`.
+
+    # Errors
+    - `offset + src.len()` is greater than the length.
+    - [`DeviceLost`]
+    - `src` could not be copied.
+    */
+    pub fn copy_into_at(&mut self, offset: usize, src: &Slice<T>) -> Result<()>
+    where
+        S: DataMut,
+    {
+        let len = self.len();
+        let end = if let Some(end) = offset.checked_add(src.len()).filter(|end| *end <= len) {
+            end
+        } else {
+            bail!(
+                "source slice length ({}) at offset {offset} is out of bounds of destination slice length ({len})",
+                src.len(),
+            );
+        };
+        self.slice_mut(offset..end).unwrap().copy_from_slice(src)
+    }
     /** A subslice with `range`.
 
     Returns None if range is out of bounds.
@@ -2460,6 +3451,78 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
         let data = self.data.as_slice_mut().slice(range)?;
         Some(SliceMut { data })
     }
+    /** Iterates over subslices of `chunk_len` elements.
+
+    The last chunk is shorter if `chunk_len` does not divide the length. Each chunk is a view of
+    the same buffer, and can be passed to a kernel.
+
+    See [`<[_]>::chunks()`](https://doc.rust-lang.org/std/primitive.slice.html#method.chunks).
+
+    # Errors
+    - `chunk_len` is 0.
+    */
+    pub fn chunks(&self, chunk_len: usize) -> Result<impl Iterator<Item = Slice<'_, T>> + '_> {
+        if chunk_len == 0 {
+            bail!("chunk_len must not be 0!");
+        }
+        let len = self.len();
+        Ok((0..len)
+            .step_by(chunk_len)
+            .map(move |start| self.slice(start..(start + chunk_len).min(len)).unwrap()))
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
+    /** Panics if not equal to `expected`.
+
+    Device buffers are downloaded. The message includes the first differing index and values.
+
+    # Panics
+    - The lengths differ.
+    - Any element differs.
+    - The buffer could not be downloaded. */
+    #[track_caller]
+    pub fn assert_eq(&self, expected: &[T]) {
+        self.assert_impl(expected, |x, y| x == y);
+    }
+    /** Panics if not within `epsilon` of `expected`.
+
+    Elements are close if `|x - y| <= epsilon`, or if both are NaN.
+
+    See [`.assert_eq()`](BufferBase::assert_eq). */
+    #[track_caller]
+    pub fn assert_close(&self, expected: &[T], epsilon: T) {
+        self.assert_impl(expected, |x, y| {
+            let nan = x.partial_cmp(&x).is_none() && y.partial_cmp(&y).is_none();
+            let diff = if x > y { x - y } else { y - x };
+            nan || diff <= epsilon
+        });
+    }
+    #[track_caller]
+    fn assert_impl(&self, expected: &[T], f: impl Fn(T, T) -> bool) {
+        let len = self.len();
+        let expected_len = expected.len();
+        if len != expected_len {
+            panic!("assertion failed: length {len} does not match expected length {expected_len}");
+        }
+        let vec;
+        let actual = if let Some(host_slice) = self.as_host_slice() {
+            host_slice
+        } else {
+            vec = self.to_vec().unwrap();
+            vec.as_slice()
+        };
+        let mut mismatches = actual
+            .iter()
+            .zip(expected)
+            .enumerate()
+            .filter(|(_, (x, y))| !f(**x, **y));
+        if let Some((index, (x, y))) = mismatches.next() {
+            let count = 1 + mismatches.count();
+            panic!("assertion failed: {count} of {len} elements differ, first at index {index}: {x} != {y} (expected)");
+        }
+    }
 }
 
 impl<T: Scalar> Slice<'_, T> {
@@ -2575,7 +3638,7 @@ impl<'de, T: Scalar, S: DataOwned<Elem = T>> Deserialize<'de> for BufferBase<S>
 #[cfg(feature = "device")]
 #[module]
 #[krnl(crate=crate)]
-mod kernels {
+pub(crate) mod kernels {
     use dry::macro_for;
     #[cfg(not(target_arch = "spirv"))]
     use krnl_core;
@@ -2676,3 +3739,40 @@ mod tests {
         assert_eq!(x_vec, y_vec);
     }
 }
+
+#[cfg(all(test, feature = "test-utils"))]
+mod test_utils_tests {
+    use super::*;
+
+    #[test]
+    fn buffer_assert_eq() {
+        let x = Buffer::from(vec![1u32, 2, 3]);
+        x.assert_eq(&[1, 2, 3]);
+        x.slice(1..).unwrap().assert_eq(&[2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "2 of 3 elements differ, first at index 1: 2 != 4 (expected)")]
+    fn buffer_assert_eq_mismatch() {
+        Buffer::from(vec![1u32, 2, 3]).assert_eq(&[1, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "length 3 does not match expected length 2")]
+    fn buffer_assert_eq_len() {
+        Buffer::from(vec![1u32, 2, 3]).assert_eq(&[1, 2]);
+    }
+
+    #[test]
+    fn buffer_assert_close() {
+        let x = Buffer::from(vec![1f32, 2.0001, f32::NAN]);
+        x.assert_close(&[1.0001, 2., f32::NAN], 0.001);
+        Buffer::from(vec![1u8, 3]).assert_close(&[2, 2], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "1 of 2 elements differ, first at index 1: 2.1 != 2 (expected)")]
+    fn buffer_assert_close_mismatch() {
+        Buffer::from(vec![1f32, 2.1]).assert_close(&[1., 2.], 0.001);
+    }
+}
@@ -13,17 +13,52 @@ dbg!(device.info());
 # Ok(())
 # }
 ```
+
+# Synchronization
+Synchronization is implicit, there are no barriers or fences to manage. Work is recorded into
+batches which are submitted to the device by a background thread. Operations do not wait for
+the device to finish, only for the work they depend on:
+- A kernel dispatch or a copy between buffers waits until previous work that wrote to any of
+its buffers has been submitted, so that it is recorded into a later batch.
+- Independent work is recorded into the same batch without waiting.
+- Reading a buffer back to the host waits for the work that last wrote it to finish.
+
+Use [`Device::wait()`](crate::device::Device::wait) to wait for all previous work to finish, ie for benchmarking.
+
+Devices, buffers, and kernels are [`Send`] and [`Sync`], and can be used from multiple threads.
+Recording and submitting work is serialized internally.
 */
 
+use crate::{
+    buffer::{BufferBase, Data, ScalarSlice, ScratchBuffer},
+    kernel::__private::KernelBuilder,
+    scalar::ScalarType,
+};
 #[cfg(feature = "device")]
-use crate::kernel::{KernelDesc, KernelKey};
-use anyhow::Result;
+use crate::{
+    buffer::{BufferUsage, MemoryKind},
+    kernel::{KernelDesc, KernelKey},
+};
+use anyhow::{bail, Result};
+#[cfg(feature = "device")]
+use parking_lot::{ReentrantMutex, ReentrantMutexGuard};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(not(target_family = "wasm"))]
+use std::time::{Duration, Instant};
 use std::{
     fmt::{self, Debug},
-    sync::Arc,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 #[cfg(feature = "device")]
-use std::{ops::Range, sync::atomic::AtomicBool};
+use std::{
+    ops::{Deref, DerefMut, Range},
+    sync::{atomic::AtomicBool, Weak},
+};
 
 #[cfg(all(not(target_family = "wasm"), feature = "device"))]
 mod vulkan_engine;
@@ -66,6 +101,19 @@ pub mod error {
         pub(super) devices: usize,
     }
 
+    /// No device has the uuid passed to [`DeviceBuilder::uuid()`](super::builder::DeviceBuilder::uuid).
+    #[cfg(any(doc, feature = "device"))]
+    #[derive(Clone, Copy, Debug, thiserror::Error)]
+    #[cfg_attr(
+        feature = "device",
+        error("No device with uuid {}!", super::uuid_string(.uuid))
+    )]
+    #[cfg_attr(not(feature = "device"), error("unreachable!"))]
+    pub struct DeviceUuidNotFound {
+        #[cfg(feature = "device")]
+        pub(super) uuid: [u8; 16],
+    }
+
     /// The Device was lost.
     #[derive(Clone, Copy, Debug, thiserror::Error)]
     pub struct DeviceLost(
@@ -107,12 +155,142 @@ pub mod builder {
                 self
             }
         }
+        /** Batches of work that can be submitted before waiting for the oldest to finish.
+
+        Work is recorded into a batch while previous batches execute. With more than 1,
+        the next batch is submitted without waiting for the previous one on the host, so
+        that the device does not idle between batches. Batches still execute in order.
+
+        Once the limit is reached, dispatches and transfers block when the batch being
+        recorded is full, bounding memory and latency.
+
+        Defaults to 2, values less than 1 are treated as 1. See [`Device::in_flight()`]. */
+        pub fn max_in_flight(self, max_in_flight: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.max_in_flight = max_in_flight;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = max_in_flight;
+                self
+            }
+        }
+        /** Keep debug names and source info in kernels.
+
+        By default, `OpName`s, `OpLine`s, and source info are stripped from the SPIR-V
+        passed to the driver. Keeping them makes captures in RenderDoc and validation
+        messages readable, at the cost of slightly more memory and compile time per kernel.
+
+        Defaults to false. */
+        pub fn keep_debug_info(self, keep_debug_info: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.keep_debug_info = keep_debug_info;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = keep_debug_info;
+                self
+            }
+        }
+        /** Zero buffers allocated with `uninit`.
+
+        Uninitialized buffers contain whatever was previously in the memory, so a kernel that
+        reads before writing produces nondeterministic results. When enabled, each
+        allocation is zeroed on the device with `vkCmdFillBuffer` before it is used, which
+        makes such bugs reproducible. Intended for debugging, this costs an extra fill
+        per allocation, roughly the bandwidth of writing the buffer once.
+
+        Defaults to false. */
+        pub fn zero_uninit(self, zero_uninit: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.zero_uninit = zero_uninit;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = zero_uninit;
+                self
+            }
+        }
+        /** Prefer devices of `device_type`.
+
+        Devices of this type are enumerated first, followed by the rest in their original
+        order, such that [`.index()`](Self::index) selects from the reordered devices. For
+        example, `.prefer(DeviceType::Integrated).index(0)` selects the first integrated
+        device if there is one. [`DeviceInfo::index()`] is not reordered.
+
+        Defaults to the order reported by the driver. */
+        pub fn prefer(self, device_type: DeviceType) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.prefer = Some(device_type);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = device_type;
+                self
+            }
+        }
+        /** Select the device with `uuid`, see [`DeviceInfo::uuid()`].
+
+        Unlike indices, which depend on the order devices are enumerated, the uuid is stable
+        across reboots, and distinguishes identical devices. Overrides
+        [`.index()`](Self::index) and [`.prefer()`](Self::prefer). */
+        pub fn uuid(self, uuid: [u8; 16]) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.uuid = Some(uuid);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = uuid;
+                self
+            }
+        }
+        /** Share the device with other handles built with `shared`.
+
+        Devices are created with their own queues, pools, and caches. When shared, building
+        returns the existing device with the same [`.index()`](Self::index) and
+        [`.prefer()`](Self::prefer) (or [`.uuid()`](Self::uuid)), if one is alive, instead of creating a new one. Such
+        handles compare equal, and buffers and kernels can be used with either.
+
+        The device is reference counted, and is dropped once all handles and the buffers
+        and kernels using it are dropped. A later shared build will create a new device.
+        Other options are only applied when the device is created.
+
+        Defaults to false. */
+        pub fn shared(self, shared: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.shared = shared;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = shared;
+                self
+            }
+        }
         /// Creates a device.
         ///
         /// # Errors
         ///
         /// - [DeviceUnavailable]
         /// - [DeviceIndexOutOfRange]
+        /// - [DeviceUuidNotFound]
         /// - The device could not be created.
         pub fn build(self) -> Result<Device> {
             #[cfg(feature = "device")]
@@ -136,28 +314,71 @@ trait DeviceEngine {
     type DeviceBuffer: DeviceEngineBuffer<Engine = Self>;
     type Kernel: DeviceEngineKernel<Engine = Self, DeviceBuffer = Self::DeviceBuffer>;
     fn new(options: DeviceOptions) -> Result<Arc<Self>>;
+    fn device_infos() -> Result<Vec<DeviceInfo>>;
     fn id(&self) -> DeviceId;
     fn info(&self) -> &Arc<DeviceInfo>;
     fn wait(&self) -> Result<(), DeviceLost>;
+    fn flush(&self) -> Result<(), DeviceLost>;
+    fn cancel_pending(&self) -> Result<(), DeviceLost>;
+    fn cancels(&self) -> u64;
+    fn check_canceled(&self, cancels: u64) -> Result<()>;
+    fn submit_lock(&self) -> &ReentrantMutex<()>;
+    fn scratch_high_water_mark(&self) -> usize;
+    fn in_flight(&self) -> Result<usize, DeviceLost>;
+    fn cached_kernels(&self) -> Vec<KernelCacheEntry>;
+    fn allocated_bytes(&self) -> u64;
+    fn finished_epoch(&self) -> Result<u64, DeviceLost>;
+    fn wait_for_epoch(&self, epoch: u64) -> Result<(), DeviceLost>;
 }
 
 #[cfg(feature = "device")]
 struct DeviceOptions {
     index: usize,
     optimal_features: Features,
+    max_in_flight: usize,
+    keep_debug_info: bool,
+    zero_uninit: bool,
+    prefer: Option<DeviceType>,
+    uuid: Option<[u8; 16]>,
+    shared: bool,
+}
+
+// Indices of devices, with those of type `prefer` first.
+#[cfg(feature = "device")]
+fn preferred_device_order(device_types: &[DeviceType], prefer: Option<DeviceType>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..device_types.len()).collect();
+    if let Some(prefer) = prefer {
+        order.sort_by_key(|i| device_types[*i] != prefer);
+    }
+    order
 }
 
 #[cfg(feature = "device")]
 trait DeviceEngineBuffer: Sized {
     type Engine;
-    unsafe fn uninit(engine: Arc<Self::Engine>, len: usize) -> Result<Self>;
+    type Map: DerefMut<Target = [u8]>;
+    unsafe fn uninit(
+        engine: Arc<Self::Engine>,
+        len: usize,
+        usage: BufferUsage,
+        memory: MemoryKind,
+    ) -> Result<Self>;
+    unsafe fn scratch(engine: Arc<Self::Engine>, len: usize) -> Result<Self>;
+    fn recycle(&self);
     fn upload(&self, data: &[u8]) -> Result<()>;
     fn download(&self, data: &mut [u8]) -> Result<()>;
+    fn wait(&self) -> Result<(), DeviceLost>;
+    fn is_finished(&self) -> Result<bool, DeviceLost>;
+    fn host_visible(&self) -> bool;
+    fn map(&self, mutable: bool) -> Result<Self::Map>;
     fn transfer(&self, dst: &Self) -> Result<()>;
+    fn copy(&self, dst: &Self) -> Result<()>;
     fn engine(&self) -> &Arc<Self::Engine>;
     fn offset(&self) -> usize;
     fn len(&self) -> usize;
     fn slice(self: &Arc<Self>, range: Range<usize>) -> Option<Arc<Self>>;
+    fn overlaps(&self, other: &Self) -> bool;
+    fn raw_handle(&self) -> Option<(u64, u64, u64)>;
 }
 
 #[cfg(feature = "device")]
@@ -169,14 +390,15 @@ trait DeviceEngineKernel: Sized {
         key: KernelKey,
         desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
     ) -> Result<Arc<Self>>;
+    fn get_cached(engine: &Arc<Self::Engine>, key: &KernelKey) -> Option<Arc<Self>>;
     unsafe fn dispatch(
         &self,
         groups: u32,
         buffers: &[Arc<Self::DeviceBuffer>],
         push_consts: Vec<u8>,
+        fence: Option<&Fence>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()>;
-    fn engine(&self) -> &Arc<Self::Engine>;
     fn desc(&self) -> &Arc<KernelDesc>;
 }
 
@@ -214,9 +436,33 @@ impl Device {
             options: DeviceOptions {
                 index: 0,
                 optimal_features: Features::all(),
+                max_in_flight: 2,
+                keep_debug_info: false,
+                zero_uninit: false,
+                prefer: None,
+                uuid: None,
+                shared: false,
             },
         }
     }
+    /** Infos of the available devices, without creating them.
+
+    Devices are enumerated in the order used by [`DeviceBuilder::index()`] (without
+    [`.prefer()`](DeviceBuilder::prefer)). Querying info is cheap, use
+    [`DeviceInfo::build()`] to create the chosen device.
+
+    Yields a single [`DeviceUnavailable`] error if devices can not be enumerated, ie
+    without the "device" feature. */
+    pub fn iter() -> impl Iterator<Item = Result<DeviceInfo>> {
+        #[cfg(feature = "device")]
+        let infos = match Engine::device_infos() {
+            Ok(infos) => infos.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        };
+        #[cfg(not(feature = "device"))]
+        let infos = vec![Err(DeviceUnavailable.into())];
+        infos.into_iter()
+    }
     /// Is the host.
     pub fn is_host(&self) -> bool {
         self.inner.is_host()
@@ -238,6 +484,41 @@ impl Device {
             DeviceInner::Device(raw) => Some(raw.info()),
         }
     }
+    /** A summary of the device for bug reports.
+
+    Includes the name, index, vendor and device ids, api version, features, limits, and
+    [allocated bytes](Device::allocated_bytes). This reads cached [info](Device::info) and
+    does not query the device.
+
+    The host returns "Host". */
+    pub fn report(&self) -> String {
+        match self.info() {
+            Some(info) => format!(
+                "{}\nallocated_bytes: {}",
+                info.report(),
+                self.allocated_bytes()
+            ),
+            None => "Host".to_string(),
+        }
+    }
+    /** Asserts that `buffer` is on this device.
+
+    Use to catch buffers passed to the wrong device earlier than when a kernel is
+    dispatched. Like [`debug_assert!`], this only checks when `debug_assertions` are
+    enabled.
+
+    # Panics
+    - `buffer` is on a different device. */
+    #[track_caller]
+    pub fn assert_owns<S: Data>(&self, buffer: &BufferBase<S>) {
+        if cfg!(debug_assertions) {
+            let buffer_device = buffer.device();
+            assert!(
+                buffer_device == *self,
+                "assertion failed: buffer is on {buffer_device:?}, expected {self:?}"
+            );
+        }
+    }
     /** Wait for previous work to finish.
 
     If host, this does nothing.
@@ -253,6 +534,389 @@ impl Device {
             DeviceInner::Device(raw) => raw.wait(),
         }
     }
+    /** Time `f`, including the device work it issues.
+
+    Runs `f`, then [waits](Device::wait) for the device to finish, returning the elapsed
+    wall-clock time.
+
+    Work is not tracked per closure, so this drains the device: work issued before `f`,
+    or concurrently by other threads, is also waited on. Call [`.wait()`](Device::wait)
+    beforehand to exclude previous work.
+
+    Not available on wasm, which has no [`Instant`].
+
+    # Errors
+    - `f` failed.
+    - [`DeviceLost`] */
+    #[cfg(not(target_family = "wasm"))]
+    pub fn time(&self, f: impl FnOnce() -> Result<()>) -> Result<Duration> {
+        let start = Instant::now();
+        f()?;
+        self.wait()?;
+        Ok(start.elapsed())
+    }
+    /** Submit previous work without waiting for it to finish.
+
+    If host, this does nothing.
+
+    Work is submitted automatically, as soon as the previously submitted batch finishes.
+    This blocks until previous work has been submitted, but not until it finishes, so that
+    the device can execute it while the host continues.
+
+    Work recorded after this method returns is submitted in a later batch, which will not
+    start until the previous work has finished.
+
+    # Errors
+    - [`DeviceLost`] */
+    pub fn flush(&self) -> Result<(), DeviceLost> {
+        match self.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.flush(),
+        }
+    }
+    /** Cancel queued work and wait for executing work to finish.
+
+    If host, this does nothing.
+
+    Work is batched before it is submitted to the device. Vulkan can not abort
+    submitted work, so only part of the pending work can be canceled:
+    - Kernel dispatches and transfers that are queued but not yet submitted are discarded.
+    - Work that has been submitted (ie is executing) runs to completion.
+
+    Buffers written by canceled work have unspecified contents. Uploads and downloads (and
+    copies between devices) that are staged through the host and in progress on other
+    threads return an error if any work is discarded, instead of returning stale or partial
+    data. This is conservative, they may fail even if their own transfers were already
+    submitted. Uploads that returned before this is called are not checked, like dispatches.
+
+    # Errors
+    - [`DeviceLost`] */
+    pub fn cancel_pending(&self) -> Result<(), DeviceLost> {
+        match self.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.cancel_pending(),
+        }
+    }
+    /** A transient buffer of `len_bytes` from the device's scratch pool.
+
+    Use for temporary storage, like the intermediate results of a reduction, to avoid
+    allocating a new buffer for each call. When the [`ScratchBuffer`] is dropped, it is
+    returned to the pool, and reused once the work recorded before the drop has finished.
+    Up to 16 buffers are kept in the pool until the device is dropped.
+
+    The contents are unspecified. If host, this allocates a new zeroed buffer.
+
+    # Errors
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`](crate::buffer::error::DeviceBufferTooLarge)
+    - [`OutOfDeviceMemory`](crate::buffer::error::OutOfDeviceMemory) */
+    pub fn scratch(&self, len_bytes: usize) -> Result<ScratchBuffer> {
+        ScratchBuffer::new(self.clone(), len_bytes)
+    }
+    /** Builds and caches `kernels` ahead of time.
+
+    Kernels are compiled on the first [`.build()`](crate::kernel#kernelbuilder), which can
+    cause a hitch. Precompile them at startup instead, ie behind a loading screen, so that
+    subsequent builds with the same threads and spec constants are fast.
+
+    Kernels are built in parallel, this blocks until all of them are finished.
+
+    If host, this does nothing.
+
+    ```no_run
+    # use krnl::{anyhow::Result, device::Device};
+    # #[krnl::macros::module]
+    # #[krnl(no_build)]
+    # mod kernels {
+    # use krnl::macros::kernel;
+    # #[kernel] pub fn foo() {}
+    # #[kernel] pub fn bar() {}
+    # }
+    # use kernels::{foo, bar};
+    # fn main() -> Result<()> {
+    let device = Device::builder().build()?;
+    device.precompile(&[&foo::builder()?, &bar::builder()?.with_threads(128)])?;
+    # Ok(())
+    # }
+    ```
+
+    # Errors
+    - The first kernel, in order, that failed to build.
+        - See [`.build()`](crate::kernel#kernelbuilder). */
+    pub fn precompile(&self, kernels: &[&(dyn AsRef<KernelBuilder> + Sync)]) -> Result<()> {
+        if self.is_host() {
+            return Ok(());
+        }
+        let parallelism = std::thread::available_parallelism().map_or(1, |x| x.get());
+        for chunk in kernels.chunks(parallelism) {
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .iter()
+                    .map(|kernel| {
+                        let device = self.clone();
+                        scope.spawn(move || kernel.as_ref().build(device).map(|_| ()))
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .try_for_each(|handle| handle.join().unwrap())
+            })?;
+        }
+        Ok(())
+    }
+    /** Downloads several slices with one submission.
+
+    Device slices are copied into a single staging buffer, which is waited on once, instead
+    of once per slice with [`.to_vec()`](BufferBase::to_vec). Host slices are copied directly.
+
+    # Errors
+    - A slice is not on the host or this device.
+    - The length of the bytes does not match the size of the slice in bytes.
+    - Pending work was discarded by [`.cancel_pending()`](Device::cancel_pending).
+    - [`DeviceLost`]
+    */
+    pub fn download_many(&self, downloads: &mut [(ScalarSlice, &mut [u8])]) -> Result<()> {
+        for (i, (slice, bytes)) in downloads.iter().enumerate() {
+            let device = slice.device();
+            if !device.is_host() && device != *self {
+                bail!("Download {i} slice is on {device:?}, expected {self:?}!");
+            }
+            let size = slice.len() * slice.scalar_type().size();
+            if bytes.len() != size {
+                bail!(
+                    "Download {i} slice is {size} bytes, found {} bytes!",
+                    bytes.len()
+                );
+            }
+        }
+        #[cfg(feature = "device")]
+        if let DeviceInner::Device(raw) = self.inner() {
+            let size = downloads
+                .iter()
+                .filter_map(|(slice, _)| slice.device_buffer())
+                .map(|x| x.len())
+                .sum();
+            if size > 0 {
+                let cancels = raw.cancels();
+                let staging = unsafe {
+                    DeviceBuffer::uninit(
+                        raw.clone(),
+                        size,
+                        BufferUsage::empty(),
+                        MemoryKind::HostVisible,
+                    )?
+                };
+                let mut offset = 0;
+                for (slice, _) in downloads.iter() {
+                    if let Some(buffer) = slice.device_buffer() {
+                        let len = buffer.len();
+                        buffer.copy(&staging.slice(offset..offset + len).unwrap())?;
+                        offset += len;
+                    }
+                }
+                let map = staging.map(false)?;
+                raw.check_canceled(cancels)?;
+                let mut offset = 0;
+                for (slice, bytes) in downloads.iter_mut() {
+                    if slice.device_buffer().is_some() {
+                        bytes.copy_from_slice(&map[offset..offset + bytes.len()]);
+                        offset += bytes.len();
+                    }
+                }
+            }
+        }
+        for (slice, bytes) in downloads.iter_mut() {
+            if let Some(host_bytes) = slice.as_host_bytes() {
+                bytes.copy_from_slice(host_bytes);
+            }
+        }
+        Ok(())
+    }
+    /** The number of batches submitted that have not finished.
+
+    At most [`max_in_flight`](DeviceBuilder::max_in_flight). If host, this is 0.
+
+    # Errors
+    - [`DeviceLost`] */
+    pub fn in_flight(&self) -> Result<usize, DeviceLost> {
+        match self.inner() {
+            DeviceInner::Host => Ok(0),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.in_flight(),
+        }
+    }
+    /** The peak number of bytes allocated for [scratch buffers](Device::scratch).
+
+    Includes buffers in use and buffers in the pool. If host, this is 0. */
+    pub fn scratch_high_water_mark(&self) -> usize {
+        match self.inner() {
+            DeviceInner::Host => 0,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.scratch_high_water_mark(),
+        }
+    }
+    /** Bytes of device memory allocated by krnl for live buffers.
+
+    Includes buffers that are still referenced by pending work, and [scratch buffers](Device::scratch)
+    kept in the pool. Allocations are aligned, so this may be larger than the sum of the
+    buffer sizes. Excludes internal staging buffers and kernels. If host, this is 0. */
+    pub fn allocated_bytes(&self) -> u64 {
+        match self.inner() {
+            DeviceInner::Host => 0,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.allocated_bytes(),
+        }
+    }
+    /** Kernels cached on the device.
+
+    Each kernel is cached when first built for a set of spec constants and threads, and is
+    kept until the device is dropped. If host, this is empty. */
+    pub fn cached_kernels(&self) -> Vec<KernelCacheEntry> {
+        match self.inner() {
+            DeviceInner::Host => Vec::new(),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.cached_kernels(),
+        }
+    }
+    /** Creates a [`Fence`] for [dispatching](crate::kernel#kernel) with.
+
+    The fence is created unsignaled. */
+    pub fn create_fence(&self) -> Fence {
+        Fence {
+            device: self.clone(),
+            epoch: AtomicU64::default(),
+        }
+    }
+    /** Lock the queue for exclusive submission.
+
+    Kernel dispatches and transfers (including uploads and downloads) recorded by
+    other threads will block until the guard is dropped, while the thread holding
+    the guard can continue to submit work. This ensures that a dependent sequence
+    of operations is not interleaved with work from other threads.
+
+    The lock is reentrant, calling this method again on the same thread will not deadlock.
+
+    Each device has a single compute queue. Locking it does not prevent other devices
+    from progressing, and does not wait for previously submitted work to finish.
+
+    If host, this does nothing.
+
+    ```no_run
+    # use krnl::{anyhow::Result, device::Device};
+    # fn main() -> Result<()> {
+    let device = Device::builder().build()?;
+    let _guard = device.lock_queue();
+    // record a dependent sequence of dispatches and copies
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn lock_queue(&self) -> QueueGuard<'_> {
+        match self.inner() {
+            DeviceInner::Host => QueueGuard {
+                #[cfg(feature = "device")]
+                _guard: None,
+                _marker: PhantomData,
+            },
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => QueueGuard {
+                _guard: Some(raw.lock_queue()),
+                _marker: PhantomData,
+            },
+        }
+    }
+}
+
+/** Exclusive submission guard.
+
+The queue is unlocked when the guard is dropped.
+
+See [`Device::lock_queue()`]. */
+#[must_use = "the queue is unlocked when the guard is dropped"]
+pub struct QueueGuard<'a> {
+    #[cfg(feature = "device")]
+    _guard: Option<ReentrantMutexGuard<'a, ()>>,
+    _marker: PhantomData<&'a Device>,
+}
+
+impl Debug for QueueGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueueGuard").finish_non_exhaustive()
+    }
+}
+
+/** A fence signaled when a dispatch completes.
+
+Created with [`Device::create_fence()`], and passed to a kernel's `.dispatch_with_fence()`.
+
+Work is recorded into batches, which are submitted in order and signal increasing values of
+a timeline semaphore. The fence stores the value of the batch the dispatch is recorded into,
+and is signaled when that batch finishes, along with all previously recorded work. Work
+recorded concurrently by other threads may land in the same batch, and is also finished
+when the fence is signaled. Waiting on a fence does not wait on later batches, unlike
+[`Device::wait()`].
+
+The batch is in flight (see [`Device::in_flight()`]) until it finishes. If the batch is
+discarded by [`Device::cancel_pending()`] before it is submitted, the fence is still
+signaled, but the dispatch does not execute.
+
+Dispatching again with the same fence replaces the previous dispatch, and the fence
+tracks the latest one.
+
+If host, the fence is signaled immediately. */
+#[derive(Debug)]
+pub struct Fence {
+    device: Device,
+    // The epoch of the batch containing the dispatch, 0 if not dispatched.
+    epoch: AtomicU64,
+}
+
+impl Fence {
+    /// The device.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+    /** Whether the dispatch has completed.
+
+    Returns false if the fence has not been dispatched with, or has been [reset](Fence::reset).
+
+    # Errors
+    - [`DeviceLost`] */
+    pub fn is_signaled(&self) -> Result<bool, DeviceLost> {
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        if epoch == 0 {
+            return Ok(false);
+        }
+        match self.device.inner() {
+            DeviceInner::Host => Ok(true),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => Ok(raw.finished_epoch()? >= epoch),
+        }
+    }
+    /** Blocks until the dispatch has completed.
+
+    # Errors
+    - The fence has not been dispatched with, or has been [reset](Fence::reset).
+    - [`DeviceLost`] */
+    pub fn wait(&self) -> Result<()> {
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        if epoch == 0 {
+            bail!("Fence has not been dispatched with!");
+        }
+        match self.device.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => Ok(raw.wait_for_epoch(epoch)?),
+        }
+    }
+    /** Resets the fence to unsignaled.
+
+    Does not affect the dispatch, which still completes. */
+    pub fn reset(&self) {
+        self.epoch.store(0, Ordering::SeqCst);
+    }
 }
 
 /// See [`Device::host()`].
@@ -316,7 +980,26 @@ pub(crate) struct RawDevice {
 #[cfg(feature = "device")]
 impl RawDevice {
     fn new(options: DeviceOptions) -> Result<Self> {
+        type SharedEngines = Vec<((usize, Option<DeviceType>, Option<[u8; 16]>), Weak<Engine>)>;
+        static SHARED_ENGINES: parking_lot::Mutex<SharedEngines> =
+            parking_lot::const_mutex(Vec::new());
+
+        if !options.shared {
+            let engine = Engine::new(options)?;
+            return Ok(Self { engine });
+        }
+        let key = (options.index, options.prefer, options.uuid);
+        let mut shared_engines = SHARED_ENGINES.lock();
+        shared_engines.retain(|(_, engine)| engine.strong_count() > 0);
+        if let Some(engine) = shared_engines
+            .iter()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, engine)| engine.upgrade())
+        {
+            return Ok(Self { engine });
+        }
         let engine = Engine::new(options)?;
+        shared_engines.push((key, Arc::downgrade(&engine)));
         Ok(Self { engine })
     }
     pub(crate) fn info(&self) -> &Arc<DeviceInfo> {
@@ -325,6 +1008,39 @@ impl RawDevice {
     pub(crate) fn wait(&self) -> Result<(), DeviceLost> {
         self.engine.wait()
     }
+    pub(crate) fn flush(&self) -> Result<(), DeviceLost> {
+        self.engine.flush()
+    }
+    pub(crate) fn cancel_pending(&self) -> Result<(), DeviceLost> {
+        self.engine.cancel_pending()
+    }
+    fn cancels(&self) -> u64 {
+        self.engine.cancels()
+    }
+    fn check_canceled(&self, cancels: u64) -> Result<()> {
+        self.engine.check_canceled(cancels)
+    }
+    fn lock_queue(&self) -> ReentrantMutexGuard<'_, ()> {
+        self.engine.submit_lock().lock()
+    }
+    fn scratch_high_water_mark(&self) -> usize {
+        self.engine.scratch_high_water_mark()
+    }
+    fn in_flight(&self) -> Result<usize, DeviceLost> {
+        self.engine.in_flight()
+    }
+    fn cached_kernels(&self) -> Vec<KernelCacheEntry> {
+        self.engine.cached_kernels()
+    }
+    fn allocated_bytes(&self) -> u64 {
+        self.engine.allocated_bytes()
+    }
+    fn finished_epoch(&self) -> Result<u64, DeviceLost> {
+        self.engine.finished_epoch()
+    }
+    fn wait_for_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.engine.wait_for_epoch(epoch)
+    }
 }
 
 #[cfg(feature = "device")]
@@ -365,6 +1081,26 @@ pub(crate) struct DeviceBuffer {
     inner: Arc<<Engine as DeviceEngine>::DeviceBuffer>,
 }
 
+#[cfg(feature = "device")]
+pub(crate) struct DeviceBufferMap {
+    inner: <<Engine as DeviceEngine>::DeviceBuffer as DeviceEngineBuffer>::Map,
+}
+
+#[cfg(feature = "device")]
+impl Deref for DeviceBufferMap {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "device")]
+impl DerefMut for DeviceBufferMap {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.inner
+    }
+}
+
 #[cfg(feature = "device")]
 fn cast_device_buffers(buffers: &[DeviceBuffer]) -> &[Arc<<Engine as DeviceEngine>::DeviceBuffer>] {
     // # Safety
@@ -375,29 +1111,69 @@ fn cast_device_buffers(buffers: &[DeviceBuffer]) -> &[Arc<<Engine as DeviceEngin
 #[cfg(feature = "device")]
 impl DeviceBuffer {
     const MAX_SIZE: usize = i32::MAX as usize;
-    pub(crate) unsafe fn uninit(device: RawDevice, len: usize) -> Result<Self> {
+    pub(crate) unsafe fn uninit(
+        device: RawDevice,
+        len: usize,
+        usage: BufferUsage,
+        memory: MemoryKind,
+    ) -> Result<Self> {
+        if len > Self::MAX_SIZE {
+            return Err(DeviceBufferTooLarge { bytes: len }.into());
+        }
+        let inner = unsafe {
+            <Engine as DeviceEngine>::DeviceBuffer::uninit(device.engine, len, usage, memory)?
+                .into()
+        };
+        Ok(Self { inner })
+    }
+    pub(crate) unsafe fn scratch(device: RawDevice, len: usize) -> Result<Self> {
         if len > Self::MAX_SIZE {
             return Err(DeviceBufferTooLarge { bytes: len }.into());
         }
         let inner =
-            unsafe { <Engine as DeviceEngine>::DeviceBuffer::uninit(device.engine, len)?.into() };
+            unsafe { <Engine as DeviceEngine>::DeviceBuffer::scratch(device.engine, len)?.into() };
         Ok(Self { inner })
     }
+    pub(crate) fn recycle(&self) {
+        self.inner.recycle();
+    }
     pub(crate) fn upload(&self, data: &[u8]) -> Result<()> {
         self.inner.upload(data)
     }
     pub(crate) fn download(&self, data: &mut [u8]) -> Result<()> {
         self.inner.download(data)
     }
+    pub(crate) fn wait(&self) -> Result<(), DeviceLost> {
+        self.inner.wait()
+    }
+    pub(crate) fn is_finished(&self) -> Result<bool, DeviceLost> {
+        self.inner.is_finished()
+    }
+    pub(crate) fn host_visible(&self) -> bool {
+        self.inner.host_visible()
+    }
+    pub(crate) fn map(&self, mutable: bool) -> Result<DeviceBufferMap> {
+        let inner = self.inner.map(mutable)?;
+        Ok(DeviceBufferMap { inner })
+    }
     pub(crate) fn transfer(&self, dst: &Self) -> Result<()> {
         self.inner.transfer(&dst.inner)
     }
+    pub(crate) fn copy(&self, dst: &Self) -> Result<()> {
+        self.inner.copy(&dst.inner)
+    }
     pub(crate) fn offset(&self) -> usize {
         self.inner.offset()
     }
     pub(crate) fn len(&self) -> usize {
         self.inner.len()
     }
+    pub(crate) fn overlaps(&self, other: &Self) -> bool {
+        self.inner.overlaps(&other.inner)
+    }
+    pub(crate) fn raw_handle(&self) -> Option<(u64, u64, u64)> {
+        self.inner.raw_handle()
+    }
     pub(crate) fn device(&self) -> RawDevice {
         RawDevice {
             engine: self.inner.engine().clone(),
@@ -454,6 +1230,15 @@ impl Features {
     ///
     /// Float64 capability.
     pub const FLOAT64: Self = Self::new(1 << 4);
+    /// Integer dot product.
+    ///
+    /// VK_KHR_shader_integer_dot_product. Kernels can't declare the DotProduct capabilities
+    /// yet, they are newer than the SPIR-V 1.5 grammar used to parse kernels.
+    pub const INTEGER_DOT_PRODUCT: Self = Self::new(1 << 5);
+    /// Cooperative matrices.
+    ///
+    /// VK_NV_cooperative_matrix, CooperativeMatrixNV capability.
+    pub const COOPERATIVE_MATRIX: Self = Self::new(1 << 6);
     /// 8 bit buffers.
     ///
     /// StorageBuffer8BitAccess capability.
@@ -526,6 +1311,8 @@ impl Features {
             .union(Self::INT64)
             .union(Self::FLOAT16)
             .union(Self::FLOAT64)
+            .union(Self::INTEGER_DOT_PRODUCT)
+            .union(Self::COOPERATIVE_MATRIX)
             .union(Self::BUFFER8)
             .union(Self::BUFFER16)
             .union(Self::PUSH_CONSTANT8)
@@ -549,7 +1336,56 @@ impl Features {
     pub const fn union(self, other: Self) -> Self {
         Self::new(self.bits | other.bits)
     }
-    fn name_iter(&self) -> impl Iterator<Item = &str> {
+    /// Features of `self` that are also in `other`.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::new(self.bits & other.bits)
+    }
+    /// Features of `self` that are not in `other`.
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self::new(self.bits & !other.bits)
+    }
+    /** Parses features from names.
+
+    Names are case sensitive, and are either the names of the constants, ie "INT8", or
+    the deprecated "shader_int8", "shader_int16", "shader_int64", "shader_float16", and
+    "shader_float64".
+
+    # Errors
+    - A name is not a feature.
+
+    ```
+    # use krnl::device::Features;
+    # fn main() -> krnl::anyhow::Result<()> {
+    let features = Features::from_names(&["INT8", "shader_float64"])?;
+    assert_eq!(features, Features::INT8 | Features::FLOAT64);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn from_names(names: &[&str]) -> Result<Self> {
+        let mut output = Self::empty();
+        for name in names {
+            let features = match *name {
+                "shader_int8" => Self::INT8,
+                "shader_int16" => Self::INT16,
+                "shader_int64" => Self::INT64,
+                "shader_float16" => Self::FLOAT16,
+                "shader_float64" => Self::FLOAT64,
+                _ => {
+                    if let Some((_, features)) = Self::names().into_iter().find(|x| x.0 == *name) {
+                        features
+                    } else {
+                        bail!("Unknown feature {name:?}!");
+                    }
+                }
+            };
+            output |= features;
+        }
+        Ok(output)
+    }
+    fn names() -> [(&'static str, Self); 19] {
         macro_rules! features {
             ($($f:ident),*) => {
                 [
@@ -566,6 +1402,8 @@ impl Features {
             INT64,
             FLOAT16,
             FLOAT64,
+            INTEGER_DOT_PRODUCT,
+            COOPERATIVE_MATRIX,
             BUFFER8,
             BUFFER16,
             PUSH_CONSTANT8,
@@ -579,8 +1417,9 @@ impl Features {
             SUBGROUP_CLUSTERED,
             SUBGROUP_QUAD
         )
-        .into_iter()
-        .filter_map(|(name, features)| {
+    }
+    fn name_iter(&self) -> impl Iterator<Item = &str> {
+        Self::names().into_iter().filter_map(|(name, features)| {
             if self.contains(features) {
                 Some(name)
             } else {
@@ -613,6 +1452,29 @@ impl Debug for Features {
     }
 }
 
+/// Serialized as a sequence of names, see [`Features::from_names()`].
+#[cfg(feature = "serde")]
+impl Serialize for Features {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.name_iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Features {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        Self::from_names(&names).map_err(serde::de::Error::custom)
+    }
+}
+
 impl core::ops::BitOr for Features {
     type Output = Self;
     fn bitor(self, rhs: Self) -> Self::Output {
@@ -626,6 +1488,32 @@ impl core::ops::BitOrAssign for Features {
     }
 }
 
+impl core::ops::BitAnd for Features {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl core::ops::BitAndAssign for Features {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl core::ops::Sub for Features {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl core::ops::SubAssign for Features {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
 // deprecated
 #[deprecated(since = "0.1.0")]
 #[doc(hidden)]
@@ -793,21 +1681,100 @@ impl Features {
 
 /// Device info.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[allow(dead_code)]
 pub struct DeviceInfo {
     index: usize,
     name: String,
     device_id: u32,
     vendor_id: u32,
+    driver_version: u32,
+    driver_id: Option<DriverId>,
+    device_type: DeviceType,
+    uuid: [u8; 16],
+    compute_units: Option<u32>,
+    api_version: (u32, u32, u32),
     max_groups: u32,
     max_threads: u32,
     min_subgroup_threads: u32,
     max_subgroup_threads: u32,
+    subgroup_threads: u32,
     features: Features,
+    limits: DeviceLimits,
+    #[cfg_attr(feature = "serde", serde(skip))]
     debug_printf: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    keep_debug_info: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    zero_uninit: bool,
 }
 
 impl DeviceInfo {
+    /** Index of the device.
+
+    The position in [`Device::iter()`], which is the order reported by the driver, even if the
+    device was built with [`DeviceBuilder::prefer()`]. */
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// PCI vendor id.
+    pub fn vendor_id(&self) -> u32 {
+        self.vendor_id
+    }
+    /// Vendor specific device id.
+    pub fn device_id(&self) -> u32 {
+        self.device_id
+    }
+    /** Driver version.
+
+    The encoding is vendor specific. */
+    pub fn driver_version(&self) -> u32 {
+        self.driver_version
+    }
+    /** Driver id.
+
+    None if `VK_KHR_driver_properties` is not available. */
+    pub fn driver_id(&self) -> Option<DriverId> {
+        self.driver_id
+    }
+    /// Device type, ie discrete or integrated.
+    pub fn device_type(&self) -> DeviceType {
+        self.device_type
+    }
+    /** Universally unique identifier of the device.
+
+    Stable across reboots and processes, see [`DeviceBuilder::uuid()`]. */
+    pub fn uuid(&self) -> [u8; 16] {
+        self.uuid
+    }
+    /** Compute units (SMs on NVIDIA, CUs on AMD).
+
+    None if neither `VK_NV_shader_sm_builtins` nor `VK_AMD_shader_core_properties` is
+    available. */
+    pub fn compute_units(&self) -> Option<u32> {
+        self.compute_units
+    }
+    /** Recommended minimum groups for a kernel with `threads` per group.
+
+    Enough groups to keep all compute units busy, assuming each can hold 2048 threads.
+    If [`.compute_units()`](Self::compute_units) is not known, it is estimated as
+    `max_compute_work_group_invocations / 32`. The result is at least 1 and at most
+    [`.max_groups()`](Self::max_groups).
+
+    This is advisory, actual occupancy also depends on the registers and shared memory
+    used by the kernel. */
+    pub fn recommended_min_groups(&self, threads: u32) -> u32 {
+        recommended_min_groups(
+            self.compute_units,
+            self.limits.max_compute_work_group_invocations,
+            self.max_groups,
+            threads,
+        )
+    }
+    /// Vulkan api version supported by the device, as (major, minor, patch).
+    pub fn api_version(&self) -> (u32, u32, u32) {
+        self.api_version
+    }
     /// Max groups per kernel dispatch.
     pub fn max_groups(&self) -> u32 {
         self.max_groups
@@ -828,24 +1795,363 @@ impl DeviceInfo {
     pub fn max_subgroup_threads(&self) -> u32 {
         self.max_subgroup_threads
     }
+    /// Default threads per subgroup.
+    ///
+    /// Between [`.min_subgroup_threads()`](Self::min_subgroup_threads) and
+    /// [`.max_subgroup_threads()`](Self::max_subgroup_threads). The subgroup size is chosen
+    /// by the driver and may differ per kernel if `VK_EXT_subgroup_size_control` is supported.
+    pub fn subgroup_threads(&self) -> u32 {
+        self.subgroup_threads
+    }
     /// Device features.
+    ///
+    /// The features enabled on the device, which excludes any that the device does not
+    /// support.
     pub fn features(&self) -> Features {
         self.features
     }
+    /// Scalar types supported by the device.
+    ///
+    /// Based on [`.features()`](Self::features), for example `F64` requires
+    /// [`Features::INT64`] and [`Features::FLOAT64`].
+    pub fn supported_scalar_types(&self) -> Vec<ScalarType> {
+        supported_scalar_types(self.features)
+    }
+    /// SPIR-V capabilities supported by the device.
+    ///
+    /// Based on [`.features()`](Self::features), ie "Float64" requires [`Features::FLOAT64`].
+    /// Kernels declaring other capabilities will fail to build.
+    pub fn supported_capabilities(&self) -> Vec<&'static str> {
+        supported_capabilities(self.features)
+    }
     /// Default threads.
     pub fn default_threads(&self) -> u32 {
         256.min(self.max_threads)
     }
+    /** Builds the device.
+
+    The device is selected by [`.uuid()`](Self::uuid), with default options. Use
+    [`DeviceBuilder::uuid()`] to set other options.
+
+    # Errors
+    See [`DeviceBuilder::build()`]. */
+    pub fn build(&self) -> Result<Device> {
+        let builder = Device::builder();
+        if self.uuid == [0; 16] {
+            builder.index(self.index)
+        } else {
+            builder.uuid(self.uuid)
+        }
+        .build()
+    }
+    /// Device limits.
+    pub fn limits(&self) -> &DeviceLimits {
+        &self.limits
+    }
     #[allow(dead_code)]
     pub(crate) fn debug_printf(&self) -> bool {
         self.debug_printf
     }
+    #[allow(dead_code)]
+    pub(crate) fn keep_debug_info(&self) -> bool {
+        self.keep_debug_info
+    }
+    #[allow(dead_code)]
+    pub(crate) fn zero_uninit(&self) -> bool {
+        self.zero_uninit
+    }
+    fn report(&self) -> String {
+        let (major, minor, patch) = self.api_version;
+        let limits = &self.limits;
+        format!(
+            "Device({index}) {name:?}
+vendor_id: {vendor_id:#06x}
+device_id: {device_id:#06x}
+driver_version: {driver_version:#x}
+driver_id: {driver_id:?}
+device_type: {device_type:?}
+uuid: {uuid}
+compute_units: {compute_units:?}
+api_version: {major}.{minor}.{patch}
+features: {features:?}
+scalar_types: {scalar_types:?}
+max_groups: {max_groups}
+max_threads: {max_threads}
+subgroup_threads: {min_subgroup_threads}..={max_subgroup_threads}
+max_compute_work_group_count: {max_compute_work_group_count:?}
+max_compute_work_group_size: {max_compute_work_group_size:?}
+max_compute_work_group_invocations: {max_compute_work_group_invocations}
+max_compute_shared_memory_size: {max_compute_shared_memory_size}
+max_push_constants_size: {max_push_constants_size}
+max_storage_buffer_range: {max_storage_buffer_range}
+min_storage_buffer_offset_alignment: {min_storage_buffer_offset_alignment}",
+            index = self.index,
+            name = self.name,
+            vendor_id = self.vendor_id,
+            device_id = self.device_id,
+            driver_version = self.driver_version,
+            driver_id = self.driver_id,
+            device_type = self.device_type,
+            uuid = uuid_string(&self.uuid),
+            compute_units = self.compute_units,
+            features = self.features,
+            scalar_types = self.supported_scalar_types(),
+            max_groups = self.max_groups,
+            max_threads = self.max_threads,
+            min_subgroup_threads = self.min_subgroup_threads,
+            max_subgroup_threads = self.max_subgroup_threads,
+            max_compute_work_group_count = limits.max_compute_work_group_count,
+            max_compute_work_group_size = limits.max_compute_work_group_size,
+            max_compute_work_group_invocations = limits.max_compute_work_group_invocations,
+            max_compute_shared_memory_size = limits.max_compute_shared_memory_size,
+            max_push_constants_size = limits.max_push_constants_size,
+            max_storage_buffer_range = limits.max_storage_buffer_range,
+            min_storage_buffer_offset_alignment = limits.min_storage_buffer_offset_alignment,
+        )
+    }
+}
+
+// Formats as 8-4-4-4-12 hex digits.
+fn uuid_string(uuid: &[u8; 16]) -> String {
+    let hex: String = uuid.iter().map(|x| format!("{x:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn recommended_min_groups(
+    compute_units: Option<u32>,
+    max_compute_work_group_invocations: u32,
+    max_groups: u32,
+    threads: u32,
+) -> u32 {
+    const RESIDENT_THREADS: u32 = 2048;
+    let compute_units = compute_units.unwrap_or(max_compute_work_group_invocations / 32);
+    let groups_per_unit = (RESIDENT_THREADS / threads.max(1)).max(1);
+    compute_units
+        .saturating_mul(groups_per_unit)
+        .clamp(1, max_groups.max(1))
+}
+
+// SPIR-V capabilities that kernels may declare, and the features they require.
+const CAPABILITY_FEATURES: [(&str, Features); 20] = [
+    ("Shader", Features::empty()),
+    ("VulkanMemoryModel", Features::empty()),
+    ("Int8", Features::INT8),
+    ("Int16", Features::INT16),
+    ("Int64", Features::INT64),
+    ("Float16", Features::FLOAT16),
+    ("Float64", Features::FLOAT64),
+    ("CooperativeMatrixNV", Features::COOPERATIVE_MATRIX),
+    ("StorageBuffer8BitAccess", Features::BUFFER8),
+    ("StorageBuffer16BitAccess", Features::BUFFER16),
+    ("StoragePushConstant8", Features::PUSH_CONSTANT8),
+    ("StoragePushConstant16", Features::PUSH_CONSTANT16),
+    ("GroupNonUniform", Features::SUBGROUP_BASIC),
+    ("GroupNonUniformVote", Features::SUBGROUP_VOTE),
+    ("GroupNonUniformArithmetic", Features::SUBGROUP_ARITHMETIC),
+    ("GroupNonUniformBallot", Features::SUBGROUP_BALLOT),
+    ("GroupNonUniformShuffle", Features::SUBGROUP_SHUFFLE),
+    (
+        "GroupNonUniformShuffleRelative",
+        Features::SUBGROUP_SHUFFLE_RELATIVE,
+    ),
+    ("GroupNonUniformClustered", Features::SUBGROUP_CLUSTERED),
+    ("GroupNonUniformQuad", Features::SUBGROUP_QUAD),
+];
+
+/// The features required for the SPIR-V `capability`, or None if it is not supported.
+#[cfg_attr(not(feature = "device"), allow(dead_code))]
+pub(crate) fn capability_features(capability: &str) -> Option<Features> {
+    CAPABILITY_FEATURES
+        .iter()
+        .find(|x| x.0 == capability)
+        .map(|x| x.1)
+}
+
+fn supported_capabilities(features: Features) -> Vec<&'static str> {
+    CAPABILITY_FEATURES
+        .iter()
+        .filter(|x| features.contains(x.1))
+        .map(|x| x.0)
+        .collect()
+}
+
+fn supported_scalar_types(features: Features) -> Vec<ScalarType> {
+    use ScalarType::*;
+    [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64]
+        .into_iter()
+        .filter(|scalar_type| {
+            let required_features = match scalar_type {
+                U8 | I8 => Features::INT8 | Features::BUFFER8,
+                U16 | I16 => Features::INT16 | Features::BUFFER16,
+                F16 | BF16 => Features::INT8 | Features::INT16 | Features::BUFFER16,
+                U32 | I32 | F32 => Features::empty(),
+                U64 | I64 => Features::INT64,
+                F64 => Features::INT64 | Features::FLOAT64,
+                _ => unreachable!(),
+            };
+            features.contains(required_features)
+        })
+        .collect()
+}
+
+/** Driver id.
+
+See [`DeviceInfo::driver_id()`].
+
+This mirrors [VkDriverId](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkDriverId.html). */
+#[allow(missing_docs)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DriverId {
+    AmdProprietary,
+    AmdOpenSource,
+    MesaRadv,
+    NvidiaProprietary,
+    IntelProprietaryWindows,
+    IntelOpenSourceMesa,
+    ImaginationProprietary,
+    QualcommProprietary,
+    ArmProprietary,
+    GoogleSwiftshader,
+    GgpProprietary,
+    BroadcomProprietary,
+    MesaLlvmpipe,
+    MoltenVk,
+    CoreaviProprietary,
+    JuiceProprietary,
+    VerisiliconProprietary,
+    MesaTurnip,
+    MesaV3dv,
+    MesaPanvk,
+    SamsungProprietary,
+    MesaVenus,
+    MesaDozen,
+    /// An id not known to krnl, the raw `VkDriverId` value.
+    Other(i32),
+}
+
+/** Device type.
+
+See [`DeviceInfo::device_type()`] and [`DeviceBuilder::prefer()`](builder::DeviceBuilder::prefer).
+
+This mirrors [VkPhysicalDeviceType](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceType.html). */
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeviceType {
+    /// A separate gpu, typically with dedicated memory.
+    Discrete,
+    /// A gpu embedded in or tightly coupled with the host cpu.
+    Integrated,
+    /// A virtual gpu, ie in a virtualization environment.
+    Virtual,
+    /// Running on the host cpu, ie a software implementation.
+    Cpu,
+    /// Does not match the other types.
+    Other,
+}
+
+/** A kernel cached on a device.
+
+See [`Device::cached_kernels()`]. */
+#[derive(Clone, Debug)]
+pub struct KernelCacheEntry {
+    pub(crate) name: String,
+    pub(crate) spec_bytes: Vec<u8>,
+    pub(crate) spirv_len: usize,
+}
+
+impl KernelCacheEntry {
+    /// The name of the kernel.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The bytes of the spec constants, followed by the threads as a `u32`.
+    pub fn spec_bytes(&self) -> &[u8] {
+        &self.spec_bytes
+    }
+    /** Approximate size in bytes.
+
+    The size of the specialized SPIR-V. The pipeline compiled by the driver is not included,
+    but is typically proportional. */
+    pub fn size(&self) -> usize {
+        self.spirv_len
+    }
+}
+
+/** Device limits.
+
+See [`DeviceInfo::limits()`].
+
+This is a subset of [VkPhysicalDeviceLimits](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceLimits.html). */
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeviceLimits {
+    pub(crate) max_compute_work_group_count: [u32; 3],
+    pub(crate) max_compute_work_group_size: [u32; 3],
+    pub(crate) max_compute_work_group_invocations: u32,
+    pub(crate) max_compute_shared_memory_size: u32,
+    pub(crate) max_push_constants_size: u32,
+    pub(crate) max_storage_buffer_range: u32,
+    pub(crate) min_storage_buffer_offset_alignment: u64,
+}
+
+impl DeviceLimits {
+    /// Max groups per dispatch, for each dimension.
+    pub fn max_compute_work_group_count(&self) -> [u32; 3] {
+        self.max_compute_work_group_count
+    }
+    /// Max threads per group, for each dimension.
+    pub fn max_compute_work_group_size(&self) -> [u32; 3] {
+        self.max_compute_work_group_size
+    }
+    /// Max total threads per group.
+    pub fn max_compute_work_group_invocations(&self) -> u32 {
+        self.max_compute_work_group_invocations
+    }
+    /// Max shared memory per group in bytes.
+    pub fn max_compute_shared_memory_size(&self) -> u32 {
+        self.max_compute_shared_memory_size
+    }
+    /// Max size of push constants in bytes.
+    pub fn max_push_constants_size(&self) -> u32 {
+        self.max_push_constants_size
+    }
+    /// Max range of a storage buffer binding in bytes.
+    pub fn max_storage_buffer_range(&self) -> u32 {
+        self.max_storage_buffer_range
+    }
+    /// Required alignment in bytes of storage buffer binding offsets.
+    pub fn min_storage_buffer_offset_alignment(&self) -> u64 {
+        self.min_storage_buffer_offset_alignment
+    }
 }
 
+#[cfg(feature = "device")]
+type EngineKernel = <Engine as DeviceEngine>::Kernel;
+
 #[cfg(feature = "device")]
 #[derive(Clone)]
 pub(crate) struct RawKernel {
-    inner: Arc<<Engine as DeviceEngine>::Kernel>,
+    device: RawDevice,
+    desc: Arc<KernelDesc>,
+    inner: Arc<KernelCell>,
+}
+
+// The engine kernel, possibly still being built on a background thread.
+#[cfg(feature = "device")]
+struct KernelCell {
+    handle: parking_lot::Mutex<Option<std::thread::JoinHandle<Result<Arc<EngineKernel>>>>>,
+    // The error is stored as a string, so that it can be returned on each dispatch.
+    kernel: std::sync::OnceLock<Result<Arc<EngineKernel>, String>>,
 }
 
 #[cfg(feature = "device")]
@@ -855,32 +2161,226 @@ impl RawKernel {
         key: KernelKey,
         desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
     ) -> Result<Self> {
+        let kernel = EngineKernel::cached(device.engine.clone(), key, desc_fn)?;
         Ok(Self {
-            inner: <Engine as DeviceEngine>::Kernel::cached(device.engine, key, desc_fn)?,
+            device,
+            desc: kernel.desc().clone(),
+            inner: Arc::new(KernelCell {
+                handle: parking_lot::Mutex::new(None),
+                kernel: Ok(kernel).into(),
+            }),
         })
     }
+    // Returns the cached kernel if any, otherwise builds it on a background thread.
+    //
+    // `stub` is used for dispatching until the kernel is built, see
+    // `KernelDesc::specialized_stub()`.
+    pub(crate) fn cached_async(
+        device: RawDevice,
+        key: KernelKey,
+        stub: Arc<KernelDesc>,
+        desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>> + Send + 'static,
+    ) -> Self {
+        if let Some(kernel) = EngineKernel::get_cached(&device.engine, &key) {
+            return Self {
+                device,
+                desc: kernel.desc().clone(),
+                inner: Arc::new(KernelCell {
+                    handle: parking_lot::Mutex::new(None),
+                    kernel: Ok(kernel).into(),
+                }),
+            };
+        }
+        let engine = device.engine.clone();
+        let handle = std::thread::spawn(move || EngineKernel::cached(engine, key, desc_fn));
+        Self {
+            device,
+            desc: stub,
+            inner: Arc::new(KernelCell {
+                handle: parking_lot::Mutex::new(Some(handle)),
+                kernel: std::sync::OnceLock::new(),
+            }),
+        }
+    }
+    pub(crate) fn is_ready(&self) -> bool {
+        self.inner.kernel.get().is_some()
+            || self
+                .inner
+                .handle
+                .lock()
+                .as_ref()
+                .map_or(true, |handle| handle.is_finished())
+    }
+    // Blocks until the kernel is built.
+    fn kernel(&self) -> Result<&Arc<EngineKernel>> {
+        let kernel = self.inner.kernel.get_or_init(|| {
+            let handle = self.inner.handle.lock().take().unwrap();
+            handle.join().unwrap().map_err(|e| format!("{e:#}"))
+        });
+        match kernel {
+            Ok(kernel) => Ok(kernel),
+            Err(e) => bail!("{e}"),
+        }
+    }
     pub(crate) unsafe fn dispatch(
         &self,
         groups: u32,
         buffers: &[DeviceBuffer],
         push_consts: Vec<u8>,
+        fence: Option<&Fence>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
         unsafe {
-            self.inner.dispatch(
+            self.kernel()?.dispatch(
                 groups,
                 cast_device_buffers(buffers),
                 push_consts,
+                fence,
                 debug_printf_panic,
             )
         }
     }
     pub(crate) fn device(&self) -> RawDevice {
-        RawDevice {
-            engine: self.inner.engine().clone(),
-        }
+        self.device.clone()
     }
     pub(crate) fn desc(&self) -> &Arc<KernelDesc> {
-        self.inner.desc()
+        &self.desc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_ops() {
+        let features: Vec<_> = Features::names().into_iter().map(|x| x.1).collect();
+        let a = Features::INT8 | Features::FLOAT64 | Features::SUBGROUP_BASIC;
+        for b in features
+            .iter()
+            .copied()
+            .chain([Features::empty(), a, Features::all()])
+        {
+            assert_eq!(a | b, a.union(b));
+            assert_eq!(a & b, a.intersection(b));
+            assert_eq!(a - b, a.difference(b));
+            assert_eq!((a & b) | (a - b), a);
+            assert!(!(a - b).contains(b) || b == Features::empty());
+            let mut c = a;
+            c |= b;
+            assert_eq!(c, a | b);
+            c &= b;
+            assert_eq!(c, b);
+            c -= a;
+            assert_eq!(c, b - a);
+        }
+        for (i, a) in features.iter().copied().enumerate() {
+            for (j, b) in features.iter().copied().enumerate() {
+                assert_eq!(a & b == a, i == j);
+                assert_eq!(a - b == Features::empty(), i == j);
+            }
+        }
+        assert_eq!(Features::all() - Features::all(), Features::empty());
+        assert_eq!(Features::all() & Features::INT8, Features::INT8);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn features_serde_tokens() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        assert_tokens(
+            &(Features::INT8 | Features::SUBGROUP_BASIC),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("INT8"),
+                Token::Str("SUBGROUP_BASIC"),
+                Token::SeqEnd,
+            ],
+        );
+        assert_tokens(
+            &Features::empty(),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+        assert_de_tokens_error::<Features>(
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Str("INT9"),
+                Token::SeqEnd,
+            ],
+            "Unknown feature \"INT9\"!",
+        );
+    }
+
+    #[test]
+    fn supported_capabilities_features() {
+        assert_eq!(
+            supported_capabilities(Features::empty()),
+            ["Shader", "VulkanMemoryModel"]
+        );
+        let capabilities = supported_capabilities(Features::FLOAT64 | Features::SUBGROUP_BALLOT);
+        assert!(capabilities.contains(&"Float64"));
+        assert!(capabilities.contains(&"GroupNonUniformBallot"));
+        assert!(!capabilities.contains(&"Int64"));
+        assert_eq!(
+            supported_capabilities(Features::all()).len(),
+            CAPABILITY_FEATURES.len()
+        );
+        assert_eq!(capability_features("Int8"), Some(Features::INT8));
+        assert_eq!(capability_features("Int64Atomics"), None);
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn preferred_device_order_prefer() {
+        use DeviceType::*;
+        let device_types = [Discrete, Integrated, Cpu, Integrated];
+        assert_eq!(preferred_device_order(&device_types, None), [0, 1, 2, 3]);
+        assert_eq!(
+            preferred_device_order(&device_types, Some(Integrated)),
+            [1, 3, 0, 2]
+        );
+        assert_eq!(
+            preferred_device_order(&device_types, Some(Virtual)),
+            [0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn uuid_string_format() {
+        let uuid = [
+            0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14, 0x17,
+            0x40, 0x00,
+        ];
+        assert_eq!(uuid_string(&uuid), "123e4567-e89b-12d3-a456-426614174000");
+    }
+
+    #[test]
+    fn recommended_min_groups_heuristic() {
+        assert_eq!(recommended_min_groups(Some(80), 1024, u32::MAX, 256), 640);
+        assert_eq!(recommended_min_groups(Some(80), 1024, u32::MAX, 4096), 80);
+        assert_eq!(recommended_min_groups(None, 1024, u32::MAX, 1024), 64);
+        assert_eq!(recommended_min_groups(Some(80), 1024, 100, 64), 100);
+        assert_eq!(recommended_min_groups(Some(0), 0, u32::MAX, 0), 1);
+    }
+
+    #[test]
+    fn supported_scalar_types_features() {
+        use ScalarType::*;
+        assert_eq!(supported_scalar_types(Features::empty()), [U32, I32, F32]);
+        assert_eq!(
+            supported_scalar_types(Features::INT8 | Features::INT64 | Features::BUFFER16),
+            [U32, I32, F32, U64, I64]
+        );
+        assert_eq!(
+            supported_scalar_types(
+                Features::INT8 | Features::INT16 | Features::BUFFER8 | Features::BUFFER16
+            ),
+            [U8, I8, U16, I16, F16, BF16, U32, I32, F32]
+        );
+        assert_eq!(
+            supported_scalar_types(Features::all()),
+            [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64]
+        );
     }
 }
@@ -1,24 +1,26 @@
 use super::{
-    error::{DeviceIndexOutOfRange, DeviceUnavailable, OutOfDeviceMemory},
-    DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo, DeviceLost,
-    DeviceOptions, Features, KernelDesc, KernelKey,
+    error::{DeviceIndexOutOfRange, DeviceUnavailable, DeviceUuidNotFound, OutOfDeviceMemory},
+    DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo, DeviceLimits,
+    DeviceLost, DeviceOptions, DeviceType, DriverId, Features, Fence, KernelCacheEntry, KernelDesc,
+    KernelKey,
 };
 
-use anyhow::{Error, Result};
+use anyhow::{bail, Error, Result};
 use ash::vk::Handle;
 use crossbeam_channel::{Receiver, Sender};
 use dashmap::DashMap;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, ReentrantMutex};
 use std::{
+    collections::VecDeque,
     mem::MaybeUninit,
-    ops::Range,
+    ops::{Deref, DerefMut, Range},
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
 };
 use vulkano::{
-    buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer},
+    buffer::{Buffer, BufferCreateInfo, BufferMemory, BufferUsage, Subbuffer},
     command_buffer::{
         pool::{CommandBufferAllocateInfo, CommandPool, CommandPoolAlloc, CommandPoolCreateInfo},
         sys::{CommandBufferBeginInfo, UnsafeCommandBuffer, UnsafeCommandBufferBuilder},
@@ -30,7 +32,8 @@ use vulkano::{
         WriteDescriptorSet,
     },
     device::{
-        Device, DeviceCreateInfo, DeviceOwned, Queue, QueueCreateInfo, QueueFlags, QueueGuard,
+        physical::PhysicalDevice, Device, DeviceCreateInfo, DeviceOwned, Queue, QueueCreateInfo,
+        QueueFlags, QueueGuard,
     },
     instance::{
         debug::{
@@ -58,10 +61,15 @@ pub struct Engine {
     semaphore: Arc<Semaphore>,
     epoch: AtomicU64,
     pending: Arc<AtomicU64>,
+    // Incremented when cancel_pending discards recorded work.
+    cancels: AtomicU64,
+    submit_lock: ReentrantMutex<()>,
     frame_outer: Mutex<FrameOuter>,
     host_buffer_sender: Sender<HostBuffer>,
     host_buffer_receiver: Receiver<HostBuffer>,
     kernels: DashMap<KernelKey, KernelInner>,
+    scratch: Mutex<ScratchPool>,
+    allocated_bytes: Arc<AtomicU64>,
     memory_allocator: Arc<StandardMemoryAllocator>,
     queue: Arc<Queue>,
     engine_exited: Arc<AtomicBool>,
@@ -74,12 +82,14 @@ impl Engine {
         &self,
         src: Subbuffer<[u8]>,
         dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
+        host_buffer: Option<&mut HostBuffer>,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) -> Result<()> {
+        let _submit_guard = self.submit_lock.lock();
         let mut frame_outer = self.frame_outer.lock();
         unsafe { frame_outer.transfer(&self.epoch, src, dst, host_buffer, dst_device_buffer) }
     }
+    #[allow(clippy::too_many_arguments)]
     unsafe fn compute(
         &self,
         kernel_desc: &Arc<KernelDesc>,
@@ -87,8 +97,10 @@ impl Engine {
         groups: u32,
         buffers: &[Arc<DeviceBuffer>],
         push_consts: &[u8],
+        fence: Option<&Fence>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
+        let _submit_guard = self.submit_lock.lock();
         let mut frame_outer = self.frame_outer.lock();
         let new_descriptors: u32 = buffers.len().try_into().unwrap();
         if frame_outer.kernels >= Frame::MAX_KERNELS
@@ -112,10 +124,25 @@ impl Engine {
                 groups,
                 buffers,
                 push_consts,
+                fence,
                 debug_printf_panic,
             )
         }
     }
+    unsafe fn fill_zero(
+        &self,
+        dst: Subbuffer<[u8]>,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        let _submit_guard = self.submit_lock.lock();
+        let mut frame_outer = self.frame_outer.lock();
+        unsafe { frame_outer.fill_zero(&self.epoch, dst, dst_device_buffer) }
+    }
+    unsafe fn cancel(&self) -> Result<()> {
+        let _submit_guard = self.submit_lock.lock();
+        let mut frame_outer = self.frame_outer.lock();
+        unsafe { frame_outer.cancel(&self.cancels) }
+    }
     fn wait_pending(&self, epoch: u64) -> Result<(), DeviceLost> {
         while self.pending.load(Ordering::SeqCst) < epoch {
             if self.worker_exited.load(Ordering::SeqCst) {
@@ -157,80 +184,38 @@ impl DeviceEngine for Engine {
     type Kernel = Kernel;
     fn new(options: DeviceOptions) -> anyhow::Result<std::sync::Arc<Self>> {
         let DeviceOptions {
-            index,
+            mut index,
             optimal_features,
+            max_in_flight,
+            keep_debug_info,
+            zero_uninit,
+            prefer,
+            uuid,
+            shared: _,
         } = options;
-        let library = VulkanLibrary::new().map_err(|e| Error::new(DeviceUnavailable).context(e))?;
-        let debug_printf = Arc::new(AtomicBool::default());
-        let debug_printf2 = debug_printf.clone();
-        let debug_create_info = DebugUtilsMessengerCreateInfo {
-            message_severity: DebugUtilsMessageSeverity::INFO,
-            message_type: DebugUtilsMessageType::VALIDATION,
-            ..DebugUtilsMessengerCreateInfo::user_callback(Arc::new(move |msg| {
-                if debug_printf2.load(Ordering::SeqCst) {
-                    return;
-                }
-                if msg.layer_prefix
-                    == Some("UNASSIGNED-khronos-validation-createinstance-status-message")
-                    && msg.description.contains("Khronos Validation Layer Active:")
-                    && msg
-                        .description
-                        .contains("Current Enables: VK_VALIDATION_FEATURE_ENABLE_DEBUG_PRINTF_EXT.")
-                {
-                    debug_printf2.store(true, Ordering::SeqCst);
-                }
-            }))
-        };
-        let instance_create_info = InstanceCreateInfo {
-            enabled_extensions: InstanceExtensions {
-                ext_debug_utils: true,
-                ..Default::default()
-            },
-            enumerate_portability: true,
-            ..InstanceCreateInfo::application_from_cargo_toml()
-        };
-        let instance = unsafe {
-            Instance::with_debug_utils_messengers(
-                library,
-                instance_create_info,
-                [debug_create_info],
-            )?
-        };
-        let debug_printf = debug_printf.load(Ordering::SeqCst);
-        let mut physical_devices = instance.enumerate_physical_devices()?;
+        let (instance, debug_printf) = create_instance()?;
+        let physical_devices: Vec<_> = instance.enumerate_physical_devices()?.collect();
         let devices = physical_devices.len();
-        let physical_device = if let Some(physical_device) = physical_devices.nth(index) {
-            physical_device
+        let device_types: Vec<_> = physical_devices
+            .iter()
+            .map(|x| device_type(x.properties().device_type))
+            .collect();
+        let order = super::preferred_device_order(&device_types, prefer);
+        if let Some(uuid) = uuid {
+            index = order
+                .iter()
+                .position(|i| physical_devices[*i].properties().device_uuid == Some(uuid))
+                .ok_or(DeviceUuidNotFound { uuid })?;
+        }
+        // `index` is into `order`, the info stores the enumeration index, as `Device::infos()`.
+        let index = if let Some(i) = order.get(index) {
+            *i
         } else {
             return Err(DeviceIndexOutOfRange { index, devices }.into());
         };
-        let name = physical_device.properties().device_name.clone();
-        let optimal_device_extensions = vulkano::device::DeviceExtensions {
-            khr_vulkan_memory_model: true,
-            ext_subgroup_size_control: true,
-            ..vulkano::device::DeviceExtensions::empty()
-        };
-        let device_extensions = physical_device
-            .supported_extensions()
-            .intersection(&optimal_device_extensions);
-        let optimal_device_features = vulkano::device::Features {
-            vulkan_memory_model: true,
-            timeline_semaphore: true,
-            subgroup_size_control: true,
-            shader_int8: optimal_features.contains(Features::INT8),
-            shader_int16: optimal_features.contains(Features::INT16),
-            shader_int64: optimal_features.contains(Features::INT64),
-            shader_float16: optimal_features.contains(Features::FLOAT16),
-            shader_float64: optimal_features.contains(Features::FLOAT64),
-            storage_buffer8_bit_access: optimal_features.contains(Features::BUFFER8),
-            storage_buffer16_bit_access: optimal_features.contains(Features::BUFFER16),
-            storage_push_constant8: optimal_features.contains(Features::PUSH_CONSTANT8),
-            storage_push_constant16: optimal_features.contains(Features::PUSH_CONSTANT16),
-            ..vulkano::device::Features::empty()
-        };
-        let device_features = physical_device
-            .supported_features()
-            .intersection(&optimal_device_features);
+        let physical_device = physical_devices[index].clone();
+        let (device_extensions, device_features) =
+            enabled_device_extensions_features(&physical_device, optimal_features);
         let compute_family = physical_device
             .queue_family_properties()
             .iter()
@@ -297,89 +282,19 @@ impl DeviceEngine for Engine {
                 .unwrap();
         }
         let kernels = DashMap::default();
-        let properties = device.physical_device().properties();
-        let (min_subgroup_threads, max_subgroup_threads) = if device_features.subgroup_size_control
-        {
-            (
-                properties.min_subgroup_size.unwrap_or(1),
-                properties.max_subgroup_size.unwrap_or(128),
-            )
-        } else {
-            (1, 128)
-        };
-
-        let mut features = Features::empty();
-        if device_features.shader_int8 {
-            features = features.union(Features::INT8);
-        }
-        if device_features.shader_int16 {
-            features = features.union(Features::INT16);
-        }
-        if device_features.shader_int64 {
-            features = features.union(Features::INT64);
-        }
-        if device_features.shader_float16 {
-            features = features.union(Features::FLOAT16);
-        }
-        if device_features.shader_float64 {
-            features = features.union(Features::FLOAT64);
-        }
-        if device_features.storage_buffer8_bit_access {
-            features = features.union(Features::BUFFER8);
-        }
-        if device_features.storage_buffer16_bit_access {
-            features = features.union(Features::BUFFER16);
-        }
-        if device_features.storage_push_constant8 {
-            features = features.union(Features::PUSH_CONSTANT8);
-        }
-        if device_features.storage_push_constant16 {
-            features = features.union(Features::PUSH_CONSTANT16);
-        }
-        if let Some(subgroup_features) = properties.subgroup_supported_operations {
-            use vulkano::device::physical::SubgroupFeatures;
-
-            if subgroup_features.contains(SubgroupFeatures::BASIC) {
-                features = features.union(Features::SUBGROUP_BASIC);
-            }
-            if subgroup_features.contains(SubgroupFeatures::VOTE) {
-                features = features.union(Features::SUBGROUP_VOTE);
-            }
-            if subgroup_features.contains(SubgroupFeatures::ARITHMETIC) {
-                features = features.union(Features::SUBGROUP_ARITHMETIC);
-            }
-            if subgroup_features.contains(SubgroupFeatures::BALLOT) {
-                features = features.union(Features::SUBGROUP_BALLOT);
-            }
-            if subgroup_features.contains(SubgroupFeatures::SHUFFLE) {
-                features = features.union(Features::SUBGROUP_SHUFFLE);
-            }
-            if subgroup_features.contains(SubgroupFeatures::SHUFFLE_RELATIVE) {
-                features = features.union(Features::SUBGROUP_SHUFFLE_RELATIVE);
-            }
-            if subgroup_features.contains(SubgroupFeatures::CLUSTERED) {
-                features = features.union(Features::SUBGROUP_CLUSTERED);
-            }
-            if subgroup_features.contains(SubgroupFeatures::QUAD) {
-                features = features.union(Features::SUBGROUP_QUAD);
-            }
-        }
-        let info = Arc::new(DeviceInfo {
+        let info = Arc::new(device_info(
+            device.physical_device(),
+            &device_features,
             index,
-            name,
-            device_id: properties.device_id,
-            vendor_id: properties.vendor_id,
-            max_groups: properties.max_compute_work_group_count[0],
-            max_threads: properties.max_compute_work_group_size[0],
-            min_subgroup_threads,
-            max_subgroup_threads,
-            features,
             debug_printf,
-        });
-        let mut worker = Worker::new(queue.clone(), index)?;
+            keep_debug_info,
+            zero_uninit,
+        ));
+        let mut worker = Worker::new(queue.clone(), index, max_in_flight)?;
         let semaphore = worker.semaphore.clone();
         let epoch = AtomicU64::default();
         let pending = worker.pending.clone();
+        let submit_lock = ReentrantMutex::new(());
         let frame_outer = Mutex::new(FrameOuter::new(
             worker.ready_frame.clone(),
             worker.empty.clone(),
@@ -392,10 +307,14 @@ impl DeviceEngine for Engine {
             semaphore,
             epoch,
             pending,
+            cancels: AtomicU64::default(),
+            submit_lock,
             frame_outer,
             host_buffer_sender,
             host_buffer_receiver,
             kernels,
+            scratch: Mutex::default(),
+            allocated_bytes: Arc::default(),
             memory_allocator,
             engine_exited,
             worker_exited,
@@ -403,6 +322,26 @@ impl DeviceEngine for Engine {
             _instance: instance,
         }))
     }
+    fn device_infos() -> Result<Vec<DeviceInfo>> {
+        let (instance, debug_printf) = create_instance()?;
+        let infos = instance
+            .enumerate_physical_devices()?
+            .enumerate()
+            .map(|(index, physical_device)| {
+                let (_, device_features) =
+                    enabled_device_extensions_features(&physical_device, Features::all());
+                device_info(
+                    &physical_device,
+                    &device_features,
+                    index,
+                    debug_printf,
+                    false,
+                    false,
+                )
+            })
+            .collect();
+        Ok(infos)
+    }
     fn id(&self) -> DeviceId {
         let index = self.info.index;
         let handle = self.queue.device().handle().as_raw().try_into().unwrap();
@@ -415,6 +354,319 @@ impl DeviceEngine for Engine {
         let epoch = self.epoch.load(Ordering::SeqCst);
         self.wait_epoch(epoch)
     }
+    fn flush(&self) -> Result<(), DeviceLost> {
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        self.wait_pending(epoch)
+    }
+    fn cancel_pending(&self) -> Result<(), DeviceLost> {
+        unsafe {
+            self.cancel().map_err(|_| DeviceLost(self.id()))?;
+        }
+        self.wait()
+    }
+    fn cancels(&self) -> u64 {
+        self.cancels.load(Ordering::SeqCst)
+    }
+    // Errors if cancel_pending discarded work since `cancels` was loaded, which may include
+    // transfers recorded in between.
+    fn check_canceled(&self, cancels: u64) -> Result<()> {
+        if self.cancels() != cancels {
+            bail!("Transfer was canceled by `Device::cancel_pending()`!");
+        }
+        Ok(())
+    }
+    fn submit_lock(&self) -> &ReentrantMutex<()> {
+        &self.submit_lock
+    }
+    fn scratch_high_water_mark(&self) -> usize {
+        self.scratch.lock().high_water_mark
+    }
+    fn allocated_bytes(&self) -> u64 {
+        self.allocated_bytes.load(Ordering::SeqCst)
+    }
+    fn cached_kernels(&self) -> Vec<KernelCacheEntry> {
+        self.kernels
+            .iter()
+            .map(|entry| KernelCacheEntry {
+                name: entry.value().desc.name.to_string(),
+                spec_bytes: entry.key().spec_bytes().to_vec(),
+                spirv_len: entry.value().desc.spirv.len() * 4,
+            })
+            .collect()
+    }
+    fn in_flight(&self) -> Result<usize, DeviceLost> {
+        let pending = self.pending.load(Ordering::SeqCst);
+        let finished = self.finished_epoch()?;
+        Ok(pending.saturating_sub(finished).try_into().unwrap())
+    }
+    fn finished_epoch(&self) -> Result<u64, DeviceLost> {
+        unsafe { semaphore_value(self.queue.device(), &self.semaphore) }
+            .map_err(|_| DeviceLost(self.id()))
+    }
+    fn wait_for_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.wait_epoch(epoch)
+    }
+}
+
+fn create_instance() -> Result<(Arc<Instance>, bool)> {
+    let library = VulkanLibrary::new().map_err(|e| Error::new(DeviceUnavailable).context(e))?;
+    let debug_printf = Arc::new(AtomicBool::default());
+    let debug_printf2 = debug_printf.clone();
+    let debug_create_info = DebugUtilsMessengerCreateInfo {
+        message_severity: DebugUtilsMessageSeverity::INFO,
+        message_type: DebugUtilsMessageType::VALIDATION,
+        ..DebugUtilsMessengerCreateInfo::user_callback(Arc::new(move |msg| {
+            if debug_printf2.load(Ordering::SeqCst) {
+                return;
+            }
+            if msg.layer_prefix
+                == Some("UNASSIGNED-khronos-validation-createinstance-status-message")
+                && msg.description.contains("Khronos Validation Layer Active:")
+                && msg
+                    .description
+                    .contains("Current Enables: VK_VALIDATION_FEATURE_ENABLE_DEBUG_PRINTF_EXT.")
+            {
+                debug_printf2.store(true, Ordering::SeqCst);
+            }
+        }))
+    };
+    let instance_create_info = InstanceCreateInfo {
+        enabled_extensions: InstanceExtensions {
+            ext_debug_utils: true,
+            ..Default::default()
+        },
+        enumerate_portability: true,
+        ..InstanceCreateInfo::application_from_cargo_toml()
+    };
+    let instance = unsafe {
+        Instance::with_debug_utils_messengers(library, instance_create_info, [debug_create_info])?
+    };
+    let debug_printf = debug_printf.load(Ordering::SeqCst);
+    Ok((instance, debug_printf))
+}
+
+// Extensions and features to enable on `physical_device`.
+fn enabled_device_extensions_features(
+    physical_device: &PhysicalDevice,
+    optimal_features: Features,
+) -> (vulkano::device::DeviceExtensions, vulkano::device::Features) {
+    let optimal_device_extensions = vulkano::device::DeviceExtensions {
+        khr_vulkan_memory_model: true,
+        ext_subgroup_size_control: true,
+        khr_shader_integer_dot_product: optimal_features.contains(Features::INTEGER_DOT_PRODUCT),
+        nv_cooperative_matrix: optimal_features.contains(Features::COOPERATIVE_MATRIX),
+        ..vulkano::device::DeviceExtensions::empty()
+    };
+    let device_extensions = physical_device
+        .supported_extensions()
+        .intersection(&optimal_device_extensions);
+    let device_features = enabled_device_features(
+        optimal_features,
+        physical_device.supported_features(),
+        &device_extensions,
+        physical_device.api_version(),
+    );
+    (device_extensions, device_features)
+}
+
+fn device_info(
+    physical_device: &PhysicalDevice,
+    device_features: &vulkano::device::Features,
+    index: usize,
+    debug_printf: bool,
+    keep_debug_info: bool,
+    zero_uninit: bool,
+) -> DeviceInfo {
+    let properties = physical_device.properties();
+    let name = properties.device_name.clone();
+    let (min_subgroup_threads, max_subgroup_threads) = if device_features.subgroup_size_control {
+        (
+            properties.min_subgroup_size.unwrap_or(1),
+            properties.max_subgroup_size.unwrap_or(128),
+        )
+    } else {
+        (1, 128)
+    };
+    let subgroup_threads = properties
+        .subgroup_size
+        .unwrap_or(max_subgroup_threads)
+        .clamp(min_subgroup_threads, max_subgroup_threads);
+
+    let mut features = Features::empty();
+    if device_features.shader_int8 {
+        features = features.union(Features::INT8);
+    }
+    if device_features.shader_int16 {
+        features = features.union(Features::INT16);
+    }
+    if device_features.shader_int64 {
+        features = features.union(Features::INT64);
+    }
+    if device_features.shader_float16 {
+        features = features.union(Features::FLOAT16);
+    }
+    if device_features.shader_float64 {
+        features = features.union(Features::FLOAT64);
+    }
+    if device_features.shader_integer_dot_product {
+        features = features.union(Features::INTEGER_DOT_PRODUCT);
+    }
+    if device_features.cooperative_matrix {
+        features = features.union(Features::COOPERATIVE_MATRIX);
+    }
+    if device_features.storage_buffer8_bit_access {
+        features = features.union(Features::BUFFER8);
+    }
+    if device_features.storage_buffer16_bit_access {
+        features = features.union(Features::BUFFER16);
+    }
+    if device_features.storage_push_constant8 {
+        features = features.union(Features::PUSH_CONSTANT8);
+    }
+    if device_features.storage_push_constant16 {
+        features = features.union(Features::PUSH_CONSTANT16);
+    }
+    if let Some(subgroup_features) = properties.subgroup_supported_operations {
+        use vulkano::device::physical::SubgroupFeatures;
+
+        if subgroup_features.contains(SubgroupFeatures::BASIC) {
+            features = features.union(Features::SUBGROUP_BASIC);
+        }
+        if subgroup_features.contains(SubgroupFeatures::VOTE) {
+            features = features.union(Features::SUBGROUP_VOTE);
+        }
+        if subgroup_features.contains(SubgroupFeatures::ARITHMETIC) {
+            features = features.union(Features::SUBGROUP_ARITHMETIC);
+        }
+        if subgroup_features.contains(SubgroupFeatures::BALLOT) {
+            features = features.union(Features::SUBGROUP_BALLOT);
+        }
+        if subgroup_features.contains(SubgroupFeatures::SHUFFLE) {
+            features = features.union(Features::SUBGROUP_SHUFFLE);
+        }
+        if subgroup_features.contains(SubgroupFeatures::SHUFFLE_RELATIVE) {
+            features = features.union(Features::SUBGROUP_SHUFFLE_RELATIVE);
+        }
+        if subgroup_features.contains(SubgroupFeatures::CLUSTERED) {
+            features = features.union(Features::SUBGROUP_CLUSTERED);
+        }
+        if subgroup_features.contains(SubgroupFeatures::QUAD) {
+            features = features.union(Features::SUBGROUP_QUAD);
+        }
+    }
+    let limits = DeviceLimits {
+        max_compute_work_group_count: properties.max_compute_work_group_count,
+        max_compute_work_group_size: properties.max_compute_work_group_size,
+        max_compute_work_group_invocations: properties.max_compute_work_group_invocations,
+        max_compute_shared_memory_size: properties.max_compute_shared_memory_size,
+        max_push_constants_size: properties.max_push_constants_size,
+        max_storage_buffer_range: properties.max_storage_buffer_range,
+        min_storage_buffer_offset_alignment: properties
+            .min_storage_buffer_offset_alignment
+            .as_devicesize(),
+    };
+    DeviceInfo {
+        index,
+        name,
+        device_id: properties.device_id,
+        vendor_id: properties.vendor_id,
+        driver_version: properties.driver_version,
+        driver_id: properties.driver_id.map(driver_id),
+        device_type: device_type(properties.device_type),
+        uuid: properties.device_uuid.unwrap_or_default(),
+        compute_units: properties.shader_sm_count.or_else(|| {
+            Some(
+                properties.shader_engine_count?
+                    * properties.shader_arrays_per_engine_count?
+                    * properties.compute_units_per_shader_array?,
+            )
+        }),
+        api_version: {
+            let version = properties.api_version;
+            (version.major, version.minor, version.patch)
+        },
+        max_groups: properties.max_compute_work_group_count[0],
+        max_threads: properties.max_compute_work_group_size[0],
+        min_subgroup_threads,
+        max_subgroup_threads,
+        subgroup_threads,
+        features,
+        limits,
+        debug_printf,
+        keep_debug_info,
+        zero_uninit,
+    }
+}
+
+// Optimal features are enabled only if supported, and if the extensions they
+// require are enabled, so that creating the device does not fail because of them.
+fn enabled_device_features(
+    optimal_features: Features,
+    supported_features: &vulkano::device::Features,
+    device_extensions: &vulkano::device::DeviceExtensions,
+    api_version: Version,
+) -> vulkano::device::Features {
+    let optimal_device_features = vulkano::device::Features {
+        vulkan_memory_model: true,
+        timeline_semaphore: true,
+        subgroup_size_control: true,
+        shader_int8: optimal_features.contains(Features::INT8),
+        shader_int16: optimal_features.contains(Features::INT16),
+        shader_int64: optimal_features.contains(Features::INT64),
+        shader_float16: optimal_features.contains(Features::FLOAT16),
+        shader_float64: optimal_features.contains(Features::FLOAT64),
+        shader_integer_dot_product: optimal_features.contains(Features::INTEGER_DOT_PRODUCT)
+            && (device_extensions.khr_shader_integer_dot_product || api_version >= Version::V1_3),
+        cooperative_matrix: optimal_features.contains(Features::COOPERATIVE_MATRIX)
+            && device_extensions.nv_cooperative_matrix,
+        storage_buffer8_bit_access: optimal_features.contains(Features::BUFFER8),
+        storage_buffer16_bit_access: optimal_features.contains(Features::BUFFER16),
+        storage_push_constant8: optimal_features.contains(Features::PUSH_CONSTANT8),
+        storage_push_constant16: optimal_features.contains(Features::PUSH_CONSTANT16),
+        ..vulkano::device::Features::empty()
+    };
+    supported_features.intersection(&optimal_device_features)
+}
+
+fn driver_id(id: vulkano::device::physical::DriverId) -> DriverId {
+    use vulkano::device::physical::DriverId as Id;
+    match id {
+        Id::AMDProprietary => DriverId::AmdProprietary,
+        Id::AMDOpenSource => DriverId::AmdOpenSource,
+        Id::MesaRADV => DriverId::MesaRadv,
+        Id::NvidiaProprietary => DriverId::NvidiaProprietary,
+        Id::IntelProprietaryWindows => DriverId::IntelProprietaryWindows,
+        Id::IntelOpenSourceMesa => DriverId::IntelOpenSourceMesa,
+        Id::ImaginationProprietary => DriverId::ImaginationProprietary,
+        Id::QualcommProprietary => DriverId::QualcommProprietary,
+        Id::ARMProprietary => DriverId::ArmProprietary,
+        Id::GoogleSwiftshader => DriverId::GoogleSwiftshader,
+        Id::GGPProprietary => DriverId::GgpProprietary,
+        Id::BroadcomProprietary => DriverId::BroadcomProprietary,
+        Id::MesaLLVMpipe => DriverId::MesaLlvmpipe,
+        Id::MoltenVK => DriverId::MoltenVk,
+        Id::CoreAVIProprietary => DriverId::CoreaviProprietary,
+        Id::JuiceProprietary => DriverId::JuiceProprietary,
+        Id::VeriSiliconPropertary => DriverId::VerisiliconProprietary,
+        Id::MesaTurnip => DriverId::MesaTurnip,
+        Id::MesaV3DV => DriverId::MesaV3dv,
+        Id::MesaPanVK => DriverId::MesaPanvk,
+        Id::SamsungProprietary => DriverId::SamsungProprietary,
+        Id::MesaVenus => DriverId::MesaVenus,
+        Id::MesaDozen => DriverId::MesaDozen,
+        _ => DriverId::Other(ash::vk::DriverId::from(id).as_raw()),
+    }
+}
+
+fn device_type(device_type: vulkano::device::physical::PhysicalDeviceType) -> DeviceType {
+    use vulkano::device::physical::PhysicalDeviceType as Type;
+    match device_type {
+        Type::DiscreteGpu => DeviceType::Discrete,
+        Type::IntegratedGpu => DeviceType::Integrated,
+        Type::VirtualGpu => DeviceType::Virtual,
+        Type::Cpu => DeviceType::Cpu,
+        _ => DeviceType::Other,
+    }
 }
 
 fn new_semaphore(device: &Arc<Device>) -> Result<Semaphore> {
@@ -448,13 +700,20 @@ unsafe fn queue_submit(
     epoch: u64,
 ) -> Result<(), ash::vk::Result> {
     let command_buffers = &[command_buffer.handle()];
+    // Batches may be submitted before the previous batch has finished, so wait for it
+    // on the device.
+    let wait_semaphore_values = &[epoch - 1];
     let signal_semaphore_values = &[epoch];
     let mut semaphore_submit_info = ash::vk::TimelineSemaphoreSubmitInfo::builder()
+        .wait_semaphore_values(wait_semaphore_values)
         .signal_semaphore_values(signal_semaphore_values);
-    let signal_semaphores = &[semaphore.handle()];
+    let semaphores = &[semaphore.handle()];
+    let wait_dst_stage_mask = &[ash::vk::PipelineStageFlags::ALL_COMMANDS];
     let submit_info = ash::vk::SubmitInfo::builder()
+        .wait_semaphores(semaphores)
+        .wait_dst_stage_mask(wait_dst_stage_mask)
         .command_buffers(command_buffers)
-        .signal_semaphores(signal_semaphores)
+        .signal_semaphores(semaphores)
         .push_next(&mut semaphore_submit_info);
     let device = queue.device();
     unsafe {
@@ -478,6 +737,33 @@ unsafe fn wait_semaphore(device: &Device, semaphore: &Semaphore, value: u64) ->
     unsafe { (device.fns().v1_2.wait_semaphores)(device.handle(), &*semaphore_wait_info, 0) }
 }
 
+unsafe fn semaphore_value(device: &Device, semaphore: &Semaphore) -> Result<u64, ash::vk::Result> {
+    let mut value = 0;
+    let result = unsafe {
+        (device.fns().v1_2.get_semaphore_counter_value)(
+            device.handle(),
+            semaphore.handle(),
+            &mut value,
+        )
+    };
+    match result {
+        ash::vk::Result::SUCCESS => Ok(value),
+        result => Err(result),
+    }
+}
+
+#[derive(Default)]
+struct ScratchPool {
+    // Recycled buffers and the epoch of the last work recorded before they were dropped.
+    buffers: Vec<(u64, Subbuffer<[u8]>, Arc<Allocation>)>,
+    allocated: usize,
+    high_water_mark: usize,
+}
+
+impl ScratchPool {
+    const MAX_BUFFERS: usize = 16;
+}
+
 struct HostBuffer {
     inner: Subbuffer<[u8]>,
     queue: Arc<Queue>,
@@ -514,7 +800,7 @@ impl FrameOuter {
         epoch: &AtomicU64,
         src: Subbuffer<[u8]>,
         dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
+        host_buffer: Option<&mut HostBuffer>,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) -> Result<()> {
         let mut frame = self.frame.lock();
@@ -532,6 +818,27 @@ impl FrameOuter {
         }
         Ok(())
     }
+    unsafe fn fill_zero(
+        &mut self,
+        epoch: &AtomicU64,
+        dst: Subbuffer<[u8]>,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        let mut frame = self.frame.lock();
+        if frame.command_buffer_builder.is_none() {
+            self.kernels = 0;
+            self.descriptors = 0;
+            unsafe {
+                frame.begin()?;
+            }
+            epoch.store(frame.epoch, Ordering::SeqCst);
+            self.empty.store(false, Ordering::SeqCst);
+        }
+        unsafe {
+            frame.fill_zero(dst, dst_device_buffer);
+        }
+        Ok(())
+    }
     #[allow(clippy::too_many_arguments)]
     unsafe fn compute(
         &mut self,
@@ -541,6 +848,7 @@ impl FrameOuter {
         groups: u32,
         buffers: &[Arc<DeviceBuffer>],
         push_consts: &[u8],
+        fence: Option<&Fence>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
         let new_descriptors: u32 = buffers.len().try_into().unwrap();
@@ -561,6 +869,7 @@ impl FrameOuter {
                 groups,
                 buffers,
                 push_consts,
+                fence,
                 debug_printf_panic,
             );
         }
@@ -568,6 +877,18 @@ impl FrameOuter {
         self.descriptors += new_descriptors;
         Ok(())
     }
+    unsafe fn cancel(&mut self, cancels: &AtomicU64) -> Result<()> {
+        let mut frame = self.frame.lock();
+        if frame.command_buffer_builder.is_some() {
+            self.kernels = 0;
+            self.descriptors = 0;
+            unsafe {
+                frame.cancel()?;
+            }
+            cancels.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(())
+    }
 }
 
 struct Frame {
@@ -653,7 +974,7 @@ impl Frame {
         &mut self,
         src: Subbuffer<[u8]>,
         dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
+        host_buffer: Option<&mut HostBuffer>,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) {
         let builder = self.command_buffer_builder.as_mut().unwrap();
@@ -661,11 +982,23 @@ impl Frame {
             builder.copy_buffer(&CopyBufferInfo::buffers(src.clone(), dst.clone()));
         }
         self.buffers.extend_from_slice(&[src, dst]);
-        host_buffer.epoch = self.epoch;
+        if let Some(host_buffer) = host_buffer {
+            host_buffer.epoch = self.epoch;
+        }
         if let Some(dst_device_buffer) = dst_device_buffer {
             dst_device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
         }
     }
+    unsafe fn fill_zero(&mut self, dst: Subbuffer<[u8]>, dst_device_buffer: &DeviceBuffer) {
+        let builder = self.command_buffer_builder.as_mut().unwrap();
+        // Allocations are padded to DeviceBuffer::ALIGN, so the size is a multiple of 4.
+        unsafe {
+            builder.fill_buffer(&dst.clone().cast_aligned::<u32>(), 0);
+        }
+        self.buffers.push(dst);
+        dst_device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
+    }
+    #[allow(clippy::too_many_arguments)]
     unsafe fn compute(
         &mut self,
         kernel_desc: &Arc<KernelDesc>,
@@ -673,6 +1006,7 @@ impl Frame {
         groups: u32,
         buffers: &[Arc<DeviceBuffer>],
         push_consts: &[u8],
+        fence: Option<&Fence>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) {
         let builder = self.command_buffer_builder.as_mut().unwrap();
@@ -682,6 +1016,9 @@ impl Frame {
         let pipeline_layout = pipeline.layout();
         if !buffers.is_empty() {
             let descriptor_set_layout = pipeline_layout.set_layouts().first().unwrap();
+            // Bind entire buffers, which are aligned to DeviceBuffer::ALIGN, so that offsets are
+            // always aligned to min_storage_buffer_offset_alignment. Slice offsets and lengths
+            // are passed as push constants.
             let write_descriptor_set = WriteDescriptorSet::buffer_array(
                 0,
                 0,
@@ -728,6 +1065,9 @@ impl Frame {
                 buffer.epoch.store(self.epoch, Ordering::SeqCst);
             }
         }
+        if let Some(fence) = fence {
+            fence.epoch.store(self.epoch, Ordering::SeqCst);
+        }
         if let Some(debug_printf_panic) = debug_printf_panic {
             self.debug_kernel_desc_panic
                 .replace((kernel_desc.clone(), debug_printf_panic));
@@ -737,6 +1077,15 @@ impl Frame {
         self.buffers.clear();
         self.debug_kernel_desc_panic.take();
     }
+    // Discards recorded commands, leaving an empty command buffer to be submitted so that
+    // the frame's epoch is still signaled.
+    unsafe fn cancel(&mut self) -> Result<()> {
+        self.command_buffer_builder.take();
+        unsafe {
+            self.finish();
+            self.begin()
+        }
+    }
 }
 
 impl Drop for Frame {
@@ -755,20 +1104,27 @@ struct Worker {
     empty: Arc<AtomicBool>,
     pending: Arc<AtomicU64>,
     ready_frame: Arc<Mutex<Frame>>,
-    pending_frame: Frame,
+    // Submitted frames in epoch order, with their command buffer and the messenger for
+    // debug printf if any.
+    pending_frames: VecDeque<(Frame, UnsafeCommandBuffer, Option<DebugUtilsMessenger>)>,
+    free_frames: Vec<Frame>,
+    max_in_flight: usize,
     engine_exited: Arc<AtomicBool>,
     worker_exited: Arc<AtomicBool>,
 }
 
 impl Worker {
-    fn new(queue: Arc<Queue>, index: usize) -> Result<Self> {
+    fn new(queue: Arc<Queue>, index: usize, max_in_flight: usize) -> Result<Self> {
         let semaphore = Arc::new(new_semaphore(queue.device())?);
         let empty = Arc::new(AtomicBool::new(true));
         let pending = Arc::new(AtomicU64::default());
         let mut ready_frame = Frame::new(queue.clone())?;
         ready_frame.epoch = 1;
         let ready_frame = Arc::new(Mutex::new(ready_frame));
-        let pending_frame = Frame::new(queue.clone())?;
+        let max_in_flight = max_in_flight.max(1);
+        let free_frames = (0..max_in_flight)
+            .map(|_| Frame::new(queue.clone()))
+            .collect::<Result<_>>()?;
         let engine_exited = Arc::new(AtomicBool::default());
         let worker_exited = Arc::new(AtomicBool::default());
         Ok(Self {
@@ -778,40 +1134,70 @@ impl Worker {
             empty,
             pending,
             ready_frame,
-            pending_frame,
+            pending_frames: VecDeque::with_capacity(max_in_flight),
+            free_frames,
+            max_in_flight,
             engine_exited,
             worker_exited,
         })
     }
+    // Finishes the oldest pending frame if it has finished, or if `wait`, once it finishes.
+    fn finish_frame(&mut self, wait: bool) -> bool {
+        let Some((frame, ..)) = self.pending_frames.front() else {
+            return false;
+        };
+        loop {
+            let result =
+                unsafe { wait_semaphore(self.queue.device(), &self.semaphore, frame.epoch) };
+            match result {
+                ash::vk::Result::SUCCESS => break,
+                ash::vk::Result::TIMEOUT if wait => std::hint::spin_loop(),
+                ash::vk::Result::TIMEOUT => return false,
+                _ => result.result().unwrap(),
+            }
+        }
+        let (mut frame, _command_buffer, _messenger) = self.pending_frames.pop_front().unwrap();
+        unsafe {
+            frame.finish();
+        }
+        self.free_frames.push(frame);
+        true
+    }
     fn run(&mut self) {
         let id = DeviceId {
             index: self.index,
             handle: self.queue.device().handle().as_raw().try_into().unwrap(),
         };
         loop {
-            while self.empty.load(Ordering::SeqCst) {
+            while self.finish_frame(false) {}
+            if self.pending_frames.len() >= self.max_in_flight {
+                self.finish_frame(true);
+                continue;
+            }
+            if self.empty.load(Ordering::SeqCst) {
                 if self.engine_exited.load(Ordering::SeqCst) {
+                    while self.finish_frame(true) {}
                     return;
                 }
                 std::hint::spin_loop();
+                continue;
             }
+            let mut frame = self.free_frames.pop().unwrap();
             {
                 let mut ready_frame = self.ready_frame.lock();
-                self.pending_frame.epoch = ready_frame.epoch + 1;
+                frame.epoch = ready_frame.epoch + 1;
                 self.empty.store(true, Ordering::SeqCst);
-                std::mem::swap(&mut *ready_frame, &mut self.pending_frame);
+                std::mem::swap(&mut *ready_frame, &mut frame);
             }
-            self.pending
-                .store(self.pending_frame.epoch, Ordering::SeqCst);
-            let command_buffer = self
-                .pending_frame
+            self.pending.store(frame.epoch, Ordering::SeqCst);
+            let command_buffer = frame
                 .command_buffer_builder
                 .take()
                 .unwrap()
                 .build()
                 .unwrap();
-            let _messenger = if let Some((kernel_desc, panicked)) =
-                self.pending_frame.debug_kernel_desc_panic.take()
+            let messenger = if let Some((kernel_desc, panicked)) =
+                frame.debug_kernel_desc_panic.take()
             {
                 Some(
                     unsafe {
@@ -849,27 +1235,12 @@ impl Worker {
                     &mut guard,
                     &command_buffer,
                     &self.semaphore,
-                    self.pending_frame.epoch,
+                    frame.epoch,
                 )
                 .unwrap();
             });
-            loop {
-                let result = unsafe {
-                    wait_semaphore(
-                        self.queue.device(),
-                        &self.semaphore,
-                        self.pending_frame.epoch,
-                    )
-                };
-                match result {
-                    ash::vk::Result::SUCCESS => break,
-                    ash::vk::Result::TIMEOUT => std::hint::spin_loop(),
-                    _ => result.result().unwrap(),
-                }
-            }
-            unsafe {
-                self.pending_frame.finish();
-            }
+            self.pending_frames
+                .push_back((frame, command_buffer, messenger));
         }
     }
 }
@@ -897,13 +1268,37 @@ const fn aligned_ceil(x: usize, align: usize) -> usize {
     }
 }
 
+// Counts the bytes of an allocation in Engine::allocated_bytes while alive.
+struct Allocation {
+    size: u64,
+    allocated_bytes: Arc<AtomicU64>,
+}
+
+impl Allocation {
+    fn new(size: u64, allocated_bytes: &Arc<AtomicU64>) -> Arc<Self> {
+        allocated_bytes.fetch_add(size, Ordering::SeqCst);
+        Arc::new(Self {
+            size,
+            allocated_bytes: allocated_bytes.clone(),
+        })
+    }
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        self.allocated_bytes.fetch_sub(self.size, Ordering::SeqCst);
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct DeviceBuffer {
     inner: Option<Subbuffer<[u8]>>,
+    allocation: Option<Arc<Allocation>>,
     engine: Arc<Engine>,
     offset: usize,
     len: usize,
     epoch: Arc<AtomicU64>,
+    scratch: bool,
 }
 
 impl DeviceBuffer {
@@ -911,33 +1306,110 @@ impl DeviceBuffer {
     const MAX_SIZE: usize = aligned_ceil(Self::MAX_LEN, Self::ALIGN);
     const ALIGN: usize = 256;
     const HOST_BUFFER_SIZE: usize = 32_000_000;
-    fn host_visible(&self) -> bool {
-        if let Some(inner) = self.inner.as_ref() {
-            inner.mapped_ptr().is_some()
-        } else {
-            false
+}
+
+pub(super) struct DeviceBufferMap {
+    buffer: Subbuffer<[u8]>,
+    range: Range<u64>,
+    mutable: bool,
+}
+
+impl DeviceBufferMap {
+    fn new(buffer: Subbuffer<[u8]>, mutable: bool) -> Result<Self> {
+        let BufferMemory::Normal(allocation) = buffer.buffer().memory() else {
+            unreachable!()
+        };
+        let atom_size = buffer
+            .device()
+            .physical_device()
+            .properties()
+            .non_coherent_atom_size
+            .as_devicesize();
+        let start = aligned_floor(buffer.offset() as usize, atom_size as usize) as u64;
+        let end = aligned_ceil(
+            (buffer.offset() + buffer.size()) as usize,
+            atom_size as usize,
+        ) as u64;
+        let range = start..end.min(allocation.size());
+        unsafe {
+            allocation.invalidate_range(range.clone())?;
+        }
+        Ok(Self {
+            buffer,
+            range,
+            mutable,
+        })
+    }
+}
+
+impl Deref for DeviceBufferMap {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        let ptr = self.buffer.mapped_ptr().unwrap().as_ptr() as *const u8;
+        unsafe { std::slice::from_raw_parts(ptr, self.buffer.size() as usize) }
+    }
+}
+
+impl DerefMut for DeviceBufferMap {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        debug_assert!(self.mutable);
+        let ptr = self.buffer.mapped_ptr().unwrap().as_ptr() as *mut u8;
+        unsafe { std::slice::from_raw_parts_mut(ptr, self.buffer.size() as usize) }
+    }
+}
+
+impl Drop for DeviceBufferMap {
+    fn drop(&mut self) {
+        if self.mutable {
+            let BufferMemory::Normal(allocation) = self.buffer.buffer().memory() else {
+                unreachable!()
+            };
+            let result = unsafe { allocation.flush_range(self.range.clone()) };
+            if !std::thread::panicking() {
+                result.unwrap();
+            }
         }
     }
 }
 
 impl DeviceEngineBuffer for DeviceBuffer {
     type Engine = Engine;
+    type Map = DeviceBufferMap;
     fn engine(&self) -> &Arc<Self::Engine> {
         &self.engine
     }
-    unsafe fn uninit(engine: Arc<Engine>, len: usize) -> Result<Self> {
+    unsafe fn uninit(
+        engine: Arc<Engine>,
+        len: usize,
+        usage: crate::buffer::BufferUsage,
+        memory: crate::buffer::MemoryKind,
+    ) -> Result<Self> {
         use vulkano::{memory::allocator::AllocationCreationError, VulkanError};
         let inner = if len > 0 {
             let len = aligned_ceil(len, Self::ALIGN);
-            let usage =
-                BufferUsage::STORAGE_BUFFER | BufferUsage::TRANSFER_DST | BufferUsage::TRANSFER_SRC;
+            let usage = {
+                use crate::buffer::BufferUsage as Usage;
+                let mut buffer_usage = BufferUsage::STORAGE_BUFFER
+                    | BufferUsage::TRANSFER_DST
+                    | BufferUsage::TRANSFER_SRC;
+                if usage.contains(Usage::INDIRECT) {
+                    buffer_usage |= BufferUsage::INDIRECT_BUFFER;
+                }
+                if usage.contains(Usage::UNIFORM) {
+                    buffer_usage |= BufferUsage::UNIFORM_BUFFER;
+                }
+                buffer_usage
+            };
             let buffer_info = BufferCreateInfo {
                 usage,
                 size: len.try_into().unwrap(),
                 ..Default::default()
             };
             let allocation_info = AllocationCreateInfo {
-                usage: MemoryUsage::DeviceOnly,
+                usage: match memory {
+                    crate::buffer::MemoryKind::DeviceLocal => MemoryUsage::DeviceOnly,
+                    crate::buffer::MemoryKind::HostVisible => MemoryUsage::Download,
+                },
                 ..Default::default()
             };
             use vulkano::{
@@ -972,13 +1444,93 @@ impl DeviceEngineBuffer for DeviceBuffer {
         } else {
             None
         };
-        Ok(Self {
+        let allocation = inner
+            .as_ref()
+            .map(|inner| Allocation::new(inner.size(), &engine.allocated_bytes));
+        let buffer = Self {
             inner,
+            allocation,
             engine,
             offset: 0,
             len,
             epoch: Arc::new(AtomicU64::new(0)),
-        })
+            scratch: false,
+        };
+        if buffer.engine.info.zero_uninit() {
+            if let Some(inner) = buffer.inner.as_ref() {
+                unsafe {
+                    buffer.engine.fill_zero(inner.clone(), &buffer)?;
+                }
+            }
+        }
+        Ok(buffer)
+    }
+    unsafe fn scratch(engine: Arc<Engine>, len: usize) -> Result<Self> {
+        if len == 0 {
+            return unsafe {
+                Self::uninit(
+                    engine,
+                    len,
+                    crate::buffer::BufferUsage::empty(),
+                    crate::buffer::MemoryKind::DeviceLocal,
+                )
+            };
+        }
+        let size = aligned_ceil(len, Self::ALIGN) as u64;
+        let mut pool = engine.scratch.lock();
+        if !pool.buffers.is_empty() {
+            let finished = unsafe { semaphore_value(engine.queue.device(), &engine.semaphore) }
+                .map_err(|_| DeviceLost(engine.id()))?;
+            let index = pool
+                .buffers
+                .iter()
+                .enumerate()
+                .filter(|(_, (epoch, buffer, _))| *epoch <= finished && buffer.size() >= size)
+                .min_by_key(|(_, (_, buffer, _))| buffer.size())
+                .map(|(index, _)| index);
+            if let Some(index) = index {
+                let (_, buffer, allocation) = pool.buffers.swap_remove(index);
+                drop(pool);
+                return Ok(Self {
+                    inner: Some(buffer),
+                    allocation: Some(allocation),
+                    engine,
+                    offset: 0,
+                    len,
+                    epoch: Arc::new(AtomicU64::new(0)),
+                    scratch: true,
+                });
+            }
+        }
+        let mut buffer = unsafe {
+            Self::uninit(
+                engine.clone(),
+                len,
+                crate::buffer::BufferUsage::empty(),
+                crate::buffer::MemoryKind::DeviceLocal,
+            )?
+        };
+        buffer.scratch = true;
+        pool.allocated += size as usize;
+        pool.high_water_mark = pool.high_water_mark.max(pool.allocated);
+        Ok(buffer)
+    }
+    fn recycle(&self) {
+        let (Some(inner), Some(allocation)) = (
+            self.inner.as_ref().filter(|_| self.scratch),
+            self.allocation.as_ref(),
+        ) else {
+            return;
+        };
+        // Any work using the buffer was recorded before it was dropped.
+        let epoch = self.engine.epoch.load(Ordering::SeqCst);
+        let mut pool = self.engine.scratch.lock();
+        pool.buffers
+            .push((epoch, inner.clone(), allocation.clone()));
+        if pool.buffers.len() > ScratchPool::MAX_BUFFERS {
+            let (_, buffer, _) = pool.buffers.remove(0);
+            pool.allocated -= buffer.size() as usize;
+        }
     }
     fn upload(&self, data: &[u8]) -> Result<()> {
         debug_assert_eq!(data.len(), self.len);
@@ -999,6 +1551,7 @@ impl DeviceEngineBuffer for DeviceBuffer {
             buffer.write().unwrap().copy_from_slice(data);
             return Ok(());
         }
+        let cancels = engine.cancels();
         let mut offset = 0;
         for chunk in data.chunks(Self::HOST_BUFFER_SIZE) {
             let mut host_buffer = engine.host_buffer_receiver.recv().unwrap();
@@ -1009,12 +1562,12 @@ impl DeviceEngineBuffer for DeviceBuffer {
             host_slice.write().unwrap().copy_from_slice(chunk);
             engine.wait_pending(buffer_epoch)?;
             unsafe {
-                engine.transfer(host_slice, buffer_slice, &mut host_buffer, Some(self))?;
+                engine.transfer(host_slice, buffer_slice, Some(&mut host_buffer), Some(self))?;
             }
             engine.host_buffer_sender.send(host_buffer).unwrap();
             offset += size;
         }
-        Ok(())
+        engine.check_canceled(cancels)
     }
     fn download(&self, data: &mut [u8]) -> Result<()> {
         debug_assert_eq!(data.len(), self.len);
@@ -1035,6 +1588,7 @@ impl DeviceEngineBuffer for DeviceBuffer {
             data.copy_from_slice(&buffer.read().unwrap());
             return Ok(());
         }
+        let cancels = engine.cancels();
         struct HostCopy<'a> {
             chunk: &'a mut [u8],
             host_buffer: HostBuffer,
@@ -1052,7 +1606,12 @@ impl DeviceEngineBuffer for DeviceBuffer {
                 let host_slice = host_buffer.inner.clone().slice(0..size);
                 engine.wait_pending(buffer_epoch)?;
                 unsafe {
-                    engine.transfer(buffer_slice, host_slice.clone(), &mut host_buffer, None)?;
+                    engine.transfer(
+                        buffer_slice,
+                        host_slice.clone(),
+                        Some(&mut host_buffer),
+                        None,
+                    )?;
                 }
                 host_copy.replace(HostCopy {
                     chunk,
@@ -1072,7 +1631,35 @@ impl DeviceEngineBuffer for DeviceBuffer {
                 engine.host_buffer_sender.send(host_buffer).unwrap();
             }
         }
-        Ok(())
+        engine.check_canceled(cancels)
+    }
+    fn wait(&self) -> Result<(), DeviceLost> {
+        self.engine.wait_epoch(self.epoch.load(Ordering::SeqCst))
+    }
+    fn is_finished(&self) -> Result<bool, DeviceLost> {
+        Ok(self.engine.finished_epoch()? >= self.epoch.load(Ordering::SeqCst))
+    }
+    fn host_visible(&self) -> bool {
+        if let Some(inner) = self.inner.as_ref() {
+            inner.mapped_ptr().is_some()
+        } else {
+            false
+        }
+    }
+    fn map(&self, mutable: bool) -> Result<Self::Map> {
+        let Some(buffer) = self.inner.as_ref().filter(|_| self.host_visible()) else {
+            bail!("Buffer is not host visible!");
+        };
+        if mutable {
+            // Reads are not tracked per buffer, so wait for all previous work.
+            self.engine.wait()?;
+        } else {
+            self.engine.wait_epoch(self.epoch.load(Ordering::SeqCst))?;
+        }
+        let buffer = buffer
+            .clone()
+            .slice(self.offset as u64..(self.offset + self.len) as u64);
+        DeviceBufferMap::new(buffer, mutable)
     }
     fn transfer(&self, dst: &Self) -> Result<()> {
         debug_assert_eq!(dst.len, self.len);
@@ -1110,6 +1697,8 @@ impl DeviceEngineBuffer for DeviceBuffer {
             engine2.wait_epoch(buffer2_epoch)?;
             return self.download(&mut buffer2.write().unwrap());
         }
+        let cancels1 = engine1.cancels();
+        let cancels2 = engine2.cancels();
         struct HostCopy {
             host_buffer1: HostBuffer,
             host_slice1: Subbuffer<[u8]>,
@@ -1134,7 +1723,7 @@ impl DeviceEngineBuffer for DeviceBuffer {
                     engine1.transfer(
                         buffer_slice1,
                         host_slice1.clone(),
-                        &mut host_buffer1,
+                        Some(&mut host_buffer1),
                         None,
                     )?;
                 }
@@ -1164,14 +1753,46 @@ impl DeviceEngineBuffer for DeviceBuffer {
                 engine1.host_buffer_sender.send(host_buffer1).unwrap();
                 engine2.wait_pending(buffer2_epoch)?;
                 unsafe {
-                    engine2.transfer(host_slice2, buffer_slice2, &mut host_buffer2, Some(dst))?;
+                    engine2.transfer(
+                        host_slice2,
+                        buffer_slice2,
+                        Some(&mut host_buffer2),
+                        Some(dst),
+                    )?;
                 }
                 engine2.host_buffer_sender.send(host_buffer2).unwrap();
             } else if size == 0 {
                 break;
             }
         }
-        Ok(())
+        engine1.check_canceled(cancels1)?;
+        engine2.check_canceled(cancels2)
+    }
+    fn copy(&self, dst: &Self) -> Result<()> {
+        debug_assert_eq!(dst.len, self.len);
+        debug_assert!(Arc::ptr_eq(&self.engine, &dst.engine));
+        if self.len == 0 {
+            return Ok(());
+        }
+        let (buffer1, buffer2) =
+            if let Some((buffer1, buffer2)) = self.inner.as_ref().zip(dst.inner.as_ref()) {
+                let buffer1 = buffer1
+                    .clone()
+                    .slice(self.offset as u64..(self.offset + self.len) as u64);
+                let buffer2 = buffer2
+                    .clone()
+                    .slice(dst.offset as u64..(dst.offset + dst.len) as u64);
+                (buffer1, buffer2)
+            } else {
+                return Ok(());
+            };
+        let engine = &self.engine;
+        let epoch = self
+            .epoch
+            .load(Ordering::SeqCst)
+            .max(dst.epoch.load(Ordering::SeqCst));
+        engine.wait_pending(epoch)?;
+        unsafe { engine.transfer(buffer1, buffer2, None, Some(dst)) }
     }
     fn offset(&self) -> usize {
         self.offset
@@ -1195,6 +1816,28 @@ impl DeviceEngineBuffer for DeviceBuffer {
             ..Self::clone(self)
         }))
     }
+    fn raw_handle(&self) -> Option<(u64, u64, u64)> {
+        let inner = self.inner.as_ref()?;
+        Some((
+            inner.buffer().handle().as_raw(),
+            inner.offset() + self.offset as u64,
+            self.len as u64,
+        ))
+    }
+    fn overlaps(&self, other: &Self) -> bool {
+        if self.len == 0 || other.len == 0 {
+            return false;
+        }
+        let (Some(a), Some(b)) = (self.inner.as_ref(), other.inner.as_ref()) else {
+            return false;
+        };
+        if !Arc::ptr_eq(a.buffer(), b.buffer()) {
+            return false;
+        }
+        let a_start = a.offset() as usize + self.offset;
+        let b_start = b.offset() as usize + other.offset;
+        a_start < b_start + other.len && b_start < a_start + self.len
+    }
 }
 
 #[derive(Clone)]
@@ -1338,14 +1981,23 @@ impl DeviceEngineKernel for Kernel {
             compute_pipeline,
         }))
     }
-    fn engine(&self) -> &Arc<Self::Engine> {
-        &self.engine
+    fn get_cached(engine: &Arc<Self::Engine>, key: &KernelKey) -> Option<Arc<Self>> {
+        let KernelInner {
+            desc,
+            compute_pipeline,
+        } = engine.kernels.get(key)?.clone();
+        Some(Arc::new(Kernel {
+            engine: engine.clone(),
+            desc,
+            compute_pipeline,
+        }))
     }
     unsafe fn dispatch(
         &self,
         groups: u32,
         buffers: &[Arc<Self::DeviceBuffer>],
         push_consts: Vec<u8>,
+        fence: Option<&Fence>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
         let engine = &self.engine;
@@ -1359,6 +2011,7 @@ impl DeviceEngineKernel for Kernel {
                 groups,
                 buffers,
                 &push_consts,
+                fence,
                 debug_printf_panic,
             )
         }
@@ -1367,3 +2020,50 @@ impl DeviceEngineKernel for Kernel {
         &self.desc
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enabled_device_features_missing_float64() {
+        let supported_features = vulkano::device::Features {
+            vulkan_memory_model: true,
+            timeline_semaphore: true,
+            shader_int8: true,
+            shader_int16: true,
+            shader_int64: true,
+            shader_float16: true,
+            shader_integer_dot_product: true,
+            cooperative_matrix: true,
+            storage_buffer8_bit_access: true,
+            ..vulkano::device::Features::empty()
+        };
+        let device_features = enabled_device_features(
+            Features::all(),
+            &supported_features,
+            &vulkano::device::DeviceExtensions::empty(),
+            Version::V1_2,
+        );
+        assert!(device_features.shader_int64);
+        assert!(device_features.storage_buffer8_bit_access);
+        assert!(!device_features.shader_float64);
+        assert!(!device_features.subgroup_size_control);
+        assert!(!device_features.storage_buffer16_bit_access);
+        assert!(!device_features.shader_integer_dot_product);
+        assert!(!device_features.cooperative_matrix);
+        let device_features = enabled_device_features(
+            Features::INT8,
+            &supported_features,
+            &vulkano::device::DeviceExtensions {
+                nv_cooperative_matrix: true,
+                ..vulkano::device::DeviceExtensions::empty()
+            },
+            Version::V1_3,
+        );
+        assert!(device_features.shader_int8);
+        assert!(!device_features.shader_int16);
+        assert!(!device_features.cooperative_matrix);
+        assert!(!device_features.shader_integer_dot_product);
+    }
+}
@@ -0,0 +1,460 @@
+/*!
+Pipelines of [kernels](crate::kernel) can be validated before they are dispatched.
+
+A [`PipelineGraph`] describes kernels, the slices they will be dispatched with, and the
+edges where one kernel's output is another kernel's input. Kernels in the same stage may
+execute concurrently. [`.validate()`](PipelineGraph::validate) checks the whole graph and
+returns every problem found, instead of failing on the first dispatch.
+
+```no_run
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device, graph::PipelineGraph};
+# #[krnl::macros::module]
+# #[krnl(no_build)]
+# mod kernels {
+# use krnl::macros::kernel;
+# #[kernel] pub fn scale(#[item] x: f32, #[item] y: &mut f32) {}
+# }
+# use kernels::scale;
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let x = Buffer::<f32>::zeros(device.clone(), 100)?;
+let y = Buffer::<f32>::zeros(device.clone(), 100)?;
+let z = Buffer::<f32>::zeros(device.clone(), 100)?;
+let kernel = scale::builder()?.build(device.clone())?;
+let mut graph = PipelineGraph::new();
+let a = graph.add_kernel(0, &kernel, [x.as_scalar_slice(), y.as_scalar_slice()]);
+let b = graph.add_kernel(1, &kernel, [y.as_scalar_slice(), z.as_scalar_slice()]);
+graph.connect(a, "y", b, "x");
+if let Err(problems) = graph.validate() {
+    for problem in problems {
+        eprintln!("{problem}");
+    }
+}
+# Ok(())
+# }
+```
+
+This is opt-in, and does not affect dispatch.
+*/
+
+use crate::{buffer::ScalarSlice, device::Device, kernel::KernelInfo, scalar::ScalarType};
+
+/// A problem found by [`PipelineGraph::validate()`].
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum GraphProblem {
+    /// A kernel was added with the wrong number of slices.
+    #[error("Kernel {kernel} expected {expected} slices, found {found}!")]
+    SliceCount {
+        /// The kernel.
+        kernel: String,
+        /// The number of slice parameters.
+        expected: usize,
+        /// The number of slices.
+        found: usize,
+    },
+    /// A slice does not match the type of its parameter.
+    #[error("Kernel {kernel} slice `{param}` expected {expected:?}, found {found:?}!")]
+    SliceType {
+        /// The kernel.
+        kernel: String,
+        /// The slice parameter.
+        param: String,
+        /// The type of the parameter.
+        expected: ScalarType,
+        /// The type of the slice.
+        found: ScalarType,
+    },
+    /// A kernel or slice is on a different device than the first kernel.
+    #[error("Kernel {kernel} {what} is on {found:?}, expected {expected:?}!")]
+    Device {
+        /// The kernel.
+        kernel: String,
+        /// The kernel or slice.
+        what: String,
+        /// The device of the first kernel.
+        expected: Device,
+        /// The device found.
+        found: Device,
+    },
+    /// An edge refers to a kernel that was not added.
+    #[error("Edge {index} refers to kernel {kernel}, but only {kernels} were added!")]
+    UnknownKernel {
+        /// The index of the edge.
+        index: usize,
+        /// The index of the kernel.
+        kernel: usize,
+        /// The number of kernels.
+        kernels: usize,
+    },
+    /// An edge refers to a parameter the kernel does not have.
+    #[error("Kernel {kernel} has no slice `{param}`!")]
+    UnknownParam {
+        /// The kernel.
+        kernel: String,
+        /// The parameter.
+        param: String,
+    },
+    /// The output of an edge is not a mutable slice.
+    #[error("Edge {from} -> {to} output is not mutable!")]
+    EdgeImmutable {
+        /// The output, as `kernel.param`.
+        from: String,
+        /// The input, as `kernel.param`.
+        to: String,
+    },
+    /// The output and input of an edge have different types.
+    #[error("Edge {from} -> {to} output is {from_type:?}, input is {to_type:?}!")]
+    EdgeType {
+        /// The output, as `kernel.param`.
+        from: String,
+        /// The input, as `kernel.param`.
+        to: String,
+        /// The type of the output.
+        from_type: ScalarType,
+        /// The type of the input.
+        to_type: ScalarType,
+    },
+    /// The output and input of an edge have different lengths.
+    #[error("Edge {from} -> {to} output len is {from_len}, input len is {to_len}!")]
+    EdgeLen {
+        /// The output, as `kernel.param`.
+        from: String,
+        /// The input, as `kernel.param`.
+        to: String,
+        /// The length of the output.
+        from_len: usize,
+        /// The length of the input.
+        to_len: usize,
+    },
+    /// A mutable slice overlaps another slice used in the same stage.
+    #[error("Stage {stage} mutable slice {mutable} overlaps {other}!")]
+    Alias {
+        /// The stage.
+        stage: usize,
+        /// The mutable slice, as `kernel.param`.
+        mutable: String,
+        /// The other slice, as `kernel.param`.
+        other: String,
+    },
+}
+
+struct Node<'a> {
+    stage: usize,
+    name: String,
+    device: Device,
+    params: Vec<(String, ScalarType, bool, bool)>,
+    slices: Vec<ScalarSlice<'a>>,
+}
+
+impl Node<'_> {
+    fn param(&self, param: &str) -> Option<usize> {
+        self.params.iter().position(|(name, ..)| name == param)
+    }
+}
+
+struct Edge {
+    from: (usize, String),
+    to: (usize, String),
+}
+
+/** A graph of kernels.
+
+See the [module](self) docs. */
+#[derive(Default)]
+pub struct PipelineGraph<'a> {
+    nodes: Vec<Node<'a>>,
+    edges: Vec<Edge>,
+}
+
+impl<'a> PipelineGraph<'a> {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /** Adds a kernel.
+
+    `slices` are the slices the kernel will be dispatched with, in the order of its slice
+    parameters. Kernels with the same `stage` may execute concurrently.
+
+    Returns the index of the kernel, for [`.connect()`](Self::connect). */
+    pub fn add_kernel(
+        &mut self,
+        stage: usize,
+        kernel: &dyn KernelInfo,
+        slices: impl IntoIterator<Item = ScalarSlice<'a>>,
+    ) -> usize {
+        self.add_node(
+            stage,
+            kernel.name(),
+            kernel.device(),
+            kernel.slice_params(),
+            slices,
+        )
+    }
+    fn add_node(
+        &mut self,
+        stage: usize,
+        name: &str,
+        device: Device,
+        params: Vec<(String, ScalarType, bool, bool)>,
+        slices: impl IntoIterator<Item = ScalarSlice<'a>>,
+    ) -> usize {
+        self.nodes.push(Node {
+            stage,
+            name: format!("{}[{}]", name, self.nodes.len()),
+            device,
+            params,
+            slices: slices.into_iter().collect(),
+        });
+        self.nodes.len() - 1
+    }
+    /// Connects the `output` slice of kernel `from` to the `input` slice of kernel `to`.
+    pub fn connect(&mut self, from: usize, output: &str, to: usize, input: &str) {
+        self.edges.push(Edge {
+            from: (from, output.to_string()),
+            to: (to, input.to_string()),
+        });
+    }
+    /** Validates the graph.
+
+    Checks that:
+    - Each kernel has a slice of the right type for each slice parameter.
+    - All kernels and slices are on the same device.
+    - The output of each edge is mutable, and has the same type and length as the input.
+    - Mutable slices do not overlap any other slice used in the same stage.
+
+    # Errors
+    All problems found, see [`GraphProblem`]. */
+    pub fn validate(&self) -> Result<(), Vec<GraphProblem>> {
+        let mut problems = Vec::new();
+        self.validate_nodes(&mut problems);
+        self.validate_edges(&mut problems);
+        self.validate_aliasing(&mut problems);
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+    fn validate_nodes(&self, problems: &mut Vec<GraphProblem>) {
+        let expected_device = self.nodes.first().map(|node| node.device.clone());
+        for node in self.nodes.iter() {
+            let expected_device = expected_device.as_ref().unwrap();
+            if node.device != *expected_device {
+                problems.push(GraphProblem::Device {
+                    kernel: node.name.clone(),
+                    what: "kernel".to_string(),
+                    expected: expected_device.clone(),
+                    found: node.device.clone(),
+                });
+            }
+            if node.slices.len() != node.params.len() {
+                problems.push(GraphProblem::SliceCount {
+                    kernel: node.name.clone(),
+                    expected: node.params.len(),
+                    found: node.slices.len(),
+                });
+            }
+            for ((param, scalar_type, ..), slice) in node.params.iter().zip(node.slices.iter()) {
+                if slice.scalar_type() != *scalar_type {
+                    problems.push(GraphProblem::SliceType {
+                        kernel: node.name.clone(),
+                        param: param.clone(),
+                        expected: *scalar_type,
+                        found: slice.scalar_type(),
+                    });
+                }
+                let device = slice.device();
+                if device != node.device {
+                    problems.push(GraphProblem::Device {
+                        kernel: node.name.clone(),
+                        what: format!("slice `{param}`"),
+                        expected: node.device.clone(),
+                        found: device,
+                    });
+                }
+            }
+        }
+    }
+    fn validate_edges(&self, problems: &mut Vec<GraphProblem>) {
+        for (index, edge) in self.edges.iter().enumerate() {
+            let mut endpoints = Vec::with_capacity(2);
+            for (kernel, param) in [&edge.from, &edge.to] {
+                let Some(node) = self.nodes.get(*kernel) else {
+                    problems.push(GraphProblem::UnknownKernel {
+                        index,
+                        kernel: *kernel,
+                        kernels: self.nodes.len(),
+                    });
+                    continue;
+                };
+                let Some(param_index) = node.param(param) else {
+                    problems.push(GraphProblem::UnknownParam {
+                        kernel: node.name.clone(),
+                        param: param.clone(),
+                    });
+                    continue;
+                };
+                endpoints.push((node, param_index));
+            }
+            let [(from_node, from_index), (to_node, to_index)] = endpoints[..] else {
+                continue;
+            };
+            let from = format!("{}.{}", from_node.name, edge.from.1);
+            let to = format!("{}.{}", to_node.name, edge.to.1);
+            let (_, from_type, from_mutable, _) = &from_node.params[from_index];
+            let (_, to_type, ..) = &to_node.params[to_index];
+            if !from_mutable {
+                problems.push(GraphProblem::EdgeImmutable {
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+            if from_type != to_type {
+                problems.push(GraphProblem::EdgeType {
+                    from: from.clone(),
+                    to: to.clone(),
+                    from_type: *from_type,
+                    to_type: *to_type,
+                });
+            }
+            if let Some((from_slice, to_slice)) = from_node
+                .slices
+                .get(from_index)
+                .zip(to_node.slices.get(to_index))
+            {
+                if from_slice.len() != to_slice.len() {
+                    problems.push(GraphProblem::EdgeLen {
+                        from,
+                        to,
+                        from_len: from_slice.len(),
+                        to_len: to_slice.len(),
+                    });
+                }
+            }
+        }
+    }
+    fn validate_aliasing(&self, problems: &mut Vec<GraphProblem>) {
+        let slices: Vec<_> = self
+            .nodes
+            .iter()
+            .flat_map(|node| {
+                node.params
+                    .iter()
+                    .zip(node.slices.iter())
+                    .map(move |((param, _, mutable, _), slice)| (node, param, *mutable, slice))
+            })
+            .collect();
+        for (i, (node, param, mutable, slice)) in slices.iter().enumerate() {
+            if !mutable {
+                continue;
+            }
+            for (j, (other_node, other_param, other_mutable, other_slice)) in
+                slices.iter().enumerate()
+            {
+                if i == j || node.stage != other_node.stage {
+                    continue;
+                }
+                // report each pair of mutable slices once
+                if *other_mutable && j < i {
+                    continue;
+                }
+                if slice.overlaps(other_slice) {
+                    problems.push(GraphProblem::Alias {
+                        stage: node.stage,
+                        mutable: format!("{}.{param}", node.name),
+                        other: format!("{}.{other_param}", other_node.name),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        buffer::{kernels, Buffer},
+        kernel::__private::KernelBuilder,
+    };
+
+    // kernels can't be built on the host, so add the builder directly
+    fn add_builder<'a>(
+        graph: &mut PipelineGraph<'a>,
+        stage: usize,
+        builder: &dyn AsRef<KernelBuilder>,
+        slices: [ScalarSlice<'a>; 2],
+    ) -> usize {
+        let builder = builder.as_ref();
+        graph.add_node(
+            stage,
+            builder.name(),
+            Device::host(),
+            builder.slice_params(),
+            slices,
+        )
+    }
+
+    #[test]
+    fn pipeline_graph_validate() {
+        let x = Buffer::from(vec![0u32; 10]);
+        let y = Buffer::from(vec![0f32; 10]);
+        let z = Buffer::from(vec![0f32; 8]);
+        let y_head = y.slice(..8).unwrap();
+        let cast = kernels::cast_u32_f32::builder().unwrap();
+        let mut graph = PipelineGraph::new();
+        let a = add_builder(
+            &mut graph,
+            0,
+            &cast,
+            [x.as_scalar_slice(), y.as_scalar_slice()],
+        );
+        let b = add_builder(
+            &mut graph,
+            1,
+            &kernels::cast_f32_f32::builder().unwrap(),
+            [y.as_scalar_slice(), y_head.as_scalar_slice()],
+        );
+        graph.connect(a, "y", b, "x");
+        graph.validate().unwrap_err();
+
+        let mut graph = PipelineGraph::new();
+        let a = add_builder(
+            &mut graph,
+            0,
+            &cast,
+            [x.as_scalar_slice(), y.as_scalar_slice()],
+        );
+        let b = add_builder(
+            &mut graph,
+            1,
+            &kernels::cast_f32_f32::builder().unwrap(),
+            [y.as_scalar_slice(), z.as_scalar_slice()],
+        );
+        graph.connect(a, "y", b, "x");
+        graph.validate().unwrap();
+        graph.connect(b, "x", a, "x");
+        graph.connect(a, "y", b, "y");
+        graph.connect(a, "w", 5, "x");
+        add_builder(
+            &mut graph,
+            0,
+            &cast,
+            [x.as_scalar_slice(), x.as_scalar_slice()],
+        );
+        let problems = graph.validate().unwrap_err();
+        let problems: Vec<_> = problems.iter().map(ToString::to_string).collect();
+        assert_eq!(problems.len(), 8, "{problems:#?}");
+        assert!(problems.iter().any(|x| x.contains("output is not mutable")));
+        assert!(problems.iter().any(|x| x.contains("input is U32")));
+        assert!(problems.iter().any(|x| x.contains("input len is 8")));
+        assert!(problems.iter().any(|x| x.contains("has no slice `w`")));
+        assert!(problems.iter().any(|x| x.contains("but only 3 were added")));
+        assert!(problems
+            .iter()
+            .any(|x| x.contains("expected F32, found U32")));
+        assert!(problems.iter().any(|x| x.contains("overlaps")));
+    }
+}
@@ -99,6 +99,16 @@ abZy8000000@}Rn2yGJu{w.WVIuQ#sT$h4DaGh)Tk%#sdtgN ..
 If the version of krnlc is incompatible with the krnl version, [`module`](#modules)
 will emit a compiler error.
 
+## Optimization
+Kernels are optimized for performance by default. Pass `--opt-level` to select:
+- `speed`: Performance passes (default).
+- `size`: Size passes, producing smaller modules.
+- `none`: Only dead code elimination.
+
+Lower levels compile faster, which can be useful while iterating, but may produce slower
+kernels. Results should be the same at any level. Optimization is disabled with
+`--debug-printf`.
+
 ## Toolchains
 To locate [modules](#modules), krnlc will use the nightly toolchain. Install it with:
 ```text
@@ -261,10 +271,17 @@ if let Some(x) = x.as_host_slice() {
 # }
 ```
 
+Items are one dimensional. All item arguments are indexed by the same item id, and the
+kernel is dispatched with enough threads for the shortest item argument. For 2D problems,
+such as images, use a kernel without items and compute the row and column from the
+global id, providing the width as a push constant.
+
 # Push Constants
 Scalar arguments without an attribute. Unlike [SpecConstants](#specialization), they are
 provided to [`.dispatch(..)`](#dispatch), and do not require rebuilding the kernel.
 
+Arrays of scalars with a literal length, like `filter: [f32; 4]`, are also push constants.
+
 At least 128 bytes of push constants can be used, depending on the device. Each [item](#items) or
 [global](#global-buffers)  argument requires 8 bytes of push constants.
 
@@ -282,6 +299,27 @@ It may range between [min_subgroup_threads](crate::device::DeviceInfo::min_subgr
 `min_subgroup_threads` and `max_subgroup_threads`, each subgroup in a group will have `subgroup_threads`
 threads, unless `threads` per group is not an exact multiple, where the last subgroup will have the remainder of threads.
 
+Kernels without [items](#items) that declare a `u32` push constant named `base` also have a
+`.dispatch_tiled(..)` method, which takes the other arguments and the total number of threads. The total is split
+into dispatches of at most [max_groups](crate::device::DeviceInfo::max_groups) groups, with `base` set to
+the global id of the first thread in each.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel]
+fn iota(base: u32, n: u32, #[global] y: UnsafeSlice<u32>) {
+    use krnl_core::buffer::UnsafeIndex;
+
+    let index = base + kernel.global_id() as u32;
+    if index < n {
+        unsafe {
+            *y.unsafe_index_mut(index as usize) = index;
+        }
+    }
+}
+# }
+```
+
 # Global Buffers
 Visible to all threads. [Slice](krnl_core::buffer::Slice) binds to [Slice](crate::buffer::Slice), [UnsafeSlice](krnl_core::buffer::UnsafeSlice) binds
 to [SliceMut](crate::buffer::SliceMut), provided to [`.dispatch(..)`](#dispatch).
@@ -289,6 +327,10 @@ to [SliceMut](crate::buffer::SliceMut), provided to [`.dispatch(..)`](#dispatch)
 For best performance, consecutive threads should access consecutive elements, allowing loads and stores to be coalesced
 into fewer memory transactions.
 
+Slices can start at any element, without copying. The entire buffer is bound at offset 0, so the binding always
+satisfies [min_storage_buffer_offset_alignment](crate::device::DeviceLimits::min_storage_buffer_offset_alignment).
+The offset and length of the slice are passed to the kernel as push constants.
+
 # Group Buffers
 Shared with all threads in the group, initialized with zeros. Can be used to minimize accesses
 to [global buffers](#global-buffers).
@@ -359,6 +401,19 @@ pub mod saxpy {
         ///
         /// Defaults to [`DeviceInfo::default_threads()`](DeviceInfo::default_threads).
         pub fn with_threads(self, threads: u32) -> Self;
+        /// Disables float optimizations that change results.
+        ///
+        /// Float arithmetic is decorated with `NoContraction`, so that the driver does not fuse
+        /// operations, ie `a * b + c` into an fma, which changes rounding. The spirv-opt performance
+        /// passes that krnlc applies do not reassociate float operations. Dispatches are then
+        /// reproducible on the same device and driver, but may be slower.
+        ///
+        /// Defaults to false.
+        pub fn deterministic(self, deterministic: bool) -> Self;
+        /// Threads per group suited to `device`, based on its subgroup size.
+        ///
+        /// Pass to [`.with_threads()`](Self::with_threads).
+        pub fn auto_threads(&self, device: &Device) -> u32;
         /// Builds the kernel for `device`.
         ///
         /// The kernel is cached, so subsequent calls to `.build()` with identical
@@ -367,8 +422,36 @@ pub mod saxpy {
         /// # Errors
         /// - `device` doesn't have required features.
         /// - The kernel is not supported on `device`.
+        /// - The kernel uses more shared memory than `device` supports.
         /// - [`DeviceLost`].
         pub fn build(&self, device: Device) -> Result<Kernel>;
+        /// Builds the kernel for `device`, creating the pipeline on a background thread.
+        ///
+        /// Returns a ready kernel if it is already cached. Otherwise dispatching blocks until
+        /// the pipeline is created, see [`Kernel::is_ready()`]. Concurrent builds of the same
+        /// kernel share the cache and compile once.
+        ///
+        /// # Errors
+        /// - See [`.build()`](Self::build).
+        /// - Errors from creating the pipeline are returned when dispatching.
+        pub fn build_async(&self, device: Device) -> Result<Kernel>;
+        /// Disassembles the SPIR-V, specialized with threads and spec constants.
+        ///
+        /// Does not require a device, so that specialization can be inspected in tests.
+        ///
+        /// # Errors
+        /// - Threads were not provided with [`.with_threads()`](Self::with_threads).
+        /// - [`DeviceUnavailable`](crate::device::error::DeviceUnavailable) without the "device" feature.
+        pub fn specialized_disassembly(&self) -> Result<String>;
+        /// Bytes of shared memory declared by the kernel, specialized with threads and spec constants.
+        ///
+        /// Does not require a device. Builds fail if this exceeds [`DeviceLimits::max_compute_shared_memory_size()`](crate::device::DeviceLimits::max_compute_shared_memory_size).
+        ///
+        /// # Errors
+        /// - Threads were not provided with [`.with_threads()`](Self::with_threads).
+        /// - The shared memory contains an unsupported type.
+        /// - [`DeviceUnavailable`](crate::device::error::DeviceUnavailable) without the "device" feature.
+        pub fn shared_memory_bytes(&self) -> Result<u32>;
     }
 
     /// Kernel.
@@ -377,6 +460,14 @@ pub mod saxpy {
     impl<G> Kernel<G> {
         /// Threads per group.
         pub fn threads(&self) -> u32;
+        /// Recommended minimum groups to keep the device busy.
+        ///
+        /// Advisory, see [`DeviceInfo::recommended_min_groups()`](crate::device::DeviceInfo::recommended_min_groups).
+        pub fn recommended_min_groups(&self) -> u32;
+        /// Whether the pipeline is created.
+        ///
+        /// Only false while building with `.build_async()`.
+        pub fn is_ready(&self) -> bool;
         /// Global threads to dispatch.
         ///
         /// Implicitly declares groups by rounding up to the next multiple of threads.
@@ -388,6 +479,14 @@ pub mod saxpy {
     }
 
     impl Kernel<WithGroups<true>> {
+        /// Sets global threads to dispatch, in place.
+        ///
+        /// See [`.with_global_threads()`](Kernel::with_global_threads).
+        pub fn set_global_threads(&mut self, global_threads: u32);
+        /// Sets groups to dispatch, in place.
+        ///
+        /// See [`.with_groups()`](Kernel::with_groups).
+        pub fn set_groups(&mut self, groups: u32);
         /// Dispatches the kernel.
         ///
         /// - Waits for immutable access to slice arguments.
@@ -398,7 +497,33 @@ pub mod saxpy {
         /// - [`DeviceLost`].
         /// - The kernel could not be queued.
         pub fn dispatch(&self, alpha: f32, x: Slice<f32>, y: SliceMut<f32>) -> Result<()>;
+        /// Dispatches the kernel, signaling `fence` when it completes.
+        ///
+        /// See [`.dispatch()`](Kernel::dispatch) and [`Fence`].
+        ///
+        /// # Errors
+        /// - `fence` is on a different device.
+        /// - See [`.dispatch()`](Kernel::dispatch).
+        pub fn dispatch_with_fence(
+            &self,
+            alpha: f32,
+            x: Slice<f32>,
+            y: SliceMut<f32>,
+            fence: &Fence,
+        ) -> Result<()>;
+        /// Dispatches the kernel if it is safe.
+        ///
+        /// Never `unsafe`. Kernels declared `unsafe fn`, which krnlc requires for
+        /// `UnsafeSlice` parameters or unchecked indexing, return an error. The slices
+        /// and push constants are checked against the kernel.
+        ///
+        /// # Errors
+        /// - The kernel is declared `unsafe fn`.
+        /// - See [`.dispatch()`](Kernel::dispatch).
+        pub fn dispatch_safe(&self, alpha: f32, x: Slice<f32>, y: SliceMut<f32>) -> Result<()>;
     }
+
+    impl<G> KernelInfo for Kernel<G> { /* .. */ }
 }
 # fn main() {}
 ```
@@ -425,8 +550,8 @@ See [`DeviceInfo::features()`](device::DeviceInfo::features).
 # Specialization
 SpecConstants are declared like const generic parameters, but are not const when compiling
 in Rust. They may be used to define the length of a [Group Buffer](#group-buffers). At runtime,
-SpecConstants are provided to the [builder](#KernelBuilder) via `.specialize(..)`. During `.build(..)`,
-they are converted to constants.
+SpecConstants are provided to the [builder](#KernelBuilder) via `.specialize(..)`, or one at a time by
+name via `.specialize_one(..)`. During `.build(..)`, they are converted to constants.
 ```no_run
 # #[krnl::macros::module] #[krnl(no_build)] mod kernels {
 # use krnl::macros::kernel;
@@ -547,6 +672,8 @@ Note: The validation layer can be configured to redirect messages to stdout. Thi
 and returning an error in case of a panic.
 */
 
+#[cfg(feature = "device")]
+use crate::device::capability_features;
 use crate::{
     device::{Device, DeviceInner, Features},
     scalar::{ScalarElem, ScalarType},
@@ -559,7 +686,7 @@ use rspirv::{binary::Assemble, dr::Operand};
 use std::{borrow::Cow, sync::Arc};
 #[cfg(feature = "device")]
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     hash::Hash,
     sync::atomic::{AtomicBool, Ordering},
 };
@@ -570,10 +697,20 @@ pub(crate) struct KernelDesc {
     pub(crate) name: Cow<'static, str>,
     pub(crate) spirv: Vec<u32>,
     features: Features,
+    safe: bool,
     pub(crate) threads: u32,
     spec_descs: &'static [SpecDesc],
-    pub(crate) slice_descs: &'static [SliceDesc],
-    push_descs: &'static [PushDesc],
+    pub(crate) slice_descs: Cow<'static, [SliceDesc]>,
+    push_descs: Cow<'static, [PushDesc]>,
+}
+
+impl KernelDesc {
+    fn slice_params(&self) -> Vec<(String, ScalarType, bool, bool)> {
+        self.slice_descs
+            .iter()
+            .map(|x| (x.name.to_string(), x.scalar_type, x.mutable, x.item))
+            .collect()
+    }
 }
 
 #[cfg(feature = "device")]
@@ -584,7 +721,7 @@ impl KernelDesc {
             while size % push_desc.scalar_type.size() != 0 {
                 size += 1;
             }
-            size += push_desc.scalar_type.size()
+            size += push_desc.scalar_type.size() * push_desc.len();
         }
         while size % 4 != 0 {
             size += 1;
@@ -592,34 +729,77 @@ impl KernelDesc {
         size += self.slice_descs.len() * 2 * 4;
         size.try_into().unwrap()
     }
+    // The size of the push constants, excluding the slice offsets and lens.
+    fn push_consts_len(&self) -> usize {
+        self.push_consts_range() as usize - self.slice_descs.len() * 2 * 4
+    }
+    // Packs the push constants with the layout of push_consts_range.
+    //
+    // The elements of arrays are packed contiguously.
+    fn push_consts_bytes(&self, push_consts: &[KernelPushArg]) -> Result<Vec<u8>> {
+        self.check_push_lens(push_consts)?;
+        let mut push_bytes = Vec::with_capacity(self.push_consts_range() as usize);
+        for (push, push_desc) in push_consts.iter().zip(self.push_descs.iter()) {
+            while push_bytes.len() % push_desc.scalar_type.size() != 0 {
+                push_bytes.push(0);
+            }
+            for elem in push.elems() {
+                debug_assert_eq!(elem.scalar_type(), push_desc.scalar_type);
+                push_bytes.extend_from_slice(elem.as_bytes());
+            }
+        }
+        while push_bytes.len() % 4 != 0 {
+            push_bytes.push(0);
+        }
+        debug_assert_eq!(push_bytes.len(), self.push_consts_len());
+        Ok(push_bytes)
+    }
+    // Appends the offset and len of each slice in elements to the push constants.
+    fn push_bytes(
+        &self,
+        mut push_bytes: Vec<u8>,
+        slices: impl IntoIterator<Item = (u32, u32)>,
+    ) -> Vec<u8> {
+        for (offset, len) in slices {
+            push_bytes.extend_from_slice(&offset.to_ne_bytes());
+            push_bytes.extend_from_slice(&len.to_ne_bytes());
+        }
+        debug_assert_eq!(push_bytes.len(), self.push_consts_range() as usize);
+        push_bytes
+    }
     fn specialize(
         &self,
         threads: u32,
         spec_consts: &[ScalarElem],
         debug_printf: bool,
+        keep_debug_info: bool,
+        deterministic: bool,
     ) -> Result<Self> {
         use rspirv::spirv::{Decoration, Op};
-        let mut module = rspirv::dr::load_words(&self.spirv).unwrap();
-        let mut spec_ids = HashMap::<u32, u32>::with_capacity(spec_consts.len());
-        let mut spec_string = format!("threads={threads}");
-        use std::fmt::Write;
-        for (desc, spec) in self.spec_descs.iter().zip(spec_consts) {
-            if !spec_string.is_empty() {
-                spec_string.push_str(", ");
+        let kernel_name = &self.name;
+        if spec_consts.len() != self.spec_descs.len() {
+            bail!(
+                "Kernel `{kernel_name}` expected {} spec constants, found {}!",
+                self.spec_descs.len(),
+                spec_consts.len()
+            );
+        }
+        for (spec, spec_desc) in spec_consts.iter().zip(self.spec_descs.iter()) {
+            let scalar_type = spec.scalar_type();
+            if scalar_type != spec_desc.scalar_type {
+                bail!(
+                    "Kernel `{kernel_name}`.`{}` expected {:?}, found {scalar_type:?}!",
+                    spec_desc.name,
+                    spec_desc.scalar_type
+                );
             }
-            let n = desc.name;
-            macro_wrap!(match spec {
-                macro_for!($T in [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64] {
-                    ScalarElem::$T(x) => write!(&mut spec_string, "{n}={x}").unwrap(),
-                })
-                _ => unreachable!("{spec:?}"),
-            });
         }
-        let name = if !spec_string.is_empty() {
-            format!("{}<{spec_string}>", self.name).into()
-        } else {
-            self.name.clone()
-        };
+        let mut module = rspirv::dr::load_words(&self.spirv).unwrap();
+        self.validate_bindings(&module)?;
+        self.validate_spec_constants(&module)?;
+        self.validate_slice_writes(&module)?;
+        let mut spec_ids = HashMap::<u32, u32>::with_capacity(spec_consts.len());
+        let name = self.specialized_name(threads, spec_consts);
         for inst in module.annotations.iter() {
             if inst.class.opcode == Op::Decorate {
                 if let [Operand::IdRef(id), Operand::Decoration(Decoration::SpecId), Operand::LiteralInt32(spec_id)] =
@@ -640,13 +820,19 @@ impl KernelDesc {
                         } else {
                             unreachable!("{inst:?}")
                         };
+                        let bits = spec_constant_bits(value);
                         match inst.operands.as_mut_slice() {
-                            [Operand::LiteralInt32(a)] => {
-                                bytemuck::bytes_of_mut(a).copy_from_slice(value.as_bytes());
+                            [Operand::LiteralInt32(x)] => {
+                                *x = bits.try_into().unwrap();
                             }
-                            [Operand::LiteralInt32(a), Operand::LiteralInt32(b)] => {
-                                bytemuck::bytes_of_mut(a).copy_from_slice(&value.as_bytes()[..8]);
-                                bytemuck::bytes_of_mut(b).copy_from_slice(&value.as_bytes()[9..]);
+                            [Operand::LiteralFloat32(x)] => {
+                                *x = f32::from_bits(bits.try_into().unwrap());
+                            }
+                            [Operand::LiteralInt64(x)] => {
+                                *x = bits;
+                            }
+                            [Operand::LiteralFloat64(x)] => {
+                                *x = f64::from_bits(bits);
                             }
                             _ => unreachable!("{:?}", inst.operands),
                         }
@@ -654,9 +840,21 @@ impl KernelDesc {
                 }
             }
         }
+        if let Some(size) = push_block_size(&module) {
+            let range = self.push_consts_range();
+            if size > range {
+                bail!("Kernel `{name}` push constants are {size} bytes after specialization, expected at most {range}!");
+            }
+        }
         if !debug_printf {
             strip_debug_printf(&mut module);
         }
+        if !keep_debug_info {
+            strip_debug_info(&mut module, debug_printf);
+        }
+        if deterministic {
+            decorate_no_contraction(&mut module);
+        }
         let spirv = module.assemble();
         Ok(Self {
             name,
@@ -666,136 +864,1102 @@ impl KernelDesc {
             ..self.clone()
         })
     }
-}
-
-#[cfg(feature = "device")]
-fn strip_debug_printf(module: &mut rspirv::dr::Module) {
-    use fxhash::FxHashSet;
-    use rspirv::spirv::Op;
+    fn specialized_name(&self, threads: u32, spec_consts: &[ScalarElem]) -> Cow<'static, str> {
+        use std::fmt::Write;
 
-    module.extensions.retain(|inst| {
-        inst.operands.first().unwrap().unwrap_literal_string() != "SPV_KHR_non_semantic_info"
-    });
-    let mut ext_insts = FxHashSet::default();
-    module.ext_inst_imports.retain(|inst| {
-        if inst
-            .operands
-            .first()
-            .unwrap()
-            .unwrap_literal_string()
-            .starts_with("NonSemantic.DebugPrintf")
-        {
-            ext_insts.insert(inst.result_id.unwrap());
-            false
+        let mut spec_string = format!("threads={threads}");
+        for (desc, spec) in self.spec_descs.iter().zip(spec_consts) {
+            if !spec_string.is_empty() {
+                spec_string.push_str(", ");
+            }
+            let n = desc.name;
+            macro_wrap!(match spec {
+                macro_for!($T in [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64] {
+                    ScalarElem::$T(x) => write!(&mut spec_string, "{n}={x}").unwrap(),
+                })
+                _ => unreachable!("{spec:?}"),
+            });
+        }
+        if !spec_string.is_empty() {
+            format!("{}<{spec_string}>", self.name).into()
         } else {
-            true
+            self.name.clone()
         }
-    });
-    if ext_insts.is_empty() {
-        return;
     }
-    module.debug_string_source.clear();
-    for func in module.functions.iter_mut() {
-        for block in func.blocks.iter_mut() {
-            block.instructions.retain(|inst| {
-                if inst.class.opcode == Op::ExtInst {
-                    let id = inst.operands.first().unwrap().unwrap_id_ref();
-                    if ext_insts.contains(&id) {
-                        return false;
-                    }
-                }
-                !matches!(inst.class.opcode, Op::Line | Op::NoLine)
-            })
+    /// The specialized desc without SPIR-V, for dispatching while the kernel is built in
+    /// the background.
+    fn specialized_stub(&self, threads: u32, spec_consts: &[ScalarElem]) -> Self {
+        Self {
+            name: self.specialized_name(threads, spec_consts),
+            spirv: Vec::new(),
+            features: self.features,
+            safe: self.safe,
+            threads,
+            spec_descs: &[],
+            slice_descs: self.slice_descs.clone(),
+            push_descs: self.push_descs.clone(),
         }
     }
 }
 
 #[cfg(feature = "device")]
-#[derive(PartialEq, Eq, Hash, Debug)]
-pub(crate) struct KernelKey {
-    id: usize,
-    spec_bytes: Vec<u8>,
-}
-
-#[doc(hidden)]
-pub mod __private {
-    #[cfg(feature = "device")]
-    use num_traits::ToPrimitive;
-
-    use super::*;
-    #[cfg(feature = "device")]
-    use crate::device::{DeviceBuffer, RawKernel};
-    use crate::{
-        buffer::{ScalarSlice, ScalarSliceMut, Slice, SliceMut},
-        scalar::Scalar,
-    };
-
-    #[derive(Clone, Copy)]
-    pub struct KernelDesc {
-        name: &'static str,
-        spirv: &'static [u8],
-        features: Features,
-        safe: bool,
-        spec_descs: &'static [SpecDesc],
-        slice_descs: &'static [SliceDesc],
-        push_descs: &'static [PushDesc],
-    }
-
-    #[derive(Clone, Copy)]
-    pub struct KernelDescArgs {
-        pub name: &'static str,
-        pub spirv: &'static [u8],
-        pub features: Features,
-        pub safe: bool,
-        pub spec_descs: &'static [SpecDesc],
-        pub slice_descs: &'static [SliceDesc],
-        pub push_descs: &'static [PushDesc],
-    }
+impl KernelDesc {
+    /// Checks that each binding is decorated at most once, and that bindings are in
+    /// the range 0..slice_descs.len(), since descriptors are bound in that order.
+    ///
+    /// Bindings may be missing, if a slice is unused it can be removed by the optimizer.
+    fn validate_bindings(&self, module: &rspirv::dr::Module) -> Result<()> {
+        use rspirv::spirv::{Decoration, Op};
+        use std::fmt::Write;
 
-    const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
-        if a.len() != b.len() {
-            return false;
+        let slices = self.slice_descs.len();
+        let mut counts = vec![0usize; slices];
+        let mut invalid = Vec::new();
+        for inst in module.annotations.iter() {
+            if inst.class.opcode == Op::Decorate {
+                if let [Operand::IdRef(_), Operand::Decoration(Decoration::Binding), Operand::LiteralInt32(binding)] =
+                    inst.operands.as_slice()
+                {
+                    let binding = *binding as usize;
+                    if let Some(count) = counts.get_mut(binding) {
+                        *count += 1;
+                    } else {
+                        invalid.push(binding);
+                    }
+                }
+            }
         }
-        let mut i = 0;
-        while i < a.len() {
-            if a[i] != b[i] {
-                return false;
+        let mut errors = String::new();
+        for (binding, count) in counts.into_iter().enumerate() {
+            if count > 1 {
+                let slice = self.slice_descs[binding].name;
+                write!(
+                    &mut errors,
+                    " binding {binding} (`{slice}`) is declared {count} times,"
+                )
+                .unwrap();
             }
-            i += 1;
         }
-        true
+        invalid.sort_unstable();
+        invalid.dedup();
+        for binding in invalid {
+            write!(
+                &mut errors,
+                " binding {binding} is out of range, expected less than {slices},"
+            )
+            .unwrap();
+        }
+        if !errors.is_empty() {
+            errors.pop();
+            bail!("Kernel `{}` has invalid bindings:{errors}!", self.name);
+        }
+        Ok(())
     }
+    // Spec constants are ids 0..spec_descs.len(), followed by threads as a u32.
+    fn validate_spec_constants(&self, module: &rspirv::dr::Module) -> Result<()> {
+        use rspirv::spirv::{Decoration, Op};
+        use std::fmt::Write;
 
-    pub const fn find_kernel(name: &str, kernels: &[KernelDesc]) -> Option<KernelDesc> {
-        let mut i = 0;
-        while i < kernels.len() {
-            if bytes_eq(name.as_bytes(), kernels[i].name.as_bytes()) {
-                return Some(kernels[i]);
+        let mut spec_ids = HashMap::<u32, u32>::new();
+        for inst in module.annotations.iter() {
+            if inst.class.opcode == Op::Decorate {
+                if let [Operand::IdRef(id), Operand::Decoration(Decoration::SpecId), Operand::LiteralInt32(spec_id)] =
+                    inst.operands.as_slice()
+                {
+                    spec_ids.insert(*id, *spec_id);
+                }
             }
-            i += 1;
         }
-        None
+        let mut errors = String::new();
+        for inst in module.types_global_values.iter() {
+            if inst.class.opcode != Op::SpecConstant {
+                continue;
+            }
+            let Some(spec_id) = inst.result_id.and_then(|id| spec_ids.get(&id).copied()) else {
+                continue;
+            };
+            let threads = spec_id as usize == self.spec_descs.len();
+            let (name, scalar_type) = if let Some(desc) = self.spec_descs.get(spec_id as usize) {
+                (desc.name, desc.scalar_type)
+            } else if threads {
+                ("threads", ScalarType::U32)
+            } else {
+                write!(
+                    &mut errors,
+                    " spec id {spec_id} is out of range, expected at most {},",
+                    self.spec_descs.len()
+                )
+                .unwrap();
+                continue;
+            };
+            let valid = match inst.operands.as_slice() {
+                [Operand::LiteralInt32(_)] => scalar_type.size() <= 4,
+                [Operand::LiteralFloat32(_)] => !threads && scalar_type.size() <= 4,
+                [Operand::LiteralInt64(_) | Operand::LiteralFloat64(_)] => {
+                    !threads && scalar_type.size() == 8
+                }
+                _ => false,
+            };
+            if !valid {
+                write!(
+                    &mut errors,
+                    " spec id {spec_id} (`{name}`) is {:?}, expected {scalar_type:?},",
+                    inst.operands
+                )
+                .unwrap();
+            }
+        }
+        if !errors.is_empty() {
+            errors.pop();
+            bail!(
+                "Kernel `{}` has invalid spec constants:{errors}!",
+                self.name
+            );
+        }
+        Ok(())
     }
+    // Immutable slices are bound read only, writes to them would be dropped.
+    fn validate_slice_writes(&self, module: &rspirv::dr::Module) -> Result<()> {
+        use rspirv::spirv::{Decoration, Op};
 
-    pub const fn validate_kernel(
-        kernel: Option<Option<KernelDesc>>,
-        safety: Safety,
-        spec_descs: &[SpecDesc],
-        slice_descs: &[SliceDesc],
-        push_descs: &[PushDesc],
-    ) -> Option<KernelDesc> {
-        if let Some(kernel) = kernel {
-            let success = if let Some(kernel) = kernel.as_ref() {
-                kernel.check_declaration(safety, spec_descs, slice_descs, push_descs)
-            } else {
-                false
+        // pointer id => binding
+        let mut pointers = HashMap::<u32, u32>::new();
+        for inst in module.annotations.iter() {
+            if inst.class.opcode == Op::Decorate {
+                if let [Operand::IdRef(id), Operand::Decoration(Decoration::Binding), Operand::LiteralInt32(binding)] =
+                    inst.operands.as_slice()
+                {
+                    pointers.insert(*id, *binding);
+                }
+            }
+        }
+        let mut written = Vec::new();
+        for inst in module
+            .functions
+            .iter()
+            .flat_map(|function| function.blocks.iter())
+            .flat_map(|block| block.instructions.iter())
+        {
+            let Some(Operand::IdRef(pointer)) = inst.operands.first() else {
+                continue;
             };
-            if !success {
-                panic!("recompile with krnlc");
+            let Some(binding) = pointers.get(pointer).copied() else {
+                continue;
+            };
+            match inst.class.opcode {
+                Op::AccessChain
+                | Op::InBoundsAccessChain
+                | Op::PtrAccessChain
+                | Op::InBoundsPtrAccessChain
+                | Op::CopyObject => {
+                    if let Some(result_id) = inst.result_id {
+                        pointers.insert(result_id, binding);
+                    }
+                }
+                Op::Store
+                | Op::CopyMemory
+                | Op::AtomicStore
+                | Op::AtomicExchange
+                | Op::AtomicCompareExchange
+                | Op::AtomicIIncrement
+                | Op::AtomicIDecrement
+                | Op::AtomicIAdd
+                | Op::AtomicISub
+                | Op::AtomicSMin
+                | Op::AtomicUMin
+                | Op::AtomicSMax
+                | Op::AtomicUMax
+                | Op::AtomicAnd
+                | Op::AtomicOr
+                | Op::AtomicXor
+                | Op::AtomicFAddEXT => written.push(binding),
+                _ => (),
             }
-            kernel
-        } else {
-            None
+        }
+        written.sort_unstable();
+        written.dedup();
+        let immutable: Vec<_> = written
+            .into_iter()
+            .filter_map(|binding| self.slice_descs.get(binding as usize))
+            .filter(|slice_desc| !slice_desc.mutable)
+            .map(|slice_desc| {
+                let item = if slice_desc.item { "item " } else { "" };
+                format!("{item}`{}`", slice_desc.name)
+            })
+            .collect();
+        if !immutable.is_empty() {
+            bail!(
+                "Kernel `{}` writes to immutable slices {}, they must be mutable!",
+                self.name,
+                immutable.join(", ")
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "device")]
+impl KernelDesc {
+    /// Checks that the capabilities declared by the module are supported with `device_features`.
+    ///
+    /// See [`DeviceInfo::supported_capabilities()`](crate::device::DeviceInfo::supported_capabilities).
+    fn check_capabilities(&self, device_features: Features) -> Result<()> {
+        let module = rspirv::dr::load_words(&self.spirv).map_err(|e| {
+            anyhow::format_err!("Kernel `{}` failed to parse SPIR-V! {e}", self.name)
+        })?;
+        for inst in module.capabilities.iter() {
+            let capability = inst.operands.first().unwrap().unwrap_capability();
+            match capability_features(&format!("{capability:?}")) {
+                Some(features) if device_features.contains(features) => (),
+                Some(features) => bail!(
+                    "Kernel `{}` capability {capability:?} requires {features:?}, found {device_features:?}!",
+                    self.name
+                ),
+                None => bail!(
+                    "Kernel `{}` capability {capability:?} is not supported!",
+                    self.name
+                ),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "device")]
+impl KernelDesc {
+    /// Derives a kernel from a SPIR-V compute shader.
+    ///
+    /// See [`KernelBuilder::from_spirv()`](__private::KernelBuilder::from_spirv).
+    fn from_spirv(words: &[u32], entry: &str) -> Result<Self> {
+        use rspirv::{
+            dr::Instruction,
+            spirv::{BuiltIn, Decoration, ExecutionMode, ExecutionModel, Op, StorageClass},
+        };
+        use std::collections::BTreeMap;
+
+        let mut module = rspirv::dr::load_words(words)
+            .map_err(|e| anyhow::format_err!("Kernel `{entry}` failed to parse SPIR-V! {e}"))?;
+        let function = module
+            .entry_points
+            .iter()
+            .find_map(|inst| match inst.operands.as_slice() {
+                [Operand::ExecutionModel(ExecutionModel::GLCompute), Operand::IdRef(function), Operand::LiteralString(name), ..]
+                    if name == entry =>
+                {
+                    Some(*function)
+                }
+                _ => None,
+            });
+        let Some(function) = function else {
+            bail!("Kernel `{entry}` compute entry point not found!");
+        };
+        // The engine uses "main" as the entry point.
+        module
+            .entry_points
+            .retain(|inst| inst.operands.get(1) == Some(&Operand::IdRef(function)));
+        module.entry_points[0].operands[2] = Operand::LiteralString("main".to_string());
+        module
+            .execution_modes
+            .retain(|inst| inst.operands.first() == Some(&Operand::IdRef(function)));
+        let mut threads = None;
+        for inst in module.execution_modes.iter() {
+            match inst.operands.as_slice() {
+                [_, Operand::ExecutionMode(ExecutionMode::LocalSize), Operand::LiteralInt32(x), Operand::LiteralInt32(y), Operand::LiteralInt32(z)] =>
+                {
+                    if *y != 1 || *z != 1 {
+                        bail!(
+                            "Kernel `{entry}` local size ({x}, {y}, {z}) must be one dimensional!"
+                        );
+                    }
+                    threads.replace(*x);
+                }
+                [_, Operand::ExecutionMode(ExecutionMode::LocalSizeId), ..] => {
+                    bail!("Kernel `{entry}` LocalSizeId is not supported!");
+                }
+                _ => (),
+            }
+        }
+        let Some(threads) = threads else {
+            bail!("Kernel `{entry}` LocalSize not found!");
+        };
+        for inst in module.annotations.iter() {
+            match inst.operands.as_slice() {
+                [_, Operand::Decoration(Decoration::SpecId), ..] => {
+                    bail!("Kernel `{entry}` spec constants are not supported!");
+                }
+                [_, Operand::Decoration(Decoration::BuiltIn), Operand::BuiltIn(BuiltIn::WorkgroupSize)] =>
+                {
+                    bail!("Kernel `{entry}` WorkgroupSize is not supported!");
+                }
+                _ => (),
+            }
+        }
+        let mut features = Features::empty();
+        for inst in module.capabilities.iter() {
+            let capability = inst.operands.first().unwrap().unwrap_capability();
+            let Some(capability_features) = capability_features(&format!("{capability:?}")) else {
+                bail!("Kernel `{entry}` capability {capability:?} is not supported!");
+            };
+            features |= capability_features;
+        }
+        let types: HashMap<u32, &Instruction> = module
+            .types_global_values
+            .iter()
+            .filter_map(|inst| Some((inst.result_id?, inst)))
+            .collect();
+        let decorations = |id: u32| {
+            module
+                .annotations
+                .iter()
+                .filter_map(move |inst| match inst.operands.as_slice() {
+                    [Operand::IdRef(x), Operand::Decoration(decoration), operands @ ..]
+                        if inst.class.opcode == Op::Decorate && *x == id =>
+                    {
+                        Some((*decoration, operands))
+                    }
+                    _ => None,
+                })
+        };
+        let member_decorations = |id: u32, member: u32| {
+            module
+                .annotations
+                .iter()
+                .filter_map(move |inst| match inst.operands.as_slice() {
+                    [Operand::IdRef(x), Operand::LiteralInt32(m), Operand::Decoration(decoration), operands @ ..]
+                        if inst.class.opcode == Op::MemberDecorate && *x == id && *m == member =>
+                    {
+                        Some((*decoration, operands))
+                    }
+                    _ => None,
+                })
+        };
+        let name = |id: u32| {
+            module
+                .debug_names
+                .iter()
+                .find_map(|inst| match inst.operands.as_slice() {
+                    [Operand::IdRef(x), Operand::LiteralString(name)]
+                        if inst.class.opcode == Op::Name && *x == id && !name.is_empty() =>
+                    {
+                        Some(name.as_str())
+                    }
+                    _ => None,
+                })
+        };
+        let member_name = |id: u32, member: u32| {
+            module
+                .debug_names
+                .iter()
+                .find_map(|inst| match inst.operands.as_slice() {
+                    [Operand::IdRef(x), Operand::LiteralInt32(m), Operand::LiteralString(name)]
+                        if inst.class.opcode == Op::MemberName
+                            && *x == id
+                            && *m == member
+                            && !name.is_empty() =>
+                    {
+                        Some(name.as_str())
+                    }
+                    _ => None,
+                })
+        };
+        let scalar_type = |id: u32| {
+            let inst = types.get(&id)?;
+            let scalar_type = match (inst.class.opcode, inst.operands.as_slice()) {
+                (Op::TypeInt, [Operand::LiteralInt32(width), Operand::LiteralInt32(signed)]) => {
+                    match (width, signed) {
+                        (8, 0) => ScalarType::U8,
+                        (8, 1) => ScalarType::I8,
+                        (16, 0) => ScalarType::U16,
+                        (16, 1) => ScalarType::I16,
+                        (32, 0) => ScalarType::U32,
+                        (32, 1) => ScalarType::I32,
+                        (64, 0) => ScalarType::U64,
+                        (64, 1) => ScalarType::I64,
+                        _ => return None,
+                    }
+                }
+                (Op::TypeFloat, [Operand::LiteralInt32(width)]) => match width {
+                    16 => ScalarType::F16,
+                    32 => ScalarType::F32,
+                    64 => ScalarType::F64,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            Some(scalar_type)
+        };
+        let pointee = |id: u32| match types
+            .get(&id)
+            .map(|inst| (inst.class.opcode, inst.operands.as_slice()))
+        {
+            Some((Op::TypePointer, [_, Operand::IdRef(pointee)])) => types.get(pointee).copied(),
+            _ => None,
+        };
+        // Before SPIR-V 1.3, storage buffers are Uniform variables of BufferBlock structs.
+        let buffer_block = |ty: u32| {
+            pointee(ty)
+                .and_then(|block| block.result_id)
+                .is_some_and(|block| {
+                    decorations(block).any(|(decoration, _)| decoration == Decoration::BufferBlock)
+                })
+        };
+        let mut slice_descs = BTreeMap::<u32, SliceDesc>::new();
+        let mut push_descs = None;
+        for inst in module.types_global_values.iter() {
+            if inst.class.opcode != Op::Variable {
+                continue;
+            }
+            let (Some(id), Some(ty)) = (inst.result_id, inst.result_type) else {
+                continue;
+            };
+            match inst.operands.first() {
+                Some(Operand::StorageClass(
+                    storage_class @ (StorageClass::StorageBuffer | StorageClass::Uniform),
+                )) if *storage_class == StorageClass::StorageBuffer || buffer_block(ty) => {
+                    let mut set = None;
+                    let mut binding = None;
+                    let mut mutable = true;
+                    for (decoration, operands) in decorations(id) {
+                        match (decoration, operands) {
+                            (Decoration::DescriptorSet, [Operand::LiteralInt32(x)]) => {
+                                set.replace(*x);
+                            }
+                            (Decoration::Binding, [Operand::LiteralInt32(x)]) => {
+                                binding.replace(*x);
+                            }
+                            (Decoration::NonWritable, []) => {
+                                mutable = false;
+                            }
+                            _ => (),
+                        }
+                    }
+                    let Some(binding) = binding else {
+                        bail!("Kernel `{entry}` storage buffer %{id} has no binding!");
+                    };
+                    if set.unwrap_or_default() != 0 {
+                        bail!("Kernel `{entry}` binding {binding} must be in descriptor set 0!");
+                    }
+                    let block = pointee(ty).filter(|inst| inst.class.opcode == Op::TypeStruct);
+                    let array = block
+                        .and_then(|block| match block.operands.as_slice() {
+                            [Operand::IdRef(array)] => types.get(array),
+                            _ => None,
+                        })
+                        .filter(|inst| inst.class.opcode == Op::TypeRuntimeArray);
+                    let (Some(block), Some(array)) = (block, array) else {
+                        bail!("Kernel `{entry}` binding {binding} must be a struct with a single runtime array!");
+                    };
+                    let block = block.result_id.unwrap();
+                    let Some(scalar_type) = array
+                        .operands
+                        .first()
+                        .and_then(|x| scalar_type(x.unwrap_id_ref()))
+                    else {
+                        bail!("Kernel `{entry}` binding {binding} must be an array of scalars!");
+                    };
+                    if member_decorations(block, 0)
+                        .any(|(decoration, _)| decoration == Decoration::NonWritable)
+                    {
+                        mutable = false;
+                    }
+                    let name = name(id)
+                        .or_else(|| member_name(block, 0))
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| format!("binding{binding}"));
+                    let slice_desc = SliceDesc {
+                        name: intern_name(name),
+                        scalar_type,
+                        mutable,
+                        item: false,
+                    };
+                    if slice_descs.insert(binding, slice_desc).is_some() {
+                        bail!("Kernel `{entry}` has invalid bindings: binding {binding} is declared more than once!");
+                    }
+                }
+                Some(Operand::StorageClass(StorageClass::PushConstant)) => {
+                    if push_descs.is_some() {
+                        bail!("Kernel `{entry}` has more than one push constant block!");
+                    }
+                    let Some(block) =
+                        pointee(ty).filter(|inst| inst.class.opcode == Op::TypeStruct)
+                    else {
+                        bail!("Kernel `{entry}` push constants must be a struct!");
+                    };
+                    let block_id = block.result_id.unwrap();
+                    let mut descs = Vec::with_capacity(block.operands.len());
+                    let mut size = 0;
+                    for (member, operand) in (0..).zip(block.operands.iter()) {
+                        let id = operand.unwrap_id_ref();
+                        let (scalar_type, array_len) = if let Some(scalar_type) = scalar_type(id) {
+                            (Some(scalar_type), None)
+                        } else {
+                            match types
+                                .get(&id)
+                                .map(|inst| (inst.class.opcode, inst.operands.as_slice()))
+                            {
+                                Some((
+                                    Op::TypeArray,
+                                    [Operand::IdRef(element), Operand::IdRef(len)],
+                                )) => {
+                                    let scalar_type = scalar_type(*element);
+                                    let stride =
+                                        decorations(id).find_map(|(decoration, operands)| {
+                                            match (decoration, operands) {
+                                                (
+                                                    Decoration::ArrayStride,
+                                                    [Operand::LiteralInt32(x)],
+                                                ) => Some(*x as usize),
+                                                _ => None,
+                                            }
+                                        });
+                                    let len = types.get(len).and_then(|inst| {
+                                        match (inst.class.opcode, inst.operands.as_slice()) {
+                                            (Op::Constant, [Operand::LiteralInt32(x)]) => Some(*x),
+                                            _ => None,
+                                        }
+                                    });
+                                    if scalar_type.map(|x| x.size()) != stride {
+                                        (None, None)
+                                    } else {
+                                        (scalar_type, len)
+                                    }
+                                }
+                                _ => (None, None),
+                            }
+                        };
+                        let Some(scalar_type) = scalar_type else {
+                            bail!("Kernel `{entry}` push constant {member} must be a scalar, or an array of scalars with a constant length!");
+                        };
+                        while size % scalar_type.size() != 0 {
+                            size += 1;
+                        }
+                        let offset = member_decorations(block_id, member).find_map(
+                            |(decoration, operands)| match (decoration, operands) {
+                                (Decoration::Offset, [Operand::LiteralInt32(x)]) => {
+                                    Some(*x as usize)
+                                }
+                                _ => None,
+                            },
+                        );
+                        if offset != Some(size) {
+                            bail!("Kernel `{entry}` push constant {member} offset {offset:?} expected {size}!");
+                        }
+                        size += scalar_type.size() * array_len.unwrap_or(1) as usize;
+                        let name = member_name(block_id, member)
+                            .map(ToString::to_string)
+                            .unwrap_or_else(|| format!("push{member}"));
+                        descs.push(PushDesc {
+                            name: intern_name(name),
+                            scalar_type,
+                            array_len,
+                        });
+                    }
+                    push_descs.replace(descs);
+                }
+                Some(Operand::StorageClass(
+                    StorageClass::Uniform | StorageClass::UniformConstant,
+                )) => {
+                    bail!(
+                        "Kernel `{entry}` only storage buffers and push constants are supported!"
+                    );
+                }
+                _ => (),
+            }
+        }
+        if let Some((binding, _)) = slice_descs
+            .keys()
+            .copied()
+            .enumerate()
+            .find(|(binding, x)| *binding != *x as usize)
+        {
+            bail!("Kernel `{entry}` has invalid bindings: binding {binding} is missing!");
+        }
+        Ok(Self {
+            name: entry.to_string().into(),
+            spirv: module.assemble(),
+            features,
+            safe: false,
+            threads,
+            spec_descs: &[],
+            slice_descs: slice_descs.into_values().collect::<Vec<_>>().into(),
+            push_descs: push_descs.unwrap_or_default().into(),
+        })
+    }
+}
+
+/** Interns slice and push constant names of kernels loaded at runtime.
+
+[`SliceDesc`](__private::SliceDesc) and [`PushDesc`](__private::PushDesc) are `Copy` and
+compared in const contexts by the macro, so their names are `&'static str`. Each distinct
+name is leaked once and shared by every kernel that declares it. Names come from
+`OpName`s (or the binding / member index), so the set stays small even when many modules
+are loaded, at the cost of never being freed. */
+#[cfg(feature = "device")]
+fn intern_name(name: String) -> &'static str {
+    static NAMES: parking_lot::Mutex<Option<HashSet<&'static str>>> =
+        parking_lot::const_mutex(None);
+    let mut names = NAMES.lock();
+    let names = names.get_or_insert_with(HashSet::new);
+    if let Some(name) = names.get(name.as_str()) {
+        return name;
+    }
+    let name = Box::leak(name.into_boxed_str());
+    names.insert(name);
+    name
+}
+
+/// The literal bits of a spec constant.
+///
+/// Values 32 bits wide or smaller take one word, with signed integers sign extended
+/// and all other types zero extended.
+#[cfg(feature = "device")]
+fn spec_constant_bits(value: ScalarElem) -> u64 {
+    match value {
+        ScalarElem::U8(x) => x.into(),
+        ScalarElem::I8(x) => (x as i32 as u32).into(),
+        ScalarElem::U16(x) => x.into(),
+        ScalarElem::I16(x) => (x as i32 as u32).into(),
+        ScalarElem::F16(x) => x.to_bits().into(),
+        ScalarElem::BF16(x) => x.to_bits().into(),
+        ScalarElem::U32(x) => x.into(),
+        ScalarElem::I32(x) => (x as u32).into(),
+        ScalarElem::F32(x) => x.to_bits().into(),
+        ScalarElem::U64(x) => x,
+        ScalarElem::I64(x) => x as u64,
+        ScalarElem::F64(x) => x.to_bits(),
+        _ => unreachable!("{value:?}"),
+    }
+}
+
+/// The size of the push constant block, with array lengths resolved from (specialized)
+/// constants.
+///
+/// Push constants are packed from the push and slice descs, so a spec constant that changes
+/// the size of the block would otherwise lead to reading past the packed bytes.
+///
+/// Returns None if there is no block, or it contains an unsupported type.
+#[cfg(feature = "device")]
+fn push_block_size(module: &rspirv::dr::Module) -> Option<u32> {
+    use rspirv::{
+        dr::Instruction,
+        spirv::{Decoration, Op, StorageClass},
+    };
+
+    let types: HashMap<u32, &Instruction> = module
+        .types_global_values
+        .iter()
+        .filter_map(|inst| Some((inst.result_id?, inst)))
+        .collect();
+    let decoration = |id: u32, member: Option<u32>, decoration: Decoration| {
+        module
+            .annotations
+            .iter()
+            .find_map(|inst| match (member, inst.operands.as_slice()) {
+                (
+                    None,
+                    [Operand::IdRef(x), Operand::Decoration(d), Operand::LiteralInt32(value)],
+                ) if *x == id && *d == decoration => Some(*value),
+                (
+                    Some(member),
+                    [Operand::IdRef(x), Operand::LiteralInt32(m), Operand::Decoration(d), Operand::LiteralInt32(value)],
+                ) if *x == id && *m == member && *d == decoration => Some(*value),
+                _ => None,
+            })
+    };
+    fn size(
+        types: &HashMap<u32, &Instruction>,
+        decoration: &dyn Fn(u32, Option<u32>, Decoration) -> Option<u32>,
+        id: u32,
+    ) -> Option<u32> {
+        let inst = types.get(&id)?;
+        match (inst.class.opcode, inst.operands.as_slice()) {
+            (Op::TypeInt, [Operand::LiteralInt32(width), _])
+            | (Op::TypeFloat, [Operand::LiteralInt32(width), ..]) => Some(width / 8),
+            (Op::TypeVector, [Operand::IdRef(component), Operand::LiteralInt32(n)]) => {
+                Some(size(types, decoration, *component)? * n)
+            }
+            (Op::TypeArray, [_, Operand::IdRef(length)]) => {
+                let length = match types.get(length)?.operands.as_slice() {
+                    [Operand::LiteralInt32(length)] => *length,
+                    [Operand::LiteralInt64(length)] => (*length).try_into().ok()?,
+                    _ => return None,
+                };
+                let stride = decoration(id, None, Decoration::ArrayStride)?;
+                Some(stride * length)
+            }
+            (Op::TypeStruct, members) => {
+                let mut output = 0;
+                for (member, ty) in members.iter().enumerate() {
+                    let offset = decoration(id, Some(member as u32), Decoration::Offset)?;
+                    let size = size(types, decoration, ty.id_ref_any()?)?;
+                    output = output.max(offset + size);
+                }
+                Some(output)
+            }
+            _ => None,
+        }
+    }
+    let ty = module.types_global_values.iter().find_map(|inst| {
+        match (inst.class.opcode, inst.operands.as_slice()) {
+            (Op::Variable, [Operand::StorageClass(StorageClass::PushConstant), ..]) => {
+                inst.result_type
+            }
+            _ => None,
+        }
+    })?;
+    let block = match types.get(&ty)?.operands.as_slice() {
+        [_, Operand::IdRef(block)] => *block,
+        _ => return None,
+    };
+    size(&types, &decoration, block)
+}
+
+/// Errors if the module uses more shared memory than `max_shared_memory`.
+#[cfg(feature = "device")]
+fn check_shared_memory(
+    name: &str,
+    spirv: &[u32],
+    device: &impl std::fmt::Debug,
+    max_shared_memory: u32,
+) -> Result<()> {
+    let module = rspirv::dr::load_words(spirv)
+        .map_err(|e| anyhow::format_err!("Kernel `{name}` failed to parse SPIR-V! {e}"))?;
+    if let Some(size) = shared_memory_size(&module) {
+        if size > max_shared_memory {
+            bail!("Kernel `{name}` uses {size} bytes of shared memory, {device:?} supports at most {max_shared_memory}!");
+        }
+    }
+    Ok(())
+}
+
+/// The size of the shared (Workgroup) memory declared by the module, with array lengths
+/// resolved from (specialized) constants.
+///
+/// Variables are laid out consecutively, each member aligned to its scalar size. Drivers may
+/// pad further, so this is a lower bound.
+///
+/// Returns None if a variable contains an unsupported type.
+#[cfg(feature = "device")]
+fn shared_memory_size(module: &rspirv::dr::Module) -> Option<u32> {
+    use rspirv::{
+        dr::Instruction,
+        spirv::{Op, StorageClass},
+    };
+
+    let types: HashMap<u32, &Instruction> = module
+        .types_global_values
+        .iter()
+        .filter_map(|inst| Some((inst.result_id?, inst)))
+        .collect();
+    fn align_up(mut x: u32, align: u32) -> u32 {
+        while x % align != 0 {
+            x += 1;
+        }
+        x
+    }
+    // (size, align)
+    fn layout(types: &HashMap<u32, &Instruction>, id: u32) -> Option<(u32, u32)> {
+        let inst = types.get(&id)?;
+        match (inst.class.opcode, inst.operands.as_slice()) {
+            (Op::TypeBool, []) => Some((4, 4)),
+            (Op::TypeInt, [Operand::LiteralInt32(width), _])
+            | (Op::TypeFloat, [Operand::LiteralInt32(width), ..]) => Some((width / 8, width / 8)),
+            (Op::TypeVector, [Operand::IdRef(component), Operand::LiteralInt32(n)]) => {
+                let (size, align) = layout(types, *component)?;
+                Some((size * n, align))
+            }
+            (Op::TypeArray, [Operand::IdRef(element), Operand::IdRef(length)]) => {
+                let length = match types.get(length)?.operands.as_slice() {
+                    [Operand::LiteralInt32(length)] => *length,
+                    [Operand::LiteralInt64(length)] => (*length).try_into().ok()?,
+                    _ => return None,
+                };
+                let (size, align) = layout(types, *element)?;
+                Some((align_up(size, align).checked_mul(length)?, align))
+            }
+            (Op::TypeStruct, members) => {
+                let mut output = (0, 1);
+                for member in members {
+                    let (size, align) = layout(types, member.id_ref_any()?)?;
+                    output.0 = align_up(output.0, align).checked_add(size)?;
+                    output.1 = output.1.max(align);
+                }
+                Some(output)
+            }
+            _ => None,
+        }
+    }
+    let mut output = 0u32;
+    for inst in module.types_global_values.iter() {
+        if let (Op::Variable, [Operand::StorageClass(StorageClass::Workgroup), ..]) =
+            (inst.class.opcode, inst.operands.as_slice())
+        {
+            let pointee = match types.get(&inst.result_type?)?.operands.as_slice() {
+                [_, Operand::IdRef(pointee)] => *pointee,
+                _ => return None,
+            };
+            let (size, align) = layout(&types, pointee)?;
+            output = align_up(output, align).checked_add(size)?;
+        }
+    }
+    Some(output)
+}
+
+/// Four subgroups per group, at least 64 threads, so that groups fill a compute unit
+/// on devices with small subgroups.
+#[cfg(feature = "device")]
+fn auto_threads(max_subgroup_threads: u32, max_threads: u32) -> u32 {
+    (4 * max_subgroup_threads).max(64).min(max_threads)
+}
+
+/// Splits `total` global threads into tiles of at most `max_groups` groups.
+///
+/// Yields the base global thread and the groups of each tile.
+#[cfg(feature = "device")]
+fn dispatch_tiles(total: u32, threads: u32, max_groups: u32) -> impl Iterator<Item = (u32, u32)> {
+    let total = u64::from(total);
+    let threads = u64::from(threads);
+    let max_groups = u64::from(max_groups.max(1));
+    let total_groups = total / threads + u64::from(total % threads != 0);
+    (0..total_groups)
+        .step_by(max_groups as usize)
+        .map(move |group| {
+            let groups = (total_groups - group).min(max_groups);
+            ((group * threads) as u32, groups as u32)
+        })
+}
+
+/// Decorates float arithmetic with NoContraction, so that the driver does not fuse
+/// operations, ie a * b + c into fma(a, b, c), which changes rounding.
+#[cfg(feature = "device")]
+fn decorate_no_contraction(module: &mut rspirv::dr::Module) {
+    use fxhash::FxHashSet;
+    use rspirv::{
+        dr::Instruction,
+        spirv::{Decoration, Op},
+    };
+
+    let decorated: FxHashSet<u32> = module
+        .annotations
+        .iter()
+        .filter_map(|inst| match inst.operands.as_slice() {
+            [Operand::IdRef(id), Operand::Decoration(Decoration::NoContraction)] => Some(*id),
+            _ => None,
+        })
+        .collect();
+    let mut ids = Vec::new();
+    for func in module.functions.iter() {
+        for block in func.blocks.iter() {
+            for inst in block.instructions.iter() {
+                if matches!(
+                    inst.class.opcode,
+                    Op::FNegate
+                        | Op::FAdd
+                        | Op::FSub
+                        | Op::FMul
+                        | Op::FDiv
+                        | Op::FRem
+                        | Op::FMod
+                        | Op::VectorTimesScalar
+                        | Op::MatrixTimesScalar
+                        | Op::VectorTimesMatrix
+                        | Op::MatrixTimesVector
+                        | Op::MatrixTimesMatrix
+                        | Op::OuterProduct
+                        | Op::Dot
+                ) {
+                    if let Some(id) = inst.result_id.filter(|id| !decorated.contains(id)) {
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+    }
+    module.annotations.extend(ids.into_iter().map(|id| {
+        Instruction::new(
+            Op::Decorate,
+            None,
+            None,
+            vec![
+                Operand::IdRef(id),
+                Operand::Decoration(Decoration::NoContraction),
+            ],
+        )
+    }));
+}
+
+/// Strips names and source info, keeping the strings used by debug printf.
+#[cfg(feature = "device")]
+fn strip_debug_info(module: &mut rspirv::dr::Module, debug_printf: bool) {
+    use rspirv::spirv::Op;
+
+    module.debug_names.clear();
+    module.debug_module_processed.clear();
+    if debug_printf {
+        return;
+    }
+    module.debug_string_source.clear();
+    for func in module.functions.iter_mut() {
+        for block in func.blocks.iter_mut() {
+            block
+                .instructions
+                .retain(|inst| !matches!(inst.class.opcode, Op::Line | Op::NoLine));
+        }
+    }
+}
+
+#[cfg(feature = "device")]
+fn strip_debug_printf(module: &mut rspirv::dr::Module) {
+    use fxhash::FxHashSet;
+    use rspirv::spirv::Op;
+
+    module.extensions.retain(|inst| {
+        inst.operands.first().unwrap().unwrap_literal_string() != "SPV_KHR_non_semantic_info"
+    });
+    let mut ext_insts = FxHashSet::default();
+    module.ext_inst_imports.retain(|inst| {
+        if inst
+            .operands
+            .first()
+            .unwrap()
+            .unwrap_literal_string()
+            .starts_with("NonSemantic.DebugPrintf")
+        {
+            ext_insts.insert(inst.result_id.unwrap());
+            false
+        } else {
+            true
+        }
+    });
+    if ext_insts.is_empty() {
+        return;
+    }
+    module.debug_string_source.clear();
+    for func in module.functions.iter_mut() {
+        for block in func.blocks.iter_mut() {
+            block.instructions.retain(|inst| {
+                if inst.class.opcode == Op::ExtInst {
+                    let id = inst.operands.first().unwrap().unwrap_id_ref();
+                    if ext_insts.contains(&id) {
+                        return false;
+                    }
+                }
+                !matches!(inst.class.opcode, Op::Line | Op::NoLine)
+            })
+        }
+    }
+}
+
+#[cfg(feature = "device")]
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub(crate) struct KernelKey {
+    id: usize,
+    // exact bits of spec constants, so NaNs with different payloads are distinct
+    spec_bytes: Vec<u8>,
+    deterministic: bool,
+}
+
+#[cfg(feature = "device")]
+impl KernelKey {
+    pub(crate) fn spec_bytes(&self) -> &[u8] {
+        &self.spec_bytes
+    }
+}
+
+/** Information about a built kernel.
+
+Implemented by each [kernel](self#kernels) `Kernel`, see
+[`PipelineGraph::add_kernel()`](crate::graph::PipelineGraph::add_kernel). */
+pub trait KernelInfo {
+    /// The name of the kernel.
+    fn name(&self) -> &str;
+    /// The device the kernel was built for.
+    fn device(&self) -> Device;
+    /// The slice parameters of the kernel in declaration order, as
+    /// `(name, scalar_type, mutable, item)`.
+    fn slice_params(&self) -> Vec<(String, ScalarType, bool, bool)>;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "device")]
+    use num_traits::ToPrimitive;
+
+    use super::*;
+    #[cfg(feature = "device")]
+    use crate::device::{DeviceBuffer, RawDevice, RawKernel};
+    use crate::{
+        buffer::{ScalarSlice, ScalarSliceMut, Slice, SliceMut},
+        device::Fence,
+        scalar::Scalar,
+    };
+
+    #[derive(Clone, Copy)]
+    pub struct KernelDesc {
+        name: &'static str,
+        spirv: &'static [u8],
+        features: Features,
+        safe: bool,
+        spec_descs: &'static [SpecDesc],
+        slice_descs: &'static [SliceDesc],
+        push_descs: &'static [PushDesc],
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct KernelDescArgs {
+        pub name: &'static str,
+        pub spirv: &'static [u8],
+        pub features: Features,
+        pub safe: bool,
+        pub spec_descs: &'static [SpecDesc],
+        pub slice_descs: &'static [SliceDesc],
+        pub push_descs: &'static [PushDesc],
+    }
+
+    const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    pub const fn find_kernel(name: &str, kernels: &[KernelDesc]) -> Option<KernelDesc> {
+        let mut i = 0;
+        while i < kernels.len() {
+            if bytes_eq(name.as_bytes(), kernels[i].name.as_bytes()) {
+                return Some(kernels[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    pub const fn validate_kernel(
+        kernel: Option<Option<KernelDesc>>,
+        safety: Safety,
+        spec_descs: &[SpecDesc],
+        slice_descs: &[SliceDesc],
+        push_descs: &[PushDesc],
+    ) -> Option<KernelDesc> {
+        if let Some(kernel) = kernel {
+            let success = if let Some(kernel) = kernel.as_ref() {
+                kernel.check_declaration(safety, spec_descs, slice_descs, push_descs)
+            } else {
+                false
+            };
+            if !success {
+                panic!("recompile with krnlc");
+            }
+            kernel
+        } else {
+            None
         }
     }
 
@@ -920,12 +2084,25 @@ pub mod __private {
     pub struct PushDesc {
         pub name: &'static str,
         pub scalar_type: ScalarType,
+        /// The length if an array, passed to dispatch as a slice of elements.
+        pub array_len: Option<u32>,
     }
 
     impl PushDesc {
+        // The number of elements.
+        #[cfg(feature = "device")]
+        pub(crate) fn len(&self) -> usize {
+            self.array_len.unwrap_or(1) as usize
+        }
         const fn const_eq(&self, other: &Self) -> bool {
+            let array_len_eq = match (self.array_len, other.array_len) {
+                (Some(a), Some(b)) => a == b,
+                (None, None) => true,
+                _ => false,
+            };
             bytes_eq(self.name.as_bytes(), other.name.as_bytes())
                 && scalar_type_const_eq(self.scalar_type, other.scalar_type)
+                && array_len_eq
         }
     }
 
@@ -951,8 +2128,9 @@ pub mod __private {
     pub struct KernelBuilder {
         id: usize,
         desc: Arc<super::KernelDesc>,
-        spec_consts: Vec<ScalarElem>,
+        spec_consts: Vec<Option<ScalarElem>>,
         threads: Option<u32>,
+        deterministic: bool,
     }
 
     impl KernelBuilder {
@@ -961,7 +2139,7 @@ pub mod __private {
                 name,
                 spirv,
                 features,
-                safe: _,
+                safe,
                 spec_descs,
                 slice_descs,
                 push_descs,
@@ -971,16 +2149,70 @@ pub mod __private {
                 name: name.into(),
                 spirv,
                 features,
+                safe,
                 threads: 0,
                 spec_descs,
-                slice_descs,
-                push_descs,
+                slice_descs: slice_descs.into(),
+                push_descs: push_descs.into(),
             };
             Ok(Self {
                 id: name.as_ptr() as usize,
+                spec_consts: vec![None; desc.spec_descs.len()],
                 desc: desc.into(),
-                spec_consts: Vec::new(),
                 threads: None,
+                deterministic: false,
+            })
+        }
+        /** Creates a builder from a SPIR-V compute shader.
+
+        The module is analyzed to derive the features, threads, slices, and push constants
+        of the kernel, so that it can be dispatched without krnlc. It is assumed that:
+        - `entry` is a GLCompute entry point with a one dimensional LocalSize.
+            - Threads can not be changed with [`.with_threads()`](Self::with_threads).
+        - There are no spec constants.
+        - Slices are StorageBuffer (or Uniform BufferBlock) variables in descriptor set 0, with
+        contiguous bindings starting at 0. Each is a struct with a single runtime array of scalars, and is
+        immutable if decorated with NonWritable. Building a kernel that writes to an
+        immutable slice fails, since it would be bound read only.
+        - There is at most one push constant block, with scalar members in declaration order
+        at offsets aligned to their size.
+            - Members may also be arrays of scalars with a constant length and tightly packed
+            elements. They are passed to dispatch as a slice of elements.
+
+        Slices are named with the variable or member name, if the module has debug info.
+
+        The kernel is not [safe](Kernel::dispatch_safe), since it was not checked by krnlc.
+
+        Slices do not define items, so the kernel must be dispatched with
+        [`.with_groups()`](Kernel::with_groups) or
+        [`.with_global_threads()`](Kernel::with_global_threads).
+
+        Slices are bound as whole buffers. As with krnl kernels, the offset and len
+        (in elements) of each slice are appended to the push constants as a pair of u32's,
+        starting at the next multiple of 4 bytes. Kernels that do not read them must be
+        dispatched with slices that start at the beginning of their buffer.
+
+        # Errors
+        - The module could not be parsed.
+        - The module does not satisfy the above assumptions.
+        - The module requires a capability that is not a [`Feature`](Features).
+        */
+        #[cfg(feature = "device")]
+        pub fn from_spirv(words: &[u32], entry: &str) -> Result<Self> {
+            use std::sync::atomic::AtomicUsize;
+
+            // Kernels generated by krnlc use the address of their name as the id,
+            // so count down from the end of the address space to avoid collisions.
+            static ID: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+            let desc = super::KernelDesc::from_spirv(words, entry)?;
+            let threads = desc.threads;
+            Ok(Self {
+                id: ID.fetch_sub(1, Ordering::SeqCst),
+                desc: desc.into(),
+                spec_consts: Vec::new(),
+                threads: Some(threads),
+                deterministic: false,
             })
         }
         pub fn with_threads(self, threads: u32) -> Self {
@@ -989,20 +2221,89 @@ pub mod __private {
                 ..self
             }
         }
-        pub fn specialize(self, spec_consts: &[ScalarElem]) -> Self {
-            debug_assert_eq!(spec_consts.len(), self.desc.spec_descs.len());
-            #[cfg(debug_assertions)]
-            for (spec_const, spec_desc) in
-                spec_consts.iter().copied().zip(self.desc.spec_descs.iter())
-            {
-                assert_eq!(spec_const.scalar_type(), spec_desc.scalar_type);
+        pub fn deterministic(self, deterministic: bool) -> Self {
+            Self {
+                deterministic,
+                ..self
             }
+        }
+        /// The spec constants are checked in [`.build()`](Self::build).
+        pub fn specialize(self, spec_consts: &[ScalarElem]) -> Self {
             Self {
-                spec_consts: spec_consts.to_vec(),
+                spec_consts: spec_consts.iter().copied().map(Some).collect(),
                 ..self
             }
         }
+        /** Specializes the spec constant `name`.
+
+        [`.build()`](Self::build) fails if any spec constants are not specialized.
+
+        # Errors
+        - The kernel has no spec constant `name`.
+        - `value` is not the type of the spec constant.
+        */
+        pub fn specialize_one(mut self, name: &str, value: ScalarElem) -> Result<Self> {
+            let kernel_name = &self.desc.name;
+            let Some(index) = self.desc.spec_descs.iter().position(|x| x.name == name) else {
+                bail!("Kernel `{kernel_name}` has no spec constant `{name}`!");
+            };
+            let spec_desc = &self.desc.spec_descs[index];
+            let scalar_type = value.scalar_type();
+            if scalar_type != spec_desc.scalar_type {
+                bail!(
+                    "Kernel `{kernel_name}`.`{name}` expected {:?}, found {scalar_type:?}!",
+                    spec_desc.scalar_type
+                );
+            }
+            self.spec_consts.resize(self.desc.spec_descs.len(), None);
+            self.spec_consts[index].replace(value);
+            Ok(self)
+        }
+        #[cfg(feature = "device")]
+        pub(super) fn spec_consts(&self) -> Result<Vec<ScalarElem>> {
+            let missing: Vec<_> = self
+                .spec_consts
+                .iter()
+                .zip(self.desc.spec_descs.iter())
+                .filter(|(spec, _)| spec.is_none())
+                .map(|(_, spec_desc)| format!("`{}`", spec_desc.name))
+                .collect();
+            if !missing.is_empty() {
+                bail!(
+                    "Kernel `{}` spec constants {} are not specialized!",
+                    self.desc.name,
+                    missing.join(", ")
+                );
+            }
+            Ok(self.spec_consts.iter().flatten().copied().collect())
+        }
         pub fn build(&self, device: Device) -> Result<Kernel> {
+            self.build_impl(device, false)
+        }
+        /** Builds the kernel, creating the pipeline on a background thread.
+
+        If the kernel is already in the device's cache, it is returned ready, without
+        spawning a thread. Otherwise features, threads and spec constants are checked on
+        the calling thread. Specialization and pipeline creation by the driver, the expensive
+        part, are done on a new thread. Dispatching blocks until they are finished, see
+        [`Kernel::is_ready()`], and returns their errors.
+
+        The kernel is inserted into the same cache as [`.build()`](Self::build). The cache is
+        shared by all threads and split into shards, each behind a lock that is held while a
+        pipeline is created. Concurrent builds of the same kernel create the pipeline once,
+        waiting on each other instead of compiling twice. Builds and cache lookups of other
+        kernels in the same shard also wait, while other shards are not blocked.
+
+        # Errors
+        - See [`.build()`](Self::build).
+        - Errors from specialization and pipeline creation are returned when dispatching.
+        */
+        pub fn build_async(&self, device: Device) -> Result<Kernel> {
+            self.build_impl(device, true)
+        }
+        fn build_impl(&self, device: Device, background: bool) -> Result<Kernel> {
+            #[cfg(not(feature = "device"))]
+            let _ = background;
             match device.inner() {
                 DeviceInner::Host => {
                     bail!("Kernel `{}` expected device, found host!", self.desc.name);
@@ -1018,12 +2319,18 @@ pub mod __private {
                         bail!("Kernel {name} requires {features:?}, {device:?} has {device_features:?}!");
                     }
                     let threads = self.threads.unwrap_or(info.default_threads());
+                    if desc.threads != 0 && threads != desc.threads {
+                        bail!(
+                            "Kernel {name} threads {threads} does not match LocalSize {}!",
+                            desc.threads
+                        );
+                    }
                     let max_threads = info.max_threads();
                     if threads > max_threads {
                         bail!("Kernel {name} threads {threads} is greater than max_threads {max_threads}!");
                     }
-                    let spec_bytes = self
-                        .spec_consts
+                    let spec_consts = self.spec_consts()?;
+                    let spec_bytes = spec_consts
                         .iter()
                         .flat_map(|x| x.as_bytes())
                         .copied()
@@ -1032,12 +2339,40 @@ pub mod __private {
                     let key = KernelKey {
                         id: self.id,
                         spec_bytes,
+                        deterministic: self.deterministic,
                     };
                     let debug_printf = info.debug_printf();
-                    let inner = RawKernel::cached(device.clone(), key, || {
-                        desc.specialize(threads, &self.spec_consts, debug_printf)
-                            .map(Arc::new)
-                    })?;
+                    let keep_debug_info = info.keep_debug_info();
+                    let max_shared_memory = info.limits().max_compute_shared_memory_size();
+                    let deterministic = self.deterministic;
+                    let desc_fn = {
+                        let desc = desc.clone();
+                        let spec_consts = spec_consts.clone();
+                        let device = device.clone();
+                        move || {
+                            desc.check_capabilities(device_features)?;
+                            let specialized = desc.specialize(
+                                threads,
+                                &spec_consts,
+                                debug_printf,
+                                keep_debug_info,
+                                deterministic,
+                            )?;
+                            check_shared_memory(
+                                &desc.name,
+                                &specialized.spirv,
+                                &device,
+                                max_shared_memory,
+                            )?;
+                            Ok(Arc::new(specialized))
+                        }
+                    };
+                    let inner = if background {
+                        let stub = Arc::new(desc.specialized_stub(threads, &spec_consts));
+                        RawKernel::cached_async(device.clone(), key, stub, desc_fn)
+                    } else {
+                        RawKernel::cached(device.clone(), key, desc_fn)?
+                    };
                     Ok(Kernel {
                         inner,
                         threads,
@@ -1046,9 +2381,100 @@ pub mod __private {
                 }
             }
         }
+        /** Threads per group suited to `device`.
+
+        Picks a multiple of the [subgroup size](crate::device::DeviceInfo::max_subgroup_threads)
+        that is at most [max_threads](crate::device::DeviceInfo::max_threads), instead of the
+        [default](crate::device::DeviceInfo::default_threads). If the threads of the kernel are
+        fixed, ie it was created [from SPIR-V](Self::from_spirv), returns them.
+
+        If host, returns the default of 256. */
+        pub fn auto_threads(&self, device: &Device) -> u32 {
+            if self.desc.threads != 0 {
+                return self.desc.threads;
+            }
+            match device.inner() {
+                DeviceInner::Host => 256,
+                #[cfg(feature = "device")]
+                DeviceInner::Device(device) => {
+                    let info = device.info();
+                    auto_threads(info.max_subgroup_threads(), info.max_threads())
+                }
+            }
+        }
+        #[cfg(feature = "device")]
+        fn specialized_module(&self) -> Result<rspirv::dr::Module> {
+            let name = &self.desc.name;
+            let threads = self.threads.unwrap_or(self.desc.threads);
+            if threads == 0 {
+                bail!("Kernel `{name}` threads must be provided with .with_threads()!");
+            }
+            let spec_consts = self.spec_consts()?;
+            let desc =
+                self.desc
+                    .specialize(threads, &spec_consts, false, true, self.deterministic)?;
+            rspirv::dr::load_words(&desc.spirv)
+                .map_err(|e| anyhow::format_err!("Kernel `{name}` failed to parse SPIR-V! {e}"))
+        }
+        pub fn specialized_disassembly(&self) -> Result<String> {
+            #[cfg(feature = "device")]
+            {
+                use rspirv::binary::Disassemble;
+
+                Ok(self.specialized_module()?.disassemble())
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                Err(crate::device::error::DeviceUnavailable.into())
+            }
+        }
+        pub fn shared_memory_bytes(&self) -> Result<u32> {
+            #[cfg(feature = "device")]
+            {
+                let Some(size) = shared_memory_size(&self.specialized_module()?) else {
+                    bail!(
+                        "Kernel `{}` shared memory contains an unsupported type!",
+                        self.desc.name
+                    );
+                };
+                Ok(size)
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                Err(crate::device::error::DeviceUnavailable.into())
+            }
+        }
         pub fn features(&self) -> Features {
             self.desc.features
         }
+        #[cfg(test)]
+        pub(crate) fn name(&self) -> &str {
+            &self.desc.name
+        }
+        /// The slice parameters of the kernel in declaration order, as
+        /// `(name, scalar_type, mutable, item)`.
+        pub fn slice_params(&self) -> Vec<(String, ScalarType, bool, bool)> {
+            self.desc.slice_params()
+        }
+        /// The push constant parameters of the kernel in declaration order, as
+        /// `(name, scalar_type, array_len)`, where `array_len` is the length of arrays.
+        pub fn push_params(&self) -> Vec<(String, ScalarType, Option<u32>)> {
+            self.desc
+                .push_descs
+                .iter()
+                .map(|x| (x.name.to_string(), x.scalar_type, x.array_len))
+                .collect()
+        }
+        /// Whether the kernel is safe, see [`Kernel::dispatch_safe()`].
+        pub fn safe(&self) -> bool {
+            self.desc.safe
+        }
+    }
+
+    impl AsRef<KernelBuilder> for KernelBuilder {
+        fn as_ref(&self) -> &KernelBuilder {
+            self
+        }
     }
 
     pub enum WithGroups<const G: bool> {}
@@ -1066,140 +2492,397 @@ pub mod __private {
         pub fn threads(&self) -> u32 {
             self.threads
         }
-        pub fn with_global_threads(self, global_threads: u32) -> Self {
+        /// Whether the pipeline is created, see [`KernelBuilder::build_async()`].
+        pub fn is_ready(&self) -> bool {
+            #[cfg(feature = "device")]
+            {
+                self.inner.is_ready()
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn recommended_min_groups(&self) -> u32 {
+            #[cfg(feature = "device")]
+            {
+                self.inner
+                    .device()
+                    .info()
+                    .recommended_min_groups(self.threads)
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn name(&self) -> &str {
+            #[cfg(feature = "device")]
+            {
+                &self.inner.desc().name
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn device(&self) -> Device {
+            #[cfg(feature = "device")]
+            {
+                self.inner.device().into()
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn slice_params(&self) -> Vec<(String, ScalarType, bool, bool)> {
+            #[cfg(feature = "device")]
+            {
+                self.inner.desc().slice_params()
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn with_global_threads(mut self, global_threads: u32) -> Self {
+            self.set_global_threads(global_threads);
+            self
+        }
+        pub fn with_groups(mut self, groups: u32) -> Self {
+            self.set_groups(groups);
+            self
+        }
+        pub fn set_global_threads(&mut self, global_threads: u32) {
+            #[cfg(feature = "device")]
+            {
+                let desc = &self.inner.desc();
+                let threads = desc.threads;
+                let groups = global_threads / threads + u32::from(global_threads % threads != 0);
+                self.set_groups(groups);
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = global_threads;
+                unreachable!()
+            }
+        }
+        pub fn set_groups(&mut self, groups: u32) {
+            #[cfg(feature = "device")]
+            {
+                self.groups.replace(groups);
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = groups;
+                unreachable!()
+            }
+        }
+        /** Dispatches the kernel with slices matched by name.
+
+        Slices can be provided in any order, and are reordered to match the declaration.
+        Prefer [`.dispatch()`](Self::dispatch) when the order is known.
+
+        # Safety
+        See [`.dispatch()`](Self::dispatch).
+
+        # Errors
+        - A slice is missing, unknown, or provided more than once.
+        - A slice has the wrong scalar type or mutability.
+        - See [`.dispatch()`](Self::dispatch).
+        */
+        pub unsafe fn dispatch_named<'a>(
+            &self,
+            slices: impl IntoIterator<Item = (&'a str, KernelSliceArg<'a>)>,
+            push_consts: &[KernelPushArg],
+        ) -> Result<()> {
+            #[cfg(feature = "device")]
+            {
+                let slices = self.inner.desc().named_slices(slices)?;
+                unsafe { self.dispatch(&slices, push_consts) }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = (slices, push_consts);
+                unreachable!()
+            }
+        }
+        /** Dispatches the kernel over `total` global threads, split into tiles.
+
+        Each tile is a separate dispatch of at most [`max_groups`](crate::device::DeviceInfo::max_groups)
+        groups. The kernel must declare a `u32` push constant named `base`, which is set to the
+        global thread of the first thread in the tile, and is omitted from `push_consts`. The
+        kernel adds `base` to its global id, and checks the result against the total, which
+        is typically passed as another push constant. Groups set with
+        [`.with_groups()`](Self::with_groups) are ignored.
+
+        # Safety
+        See [`.dispatch()`](Self::dispatch).
+
+        # Errors
+        - The kernel does not have a `u32` push constant `base`.
+        - See [`.dispatch()`](Self::dispatch).
+        */
+        pub unsafe fn dispatch_tiled(
+            &self,
+            slices: &[KernelSliceArg],
+            push_consts: &[KernelPushArg],
+            total: u32,
+        ) -> Result<()> {
+            #[cfg(feature = "device")]
+            {
+                let desc = self.inner.desc();
+                let (base_index, mut push_consts) = desc.tiled_push_consts(push_consts)?;
+                let max_groups = self.inner.device().info().max_groups();
+                let mut kernel = self.clone();
+                for (base, groups) in super::dispatch_tiles(total, self.threads, max_groups) {
+                    push_consts[base_index] = KernelPushArg::Scalar(ScalarElem::U32(base));
+                    kernel.set_groups(groups);
+                    unsafe {
+                        kernel.dispatch(slices, &push_consts)?;
+                    }
+                }
+                Ok(())
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = (slices, push_consts, total);
+                unreachable!()
+            }
+        }
+        /** Dispatches a safe kernel.
+
+        A kernel is safe if it is not declared `unsafe fn`, which krnlc requires when the kernel
+        has [`UnsafeSlice`](krnl_core::buffer::UnsafeSlice) parameters or otherwise uses
+        unchecked indexing. Kernels created with
+        [`KernelBuilder::from_spirv()`](KernelBuilder::from_spirv) are never safe.
+
+        Unlike [`.dispatch()`](Self::dispatch), the number, scalar types, and mutability of
+        the slices and push constants are always checked.
+
+        # Errors
+        - The kernel is not safe.
+        - The slices or push constants do not match the kernel.
+        - See [`.dispatch()`](Self::dispatch).
+        */
+        pub fn dispatch_safe(
+            &self,
+            slices: &[KernelSliceArg],
+            push_consts: &[KernelPushArg],
+        ) -> Result<()> {
+            #[cfg(feature = "device")]
+            {
+                let desc = self.inner.desc();
+                if !desc.safe {
+                    bail!("Kernel `{}` is not safe!", desc.name);
+                }
+                desc.check_args(slices, push_consts)?;
+                unsafe { self.dispatch(slices, push_consts) }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = (slices, push_consts);
+                unreachable!()
+            }
+        }
+        /// Whether the kernel is safe, see [`.dispatch_safe()`](Self::dispatch_safe).
+        pub fn safe(&self) -> bool {
+            #[cfg(feature = "device")]
+            {
+                self.inner.desc().safe
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub unsafe fn dispatch(
+            &self,
+            slices: &[KernelSliceArg],
+            push_consts: &[KernelPushArg],
+        ) -> Result<()> {
             #[cfg(feature = "device")]
             {
-                let desc = &self.inner.desc();
-                let threads = desc.threads;
-                let groups = global_threads / threads + u32::from(global_threads % threads != 0);
-                self.with_groups(groups)
+                let push_bytes = self.inner.desc().push_consts_bytes(push_consts)?;
+                unsafe { self.dispatch_push_bytes(slices, push_bytes, None) }
             }
             #[cfg(not(feature = "device"))]
             {
-                let _ = global_threads;
+                let _ = (slices, push_consts);
                 unreachable!()
             }
         }
-        pub fn with_groups(self, groups: u32) -> Self {
+        /** Dispatches, signaling `fence` when the dispatch completes.
+
+        # Safety
+        See [`.dispatch()`](Self::dispatch).
+
+        # Errors
+        - `fence` is on a different device.
+        - See [`.dispatch()`](Self::dispatch).
+        */
+        pub unsafe fn dispatch_with_fence(
+            &self,
+            slices: &[KernelSliceArg],
+            push_consts: &[KernelPushArg],
+            fence: &Fence,
+        ) -> Result<()> {
             #[cfg(feature = "device")]
             {
-                Self {
-                    groups: Some(groups),
-                    ..self
+                let device = Device::from(self.inner.device());
+                if fence.device() != &device {
+                    bail!(
+                        "Kernel `{}` is on {device:?}, fence is on {:?}!",
+                        self.inner.desc().name,
+                        fence.device(),
+                    );
                 }
+                let push_bytes = self.inner.desc().push_consts_bytes(push_consts)?;
+                unsafe { self.dispatch_push_bytes(slices, push_bytes, Some(fence)) }
             }
             #[cfg(not(feature = "device"))]
             {
-                let _ = groups;
+                let _ = (slices, push_consts, fence);
                 unreachable!()
             }
         }
-        pub unsafe fn dispatch(
+        /** Dispatches with push constants as bytes.
+
+        For kernels with push constants that can't be described with scalars, like nested
+        structs. `push_bytes` is the push constant block before the offsets and lengths of
+        the slices, which are appended as with [`.dispatch()`](Self::dispatch).
+
+        # Safety
+        See [`.dispatch()`](Self::dispatch). The bytes are not checked against the layout
+        of the push constants.
+
+        # Errors
+        - The length of `push_bytes` does not match the push constants.
+        - See [`.dispatch()`](Self::dispatch).
+        */
+        pub unsafe fn dispatch_raw(
             &self,
             slices: &[KernelSliceArg],
-            push_consts: &[ScalarElem],
+            push_bytes: &[u8],
         ) -> Result<()> {
             #[cfg(feature = "device")]
             {
-                let desc = &self.inner.desc();
-                let kernel_name = &desc.name;
-                let mut buffers = Vec::with_capacity(desc.slice_descs.len());
-                let mut items: Option<u32> = None;
-                let device = self.inner.device();
-                let mut push_bytes = Vec::with_capacity(desc.push_consts_range() as usize);
-                debug_assert_eq!(push_consts.len(), desc.push_descs.len());
-                for (push, push_desc) in push_consts.iter().zip(desc.push_descs.iter()) {
-                    debug_assert_eq!(push.scalar_type(), push_desc.scalar_type);
-                    debug_assert_eq!(push_bytes.len() % push.scalar_type().size(), 0);
-                    push_bytes.extend_from_slice(push.as_bytes());
-                }
-                while push_bytes.len() % 4 != 0 {
-                    push_bytes.push(0);
+                let push_bytes = self.inner.desc().raw_push_consts_bytes(push_bytes)?;
+                unsafe { self.dispatch_push_bytes(slices, push_bytes, None) }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = (slices, push_bytes);
+                unreachable!()
+            }
+        }
+        #[cfg(feature = "device")]
+        unsafe fn dispatch_push_bytes(
+            &self,
+            slices: &[KernelSliceArg],
+            push_bytes: Vec<u8>,
+            fence: Option<&Fence>,
+        ) -> Result<()> {
+            let desc = &self.inner.desc();
+            let kernel_name = &desc.name;
+            let mut buffers = Vec::with_capacity(desc.slice_descs.len());
+            let mut items: Option<u32> = None;
+            let device = self.inner.device();
+            desc.check_devices(&device, slices)?;
+            let mut slice_offsets_lens = Vec::with_capacity(slices.len());
+            for (slice, slice_desc) in slices.iter().zip(desc.slice_descs.iter()) {
+                debug_assert_eq!(slice.scalar_type(), slice_desc.scalar_type);
+                debug_assert!(!slice_desc.mutable || slice.mutable());
+                let slice_name = &slice_desc.name;
+                if slice.len() == 0 {
+                    bail!("Kernel `{kernel_name}`.`{slice_name}` is empty!");
                 }
-                for (slice, slice_desc) in slices.iter().zip(desc.slice_descs.iter()) {
-                    debug_assert_eq!(slice.scalar_type(), slice_desc.scalar_type);
-                    debug_assert!(!slice_desc.mutable || slice.mutable());
-                    let slice_name = &slice_desc.name;
-                    if slice.len() == 0 {
-                        bail!("Kernel `{kernel_name}`.`{slice_name}` is empty!");
-                    }
-                    let buffer = if let Some(buffer) = slice.device_buffer() {
-                        buffer
+                let buffer = slice.device_buffer().unwrap();
+                buffers.push(buffer.clone());
+                if slice_desc.item {
+                    items.replace(if let Some(items) = items {
+                        items.min(slice.len() as u32)
                     } else {
-                        bail!("Kernel `{kernel_name}`.`{slice_name}` expected device, found host!");
-                    };
-                    let buffer_device = buffer.device();
-                    if device != buffer_device {
-                        bail!(
-                            "Kernel `{kernel_name}`.`{slice_name}`, expected `{device:?}`, found {buffer_device:?}!"
-                        );
-                    }
-                    buffers.push(buffer.clone());
-                    if slice_desc.item {
-                        items.replace(if let Some(items) = items {
-                            items.min(slice.len() as u32)
-                        } else {
-                            slice.len() as u32
-                        });
-                    }
-                    let width = slice_desc.scalar_type.size();
-                    let offset = buffer.offset() / width;
-                    let len = buffer.len() / width;
-                    push_bytes.extend_from_slice(&offset.to_u32().unwrap().to_ne_bytes());
-                    push_bytes.extend_from_slice(&len.to_u32().unwrap().to_ne_bytes());
+                        slice.len() as u32
+                    });
                 }
-                let info = self.inner.device().info().clone();
-                let max_groups = info.max_groups();
-                let groups = if let Some(groups) = self.groups {
-                    if groups > max_groups {
-                        bail!("Kernel `{kernel_name}` groups {groups} is greater than max_groups {max_groups}!");
-                    }
-                    groups
-                } else if let Some(items) = items {
-                    let threads = self.threads;
-                    let groups = items / threads + u32::from(items % threads != 0);
-                    groups.min(max_groups)
-                } else {
-                    unreachable!("groups not provided!")
-                };
-                let debug_printf_panic = if info.debug_printf() {
-                    Some(Arc::new(AtomicBool::default()))
-                } else {
-                    None
-                };
-                unsafe {
-                    self.inner.dispatch(
-                        groups,
-                        &buffers,
-                        push_bytes,
-                        debug_printf_panic.clone(),
-                    )?;
+                let width = slice_desc.scalar_type.size();
+                let offset = buffer.offset() / width;
+                let len = buffer.len() / width;
+                slice_offsets_lens.push((offset.to_u32().unwrap(), len.to_u32().unwrap()));
+            }
+            let push_bytes = desc.push_bytes(push_bytes, slice_offsets_lens);
+            let info = self.inner.device().info().clone();
+            let max_groups = info.max_groups();
+            let groups = if let Some(groups) = self.groups {
+                if groups > max_groups {
+                    bail!("Kernel `{kernel_name}` groups {groups} is greater than max_groups {max_groups}!");
                 }
-                if let Some(debug_printf_panic) = debug_printf_panic {
-                    device.wait()?;
-                    while Arc::strong_count(&debug_printf_panic) > 1 {
-                        std::thread::yield_now();
-                    }
-                    if debug_printf_panic.load(Ordering::SeqCst) {
-                        bail!("Kernel `{kernel_name}` panicked!");
-                    }
+                groups
+            } else if let Some(items) = items {
+                let threads = self.threads;
+                let groups = items / threads + u32::from(items % threads != 0);
+                groups.min(max_groups)
+            } else {
+                bail!("Kernel `{kernel_name}` has no item slices, groups or global threads must be provided!");
+            };
+            let debug_printf_panic = if info.debug_printf() {
+                Some(Arc::new(AtomicBool::default()))
+            } else {
+                None
+            };
+            unsafe {
+                self.inner.dispatch(
+                    groups,
+                    &buffers,
+                    push_bytes,
+                    fence,
+                    debug_printf_panic.clone(),
+                )?;
+            }
+            if let Some(debug_printf_panic) = debug_printf_panic {
+                device.wait()?;
+                while Arc::strong_count(&debug_printf_panic) > 1 {
+                    std::thread::yield_now();
                 }
-                Ok(())
+                if debug_printf_panic.load(Ordering::SeqCst) {
+                    bail!("Kernel `{kernel_name}` panicked!");
+                }
+            }
+            Ok(())
+        }
+        pub fn features(&self) -> Features {
+            #[cfg(feature = "device")]
+            {
+                return self.inner.desc().features;
             }
             #[cfg(not(feature = "device"))]
             {
-                let _ = (slices, push_consts);
                 unreachable!()
             }
         }
-        pub fn features(&self) -> Features {
+    }
+
+    impl std::fmt::Debug for Kernel {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             #[cfg(feature = "device")]
             {
-                return self.inner.desc().features;
+                let desc = self.inner.desc();
+                f.debug_struct("Kernel")
+                    .field("name", &desc.name)
+                    .field("threads", &self.threads)
+                    .field("groups", &self.groups)
+                    .field("features", &desc.features)
+                    .field("slices", &desc.slice_descs.len())
+                    .field("push_consts", &desc.push_descs.len())
+                    .finish()
             }
             #[cfg(not(feature = "device"))]
             {
-                unreachable!()
+                f.debug_struct("Kernel")
+                    .field("threads", &self.threads)
+                    .finish_non_exhaustive()
             }
         }
     }
@@ -1210,6 +2893,35 @@ pub mod __private {
         SliceMut(ScalarSliceMut<'a>),
     }
 
+    #[doc(hidden)]
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum KernelPushArg<'a> {
+        Scalar(ScalarElem),
+        Array(&'a [ScalarElem]),
+    }
+
+    #[cfg(feature = "device")]
+    impl KernelPushArg<'_> {
+        pub(crate) fn elems(&self) -> &[ScalarElem] {
+            match self {
+                Self::Scalar(x) => std::slice::from_ref(x),
+                Self::Array(x) => x,
+            }
+        }
+    }
+
+    impl From<ScalarElem> for KernelPushArg<'_> {
+        fn from(elem: ScalarElem) -> Self {
+            Self::Scalar(elem)
+        }
+    }
+
+    impl<'a> From<&'a [ScalarElem]> for KernelPushArg<'a> {
+        fn from(elems: &'a [ScalarElem]) -> Self {
+            Self::Array(elems)
+        }
+    }
+
     #[cfg(feature = "device")]
     impl KernelSliceArg<'_> {
         fn scalar_type(&self) -> ScalarType {
@@ -1238,6 +2950,189 @@ pub mod __private {
         }
     }
 
+    #[cfg(feature = "device")]
+    impl super::KernelDesc {
+        pub(super) fn named_slices<'a>(
+            &self,
+            slices: impl IntoIterator<Item = (&'a str, KernelSliceArg<'a>)>,
+        ) -> Result<Vec<KernelSliceArg<'a>>> {
+            let kernel_name = &self.name;
+            let mut output: Vec<Option<KernelSliceArg>> = std::iter::repeat_with(|| None)
+                .take(self.slice_descs.len())
+                .collect();
+            for (name, slice) in slices {
+                let Some(index) = self.slice_descs.iter().position(|x| x.name == name) else {
+                    bail!("Kernel `{kernel_name}` has no slice `{name}`!");
+                };
+                let slice_desc = &self.slice_descs[index];
+                let scalar_type = slice.scalar_type();
+                if scalar_type != slice_desc.scalar_type {
+                    bail!(
+                        "Kernel `{kernel_name}`.`{name}` expected {:?}, found {scalar_type:?}!",
+                        slice_desc.scalar_type
+                    );
+                }
+                if slice_desc.mutable && !slice.mutable() {
+                    bail!("Kernel `{kernel_name}`.`{name}` expected SliceMut, found Slice!");
+                }
+                if output[index].replace(slice).is_some() {
+                    bail!("Kernel `{kernel_name}`.`{name}` was provided more than once!");
+                }
+            }
+            output
+                .into_iter()
+                .zip(self.slice_descs.iter())
+                .map(|(slice, slice_desc)| {
+                    slice.ok_or_else(|| {
+                        anyhow::format_err!(
+                            "Kernel `{kernel_name}`.`{}` is missing!",
+                            slice_desc.name
+                        )
+                    })
+                })
+                .collect()
+        }
+        pub(super) fn raw_push_consts_bytes(&self, push_bytes: &[u8]) -> Result<Vec<u8>> {
+            let push_consts_len = self.push_consts_len();
+            if push_bytes.len() != push_consts_len {
+                bail!(
+                    "Kernel `{}` expected {push_consts_len} push constant bytes, found {}!",
+                    self.name,
+                    push_bytes.len()
+                );
+            }
+            Ok(push_bytes.to_vec())
+        }
+        // Checks that every slice is on the device the kernel was built for, reporting all
+        // mismatched slices at once.
+        pub(super) fn check_devices(
+            &self,
+            device: &RawDevice,
+            slices: &[KernelSliceArg],
+        ) -> Result<()> {
+            let mismatched: Vec<String> = slices
+                .iter()
+                .zip(self.slice_descs.iter())
+                .filter_map(|(slice, slice_desc)| {
+                    let name = &slice_desc.name;
+                    match slice.device_buffer().map(DeviceBuffer::device) {
+                        Some(buffer_device) if buffer_device == *device => None,
+                        Some(buffer_device) => Some(format!("`{name}` is on {buffer_device:?}")),
+                        None => Some(format!("`{name}` is on host")),
+                    }
+                })
+                .collect();
+            if !mismatched.is_empty() {
+                bail!(
+                    "Kernel `{}` was built for {device:?}, but {}!",
+                    self.name,
+                    mismatched.join(", ")
+                );
+            }
+            Ok(())
+        }
+        pub(super) fn check_args(
+            &self,
+            slices: &[KernelSliceArg],
+            push_consts: &[KernelPushArg],
+        ) -> Result<()> {
+            let kernel_name = &self.name;
+            if slices.len() != self.slice_descs.len() {
+                bail!(
+                    "Kernel `{kernel_name}` expected {} slices, found {}!",
+                    self.slice_descs.len(),
+                    slices.len()
+                );
+            }
+            for (slice, slice_desc) in slices.iter().zip(self.slice_descs.iter()) {
+                let name = &slice_desc.name;
+                let scalar_type = slice.scalar_type();
+                if scalar_type != slice_desc.scalar_type {
+                    bail!(
+                        "Kernel `{kernel_name}`.`{name}` expected {:?}, found {scalar_type:?}!",
+                        slice_desc.scalar_type
+                    );
+                }
+                if slice_desc.mutable && !slice.mutable() {
+                    bail!("Kernel `{kernel_name}`.`{name}` expected SliceMut, found Slice!");
+                }
+            }
+            self.check_push_lens(push_consts)?;
+            for (push, push_desc) in push_consts.iter().zip(self.push_descs.iter()) {
+                let name = &push_desc.name;
+                for elem in push.elems() {
+                    let scalar_type = elem.scalar_type();
+                    if scalar_type != push_desc.scalar_type {
+                        bail!(
+                            "Kernel `{kernel_name}`.`{name}` expected {:?}, found {scalar_type:?}!",
+                            push_desc.scalar_type
+                        );
+                    }
+                }
+            }
+            Ok(())
+        }
+        // Checks the number of push constants, and the length of arrays.
+        pub(super) fn check_push_lens(&self, push_consts: &[KernelPushArg]) -> Result<()> {
+            let kernel_name = &self.name;
+            if push_consts.len() != self.push_descs.len() {
+                bail!(
+                    "Kernel `{kernel_name}` expected {} push constants, found {}!",
+                    self.push_descs.len(),
+                    push_consts.len()
+                );
+            }
+            for (push, push_desc) in push_consts.iter().zip(self.push_descs.iter()) {
+                let name = &push_desc.name;
+                match (push, push_desc.array_len) {
+                    (KernelPushArg::Scalar(_), None) => (),
+                    (KernelPushArg::Scalar(_), Some(len)) => {
+                        bail!("Kernel `{kernel_name}`.`{name}` expected an array of {len}, found a scalar!");
+                    }
+                    (KernelPushArg::Array(elems), None) => {
+                        bail!(
+                            "Kernel `{kernel_name}`.`{name}` expected a scalar, found an array of {}!",
+                            elems.len()
+                        );
+                    }
+                    (KernelPushArg::Array(elems), Some(len)) => {
+                        if elems.len() != len as usize {
+                            bail!(
+                                "Kernel `{kernel_name}`.`{name}` expected an array of {len}, found {}!",
+                                elems.len()
+                            );
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        pub(super) fn tiled_push_consts<'a>(
+            &self,
+            push_consts: &[KernelPushArg<'a>],
+        ) -> Result<(usize, Vec<KernelPushArg<'a>>)> {
+            let kernel_name = &self.name;
+            let Some(index) = self.push_descs.iter().position(|x| x.name == "base") else {
+                bail!("Kernel `{kernel_name}` has no push constant `base`!");
+            };
+            let push_desc = &self.push_descs[index];
+            let scalar_type = push_desc.scalar_type;
+            if scalar_type != ScalarType::U32 || push_desc.array_len.is_some() {
+                bail!("Kernel `{kernel_name}`.`base` expected U32, found {scalar_type:?}!");
+            }
+            if push_consts.len() + 1 != self.push_descs.len() {
+                bail!(
+                    "Kernel `{kernel_name}` expected {} push constants excluding `base`, found {}!",
+                    self.push_descs.len() - 1,
+                    push_consts.len()
+                );
+            }
+            let mut output = push_consts.to_vec();
+            output.insert(index, KernelPushArg::Scalar(ScalarElem::U32(0)));
+            Ok((index, output))
+        }
+    }
+
     impl<'a, T: Scalar> From<Slice<'a, T>> for KernelSliceArg<'a> {
         fn from(slice: Slice<'a, T>) -> Self {
             Self::Slice(slice.into())
@@ -1251,4 +3146,1179 @@ pub mod __private {
     }
 }
 
+#[cfg(feature = "device")]
+use __private::KernelPushArg;
 pub(crate) use __private::{PushDesc, SliceDesc, SpecDesc};
+
+#[cfg(all(test, feature = "device"))]
+mod tests {
+    use super::*;
+    use rspirv::spirv::{
+        AddressingModel, Capability, Decoration, ExecutionMode, ExecutionModel, FunctionControl,
+        MemoryModel, StorageClass,
+    };
+
+    fn specialize_spec_constants(spec_consts: &[ScalarElem]) -> Vec<Operand> {
+        static SPEC_DESCS: [SpecDesc; 6] = [
+            SpecDesc {
+                name: "a",
+                scalar_type: ScalarType::I8,
+            },
+            SpecDesc {
+                name: "b",
+                scalar_type: ScalarType::I16,
+            },
+            SpecDesc {
+                name: "c",
+                scalar_type: ScalarType::I32,
+            },
+            SpecDesc {
+                name: "d",
+                scalar_type: ScalarType::I64,
+            },
+            SpecDesc {
+                name: "e",
+                scalar_type: ScalarType::F32,
+            },
+            SpecDesc {
+                name: "f",
+                scalar_type: ScalarType::F64,
+            },
+        ];
+        let mut builder = rspirv::dr::Builder::new();
+        let types = [
+            builder.type_int(8, 1),
+            builder.type_int(16, 1),
+            builder.type_int(32, 1),
+            builder.type_int(64, 1),
+            builder.type_float(32),
+            builder.type_float(64),
+            builder.type_int(32, 0),
+        ];
+        let ids = [
+            builder.spec_constant_u32(types[0], 0),
+            builder.spec_constant_u32(types[1], 0),
+            builder.spec_constant_u32(types[2], 0),
+            builder.spec_constant_u64(types[3], 0),
+            builder.spec_constant_f32(types[4], 0.),
+            builder.spec_constant_f64(types[5], 0.),
+            builder.spec_constant_u32(types[6], 1),
+        ];
+        for (spec_id, id) in ids.iter().copied().enumerate() {
+            builder.decorate(
+                id,
+                Decoration::SpecId,
+                [Operand::LiteralInt32(spec_id.try_into().unwrap())],
+            );
+        }
+        let desc = KernelDesc {
+            name: "spec_constants".into(),
+            spirv: builder.module().assemble(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &SPEC_DESCS,
+            slice_descs: Cow::Borrowed(&[]),
+            push_descs: Cow::Borrowed(&[]),
+        };
+        let desc = desc
+            .specialize(64, spec_consts, false, false, false)
+            .unwrap();
+        let module = rspirv::dr::load_words(&desc.spirv).unwrap();
+        module
+            .types_global_values
+            .iter()
+            .filter(|inst| inst.class.opcode == rspirv::spirv::Op::SpecConstant)
+            .flat_map(|inst| inst.operands.iter().cloned())
+            .collect()
+    }
+
+    #[test]
+    fn specialize_negative_spec_constants() {
+        let operands = specialize_spec_constants(&[
+            (-1i8).into(),
+            (-2i16).into(),
+            (-3i32).into(),
+            (-4i64).into(),
+            (-5f32).into(),
+            (-6f64).into(),
+        ]);
+        assert_eq!(
+            operands,
+            [
+                Operand::LiteralInt32(-1i32 as u32),
+                Operand::LiteralInt32(-2i32 as u32),
+                Operand::LiteralInt32(-3i32 as u32),
+                Operand::LiteralInt64(-4i64 as u64),
+                Operand::LiteralFloat32(-5.),
+                Operand::LiteralFloat64(-6.),
+                Operand::LiteralInt32(64),
+            ]
+        );
+    }
+
+    fn specialize_bindings(bindings: &[u32]) -> Result<KernelDesc> {
+        static SLICE_DESCS: [SliceDesc; 2] = [
+            SliceDesc {
+                name: "x",
+                scalar_type: ScalarType::U32,
+                mutable: false,
+                item: false,
+            },
+            SliceDesc {
+                name: "y",
+                scalar_type: ScalarType::U32,
+                mutable: true,
+                item: false,
+            },
+        ];
+        let mut builder = rspirv::dr::Builder::new();
+        for binding in bindings.iter().copied() {
+            let id = builder.id();
+            builder.decorate(id, Decoration::DescriptorSet, [Operand::LiteralInt32(0)]);
+            builder.decorate(id, Decoration::Binding, [Operand::LiteralInt32(binding)]);
+        }
+        let desc = KernelDesc {
+            name: "bindings".into(),
+            spirv: builder.module().assemble(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &[],
+            slice_descs: Cow::Borrowed(&SLICE_DESCS),
+            push_descs: Cow::Borrowed(&[]),
+        };
+        desc.specialize(64, &[], false, false, false)
+    }
+
+    #[test]
+    fn push_consts_bytes_nan_inf() {
+        static PUSH_DESCS: [PushDesc; 4] = [
+            PushDesc {
+                name: "a",
+                scalar_type: ScalarType::F32,
+                array_len: None,
+            },
+            PushDesc {
+                name: "b",
+                scalar_type: ScalarType::F32,
+                array_len: None,
+            },
+            PushDesc {
+                name: "c",
+                scalar_type: ScalarType::F32,
+                array_len: None,
+            },
+            PushDesc {
+                name: "d",
+                scalar_type: ScalarType::F64,
+                array_len: None,
+            },
+        ];
+        let desc = KernelDesc {
+            name: "push_consts".into(),
+            spirv: Vec::new(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &[],
+            slice_descs: Cow::Borrowed(&[]),
+            push_descs: Cow::Borrowed(&PUSH_DESCS),
+        };
+        let nan_payload = f32::from_bits(0x7fc0_1234);
+        let push_consts = [
+            ScalarElem::from(f32::NAN).into(),
+            ScalarElem::from(nan_payload).into(),
+            ScalarElem::from(f32::NEG_INFINITY).into(),
+            ScalarElem::from(f64::INFINITY).into(),
+        ];
+        let bytes = desc.push_consts_bytes(&push_consts).unwrap();
+        let word = |i: usize| u32::from_ne_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        assert_eq!(word(0), f32::NAN.to_bits());
+        assert_eq!(word(1), 0x7fc0_1234);
+        assert_eq!(word(2), f32::NEG_INFINITY.to_bits());
+        assert_eq!(
+            u64::from_ne_bytes(bytes[16..24].try_into().unwrap()),
+            f64::INFINITY.to_bits()
+        );
+        assert_ne!(
+            ScalarElem::from(f32::NAN).as_bytes(),
+            ScalarElem::from(nan_payload).as_bytes()
+        );
+        assert_eq!(spec_constant_bits(nan_payload.into()), 0x7fc0_1234);
+    }
+
+    fn specialize_push_array(spec_consts: &[ScalarElem]) -> Result<KernelDesc> {
+        static SPEC_DESCS: [SpecDesc; 1] = [SpecDesc {
+            name: "N",
+            scalar_type: ScalarType::U32,
+        }];
+        static PUSH_DESCS: [PushDesc; 1] = [PushDesc {
+            name: "a",
+            scalar_type: ScalarType::U32,
+            array_len: None,
+        }];
+        let mut builder = rspirv::dr::Builder::new();
+        let u32 = builder.type_int(32, 0);
+        let length = builder.spec_constant_u32(u32, 1);
+        builder.decorate(length, Decoration::SpecId, [Operand::LiteralInt32(0)]);
+        let array = builder.type_array(u32, length);
+        builder.decorate(array, Decoration::ArrayStride, [Operand::LiteralInt32(4)]);
+        let push_block = builder.type_struct([array]);
+        builder.decorate(push_block, Decoration::Block, []);
+        builder.member_decorate(
+            push_block,
+            0,
+            Decoration::Offset,
+            [Operand::LiteralInt32(0)],
+        );
+        let push_pointer = builder.type_pointer(None, StorageClass::PushConstant, push_block);
+        builder.variable(push_pointer, None, StorageClass::PushConstant, None);
+        let desc = KernelDesc {
+            name: "push_array".into(),
+            spirv: builder.module().assemble(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &SPEC_DESCS,
+            slice_descs: Cow::Borrowed(&[]),
+            push_descs: Cow::Borrowed(&PUSH_DESCS),
+        };
+        desc.specialize(64, spec_consts, false, false, false)
+    }
+
+    #[test]
+    fn specialize_push_block_size() {
+        let desc = specialize_push_array(&[ScalarElem::U32(1)]).unwrap();
+        let module = rspirv::dr::load_words(&desc.spirv).unwrap();
+        assert_eq!(push_block_size(&module), Some(desc.push_consts_range()));
+        let error = specialize_push_array(&[ScalarElem::U32(4)])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("push constants are 16 bytes after specialization, expected at most 4"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn kernel_builder_specialize_one() {
+        static SPEC_DESCS: [SpecDesc; 2] = [
+            SpecDesc {
+                name: "A",
+                scalar_type: ScalarType::U32,
+            },
+            SpecDesc {
+                name: "B",
+                scalar_type: ScalarType::F32,
+            },
+        ];
+        let builder = kernel_builder_from_words("specialize_one", &[0], &SPEC_DESCS);
+        let error = builder.spec_consts().unwrap_err().to_string();
+        assert!(
+            error.contains("spec constants `A`, `B` are not specialized"),
+            "{error}"
+        );
+        let error = builder
+            .clone()
+            .specialize_one("C", ScalarElem::U32(1))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("has no spec constant `C`"), "{error}");
+        let error = builder
+            .clone()
+            .specialize_one("A", ScalarElem::F32(1.))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("`A` expected U32, found F32"), "{error}");
+        let builder = builder.specialize_one("B", ScalarElem::F32(2.)).unwrap();
+        let error = builder.spec_consts().unwrap_err().to_string();
+        assert!(
+            error.contains("spec constants `A` are not specialized"),
+            "{error}"
+        );
+        let builder = builder.specialize_one("A", ScalarElem::U32(1)).unwrap();
+        assert_eq!(
+            builder.spec_consts().unwrap(),
+            [ScalarElem::U32(1), ScalarElem::F32(2.)]
+        );
+    }
+
+    #[test]
+    fn kernel_builder_specialized_disassembly() {
+        static SPEC_DESCS: [SpecDesc; 1] = [SpecDesc {
+            name: "N",
+            scalar_type: ScalarType::U32,
+        }];
+        let mut builder = rspirv::dr::Builder::new();
+        let u32 = builder.type_int(32, 0);
+        for spec_id in 0..2 {
+            let id = builder.spec_constant_u32(u32, 1);
+            builder.decorate(id, Decoration::SpecId, [Operand::LiteralInt32(spec_id)]);
+        }
+        let words = builder.module().assemble();
+        let builder = kernel_builder_from_words("specialized_disassembly", &words, &SPEC_DESCS)
+            .specialize(&[ScalarElem::U32(7)]);
+        let error = builder.specialized_disassembly().unwrap_err().to_string();
+        assert!(error.contains("threads must be provided"), "{error}");
+        let disassembly = builder.with_threads(64).specialized_disassembly().unwrap();
+        let spec_consts: Vec<_> = disassembly
+            .lines()
+            .filter(|x| x.contains("OpSpecConstant"))
+            .collect();
+        assert_eq!(spec_consts.len(), 2, "{disassembly}");
+        assert!(spec_consts[0].ends_with(" 7"), "{disassembly}");
+        assert!(spec_consts[1].ends_with(" 64"), "{disassembly}");
+    }
+
+    #[test]
+    fn kernel_builder_shared_memory_bytes() {
+        use rspirv::spirv::StorageClass;
+
+        static SPEC_DESCS: [SpecDesc; 1] = [SpecDesc {
+            name: "N",
+            scalar_type: ScalarType::U32,
+        }];
+        let mut builder = rspirv::dr::Builder::new();
+        let u32 = builder.type_int(32, 0);
+        let f32 = builder.type_float(32);
+        let vec2 = builder.type_vector(u32, 2);
+        let n = builder.spec_constant_u32(u32, 8192);
+        builder.decorate(n, Decoration::SpecId, [Operand::LiteralInt32(0)]);
+        let threads = builder.spec_constant_u32(u32, 1);
+        builder.decorate(threads, Decoration::SpecId, [Operand::LiteralInt32(1)]);
+        let array = builder.type_array(f32, n);
+        let array_ptr = builder.type_pointer(None, StorageClass::Workgroup, array);
+        builder.variable(array_ptr, None, StorageClass::Workgroup, None);
+        let vec2_ptr = builder.type_pointer(None, StorageClass::Workgroup, vec2);
+        builder.variable(vec2_ptr, None, StorageClass::Workgroup, None);
+        let words = builder.module().assemble();
+        let builder = kernel_builder_from_words("shared_memory_bytes", &words, &SPEC_DESCS)
+            .with_threads(64)
+            .specialize(&[ScalarElem::U32(8192)]);
+        let bytes = builder.shared_memory_bytes().unwrap();
+        assert_eq!(bytes, 8192 * 4 + 8);
+        check_shared_memory("shared_memory_bytes", &words, &"device", bytes).unwrap();
+        let error = check_shared_memory("shared_memory_bytes", &words, &"device", 16384)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("uses 32776 bytes of shared memory")
+                && error.contains("supports at most 16384"),
+            "{error}"
+        );
+        let error = check_shared_memory("shared_memory_bytes", &words[..3], &"device", bytes)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("failed to parse SPIR-V"), "{error}");
+    }
+
+    #[test]
+    fn specialize_spec_constants_mismatch() {
+        let error = specialize_push_array(&[]).unwrap_err().to_string();
+        assert!(
+            error.contains("expected 1 spec constants, found 0"),
+            "{error}"
+        );
+        let error = specialize_push_array(&[ScalarElem::U32(1), ScalarElem::U32(1)])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("expected 1 spec constants, found 2"),
+            "{error}"
+        );
+        let error = specialize_push_array(&[ScalarElem::I32(1)])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("`N` expected U32, found I32"), "{error}");
+    }
+
+    #[test]
+    fn kernel_desc_from_spirv_push_block_size() {
+        let desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        let module = rspirv::dr::load_words(&desc.spirv).unwrap();
+        assert_eq!(push_block_size(&module), Some(16));
+        desc.specialize(64, &[], false, false, false).unwrap();
+    }
+
+    fn specialize_slice_writes(mutable: bool) -> Result<KernelDesc> {
+        let mut builder = rspirv::dr::Builder::new();
+        let void = builder.type_void();
+        let u32 = builder.type_int(32, 0);
+        let array = builder.type_runtime_array(u32);
+        let block = builder.type_struct([array]);
+        let pointer = builder.type_pointer(None, StorageClass::StorageBuffer, block);
+        let element_pointer = builder.type_pointer(None, StorageClass::StorageBuffer, u32);
+        let variable = builder.variable(pointer, None, StorageClass::StorageBuffer, None);
+        builder.decorate(
+            variable,
+            Decoration::DescriptorSet,
+            [Operand::LiteralInt32(0)],
+        );
+        builder.decorate(variable, Decoration::Binding, [Operand::LiteralInt32(0)]);
+        let zero = builder.constant_u32(u32, 0);
+        let one = builder.constant_u32(u32, 1);
+        let function_type = builder.type_function(void, []);
+        builder
+            .begin_function(void, None, FunctionControl::NONE, function_type)
+            .unwrap();
+        builder.begin_block(None).unwrap();
+        let element = builder
+            .access_chain(element_pointer, None, variable, [zero, zero])
+            .unwrap();
+        builder.store(element, one, None, []).unwrap();
+        builder.ret().unwrap();
+        builder.end_function().unwrap();
+        let desc = KernelDesc {
+            name: "slice_writes".into(),
+            spirv: builder.module().assemble(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &[],
+            slice_descs: Cow::Owned(vec![SliceDesc {
+                name: "y",
+                scalar_type: ScalarType::U32,
+                mutable,
+                item: true,
+            }]),
+            push_descs: Cow::Borrowed(&[]),
+        };
+        desc.specialize(64, &[], false, false, false)
+    }
+
+    #[test]
+    fn specialize_slice_writes_immutable() {
+        specialize_slice_writes(true).unwrap();
+        let error = specialize_slice_writes(false).unwrap_err().to_string();
+        assert!(
+            error.contains("writes to immutable slices item `y`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn specialize_bindings_valid() {
+        specialize_bindings(&[0, 1]).unwrap();
+        specialize_bindings(&[1]).unwrap();
+    }
+
+    #[test]
+    fn specialize_bindings_duplicate() {
+        let error = specialize_bindings(&[0, 1, 1]).unwrap_err().to_string();
+        assert!(
+            error.contains("binding 1 (`y`) is declared 2 times"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn specialize_bindings_out_of_range() {
+        let error = specialize_bindings(&[0, 1, 3]).unwrap_err().to_string();
+        assert!(error.contains("binding 3 is out of range"), "{error}");
+    }
+
+    #[test]
+    fn specialize_malformed_spec_constants() {
+        static SPEC_DESCS: [SpecDesc; 1] = [SpecDesc {
+            name: "a",
+            scalar_type: ScalarType::U32,
+        }];
+        let mut builder = rspirv::dr::Builder::new();
+        let u32_type = builder.type_int(32, 0);
+        let f32_type = builder.type_float(32);
+        let ids = [
+            builder.spec_constant_u32(u32_type, 0),
+            builder.spec_constant_f32(f32_type, 1.),
+            builder.spec_constant_u32(u32_type, 0),
+        ];
+        for (spec_id, id) in ids.iter().copied().enumerate() {
+            builder.decorate(
+                id,
+                Decoration::SpecId,
+                [Operand::LiteralInt32(spec_id.try_into().unwrap())],
+            );
+        }
+        let desc = KernelDesc {
+            name: "malformed".into(),
+            spirv: builder.module().assemble(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &SPEC_DESCS,
+            slice_descs: Cow::Borrowed(&[]),
+            push_descs: Cow::Borrowed(&[]),
+        };
+        let error = desc
+            .specialize(64, &[1u32.into()], false, false, false)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("spec id 1 (`threads`)"), "{error}");
+        assert!(error.contains("spec id 2 is out of range"), "{error}");
+    }
+
+    #[test]
+    fn specialize_extreme_spec_constants() {
+        let operands = specialize_spec_constants(&[
+            i8::MIN.into(),
+            i16::MIN.into(),
+            i32::MIN.into(),
+            i64::MIN.into(),
+            f32::MIN.into(),
+            f64::MIN.into(),
+        ]);
+        assert_eq!(
+            operands,
+            [
+                Operand::LiteralInt32(i8::MIN as i32 as u32),
+                Operand::LiteralInt32(i16::MIN as i32 as u32),
+                Operand::LiteralInt32(i32::MIN as u32),
+                Operand::LiteralInt64(i64::MIN as u64),
+                Operand::LiteralFloat32(f32::MIN),
+                Operand::LiteralFloat64(f64::MIN),
+                Operand::LiteralInt32(64),
+            ]
+        );
+    }
+
+    // A builder for a kernel with `words` and `spec_descs`, as the macro would generate.
+    fn kernel_builder_from_words(
+        name: &'static str,
+        words: &[u32],
+        spec_descs: &'static [SpecDesc],
+    ) -> __private::KernelBuilder {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytemuck::cast_slice(words)).unwrap();
+        let spirv: &'static [u8] = Vec::leak(encoder.finish().unwrap());
+        let desc = __private::KernelDesc::from_args(__private::KernelDescArgs {
+            name,
+            spirv,
+            features: Features::empty(),
+            safe: true,
+            spec_descs,
+            slice_descs: &[],
+            push_descs: &[],
+        });
+        __private::KernelBuilder::from_desc(desc).unwrap()
+    }
+
+    // A module like glslang would produce for:
+    //
+    // layout(local_size_x = 64) in;
+    // layout(binding = 0) readonly buffer X { double x[]; };
+    // layout(binding = 1) buffer Y { double y[]; };
+    // layout(push_constant) uniform P { uint16_t n; double alpha; };
+    fn spirv_module(bindings: &[u32], local_size: [u32; 3]) -> Vec<u32> {
+        let mut builder = rspirv::dr::Builder::new();
+        builder.capability(Capability::Shader);
+        builder.capability(Capability::Int16);
+        builder.capability(Capability::Float64);
+        builder.capability(Capability::StoragePushConstant16);
+        builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+        let void = builder.type_void();
+        let u16 = builder.type_int(16, 0);
+        let f64 = builder.type_float(64);
+        let mut variables = Vec::new();
+        for (i, binding) in bindings.iter().copied().enumerate() {
+            let array = builder.id();
+            builder
+                .module_mut()
+                .types_global_values
+                .push(rspirv::dr::Instruction::new(
+                    rspirv::spirv::Op::TypeRuntimeArray,
+                    None,
+                    Some(array),
+                    vec![Operand::IdRef(f64)],
+                ));
+            builder.decorate(array, Decoration::ArrayStride, [Operand::LiteralInt32(8)]);
+            let block = builder.type_struct([array]);
+            builder.decorate(block, Decoration::Block, []);
+            builder.member_decorate(block, 0, Decoration::Offset, [Operand::LiteralInt32(0)]);
+            let name = ["x", "y", "z"][i];
+            builder.member_name(block, 0, name);
+            if i == 0 {
+                builder.member_decorate(block, 0, Decoration::NonWritable, []);
+            }
+            let pointer = builder.type_pointer(None, StorageClass::StorageBuffer, block);
+            let variable = builder.variable(pointer, None, StorageClass::StorageBuffer, None);
+            builder.decorate(
+                variable,
+                Decoration::DescriptorSet,
+                [Operand::LiteralInt32(0)],
+            );
+            builder.decorate(
+                variable,
+                Decoration::Binding,
+                [Operand::LiteralInt32(binding)],
+            );
+            variables.push(variable);
+        }
+        let push_block = builder.type_struct([u16, f64]);
+        builder.decorate(push_block, Decoration::Block, []);
+        builder.member_decorate(
+            push_block,
+            0,
+            Decoration::Offset,
+            [Operand::LiteralInt32(0)],
+        );
+        builder.member_decorate(
+            push_block,
+            1,
+            Decoration::Offset,
+            [Operand::LiteralInt32(8)],
+        );
+        builder.member_name(push_block, 0, "n");
+        builder.member_name(push_block, 1, "alpha");
+        let push_pointer = builder.type_pointer(None, StorageClass::PushConstant, push_block);
+        let push_variable = builder.variable(push_pointer, None, StorageClass::PushConstant, None);
+        variables.push(push_variable);
+        let function_type = builder.type_function(void, []);
+        let function = builder
+            .begin_function(void, None, FunctionControl::NONE, function_type)
+            .unwrap();
+        builder.begin_block(None).unwrap();
+        builder.ret().unwrap();
+        builder.end_function().unwrap();
+        builder.entry_point(ExecutionModel::GLCompute, function, "axpy", variables);
+        builder.execution_mode(function, ExecutionMode::LocalSize, local_size);
+        builder.module().assemble()
+    }
+
+    // A module with a single Uniform variable, of a struct decorated with `block_decoration`.
+    fn spirv_module_uniform(block_decoration: Decoration) -> Vec<u32> {
+        let mut builder = rspirv::dr::Builder::new();
+        builder.capability(Capability::Shader);
+        builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+        let void = builder.type_void();
+        let u32 = builder.type_int(32, 0);
+        let array = builder.type_runtime_array(u32);
+        builder.decorate(array, Decoration::ArrayStride, [Operand::LiteralInt32(4)]);
+        let block = builder.type_struct([array]);
+        builder.decorate(block, block_decoration, []);
+        builder.member_decorate(block, 0, Decoration::Offset, [Operand::LiteralInt32(0)]);
+        builder.member_name(block, 0, "y");
+        let pointer = builder.type_pointer(None, StorageClass::Uniform, block);
+        let variable = builder.variable(pointer, None, StorageClass::Uniform, None);
+        builder.decorate(
+            variable,
+            Decoration::DescriptorSet,
+            [Operand::LiteralInt32(0)],
+        );
+        builder.decorate(variable, Decoration::Binding, [Operand::LiteralInt32(0)]);
+        let function_type = builder.type_function(void, []);
+        let function = builder
+            .begin_function(void, None, FunctionControl::NONE, function_type)
+            .unwrap();
+        builder.begin_block(None).unwrap();
+        builder.ret().unwrap();
+        builder.end_function().unwrap();
+        builder.entry_point(ExecutionModel::GLCompute, function, "main", [variable]);
+        builder.execution_mode(function, ExecutionMode::LocalSize, [64, 1, 1]);
+        builder.module().assemble()
+    }
+
+    #[test]
+    fn kernel_desc_from_spirv_buffer_block() {
+        let desc =
+            KernelDesc::from_spirv(&spirv_module_uniform(Decoration::BufferBlock), "main").unwrap();
+        let slices: Vec<_> = desc
+            .slice_descs
+            .iter()
+            .map(|x| (x.name, x.scalar_type, x.mutable))
+            .collect();
+        assert_eq!(slices, [("y", ScalarType::U32, true)]);
+        let error = KernelDesc::from_spirv(&spirv_module_uniform(Decoration::Block), "main")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            error.contains("only storage buffers and push constants are supported"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn kernel_desc_from_spirv() {
+        let desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        assert_eq!(desc.name, "axpy");
+        assert_eq!(desc.threads, 64);
+        assert_eq!(
+            desc.features,
+            Features::INT16 | Features::FLOAT64 | Features::PUSH_CONSTANT16
+        );
+        let slices: Vec<_> = desc
+            .slice_descs
+            .iter()
+            .map(|x| (x.name, x.scalar_type, x.mutable))
+            .collect();
+        assert_eq!(
+            slices,
+            [("x", ScalarType::F64, false), ("y", ScalarType::F64, true)]
+        );
+        let push_consts: Vec<_> = desc
+            .push_descs
+            .iter()
+            .map(|x| (x.name, x.scalar_type))
+            .collect();
+        assert_eq!(
+            push_consts,
+            [("n", ScalarType::U16), ("alpha", ScalarType::F64)]
+        );
+        assert_eq!(desc.push_consts_range(), 16 + 2 * 8);
+        let push_consts_bytes = desc
+            .push_consts_bytes(&[ScalarElem::U16(3).into(), ScalarElem::F64(2.).into()])
+            .unwrap();
+        let raw_push_consts_bytes: Vec<u8> = 3u16
+            .to_ne_bytes()
+            .into_iter()
+            .chain([0; 6])
+            .chain(2f64.to_ne_bytes())
+            .collect();
+        assert_eq!(
+            desc.raw_push_consts_bytes(&raw_push_consts_bytes).unwrap(),
+            push_consts_bytes
+        );
+        let error = desc
+            .raw_push_consts_bytes(&raw_push_consts_bytes[..10])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("expected 16 push constant bytes, found 10"),
+            "{error}"
+        );
+        let push_bytes = desc.push_bytes(push_consts_bytes, [(1, 10), (0, 20)]);
+        assert_eq!(push_bytes.len(), 16 + 2 * 8);
+        assert_eq!(&push_bytes[..2], 3u16.to_ne_bytes());
+        assert_eq!(&push_bytes[8..16], 2f64.to_ne_bytes());
+        let offsets_lens: Vec<u32> = push_bytes[16..]
+            .chunks(4)
+            .map(|x| u32::from_ne_bytes(x.try_into().unwrap()))
+            .collect();
+        assert_eq!(offsets_lens, [1, 10, 0, 20]);
+        let module = rspirv::dr::load_words(&desc.spirv).unwrap();
+        assert_eq!(
+            module.entry_points[0].operands[2],
+            Operand::LiteralString("main".to_string())
+        );
+        desc.specialize(64, &[], false, false, false).unwrap();
+    }
+
+    #[test]
+    fn specialize_deterministic() {
+        let mut builder = rspirv::dr::Builder::new();
+        let void = builder.type_void();
+        let f32 = builder.type_float(32);
+        let a = builder.constant_f32(f32, 2.);
+        let function_type = builder.type_function(void, []);
+        builder
+            .begin_function(void, None, FunctionControl::NONE, function_type)
+            .unwrap();
+        builder.begin_block(None).unwrap();
+        let b = builder.f_mul(f32, None, a, a).unwrap();
+        let c = builder.f_add(f32, None, b, a).unwrap();
+        builder.ret().unwrap();
+        builder.end_function().unwrap();
+        let desc = KernelDesc {
+            name: "deterministic".into(),
+            spirv: builder.module().assemble(),
+            features: Features::empty(),
+            safe: false,
+            threads: 0,
+            spec_descs: &[],
+            slice_descs: Cow::Borrowed(&[]),
+            push_descs: Cow::Borrowed(&[]),
+        };
+        let no_contraction = |desc: &KernelDesc| -> Vec<u32> {
+            let module = rspirv::dr::load_words(&desc.spirv).unwrap();
+            module
+                .annotations
+                .iter()
+                .filter_map(|inst| match inst.operands.as_slice() {
+                    [Operand::IdRef(id), Operand::Decoration(Decoration::NoContraction)] => {
+                        Some(*id)
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+        let fast = desc.specialize(64, &[], false, false, false).unwrap();
+        assert!(no_contraction(&fast).is_empty());
+        let deterministic = desc.specialize(64, &[], false, false, true).unwrap();
+        assert_eq!(no_contraction(&deterministic), [b, c]);
+        let again = deterministic
+            .specialize(64, &[], false, false, true)
+            .unwrap();
+        assert_eq!(no_contraction(&again), [b, c]);
+        assert_eq!(
+            desc.specialize(64, &[], false, false, true).unwrap().spirv,
+            deterministic.spirv
+        );
+    }
+
+    #[test]
+    fn specialize_keep_debug_info() {
+        let desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        let stripped = desc.specialize(64, &[], false, false, false).unwrap();
+        let module = rspirv::dr::load_words(&stripped.spirv).unwrap();
+        assert!(module.debug_names.is_empty());
+        let kept = desc.specialize(64, &[], false, true, false).unwrap();
+        let module = rspirv::dr::load_words(&kept.spirv).unwrap();
+        assert!(!module.debug_names.is_empty());
+    }
+
+    #[test]
+    fn kernel_desc_check_capabilities() {
+        let mut desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        desc.check_capabilities(desc.features).unwrap();
+        let error = desc
+            .check_capabilities(Features::INT16 | Features::PUSH_CONSTANT16)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("capability Float64 requires Features(FLOAT64)"),
+            "{error}"
+        );
+        let mut module = rspirv::dr::load_words(&desc.spirv).unwrap();
+        let mut builder = rspirv::dr::Builder::new_from_module(module);
+        builder.capability(Capability::Int64Atomics);
+        module = builder.module();
+        desc.spirv = module.assemble();
+        let error = desc
+            .check_capabilities(Features::all())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("capability Int64Atomics is not supported"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn kernel_dispatch_safe() {
+        use crate::buffer::{kernels, Buffer};
+
+        let Ok(device) = Device::builder().build() else {
+            return;
+        };
+        let kernel = kernels::fill_u32::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        let mut y = Buffer::<u32>::zeros(device, 10).unwrap();
+        kernel.dispatch_safe(7, y.as_slice_mut()).unwrap();
+        assert_eq!(y.to_vec().unwrap(), [7; 10]);
+    }
+
+    #[test]
+    fn kernel_no_slices() {
+        let words = spirv_module(&[], [64, 1, 1]);
+        let desc = KernelDesc::from_spirv(&words, "axpy").unwrap();
+        assert!(desc.slice_descs.is_empty());
+        desc.specialize(64, &[], false, false, false).unwrap();
+        let Ok(device) = Device::builder().build() else {
+            return;
+        };
+        let kernel = __private::KernelBuilder::from_spirv(&words, "axpy")
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        let push_consts = [ScalarElem::U16(1).into(), ScalarElem::F64(2.).into()];
+        let error = unsafe { kernel.dispatch(&[], &push_consts) }
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("groups or global threads"), "{error}");
+        unsafe {
+            kernel.with_groups(1).dispatch(&[], &push_consts).unwrap();
+        }
+        device.wait().unwrap();
+    }
+
+    #[test]
+    fn kernel_desc_from_spirv_errors() {
+        let spirv = spirv_module(&[0, 1], [64, 1, 1]);
+        let error = KernelDesc::from_spirv(&spirv, "main")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("entry point not found"), "{error}");
+        let spirv = spirv_module(&[0, 1], [8, 8, 1]);
+        let error = KernelDesc::from_spirv(&spirv, "axpy")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("must be one dimensional"), "{error}");
+        let spirv = spirv_module(&[0, 0], [64, 1, 1]);
+        let error = KernelDesc::from_spirv(&spirv, "axpy")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("binding 0 is declared more than once"),
+            "{error}"
+        );
+        let spirv = spirv_module(&[0, 2], [64, 1, 1]);
+        let error = KernelDesc::from_spirv(&spirv, "axpy")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("binding 1 is missing"), "{error}");
+    }
+
+    #[test]
+    fn kernel_desc_named_slices() {
+        use crate::buffer::{Slice, SliceMut};
+        use __private::KernelSliceArg;
+
+        let desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        let x = [1f64; 4];
+        let mut y = [2f64; 4];
+        let slices = desc
+            .named_slices([
+                ("y", SliceMut::from(y.as_mut_slice()).into()),
+                ("x", Slice::from(x.as_slice()).into()),
+            ])
+            .unwrap();
+        assert!(matches!(
+            slices.as_slice(),
+            [KernelSliceArg::Slice(_), KernelSliceArg::SliceMut(_)]
+        ));
+        let error = desc
+            .named_slices([("x", Slice::from(x.as_slice()).into())])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("`y` is missing"), "{error}");
+        let error = desc
+            .named_slices([("z", Slice::from(x.as_slice()).into())])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("has no slice `z`"), "{error}");
+        let error = desc
+            .named_slices([
+                ("x", Slice::from(x.as_slice()).into()),
+                ("y", Slice::from(x.as_slice()).into()),
+            ])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("expected SliceMut"), "{error}");
+        let error = desc
+            .named_slices([
+                ("x", Slice::from(x.as_slice()).into()),
+                ("x", Slice::from(x.as_slice()).into()),
+            ])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("more than once"), "{error}");
+        let z = [1f32; 4];
+        let error = desc
+            .named_slices([("x", Slice::from(z.as_slice()).into())])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("expected F64, found F32"), "{error}");
+    }
+
+    #[test]
+    fn kernel_builder_params() {
+        let builder =
+            __private::KernelBuilder::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy")
+                .unwrap();
+        assert_eq!(
+            builder.slice_params(),
+            [
+                ("x".to_string(), ScalarType::F64, false, false),
+                ("y".to_string(), ScalarType::F64, true, false),
+            ]
+        );
+        assert_eq!(
+            builder.push_params(),
+            [
+                ("n".to_string(), ScalarType::U16, None),
+                ("alpha".to_string(), ScalarType::F64, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn kernel_dispatch_tiles() {
+        assert_eq!(dispatch_tiles(0, 64, 4).count(), 0);
+        assert_eq!(dispatch_tiles(1, 64, 4).collect::<Vec<_>>(), [(0, 1)]);
+        assert_eq!(
+            dispatch_tiles(64 * 9 + 1, 64, 4).collect::<Vec<_>>(),
+            [(0, 4), (64 * 4, 4), (64 * 8, 2)]
+        );
+        let (base, groups) = dispatch_tiles(u32::MAX, 256, 65535).last().unwrap();
+        assert_eq!(u64::from(base) + u64::from(groups) * 256, 1 << 32);
+    }
+
+    #[test]
+    fn kernel_auto_threads() {
+        assert_eq!(auto_threads(32, 1024), 128);
+        assert_eq!(auto_threads(64, 1024), 256);
+        assert_eq!(auto_threads(8, 1024), 64);
+        assert_eq!(auto_threads(128, 256), 256);
+    }
+
+    #[test]
+    fn kernel_desc_tiled_push_consts() {
+        let mut desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        let error = desc
+            .tiled_push_consts(&[ScalarElem::U16(1).into()])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("no push constant `base`"), "{error}");
+        desc.push_descs = vec![
+            PushDesc {
+                name: "alpha",
+                scalar_type: ScalarType::F64,
+                array_len: None,
+            },
+            PushDesc {
+                name: "base",
+                scalar_type: ScalarType::U32,
+                array_len: None,
+            },
+            PushDesc {
+                name: "n",
+                scalar_type: ScalarType::U16,
+                array_len: None,
+            },
+        ]
+        .into();
+        let (index, push_consts) = desc
+            .tiled_push_consts(&[ScalarElem::F64(2.).into(), ScalarElem::U16(1).into()])
+            .unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(
+            push_consts,
+            [
+                ScalarElem::F64(2.).into(),
+                ScalarElem::U32(0).into(),
+                ScalarElem::U16(1).into()
+            ]
+        );
+        let error = desc
+            .tiled_push_consts(&[ScalarElem::F64(2.).into()])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("expected 2 push constants"), "{error}");
+    }
+    #[test]
+    fn kernel_desc_check_args() {
+        let desc = KernelDesc::from_spirv(&spirv_module(&[0, 1], [64, 1, 1]), "axpy").unwrap();
+        assert!(!desc.safe);
+        let error = desc.check_args(&[], &[]).unwrap_err().to_string();
+        assert!(error.contains("expected 2 slices, found 0"), "{error}");
+        let desc = KernelDesc::from_spirv(&spirv_module(&[], [64, 1, 1]), "axpy").unwrap();
+        let error = desc
+            .check_args(&[], &[ScalarElem::U16(1).into()])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("expected 2 push constants, found 1"),
+            "{error}"
+        );
+        let error = desc
+            .check_args(
+                &[],
+                &[ScalarElem::U32(1).into(), ScalarElem::F64(2.).into()],
+            )
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("`n` expected U16, found U32"), "{error}");
+        desc.check_args(
+            &[],
+            &[ScalarElem::U16(1).into(), ScalarElem::F64(2.).into()],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn kernel_desc_push_array() {
+        let mut builder = rspirv::dr::Builder::new();
+        builder.capability(Capability::Shader);
+        builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+        let void = builder.type_void();
+        let u32 = builder.type_int(32, 0);
+        let f32 = builder.type_float(32);
+        let len = builder.constant_u32(u32, 4);
+        let array = builder.type_array(f32, len);
+        builder.decorate(array, Decoration::ArrayStride, [Operand::LiteralInt32(4)]);
+        let push_block = builder.type_struct([u32, array]);
+        builder.decorate(push_block, Decoration::Block, []);
+        for (member, offset) in [(0, 0), (1, 4)] {
+            builder.member_decorate(
+                push_block,
+                member,
+                Decoration::Offset,
+                [Operand::LiteralInt32(offset)],
+            );
+        }
+        builder.member_name(push_block, 0, "n");
+        builder.member_name(push_block, 1, "filter");
+        let push_pointer = builder.type_pointer(None, StorageClass::PushConstant, push_block);
+        let push_variable = builder.variable(push_pointer, None, StorageClass::PushConstant, None);
+        let function_type = builder.type_function(void, []);
+        let function = builder
+            .begin_function(void, None, FunctionControl::NONE, function_type)
+            .unwrap();
+        builder.begin_block(None).unwrap();
+        builder.ret().unwrap();
+        builder.end_function().unwrap();
+        builder.entry_point(ExecutionModel::GLCompute, function, "main", [push_variable]);
+        builder.execution_mode(function, ExecutionMode::LocalSize, [64, 1, 1]);
+        let words = builder.module().assemble();
+        assert_eq!(
+            __private::KernelBuilder::from_spirv(&words, "main")
+                .unwrap()
+                .push_params(),
+            [
+                ("n".to_string(), ScalarType::U32, None),
+                ("filter".to_string(), ScalarType::F32, Some(4)),
+            ]
+        );
+        let desc = KernelDesc::from_spirv(&words, "main").unwrap();
+        let push_descs: Vec<_> = desc
+            .push_descs
+            .iter()
+            .map(|x| (x.name, x.scalar_type, x.array_len))
+            .collect();
+        assert_eq!(
+            push_descs,
+            [
+                ("n", ScalarType::U32, None),
+                ("filter", ScalarType::F32, Some(4))
+            ]
+        );
+        assert_eq!(desc.push_consts_range(), 20);
+        let filter = [1f32, 2., 3., 4.];
+        let filter_elems = filter.map(ScalarElem::F32);
+        let push_consts = [ScalarElem::U32(7).into(), filter_elems.as_slice().into()];
+        desc.check_args(&[], &push_consts).unwrap();
+        let short = [ScalarElem::U32(7).into(), filter_elems[..3].into()];
+        let error = desc.check_args(&[], &short).unwrap_err().to_string();
+        assert!(
+            error.contains("`filter` expected an array of 4, found 3"),
+            "{error}"
+        );
+        let error = desc.push_consts_bytes(&short).unwrap_err().to_string();
+        assert!(
+            error.contains("`filter` expected an array of 4, found 3"),
+            "{error}"
+        );
+        let scalar = [ScalarElem::U32(7).into(), ScalarElem::F32(1.).into()];
+        let error = desc.check_args(&[], &scalar).unwrap_err().to_string();
+        assert!(
+            error.contains("`filter` expected an array of 4, found a scalar"),
+            "{error}"
+        );
+        let bytes = desc.push_consts_bytes(&push_consts).unwrap();
+        assert_eq!(bytes[..4], 7u32.to_ne_bytes());
+        assert_eq!(bytes[4..], *bytemuck::cast_slice::<f32, u8>(&filter));
+        desc.specialize(64, &[], false, false, false).unwrap();
+    }
+}
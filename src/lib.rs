@@ -56,5 +56,27 @@ pub use krnl_core::scalar;
 pub mod buffer;
 /// Devices.
 pub mod device;
+/// Pipeline graphs.
+pub mod graph;
 /// Kernels.
 pub mod kernel;
+
+// Devices, buffers, and kernels can be shared between threads.
+const _: () = {
+    const fn send_sync<T: Send + Sync>() {}
+    send_sync::<device::Device>();
+    send_sync::<device::DeviceInfo>();
+    send_sync::<buffer::Buffer<u32>>();
+    send_sync::<buffer::Slice<u32>>();
+    send_sync::<buffer::SliceMut<u32>>();
+    send_sync::<buffer::ArcBuffer<u32>>();
+    send_sync::<buffer::CowBuffer<u32>>();
+    send_sync::<buffer::ScalarBuffer>();
+    send_sync::<buffer::ScalarSlice>();
+    send_sync::<buffer::ScalarSliceMut>();
+    send_sync::<buffer::ScalarArcBuffer>();
+    send_sync::<buffer::ScalarCowBuffer>();
+    send_sync::<buffer::ScratchBuffer>();
+    send_sync::<kernel::__private::KernelBuilder>();
+    send_sync::<kernel::__private::Kernel>();
+};
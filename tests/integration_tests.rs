@@ -1,8 +1,13 @@
 use dry::macro_for;
 use half::{bf16, f16};
-#[cfg(feature = "device")]
-use krnl::buffer::Buffer;
-use krnl::{buffer::Slice, device::Device, scalar::Scalar};
+use krnl::{
+    buffer::{
+        ArcBuffer, Buffer, BufferUsage, MemoryKind, ScalarBuffer, ScanMode, Slice,
+        StreamingUploader,
+    },
+    device::Device,
+    scalar::Scalar,
+};
 #[cfg(not(target_family = "wasm"))]
 use krnl::{device::Features, scalar::ScalarType};
 #[cfg(not(target_family = "wasm"))]
@@ -71,7 +76,110 @@ fn device_test(device: &Device, name: &str, f: impl Fn(Device) + Send + Sync + '
 
 #[cfg(not(target_family = "wasm"))]
 fn tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<Item = Trial> {
-    buffer_tests(device, device2)
+    device_tests(device)
+        .into_iter()
+        .chain(buffer_tests(device, device2))
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn device_tests(device: &Device) -> impl IntoIterator<Item = Trial> {
+    let mut tests = vec![device_test(
+        device,
+        "device_cancel_pending",
+        device_cancel_pending,
+    )];
+    tests.push(device_test(device, "device_lock_queue", device_lock_queue));
+    tests.push(device_test(device, "device_flush", device_flush));
+    tests.push(device_test(device, "device_in_flight", device_in_flight));
+    tests.push(device_test(
+        device,
+        "device_assert_owns",
+        device_assert_owns,
+    ));
+    tests.push(device_test(
+        device,
+        "device_download_many",
+        device_download_many,
+    ));
+    tests.push(device_test(device, "device_time", device_time));
+    tests.push(device_test(device, "device_fence", device_fence));
+    tests.push(device_test(device, "device_shared", device_shared));
+    tests.push(device_test(
+        device,
+        "device_zero_uninit",
+        device_zero_uninit,
+    ));
+    tests.push(device_test(device, "device_uuid", device_uuid));
+    tests.push(device_test(device, "device_iter", device_iter));
+    tests.push(device_test(
+        device,
+        "device_allocated_bytes",
+        device_allocated_bytes,
+    ));
+    tests.push(device_test(
+        device,
+        "device_cached_kernels",
+        device_cached_kernels,
+    ));
+    #[cfg(feature = "device")]
+    tests.push(device_test(
+        device,
+        "kernel_build_async",
+        kernel_build_async,
+    ));
+    #[cfg(feature = "device")]
+    tests.push(device_test(device, "kernel_push_array", kernel_push_array));
+    #[cfg(feature = "device")]
+    tests.push(device_test(
+        device,
+        "kernel_dispatch_safe",
+        kernel_dispatch_safe,
+    ));
+    #[cfg(feature = "device")]
+    tests.push(device_test(
+        device,
+        "kernel_dispatch_tiled",
+        kernel_dispatch_tiled,
+    ));
+    #[cfg(feature = "device")]
+    tests.push(device_test(
+        device,
+        "kernel_dispatch_named",
+        kernel_dispatch_named,
+    ));
+    #[cfg(feature = "device")]
+    tests.push(device_test(
+        device,
+        "kernel_dispatch_raw",
+        kernel_dispatch_raw,
+    ));
+    #[cfg(feature = "serde")]
+    tests.push(device_test(device, "device_info_serde", device_info_serde));
+    if device.is_host() {
+        tests.push(Trial::test("features_from_names", || {
+            features_from_names();
+            Ok(())
+        }));
+        tests.push(Trial::test("scalar_elem_from_bytes", || {
+            scalar_elem_from_bytes();
+            Ok(())
+        }));
+        tests.push(Trial::test("output_slice", || {
+            output_slice();
+            Ok(())
+        }));
+        tests.push(Trial::test("catch_out_of_bounds", || {
+            catch_out_of_bounds();
+            Ok(())
+        }));
+    }
+    tests.push(device_test(device, "device_scratch", device_scratch));
+    if device.is_device() {
+        tests.push(device_test(device, "device_limits", device_limits));
+        tests.push(device_test(device, "device_features", device_features));
+        tests.push(device_test(device, "device_report", device_report));
+    }
+    tests
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -83,6 +191,54 @@ fn buffer_tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<
     let mut tests = Vec::new();
 
     tests.push(device_test(device, "buffer_from_vec", buffer_from_vec));
+    tests.push(device_test(
+        device,
+        "buffer_from_vec_host_visible",
+        buffer_from_vec_host_visible,
+    ));
+    tests.push(device_test(device, "buffer_wait", buffer_wait));
+    tests.push(device_test(
+        device,
+        "buffer_to_vec_nonblocking",
+        buffer_to_vec_nonblocking,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_uninit_with_usage",
+        buffer_uninit_with_usage,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_uninit_with_memory",
+        buffer_uninit_with_memory,
+    ));
+    tests.push(device_test(device, "buffer_map", buffer_map));
+    tests.push(device_test(device, "buffer_scan", buffer_scan));
+    tests.push(device_test(device, "buffer_argmax", buffer_argmax));
+    tests.push(device_test(
+        device,
+        "buffer_for_each_host",
+        buffer_for_each_host,
+    ));
+    tests.push(device_test(device, "buffer_overlaps", buffer_overlaps));
+    tests.push(device_test(device, "buffer_resize", buffer_resize));
+    tests.push(device_test(device, "buffer_chunks", buffer_chunks));
+    tests.push(device_test(device, "buffer_get", buffer_get));
+    tests.push(device_test(
+        device,
+        "buffer_raw_vulkan_handle",
+        buffer_raw_vulkan_handle,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_streaming_uploader",
+        buffer_streaming_uploader,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_pod_round_trip",
+        buffer_pod_round_trip,
+    ));
 
     if device.is_device() {
         #[cfg(feature = "device")]
@@ -124,10 +280,30 @@ fn buffer_tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<
                     device_test(device, stringify!([<buffer_fill_ $T>]), [<buffer_fill>]::<$T>)
                 };
                 tests.push(trial.with_ignored_flag(ignore));
+                let trial = paste! {
+                    device_test(device, stringify!([<buffer_from_elem_ $T>]), [<buffer_from_elem>]::<$T>)
+                };
+                tests.push(trial.with_ignored_flag(ignore));
             }
         }
     });
 
+    macro_for!($T in [u8, u16, u32, u64] {
+        paste! {
+            tests.push(device_test(device, stringify!([<buffer_copy_into_at_ $T>]), buffer_copy_into_at::<$T>));
+        }
+    });
+    macro_for!($T in [u8, u16, u32, u64, f32, f64] {
+        paste! {
+            tests.push(device_test(device, stringify!([<buffer_bytes_round_trip_ $T>]), buffer_bytes_round_trip::<$T>));
+        }
+    });
+    tests.push(device_test(
+        device,
+        "scalar_buffer_copy_from_scalar_slice",
+        scalar_buffer_copy_from_scalar_slice,
+    ));
+
     fn buffer_cast_features(x: ScalarType, y: ScalarType) -> Features {
         fn features(ty: ScalarType) -> Features {
             use ScalarType::*;
@@ -158,9 +334,1050 @@ fn buffer_tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<
         });
     });
 
+    macro_for!($X in [f32, f16, bf16, i32, u8] {
+        macro_for!($Y in [f32, f16, bf16, f64, u32] {
+            {
+                let ignore = !device.is_host() && !features.contains(buffer_cast_features($X::SCALAR_TYPE, $Y::SCALAR_TYPE));
+                paste! {
+                    let trial = device_test(device, stringify!([<buffer_cast_into_slice_ $X _ $Y>]), [<buffer_cast_into_slice>]::<$X, $Y>);
+                    tests.push(trial.with_ignored_flag(ignore));
+                }
+            }
+        });
+    });
+
     tests
 }
 
+fn device_cancel_pending(device: Device) {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let x = vec![1u32; 1000];
+    let mut y = Slice::from(x.as_slice()).to_device(device.clone()).unwrap();
+    for _ in 0..10 {
+        y.fill(2).unwrap();
+    }
+    device.cancel_pending().unwrap();
+    y.fill(3).unwrap();
+    let y = y.into_vec().unwrap();
+    assert!(y.iter().all(|y| *y == 3));
+    if device.is_host() {
+        return;
+    }
+    // Staged downloads on another thread fail instead of returning stale data. Whether any
+    // are canceled depends on timing, so only the downloads that fail are checked.
+    let x = Buffer::from(vec![1u32; 1000])
+        .into_device(device.clone())
+        .unwrap();
+    if x.is_host_visible() {
+        return;
+    }
+    device.wait().unwrap();
+    let mut z = Buffer::<u32>::zeros(device.clone(), 1 << 22).unwrap();
+    let stop = AtomicBool::default();
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            while !stop.load(Ordering::SeqCst) {
+                device.cancel_pending().unwrap();
+            }
+        });
+        for i in 2..102u32 {
+            // keep the device busy, so that the download is not submitted immediately
+            for _ in 0..8 {
+                z.fill(i).unwrap();
+            }
+            // leave different data in the staging buffers
+            let _ = Buffer::from(vec![i; 1000]).into_device(device.clone());
+            let mut y = vec![0u8; 4000];
+            let result = if i % 2 == 0 {
+                x.to_bytes().map(|x| y = x)
+            } else {
+                device.download_many(&mut [(x.as_slice().into(), &mut y)])
+            };
+            match result {
+                Ok(()) => assert!(y.chunks(4).all(|y| y == 1u32.to_ne_bytes())),
+                Err(e) => assert!(e.to_string().contains("canceled"), "{e}"),
+            }
+        }
+        stop.store(true, Ordering::SeqCst);
+    });
+}
+
+fn device_flush(device: Device) {
+    let x = vec![1u32; 1000];
+    let mut y = Slice::from(x.as_slice()).to_device(device.clone()).unwrap();
+    y.fill(2).unwrap();
+    device.flush().unwrap();
+    y.fill(3).unwrap();
+    device.flush().unwrap();
+    let y = y.into_vec().unwrap();
+    assert!(y.iter().all(|y| *y == 3));
+}
+
+fn device_in_flight(device: Device) {
+    let devices: Vec<_> = if device.is_host() {
+        vec![(device, 0)]
+    } else {
+        [(None, 2), (Some(1), 1), (Some(3), 3)]
+            .into_iter()
+            .map(|(max_in_flight, expected)| {
+                let mut builder = Device::builder().index(device.info().unwrap().index());
+                if let Some(max_in_flight) = max_in_flight {
+                    builder = builder.max_in_flight(max_in_flight);
+                }
+                (builder.build().unwrap(), expected)
+            })
+            .collect()
+    };
+    for (device, max_in_flight) in devices {
+        let mut y = Buffer::from(vec![1u32; 1000])
+            .into_device(device.clone())
+            .unwrap();
+        for i in 2..10 {
+            y.fill(i).unwrap();
+            device.flush().unwrap();
+            assert!(device.in_flight().unwrap() <= max_in_flight);
+        }
+        device.wait().unwrap();
+        assert_eq!(device.in_flight().unwrap(), 0);
+        assert!(y.to_vec().unwrap().iter().all(|y| *y == 9));
+    }
+}
+
+fn device_assert_owns(device: Device) {
+    let x = Buffer::from(vec![1u32; 10])
+        .into_device(device.clone())
+        .unwrap();
+    device.assert_owns(&x);
+    device.assert_owns(&x.slice(2..).unwrap());
+    if !device.is_host() && cfg!(debug_assertions) {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            Device::host().assert_owns(&x)
+        }));
+        assert!(result.is_err());
+    }
+}
+
+fn device_download_many(device: Device) {
+    let x = Buffer::from((0..10u32).collect::<Vec<_>>())
+        .into_device(device.clone())
+        .unwrap();
+    let y = Buffer::from(vec![1.5f32; 7])
+        .into_device(device.clone())
+        .unwrap();
+    let z = Buffer::from(vec![7u8; 3]);
+    let mut x_bytes = vec![0u8; 5 * 4];
+    let mut y_bytes = vec![0u8; 7 * 4];
+    let mut z_bytes = vec![0u8; 3];
+    device
+        .download_many(&mut [
+            (x.slice(5..).unwrap().into(), &mut x_bytes),
+            (y.as_slice().into(), &mut y_bytes),
+            (z.as_slice().into(), &mut z_bytes),
+        ])
+        .unwrap();
+    assert_eq!(x_bytes, x.slice(5..).unwrap().to_bytes().unwrap());
+    assert_eq!(y_bytes, y.to_bytes().unwrap());
+    assert_eq!(z_bytes, z.to_bytes().unwrap());
+    let result = device.download_many(&mut [(x.as_slice().into(), &mut x_bytes)]);
+    assert!(result.is_err());
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn device_time(device: Device) {
+    let mut y = Buffer::zeros(device.clone(), 1000).unwrap();
+    let duration = device
+        .time(|| {
+            y.fill(1u32)?;
+            Ok(())
+        })
+        .unwrap();
+    assert!(duration.as_secs() < 60);
+    assert_eq!(y.to_vec().unwrap(), vec![1u32; 1000]);
+    let result = device.time(|| krnl::anyhow::bail!("error"));
+    assert!(result.is_err());
+}
+
+fn device_fence(device: Device) {
+    let fence = device.create_fence();
+    assert_eq!(fence.device(), &device);
+    assert!(!fence.is_signaled().unwrap());
+    assert!(fence.wait().is_err());
+    fence.reset();
+    assert!(!fence.is_signaled().unwrap());
+    #[cfg(feature = "device")]
+    if device.is_device() {
+        use krnl::{
+            kernel::__private::{KernelBuilder, KernelSliceArg},
+            scalar::ScalarElem,
+        };
+
+        let kernel = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main")
+            .unwrap()
+            .build(device.clone())
+            .unwrap()
+            .with_groups(1);
+        let x = [1f32, 2., 3., 4.].map(ScalarElem::F32);
+        let push_consts = [x.as_slice().into()];
+        for _ in 0..2 {
+            let mut y = Buffer::<f32>::zeros(device.clone(), 4).unwrap();
+            let slices = [KernelSliceArg::SliceMut(y.as_scalar_slice_mut())];
+            unsafe {
+                kernel
+                    .dispatch_with_fence(&slices, &push_consts, &fence)
+                    .unwrap();
+            }
+            fence.wait().unwrap();
+            assert!(fence.is_signaled().unwrap());
+            assert_eq!(y.to_vec().unwrap(), [1., 2., 3., 4.]);
+            fence.reset();
+            assert!(!fence.is_signaled().unwrap());
+        }
+        let other = Device::builder()
+            .index(device.info().unwrap().index())
+            .build()
+            .unwrap()
+            .create_fence();
+        let mut y = Buffer::<f32>::zeros(device, 4).unwrap();
+        let slices = [KernelSliceArg::SliceMut(y.as_scalar_slice_mut())];
+        unsafe { kernel.dispatch_with_fence(&slices, &push_consts, &other) }.unwrap_err();
+        assert!(!other.is_signaled().unwrap());
+    }
+}
+
+#[cfg(feature = "device")]
+fn kernel_build_async(device: Device) {
+    use krnl::{
+        kernel::__private::{KernelBuilder, KernelSliceArg},
+        scalar::ScalarElem,
+    };
+
+    let builder = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main").unwrap();
+    if device.is_host() {
+        let error = builder.build_async(device).unwrap_err().to_string();
+        assert!(error.contains("expected device, found host"), "{error}");
+        return;
+    }
+    let x = [1f32, 2., 3., 4.].map(ScalarElem::F32);
+    let push_consts = [x.as_slice().into()];
+    let kernel = builder.build_async(device.clone()).unwrap().with_groups(1);
+    let mut y = Buffer::<f32>::zeros(device.clone(), 4).unwrap();
+    unsafe {
+        kernel
+            .dispatch(
+                &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+                &push_consts,
+            )
+            .unwrap();
+    }
+    assert!(kernel.is_ready());
+    assert_eq!(y.to_vec().unwrap(), [1., 2., 3., 4.]);
+    // cached, so the kernel is ready without a background thread
+    let kernel = builder.build_async(device.clone()).unwrap();
+    assert!(kernel.is_ready());
+    let mut y = Buffer::<f32>::zeros(device, 4).unwrap();
+    unsafe {
+        kernel
+            .with_groups(1)
+            .dispatch(
+                &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+                &push_consts,
+            )
+            .unwrap();
+    }
+    assert_eq!(y.to_vec().unwrap(), [1., 2., 3., 4.]);
+}
+
+#[cfg(feature = "device")]
+fn kernel_push_array(device: Device) {
+    use krnl::{
+        kernel::__private::{KernelBuilder, KernelPushArg, KernelSliceArg},
+        scalar::ScalarElem,
+    };
+
+    if device.is_host() {
+        return;
+    }
+    let kernel = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main")
+        .unwrap()
+        .build(device.clone())
+        .unwrap()
+        .with_groups(1);
+    let x = [1f32, -2., 0.5, 4.].map(ScalarElem::F32);
+    let mut y = Buffer::<f32>::zeros(device.clone(), 4).unwrap();
+    unsafe {
+        kernel
+            .dispatch(
+                &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+                &[KernelPushArg::Array(&x)],
+            )
+            .unwrap();
+    }
+    assert_eq!(y.to_vec().unwrap(), [1., -2., 0.5, 4.]);
+    let error = unsafe {
+        kernel.dispatch(
+            &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+            &[KernelPushArg::Array(&x[..3])],
+        )
+    }
+    .unwrap_err()
+    .to_string();
+    assert!(
+        error.contains("`x` expected an array of 4, found 3"),
+        "{error}"
+    );
+    let error = unsafe {
+        kernel.dispatch(
+            &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+            &[KernelPushArg::Scalar(x[0])],
+        )
+    }
+    .unwrap_err()
+    .to_string();
+    assert!(
+        error.contains("`x` expected an array of 4, found a scalar"),
+        "{error}"
+    );
+}
+
+#[cfg(feature = "device")]
+fn kernel_dispatch_safe(device: Device) {
+    use krnl::{
+        kernel::__private::{KernelBuilder, KernelSliceArg},
+        scalar::ScalarElem,
+    };
+
+    if device.is_host() {
+        return;
+    }
+    let kernel = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main")
+        .unwrap()
+        .build(device.clone())
+        .unwrap()
+        .with_groups(1);
+    assert!(!kernel.safe());
+    let x = [1f32, 2., 3., 4.].map(ScalarElem::F32);
+    let mut y = Buffer::<f32>::zeros(device, 4).unwrap();
+    let error = kernel
+        .dispatch_safe(
+            &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+            &[x.as_slice().into()],
+        )
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("is not safe"), "{error}");
+    assert_eq!(y.to_vec().unwrap(), [0.; 4]);
+}
+
+#[cfg(feature = "device")]
+fn kernel_dispatch_tiled(device: Device) {
+    use krnl::{
+        kernel::__private::{KernelBuilder, KernelPushArg, KernelSliceArg},
+        scalar::ScalarElem,
+    };
+
+    if device.is_host() {
+        return;
+    }
+    let kernel = KernelBuilder::from_spirv(&tiled_iota_spirv(), "main")
+        .unwrap()
+        .build(device.clone())
+        .unwrap();
+    let n = 1000;
+    let mut y = Buffer::from(vec![u32::MAX; 1024])
+        .into_device(device.clone())
+        .unwrap();
+    unsafe {
+        kernel
+            .dispatch_tiled(
+                &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+                &[KernelPushArg::Scalar(ScalarElem::U32(n))],
+                n,
+            )
+            .unwrap();
+    }
+    let y = y.to_vec().unwrap();
+    assert!(y[..n as usize].iter().copied().eq(0..n));
+    assert!(y[n as usize..].iter().all(|x| *x == u32::MAX));
+    let kernel = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main")
+        .unwrap()
+        .build(device.clone())
+        .unwrap();
+    let mut y = Buffer::<f32>::zeros(device, 4).unwrap();
+    let error = unsafe {
+        kernel.dispatch_tiled(&[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())], &[], 4)
+    }
+    .unwrap_err()
+    .to_string();
+    assert!(error.contains("has no push constant `base`"), "{error}");
+}
+
+#[cfg(feature = "device")]
+fn kernel_dispatch_named(device: Device) {
+    use krnl::{
+        kernel::__private::{KernelBuilder, KernelPushArg, KernelSliceArg},
+        scalar::ScalarElem,
+    };
+
+    if device.is_host() {
+        return;
+    }
+    let kernel = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main")
+        .unwrap()
+        .build(device.clone())
+        .unwrap()
+        .with_groups(1);
+    let x = [1f32, -2., 0.5, 4.].map(ScalarElem::F32);
+    let mut y = Buffer::<f32>::zeros(device, 4).unwrap();
+    unsafe {
+        kernel
+            .dispatch_named(
+                [("y", KernelSliceArg::SliceMut(y.as_scalar_slice_mut()))],
+                &[KernelPushArg::Array(&x)],
+            )
+            .unwrap();
+    }
+    assert_eq!(y.to_vec().unwrap(), [1., -2., 0.5, 4.]);
+    let error = unsafe {
+        kernel.dispatch_named(
+            [("z", KernelSliceArg::SliceMut(y.as_scalar_slice_mut()))],
+            &[KernelPushArg::Array(&x)],
+        )
+    }
+    .unwrap_err()
+    .to_string();
+    assert!(error.contains("has no slice `z`"), "{error}");
+}
+
+#[cfg(feature = "device")]
+fn kernel_dispatch_raw(device: Device) {
+    use krnl::kernel::__private::{KernelBuilder, KernelSliceArg};
+
+    if device.is_host() {
+        return;
+    }
+    let kernel = KernelBuilder::from_spirv(&copy_push_array_spirv(), "main")
+        .unwrap()
+        .build(device.clone())
+        .unwrap()
+        .with_groups(1);
+    let x = [1f32, -2., 0.5, 4.];
+    let mut y = Buffer::<f32>::zeros(device, 4).unwrap();
+    unsafe {
+        kernel
+            .dispatch_raw(
+                &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+                bytemuck::cast_slice(&x),
+            )
+            .unwrap();
+    }
+    assert_eq!(y.to_vec().unwrap(), x);
+    let error = unsafe {
+        kernel.dispatch_raw(
+            &[KernelSliceArg::SliceMut(y.as_scalar_slice_mut())],
+            bytemuck::cast_slice(&x[..3]),
+        )
+    }
+    .unwrap_err()
+    .to_string();
+    assert!(
+        error.contains("expected 16 push constant bytes, found 12"),
+        "{error}"
+    );
+}
+
+// A kernel with 4 threads, where each thread writes `x[i]` of a `[f32; 4]` push constant
+// into `y[i]`.
+#[cfg(feature = "device")]
+fn copy_push_array_spirv() -> Vec<u32> {
+    use rspirv::{
+        binary::Assemble,
+        dr::{Builder, Instruction, Operand},
+        spirv::{
+            AddressingModel, BuiltIn, Capability, Decoration, ExecutionMode, ExecutionModel,
+            FunctionControl, MemoryModel, Op, StorageClass,
+        },
+    };
+
+    let mut builder = Builder::new();
+    builder.capability(Capability::Shader);
+    builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+    let void = builder.type_void();
+    let u32 = builder.type_int(32, 0);
+    let f32 = builder.type_float(32);
+    let zero = builder.constant_u32(u32, 0);
+    let four = builder.constant_u32(u32, 4);
+    let x_array = builder.type_array(f32, four);
+    builder.decorate(x_array, Decoration::ArrayStride, [Operand::LiteralInt32(4)]);
+    let push_block = builder.type_struct([x_array]);
+    builder.decorate(push_block, Decoration::Block, []);
+    builder.member_decorate(
+        push_block,
+        0,
+        Decoration::Offset,
+        [Operand::LiteralInt32(0)],
+    );
+    builder.member_name(push_block, 0, "x");
+    let push_pointer = builder.type_pointer(None, StorageClass::PushConstant, push_block);
+    let push = builder.variable(push_pointer, None, StorageClass::PushConstant, None);
+    let y_array = builder.id();
+    builder
+        .module_mut()
+        .types_global_values
+        .push(Instruction::new(
+            Op::TypeRuntimeArray,
+            None,
+            Some(y_array),
+            vec![Operand::IdRef(f32)],
+        ));
+    builder.decorate(y_array, Decoration::ArrayStride, [Operand::LiteralInt32(4)]);
+    let y_block = builder.type_struct([y_array]);
+    builder.decorate(y_block, Decoration::Block, []);
+    builder.member_decorate(y_block, 0, Decoration::Offset, [Operand::LiteralInt32(0)]);
+    builder.member_name(y_block, 0, "y");
+    let y_pointer = builder.type_pointer(None, StorageClass::StorageBuffer, y_block);
+    let y = builder.variable(y_pointer, None, StorageClass::StorageBuffer, None);
+    builder.decorate(y, Decoration::DescriptorSet, [Operand::LiteralInt32(0)]);
+    builder.decorate(y, Decoration::Binding, [Operand::LiteralInt32(0)]);
+    let uvec3 = builder.type_vector(u32, 3);
+    let global_id_pointer = builder.type_pointer(None, StorageClass::Input, uvec3);
+    let global_id = builder.variable(global_id_pointer, None, StorageClass::Input, None);
+    builder.decorate(
+        global_id,
+        Decoration::BuiltIn,
+        [Operand::BuiltIn(BuiltIn::GlobalInvocationId)],
+    );
+    let push_f32_pointer = builder.type_pointer(None, StorageClass::PushConstant, f32);
+    let storage_f32_pointer = builder.type_pointer(None, StorageClass::StorageBuffer, f32);
+    let function_type = builder.type_function(void, []);
+    let function = builder
+        .begin_function(void, None, FunctionControl::NONE, function_type)
+        .unwrap();
+    builder.begin_block(None).unwrap();
+    let global_id_value = builder.load(uvec3, None, global_id, None, []).unwrap();
+    let index = builder
+        .composite_extract(u32, None, global_id_value, [0])
+        .unwrap();
+    let x_pointer = builder
+        .access_chain(push_f32_pointer, None, push, [zero, index])
+        .unwrap();
+    let x = builder.load(f32, None, x_pointer, None, []).unwrap();
+    let y_pointer = builder
+        .access_chain(storage_f32_pointer, None, y, [zero, index])
+        .unwrap();
+    builder.store(y_pointer, x, None, []).unwrap();
+    builder.ret().unwrap();
+    builder.end_function().unwrap();
+    builder.entry_point(
+        ExecutionModel::GLCompute,
+        function,
+        "main",
+        [global_id, push, y],
+    );
+    builder.execution_mode(function, ExecutionMode::LocalSize, [4, 1, 1]);
+    builder.module().assemble()
+}
+
+// A kernel with 64 threads, where each thread writes its index `base + global_id` into
+// `y[index]` if it is less than `n`.
+#[cfg(feature = "device")]
+fn tiled_iota_spirv() -> Vec<u32> {
+    use rspirv::{
+        binary::Assemble,
+        dr::{Builder, Instruction, Operand},
+        spirv::{
+            AddressingModel, BuiltIn, Capability, Decoration, ExecutionMode, ExecutionModel,
+            FunctionControl, MemoryModel, Op, SelectionControl, StorageClass,
+        },
+    };
+
+    let mut builder = Builder::new();
+    builder.capability(Capability::Shader);
+    builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+    let void = builder.type_void();
+    let bool = builder.type_bool();
+    let u32 = builder.type_int(32, 0);
+    let zero = builder.constant_u32(u32, 0);
+    let one = builder.constant_u32(u32, 1);
+    let push_block = builder.type_struct([u32, u32]);
+    builder.decorate(push_block, Decoration::Block, []);
+    for (i, name) in ["base", "n"].into_iter().enumerate() {
+        builder.member_decorate(
+            push_block,
+            i as u32,
+            Decoration::Offset,
+            [Operand::LiteralInt32(4 * i as u32)],
+        );
+        builder.member_name(push_block, i as u32, name);
+    }
+    let push_pointer = builder.type_pointer(None, StorageClass::PushConstant, push_block);
+    let push = builder.variable(push_pointer, None, StorageClass::PushConstant, None);
+    let y_array = builder.id();
+    builder
+        .module_mut()
+        .types_global_values
+        .push(Instruction::new(
+            Op::TypeRuntimeArray,
+            None,
+            Some(y_array),
+            vec![Operand::IdRef(u32)],
+        ));
+    builder.decorate(y_array, Decoration::ArrayStride, [Operand::LiteralInt32(4)]);
+    let y_block = builder.type_struct([y_array]);
+    builder.decorate(y_block, Decoration::Block, []);
+    builder.member_decorate(y_block, 0, Decoration::Offset, [Operand::LiteralInt32(0)]);
+    builder.member_name(y_block, 0, "y");
+    let y_pointer = builder.type_pointer(None, StorageClass::StorageBuffer, y_block);
+    let y = builder.variable(y_pointer, None, StorageClass::StorageBuffer, None);
+    builder.decorate(y, Decoration::DescriptorSet, [Operand::LiteralInt32(0)]);
+    builder.decorate(y, Decoration::Binding, [Operand::LiteralInt32(0)]);
+    let uvec3 = builder.type_vector(u32, 3);
+    let global_id_pointer = builder.type_pointer(None, StorageClass::Input, uvec3);
+    let global_id = builder.variable(global_id_pointer, None, StorageClass::Input, None);
+    builder.decorate(
+        global_id,
+        Decoration::BuiltIn,
+        [Operand::BuiltIn(BuiltIn::GlobalInvocationId)],
+    );
+    let push_u32_pointer = builder.type_pointer(None, StorageClass::PushConstant, u32);
+    let storage_u32_pointer = builder.type_pointer(None, StorageClass::StorageBuffer, u32);
+    let function_type = builder.type_function(void, []);
+    let function = builder
+        .begin_function(void, None, FunctionControl::NONE, function_type)
+        .unwrap();
+    builder.begin_block(None).unwrap();
+    let global_id_value = builder.load(uvec3, None, global_id, None, []).unwrap();
+    let global_id_x = builder
+        .composite_extract(u32, None, global_id_value, [0])
+        .unwrap();
+    let base_pointer = builder
+        .access_chain(push_u32_pointer, None, push, [zero])
+        .unwrap();
+    let base = builder.load(u32, None, base_pointer, None, []).unwrap();
+    let n_pointer = builder
+        .access_chain(push_u32_pointer, None, push, [one])
+        .unwrap();
+    let n = builder.load(u32, None, n_pointer, None, []).unwrap();
+    let index = builder.i_add(u32, None, base, global_id_x).unwrap();
+    let in_bounds = builder.u_less_than(bool, None, index, n).unwrap();
+    let store_label = builder.id();
+    let merge_label = builder.id();
+    builder
+        .selection_merge(merge_label, SelectionControl::NONE)
+        .unwrap();
+    builder
+        .branch_conditional(in_bounds, store_label, merge_label, [])
+        .unwrap();
+    builder.begin_block(Some(store_label)).unwrap();
+    let y_pointer = builder
+        .access_chain(storage_u32_pointer, None, y, [zero, index])
+        .unwrap();
+    builder.store(y_pointer, index, None, []).unwrap();
+    builder.branch(merge_label).unwrap();
+    builder.begin_block(Some(merge_label)).unwrap();
+    builder.ret().unwrap();
+    builder.end_function().unwrap();
+    builder.entry_point(
+        ExecutionModel::GLCompute,
+        function,
+        "main",
+        [global_id, push, y],
+    );
+    builder.execution_mode(function, ExecutionMode::LocalSize, [64, 1, 1]);
+    builder.module().assemble()
+}
+
+fn device_shared(device: Device) {
+    if device.is_host() {
+        return;
+    }
+    let index = device.info().unwrap().index();
+    let a = Device::builder().index(index).shared(true).build().unwrap();
+    let b = Device::builder().index(index).shared(true).build().unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, Device::builder().index(index).build().unwrap());
+    let x = Buffer::from(vec![1u32; 10]).into_device(a).unwrap();
+    let y = x.to_device(b.clone()).unwrap();
+    assert_eq!(y.device(), b);
+    assert_eq!(y.to_vec().unwrap(), vec![1u32; 10]);
+}
+
+fn device_uuid(device: Device) {
+    if device.is_host() {
+        return;
+    }
+    let uuid = device.info().unwrap().uuid();
+    let report = device.report();
+    assert!(report.contains("\nuuid: "), "{report}");
+    let device = Device::builder().index(1).uuid(uuid).build().unwrap();
+    assert_eq!(device.info().unwrap().uuid(), uuid);
+    // `DeviceInfo::build()` selects by uuid, or by index if the uuid is not reported.
+    for info in Device::iter() {
+        let device = Device::builder()
+            .prefer(info.unwrap().device_type())
+            .build()
+            .unwrap();
+        let info = device.info().unwrap();
+        assert_eq!(info.build().unwrap().info().unwrap().uuid(), info.uuid());
+        let device = Device::builder().index(info.index()).build().unwrap();
+        assert_eq!(device.info().unwrap().uuid(), info.uuid());
+    }
+    #[cfg(feature = "device")]
+    {
+        use krnl::device::error::DeviceUuidNotFound;
+
+        let mut uuid = uuid;
+        uuid[0] = !uuid[0];
+        if let Err(error) = Device::builder().uuid(uuid).build() {
+            let error = error.downcast_ref::<DeviceUuidNotFound>().unwrap();
+            assert!(error.to_string().starts_with("No device with uuid "));
+        }
+    }
+}
+
+fn device_iter(device: Device) {
+    if device.is_host() {
+        if !cfg!(feature = "device") {
+            let error = Device::iter().next().unwrap().unwrap_err();
+            error
+                .downcast_ref::<krnl::device::error::DeviceUnavailable>()
+                .unwrap();
+        }
+        return;
+    }
+    let info = device.info().unwrap();
+    let infos: Vec<_> = Device::iter().collect::<Result<_, _>>().unwrap();
+    let other = infos.iter().find(|x| x.uuid() == info.uuid()).unwrap();
+    assert_eq!(other.index(), info.index());
+    assert_eq!(other.device_id(), info.device_id());
+    assert_eq!(other.features(), info.features());
+    assert_eq!(other.max_threads(), info.max_threads());
+    let device = other.build().unwrap();
+    assert_eq!(device.info().unwrap().uuid(), info.uuid());
+    // The index is in the order of `Device::iter()`, not the preferred order.
+    for prefer in infos.iter().map(|x| x.device_type()) {
+        let device = Device::builder().prefer(prefer).build().unwrap();
+        let info = device.info().unwrap();
+        assert_eq!(info.device_type(), prefer);
+        assert_eq!(infos[info.index()].uuid(), info.uuid());
+    }
+}
+
+fn device_zero_uninit(device: Device) {
+    if device.is_host() {
+        return;
+    }
+    let device = Device::builder()
+        .index(device.info().unwrap().index())
+        .zero_uninit(true)
+        .build()
+        .unwrap();
+    let len = 1000;
+    for _ in 0..2 {
+        // Dirty freed memory, so that a reused allocation would be garbage without zeroing.
+        Buffer::from_elem(device.clone(), len, u32::MAX).unwrap();
+        let x = unsafe { Buffer::<u32>::uninit(device.clone(), len).unwrap() };
+        assert_eq!(x.to_vec().unwrap(), vec![0; len]);
+    }
+    let x = unsafe {
+        Buffer::<u32>::uninit_with_memory(device.clone(), len, MemoryKind::HostVisible).unwrap()
+    };
+    assert_eq!(x.to_vec().unwrap(), vec![0; len]);
+}
+
+fn device_allocated_bytes(device: Device) {
+    if device.is_host() {
+        assert_eq!(device.allocated_bytes(), 0);
+        return;
+    }
+    let device = Device::builder()
+        .index(device.info().unwrap().index())
+        .build()
+        .unwrap();
+    let baseline = device.allocated_bytes();
+    let x = Buffer::<u32>::zeros(device.clone(), 1000)
+        .unwrap()
+        .into_shared()
+        .unwrap();
+    let y = x.slice(10..).unwrap().to_device(device.clone()).unwrap();
+    assert!(device.allocated_bytes() >= baseline + 4000 + 3960);
+    let x_bytes = device.allocated_bytes() - baseline;
+    let z = x.clone();
+    drop(x);
+    assert_eq!(device.allocated_bytes() - baseline, x_bytes);
+    drop((y, z));
+    device.wait().unwrap();
+    assert_eq!(device.allocated_bytes(), baseline);
+}
+
+fn device_cached_kernels(device: Device) {
+    if device.is_host() {
+        assert!(device.cached_kernels().is_empty());
+        return;
+    }
+    let device = Device::builder()
+        .index(device.info().unwrap().index())
+        .build()
+        .unwrap();
+    assert!(device.cached_kernels().is_empty());
+    let mut x = Buffer::<u32>::zeros(device.clone(), 1).unwrap();
+    x.fill(1).unwrap();
+    let y = x.cast_into::<f32>().unwrap();
+    assert_eq!(y.to_vec().unwrap(), [1.]);
+    let kernels = device.cached_kernels();
+    assert_eq!(kernels.len(), 2, "{kernels:#?}");
+    for kernel in kernels {
+        assert!(kernel.size() > 0);
+        assert!(!kernel.spec_bytes().is_empty());
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "serde"))]
+fn device_info_serde(device: Device) {
+    let value = serde_json::to_value(device.info()).unwrap();
+    let Some(info) = device.info() else {
+        assert!(value.is_null());
+        return;
+    };
+    assert_eq!(value["vendor_id"], info.vendor_id());
+    assert_eq!(value["max_threads"], info.max_threads());
+    assert_eq!(
+        value["limits"]["max_push_constants_size"],
+        info.limits().max_push_constants_size()
+    );
+    let features: Features = serde_json::from_value(value["features"].clone()).unwrap();
+    assert_eq!(features, info.features());
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn device_lock_queue(device: Device) {
+    let x = vec![1u32; 1000];
+    let mut y = Slice::from(x.as_slice()).to_device(device.clone()).unwrap();
+    let mut z = y.to_device(device.clone()).unwrap();
+    std::thread::scope(|scope| {
+        let guard = device.lock_queue();
+        let handle = scope.spawn(|| {
+            let mut w = Slice::from(x.as_slice()).to_device(device.clone()).unwrap();
+            w.fill(4).unwrap();
+            w.into_vec().unwrap()
+        });
+        {
+            let _guard = device.lock_queue();
+            y.fill(2).unwrap();
+        }
+        z.copy_from_slice(&y.as_slice()).unwrap();
+        y.fill(3).unwrap();
+        drop(guard);
+        let w = handle.join().unwrap();
+        assert!(w.iter().all(|w| *w == 4));
+    });
+    assert!(y.into_vec().unwrap().iter().all(|y| *y == 3));
+    assert!(z.into_vec().unwrap().iter().all(|z| *z == 2));
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn device_limits(device: Device) {
+    let info = device.info().unwrap();
+    let limits = info.limits();
+    assert_eq!(limits.max_compute_work_group_count()[0], info.max_groups());
+    assert_eq!(limits.max_compute_work_group_size()[0], info.max_threads());
+    assert!(limits.max_compute_work_group_invocations() >= info.default_threads());
+    assert!(limits.max_compute_shared_memory_size() >= 16384);
+    assert!(limits.max_push_constants_size() >= 128);
+    assert!(limits
+        .min_storage_buffer_offset_alignment()
+        .is_power_of_two());
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn features_from_names() {
+    assert_eq!(Features::from_names(&[]).unwrap(), Features::empty());
+    assert_eq!(
+        Features::from_names(&["INT8", "BUFFER8", "shader_float64"]).unwrap(),
+        Features::INT8 | Features::BUFFER8 | Features::FLOAT64
+    );
+    let error = Features::from_names(&["INT8", "FLOAT"]).unwrap_err();
+    assert!(error.to_string().contains("\"FLOAT\""), "{error}");
+    Features::from_names(&["int8"]).unwrap_err();
+    Features::from_names(&["SHADER_INT8"]).unwrap_err();
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn output_slice() {
+    use krnl::krnl_core::buffer::OutputSlice;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut y = vec![0u32; 4];
+    let output = OutputSlice::from(y.as_mut_slice());
+    std::thread::scope(|s| {
+        for i in 0..output.len() {
+            let output = output.clone();
+            s.spawn(move || unsafe { output.write(i, i as u32 + 1) });
+        }
+    });
+    output.finish();
+    assert_eq!(y, [1, 2, 3, 4]);
+    if cfg!(debug_assertions) {
+        let mut y = vec![0u32; 4];
+        let output = OutputSlice::from(y.as_mut_slice());
+        unsafe {
+            output.write(1, 1);
+        }
+        let result = catch_unwind(AssertUnwindSafe(|| unsafe { output.write(1, 2) }));
+        assert!(result.is_err());
+        let result = catch_unwind(AssertUnwindSafe(|| output.finish()));
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn catch_out_of_bounds() {
+    use krnl::krnl_core::buffer::{self, Slice, UnsafeIndex, UnsafeSlice};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let x = [1u32, 2, 3];
+    let x = Slice::from(x.as_slice());
+    assert_eq!(buffer::catch_out_of_bounds(|| x[2]).unwrap(), 3);
+    let error = buffer::catch_out_of_bounds(|| x[3]).unwrap_err();
+    assert_eq!((error.index(), error.len()), (3, 3));
+    let mut y = [0u32; 2];
+    let y = UnsafeSlice::from(y.as_mut_slice());
+    let error = buffer::catch_out_of_bounds(AssertUnwindSafe(|| unsafe {
+        *y.unsafe_index_mut(5) = 1;
+    }))
+    .unwrap_err();
+    assert_eq!((error.index(), error.len()), (5, 2));
+    let result = catch_unwind(|| buffer::catch_out_of_bounds(|| panic!("other")));
+    assert!(result.is_err());
+    let result = catch_unwind(|| x[3]);
+    let message = result.unwrap_err().downcast::<String>().unwrap();
+    assert!(
+        message.contains("the len is 3 but the index is 3"),
+        "{message}"
+    );
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn scalar_elem_from_bytes() {
+    use krnl::scalar::ScalarElem;
+
+    let elems: [ScalarElem; 12] = [
+        1u8.into(),
+        (-2i8).into(),
+        3u16.into(),
+        (-4i16).into(),
+        f16::from_f32(5.5).into(),
+        bf16::from_f32(-6.5).into(),
+        7u32.into(),
+        (-8i32).into(),
+        9.25f32.into(),
+        u64::MAX.into(),
+        i64::MIN.into(),
+        (-10.125f64).into(),
+    ];
+    for elem in elems {
+        let scalar_type = elem.scalar_type();
+        let output = ScalarElem::from_bytes(scalar_type, elem.as_bytes()).unwrap();
+        assert_eq!(output.scalar_type(), scalar_type);
+        assert_eq!(output.as_bytes(), elem.as_bytes());
+        let bytes = vec![0; scalar_type.size() + 1];
+        let error = ScalarElem::from_bytes(scalar_type, &bytes).unwrap_err();
+        assert!(
+            error.to_string().contains(&format!(
+                "expected {} bytes for {scalar_type:?}",
+                scalar_type.size()
+            )),
+            "{error}"
+        );
+    }
+    assert_eq!(
+        ScalarElem::from_bytes(ScalarType::U32, &7u32.to_ne_bytes()).unwrap(),
+        ScalarElem::U32(7)
+    );
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn device_features(device: Device) {
+    let features = device.info().unwrap().features();
+    assert!(Features::all().contains(features));
+    let debug = format!("{features:?}");
+    for (name, feature) in [
+        ("INTEGER_DOT_PRODUCT", Features::INTEGER_DOT_PRODUCT),
+        ("COOPERATIVE_MATRIX", Features::COOPERATIVE_MATRIX),
+    ] {
+        assert_eq!(debug.contains(name), features.contains(feature), "{debug}");
+    }
+}
+
+fn device_scratch(device: Device) {
+    let mut x = device.scratch(1000).unwrap();
+    assert_eq!(x.len(), 1000);
+    assert_eq!(x.device(), device);
+    let y = Buffer::from(vec![1u32; 250])
+        .into_device(device.clone())
+        .unwrap();
+    x.bitcast_mut::<u32>()
+        .unwrap()
+        .copy_from_slice(&y.as_slice())
+        .unwrap();
+    assert_eq!(x.bitcast::<u32>().unwrap().to_vec().unwrap(), [1u32; 250]);
+    let high_water_mark = device.scratch_high_water_mark();
+    if device.is_host() {
+        assert_eq!(high_water_mark, 0);
+    } else {
+        assert!(high_water_mark >= 1000);
+    }
+    drop(x);
+    device.wait().unwrap();
+    let z = device.scratch(100).unwrap();
+    assert_eq!(z.len(), 100);
+    assert_eq!(device.scratch_high_water_mark(), high_water_mark);
+}
+
+fn device_report(device: Device) {
+    let info = device.info().unwrap();
+    let report = device.report();
+    assert!(report.starts_with("Device("), "{report}");
+    assert!(
+        report.contains(&format!("{:?}", info.features())),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("vendor_id: {:#06x}", info.vendor_id())),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("driver_id: {:?}", info.driver_id())),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("device_type: {:?}", info.device_type())),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("compute_units: {:?}", info.compute_units())),
+        "{report}"
+    );
+    assert!(info.recommended_min_groups(info.default_threads()) >= 1);
+    assert!((info.min_subgroup_threads()..=info.max_subgroup_threads())
+        .contains(&info.subgroup_threads()));
+    let (major, minor, patch) = info.api_version();
+    assert!(
+        report.contains(&format!("api_version: {major}.{minor}.{patch}")),
+        "{report}"
+    );
+    assert!(report.contains("allocated_bytes: "), "{report}");
+    // allocated_bytes changes as other tests allocate
+    let strip = |report: String| -> Vec<String> {
+        report
+            .lines()
+            .filter(|line| !line.starts_with("allocated_bytes"))
+            .map(Into::into)
+            .collect()
+    };
+    assert_eq!(strip(report), strip(device.report()));
+}
+
 fn buffer_test_lengths() -> impl ExactSizeIterator<Item = usize> {
     [0, 1, 3, 4, 16, 67, 157].into_iter()
 }
@@ -194,6 +1411,290 @@ fn buffer_from_vec(device: Device) {
     }
 }
 
+fn buffer_uninit_with_usage(device: Device) {
+    let usage = BufferUsage::INDIRECT | BufferUsage::UNIFORM;
+    assert_eq!(format!("{usage:?}"), "BufferUsage(INDIRECT|UNIFORM)");
+    for n in buffer_test_lengths() {
+        let x = (10..20).cycle().take(n).collect::<Vec<u32>>();
+        let mut y = unsafe { Buffer::<u32>::uninit_with_usage(device.clone(), n, usage).unwrap() };
+        y.copy_from_slice(&Slice::from(x.as_slice())).unwrap();
+        assert_eq!(y.into_vec().unwrap(), x);
+    }
+}
+
+fn buffer_from_vec_host_visible(device: Device) {
+    for n in buffer_transfer_test_lengths() {
+        let x = (10..20).cycle().take(n).collect::<Vec<u32>>();
+        let ptr = x.as_ptr();
+        let capacity = x.capacity();
+        let x_clone = x.clone();
+        let x_clone_ptr = x_clone.as_ptr();
+        let y = Buffer::from_vec_host_visible(device.clone(), x_clone).unwrap();
+        assert_eq!(y.device(), device);
+        if let Some(y) = y.as_host_slice() {
+            assert_eq!(y.as_ptr(), x_clone_ptr);
+        }
+        if n > 0 {
+            assert!(y.is_host_visible());
+        }
+        assert_eq!(y.to_vec().unwrap(), x);
+        let y = Buffer::from_vec(x);
+        assert_eq!(y.as_host_slice().unwrap().as_ptr(), ptr);
+        let x = y.into_vec().unwrap();
+        assert_eq!(x.as_ptr(), ptr);
+        assert_eq!(x.capacity(), capacity);
+    }
+}
+
+fn buffer_uninit_with_memory(device: Device) {
+    for memory in [MemoryKind::DeviceLocal, MemoryKind::HostVisible] {
+        for n in buffer_test_lengths() {
+            let x = (10..20).cycle().take(n).collect::<Vec<u32>>();
+            let mut y =
+                unsafe { Buffer::<u32>::uninit_with_memory(device.clone(), n, memory).unwrap() };
+            if device.is_host() || (n > 0 && memory == MemoryKind::HostVisible) {
+                assert!(y.is_host_visible());
+            }
+            y.copy_from_slice(&Slice::from(x.as_slice())).unwrap();
+            let mut z = y.to_device(device.clone()).unwrap();
+            z.copy_from_slice(&y.as_slice()).unwrap();
+            assert_eq!(y.into_vec().unwrap(), x);
+            assert_eq!(z.into_vec().unwrap(), x);
+        }
+    }
+}
+
+fn buffer_map(device: Device) {
+    let n = 1000;
+    let mut x = unsafe {
+        Buffer::<u32>::uninit_with_memory(device.clone(), n, MemoryKind::HostVisible).unwrap()
+    };
+    x.fill(1).unwrap();
+    assert!(x.map().unwrap().iter().all(|x| *x == 1));
+    for (i, x) in x.map_mut().unwrap().iter_mut().enumerate() {
+        *x = i as u32;
+    }
+    assert_eq!(x.to_vec().unwrap(), (0..n as u32).collect::<Vec<_>>());
+    assert_eq!(
+        *x.slice(10..20).unwrap().map().unwrap(),
+        (10..20).collect::<Vec<u32>>()
+    );
+    x.fill(2).unwrap();
+    assert!(x.map().unwrap().iter().all(|x| *x == 2));
+    let y = Buffer::from(vec![0u32; n]).into_device(device).unwrap();
+    assert_eq!(y.map().is_ok(), y.is_host_visible());
+}
+
+fn buffer_scan(device: Device) {
+    let x = Buffer::from(vec![1u32, 2, 3, 4])
+        .into_device(device.clone())
+        .unwrap();
+    let y = x.scan(ScanMode::Inclusive).unwrap();
+    assert_eq!(y.device(), device);
+    assert_eq!(y.to_vec().unwrap(), [1, 3, 6, 10]);
+    let y = x.slice(1..).unwrap().scan(ScanMode::Exclusive).unwrap();
+    assert_eq!(y.to_vec().unwrap(), [0, 2, 5]);
+    let x = Buffer::from(vec![200u8, 100, 1])
+        .into_device(device.clone())
+        .unwrap();
+    let y = x.scan(ScanMode::Inclusive).unwrap();
+    assert_eq!(y.to_vec().unwrap(), [200, 44, 45]);
+    let x = Buffer::from(vec![0.5f32, 1.5, -2.])
+        .into_device(device.clone())
+        .unwrap();
+    let y = x.scan(ScanMode::Exclusive).unwrap();
+    assert_eq!(y.to_vec().unwrap(), [0., 0.5, 2.]);
+    let x = Buffer::<i32>::from(Vec::new()).into_device(device).unwrap();
+    assert!(x.scan(ScanMode::Inclusive).unwrap().is_empty());
+}
+
+fn buffer_for_each_host(device: Device) {
+    let mut x = Buffer::from((0..100u32).collect::<Vec<_>>())
+        .into_device(device)
+        .unwrap();
+    let mut sum = 0;
+    x.for_each_host(|i, x| {
+        assert_eq!(*x, i as u32);
+        sum += x;
+    })
+    .unwrap();
+    assert_eq!(sum, 4950);
+    x.slice_mut(10..20)
+        .unwrap()
+        .modify_host(|i, x| *x += 2 * i as u32)
+        .unwrap();
+    let x = x.to_vec().unwrap();
+    for (i, x) in x.into_iter().enumerate() {
+        if (10..20).contains(&i) {
+            assert_eq!(x, 3 * i as u32 - 20);
+        } else {
+            assert_eq!(x, i as u32);
+        }
+    }
+}
+
+fn buffer_overlaps(device: Device) {
+    let x = Buffer::from(vec![0u32; 10])
+        .into_device(device.clone())
+        .unwrap();
+    let y = x.to_device(device).unwrap();
+    assert!(x.overlaps(&x));
+    assert!(!x.overlaps(&y));
+    let a = x.slice(..6).unwrap();
+    assert!(a.overlaps(&x.slice(5..).unwrap()));
+    assert!(!a.overlaps(&x.slice(6..).unwrap()));
+    assert!(!a.overlaps(&x.slice(3..3).unwrap()));
+    let bytes = x.bitcast::<u8>().unwrap();
+    assert!(a.overlaps(&bytes.slice(23..24).unwrap()));
+    assert!(!a.overlaps(&bytes.slice(24..).unwrap()));
+}
+
+fn buffer_resize(device: Device) {
+    let x_vec: Vec<u32> = (0..10).collect();
+    let mut x = Buffer::from(x_vec.clone())
+        .into_device(device.clone())
+        .unwrap();
+    x.resize(15, 7).unwrap();
+    assert_eq!(x.device(), device);
+    let mut y_vec = x_vec.clone();
+    y_vec.resize(15, 7);
+    assert_eq!(x.to_vec().unwrap(), y_vec);
+    x.resize(4, 7).unwrap();
+    assert_eq!(x.to_vec().unwrap(), x_vec[..4]);
+    let mut y = ArcBuffer::from(x);
+    let z = y.clone();
+    y.resize(0, 7).unwrap();
+    assert!(y.is_empty());
+    assert_eq!(z.to_vec().unwrap(), x_vec[..4]);
+    y.resize(2, 1).unwrap();
+    assert_eq!(y.to_vec().unwrap(), [1, 1]);
+}
+
+fn buffer_chunks(device: Device) {
+    let x_vec: Vec<u32> = (0..10).collect();
+    let x = Buffer::from(x_vec.clone()).into_device(device).unwrap();
+    for chunk_len in [1, 3, 5, 10, 11] {
+        let chunks: Vec<Vec<u32>> = x
+            .chunks(chunk_len)
+            .unwrap()
+            .map(|chunk| chunk.to_vec().unwrap())
+            .collect();
+        let expected: Vec<Vec<u32>> = x_vec.chunks(chunk_len).map(|x| x.to_vec()).collect();
+        assert_eq!(chunks, expected);
+    }
+    assert!(x.slice(..0).unwrap().chunks(3).unwrap().next().is_none());
+    assert!(x.chunks(0).is_err());
+}
+
+fn buffer_get(device: Device) {
+    let n = 1_000_000;
+    let x_vec: Vec<u32> = (0..n as u32).collect();
+    let x = Buffer::from(x_vec).into_device(device).unwrap();
+    assert_eq!(x.get(0).unwrap(), 0);
+    assert_eq!(x.get(n / 2).unwrap(), (n / 2) as u32);
+    assert_eq!(x.slice(10..).unwrap().get(5).unwrap(), 15);
+    assert!(x.get(n).is_err());
+}
+
+fn buffer_raw_vulkan_handle(device: Device) {
+    let x = Buffer::from(vec![1u32; 100])
+        .into_device(device.clone())
+        .unwrap()
+        .into_shared()
+        .unwrap();
+    let handle = unsafe { x.raw_vulkan_handle() };
+    if device.is_host() {
+        assert!(handle.is_none());
+        return;
+    }
+    let (buffer, offset, size) = handle.unwrap();
+    assert_eq!(size, 400);
+    assert_eq!(unsafe { x.clone().raw_vulkan_handle() }, handle);
+    let slice = x.slice(10..20).unwrap();
+    assert_eq!(
+        unsafe { slice.raw_vulkan_handle() },
+        Some((buffer, offset + 40, 40))
+    );
+    let empty = Buffer::<u32>::zeros(device, 0).unwrap();
+    assert!(unsafe { empty.raw_vulkan_handle() }.is_none());
+}
+
+fn buffer_streaming_uploader(device: Device) {
+    let data: Vec<u32> = (0..10).collect();
+    let mut uploader = StreamingUploader::<u32>::new(device.clone(), 4).unwrap();
+    assert_eq!(uploader.device(), device);
+    let mut output = Vec::new();
+    for chunk in data.chunks(uploader.batch_len()) {
+        uploader.batch_mut().as_host_slice_mut().unwrap()[..chunk.len()].copy_from_slice(chunk);
+        let batch = uploader.upload(chunk.len()).unwrap();
+        assert_eq!(batch.device(), device);
+        output.extend(batch.to_vec().unwrap());
+    }
+    assert_eq!(output, data);
+    assert!(uploader.upload(5).is_err());
+    assert!(StreamingUploader::<u32>::new(device, 0).is_err());
+}
+
+fn buffer_argmax(device: Device) {
+    let x = Buffer::from(vec![3u32, 1, 4, 1, 5, 9, 2, 6, 9, 1])
+        .into_device(device.clone())
+        .unwrap();
+    assert_eq!(x.argmax().unwrap(), (5, 9));
+    assert_eq!(x.argmin().unwrap(), (1, 1));
+    assert_eq!(x.slice(6..8).unwrap().argmax().unwrap(), (1, 6));
+    let x = Buffer::from(vec![7i32; 100])
+        .into_device(device.clone())
+        .unwrap();
+    assert_eq!(x.argmax().unwrap(), (0, 7));
+    assert_eq!(x.argmin().unwrap(), (0, 7));
+    let x = Buffer::from(vec![1f32, f32::NAN, 3., f32::NAN])
+        .into_device(device.clone())
+        .unwrap();
+    let (index, value) = x.argmax().unwrap();
+    assert_eq!(index, 1);
+    assert!(value.is_nan());
+    assert_eq!(x.argmin().unwrap().0, 1);
+    let x = Buffer::from(vec![-1f64, 2., -3.])
+        .into_device(device.clone())
+        .unwrap();
+    assert_eq!(x.argmax().unwrap(), (1, 2.));
+    assert_eq!(x.argmin().unwrap(), (2, -3.));
+    let x = Buffer::<u32>::from(Vec::new()).into_device(device).unwrap();
+    assert!(x.argmax().is_err());
+    assert!(x.argmin().is_err());
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Particle {
+    position: [f32; 3],
+    id: u32,
+}
+
+unsafe impl bytemuck::Zeroable for Particle {}
+
+unsafe impl bytemuck::Pod for Particle {}
+
+fn buffer_pod_round_trip(device: Device) {
+    let particles: Vec<_> = (0..100)
+        .map(|i| Particle {
+            position: [i as f32, -1., 0.5],
+            id: i,
+        })
+        .collect();
+    let x = Buffer::<u32>::from_pod_slice(device.clone(), &particles).unwrap();
+    assert_eq!(x.device(), device);
+    assert_eq!(x.len(), particles.len() * 4);
+    assert_eq!(x.to_pod_vec::<Particle>().unwrap(), particles);
+    assert_eq!(
+        x.slice(4..8).unwrap().to_pod_vec::<Particle>().unwrap(),
+        particles[1..2]
+    );
+    assert!(x.slice(4..6).unwrap().to_pod_vec::<Particle>().is_err());
+    assert!(Buffer::<u64>::from_pod_slice(device.clone(), &[[0u32; 3]]).is_err());
+    assert!(Buffer::<u32>::from_pod_slice(device, &[(); 1]).is_err());
+}
+
 #[cfg(feature = "device")]
 fn device_buffer_too_large(device: Device) {
     use krnl::buffer::error::DeviceBufferTooLarge;
@@ -243,6 +1744,136 @@ fn buffer_fill<T: Scalar>(device: Device) {
     }
 }
 
+fn buffer_wait(device: Device) {
+    let x = Buffer::from(vec![1u32; 1000])
+        .into_device(device.clone())
+        .unwrap();
+    x.wait().unwrap();
+    let mut y = x.to_device(device.clone()).unwrap();
+    let mut z = x.to_device(device).unwrap();
+    y.fill(2).unwrap();
+    z.fill(3).unwrap();
+    y.wait().unwrap();
+    z.as_scalar_slice().wait().unwrap();
+    assert!(y.into_vec().unwrap().iter().all(|y| *y == 2));
+    assert!(z.into_vec().unwrap().iter().all(|z| *z == 3));
+}
+
+fn buffer_to_vec_nonblocking(device: Device) {
+    let mut x = Buffer::<u32>::zeros(device.clone(), 1_000_000).unwrap();
+    x.fill(1).unwrap();
+    // pending
+    let y = loop {
+        if let Some(y) = x.to_vec_nonblocking().unwrap() {
+            break y;
+        }
+        assert!(!device.is_host());
+    };
+    assert!(y.iter().all(|y| *y == 1));
+    // completed
+    x.fill(2).unwrap();
+    x.wait().unwrap();
+    let y = x.to_vec_nonblocking().unwrap().unwrap();
+    assert!(y.iter().all(|y| *y == 2));
+}
+
+fn buffer_copy_into_at<T: Scalar>(device: Device) {
+    let n = buffer_test_lengths().last().unwrap();
+    let x = (10..20)
+        .cycle()
+        .map(|x| T::from_u32(x).unwrap())
+        .take(n)
+        .collect::<Vec<_>>();
+    for n in buffer_test_lengths() {
+        let x = &x[..n];
+        let x_device = Slice::from(x).to_device(device.clone()).unwrap();
+        for offset in [0, 1, 7] {
+            let mut y = Buffer::from(vec![T::zero(); offset + n + 3])
+                .into_device(device.clone())
+                .unwrap();
+            y.copy_into_at(offset, &x_device.as_slice()).unwrap();
+            let y = y.into_vec().unwrap();
+            assert!(y[..offset].iter().all(|y| *y == T::zero()));
+            assert_eq!(&y[offset..offset + n], x);
+            assert!(y[offset + n..].iter().all(|y| *y == T::zero()));
+        }
+        let mut y = Buffer::from(vec![T::zero(); n])
+            .into_device(device.clone())
+            .unwrap();
+        assert!(y.copy_into_at(1, &x_device.as_slice()).is_err());
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn buffer_bytes_round_trip<T: Scalar>(device: Device) {
+    let n = buffer_test_lengths().last().unwrap();
+    let x = (10..20)
+        .cycle()
+        .map(|x| T::from_u32(x).unwrap())
+        .take(n)
+        .collect::<Vec<_>>();
+    let path = std::env::temp_dir().join(format!(
+        "krnl_buffer_bytes_round_trip_{:?}_{}_{}.bin",
+        T::SCALAR_TYPE,
+        device.is_host(),
+        std::process::id(),
+    ));
+    for n in buffer_test_lengths() {
+        let x = &x[..n];
+        let x_device = Slice::from(x).to_device(device.clone()).unwrap();
+        std::fs::write(&path, x_device.to_bytes().unwrap()).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(bytes.len(), n * size_of::<T>());
+        let y = Buffer::<T>::from_bytes(device.clone(), &bytes).unwrap();
+        assert_eq!(y.device(), device);
+        assert_eq!(y.into_vec().unwrap(), x);
+        if size_of::<T>() > 1 && n > 0 {
+            assert!(Buffer::<T>::from_bytes(device.clone(), &bytes[1..]).is_err());
+        }
+    }
+    std::fs::remove_file(&path).unwrap();
+}
+
+fn scalar_buffer_copy_from_scalar_slice(device: Device) {
+    let x = Buffer::from((10..20).collect::<Vec<u32>>())
+        .into_device(device.clone())
+        .unwrap();
+    let x = ScalarBuffer::from(x);
+    let mut y = ScalarBuffer::from(
+        Buffer::from(vec![0u32; x.len()])
+            .into_device(device.clone())
+            .unwrap(),
+    );
+    y.copy_from_scalar_slice(&x.as_scalar_slice()).unwrap();
+    let y = Buffer::<u32>::try_from(y).unwrap().into_vec().unwrap();
+    assert_eq!(y, (10..20).collect::<Vec<u32>>());
+    let mut y = ScalarBuffer::from(
+        Buffer::from(vec![0u32; x.len() + 1])
+            .into_device(device.clone())
+            .unwrap(),
+    );
+    assert!(y.copy_from_scalar_slice(&x.as_scalar_slice()).is_err());
+    let mut y = ScalarBuffer::from(
+        Buffer::from(vec![0i32; x.len()])
+            .into_device(device)
+            .unwrap(),
+    );
+    assert!(y.copy_from_scalar_slice(&x.as_scalar_slice()).is_err());
+}
+
+fn buffer_from_elem<T: Scalar>(device: Device) {
+    let elem = T::from_u32(7).unwrap();
+    for n in buffer_test_lengths() {
+        let y = Buffer::from_elem(device.clone(), n, elem).unwrap();
+        assert_eq!(y.device(), device);
+        let y: Vec<T> = y.into_vec().unwrap();
+        assert_eq!(y.len(), n);
+        for y in y.into_iter() {
+            assert_eq!(y, elem);
+        }
+    }
+}
+
 fn buffer_cast<X: Scalar, Y: Scalar>(device: Device) {
     let n = buffer_test_lengths().last().unwrap();
     let x = (10..20)
@@ -265,6 +1896,25 @@ fn buffer_cast<X: Scalar, Y: Scalar>(device: Device) {
     }
 }
 
+fn buffer_cast_into_slice<X: Scalar, Y: Scalar>(device: Device) {
+    let n = buffer_test_lengths().last().unwrap();
+    let x = (10..20)
+        .cycle()
+        .map(|x| X::from_u32(x).unwrap())
+        .take(n)
+        .collect::<Vec<_>>();
+    for n in buffer_test_lengths() {
+        let x = Slice::<X>::from(&x[..n]).to_device(device.clone()).unwrap();
+        let mut y = unsafe { Buffer::<Y>::uninit(device.clone(), n).unwrap() };
+        x.cast_into_slice(&mut y.as_slice_mut()).unwrap();
+        let mut z = unsafe { Buffer::<X>::uninit(device.clone(), n).unwrap() };
+        y.cast_into_slice(&mut z.as_slice_mut()).unwrap();
+        assert_eq!(z.into_vec().unwrap(), x.to_vec().unwrap());
+        let mut y = unsafe { Buffer::<Y>::uninit(device.clone(), n + 1).unwrap() };
+        assert!(x.cast_into_slice(&mut y.as_slice_mut()).is_err());
+    }
+}
+
 fn buffer_bitcast<X: Scalar, Y: Scalar>(device: Device) {
     let x_host = vec![0u64; 16];
     let x_host: &[X] = &bytemuck::cast_slice(&x_host)[..16];
@@ -282,11 +1932,162 @@ fn buffer_bitcast<X: Scalar, Y: Scalar>(device: Device) {
     }
 }
 
+#[test]
+fn device_cancel_pending_host() {
+    device_cancel_pending(Device::host());
+}
+
+#[test]
+fn device_flush_host() {
+    device_flush(Device::host());
+}
+
+#[test]
+fn device_in_flight_host() {
+    device_in_flight(Device::host());
+}
+
+#[test]
+fn device_assert_owns_host() {
+    device_assert_owns(Device::host());
+}
+
+#[test]
+fn device_download_many_host() {
+    device_download_many(Device::host());
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn device_time_host() {
+    device_time(Device::host());
+}
+
+#[test]
+fn device_fence_host() {
+    device_fence(Device::host());
+}
+
+#[test]
+fn device_iter_host() {
+    device_iter(Device::host());
+}
+
+#[test]
+fn device_report_host() {
+    assert_eq!(Device::host().report(), "Host");
+}
+
+#[test]
+fn device_scratch_host() {
+    device_scratch(Device::host());
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn device_lock_queue_host() {
+    device_lock_queue(Device::host());
+}
+
+#[test]
+fn scalar_buffer_copy_from_scalar_slice_host() {
+    scalar_buffer_copy_from_scalar_slice(Device::host());
+}
+
 #[test]
 fn buffer_from_vec_host() {
     buffer_from_vec(Device::host());
 }
 
+#[test]
+fn buffer_from_vec_host_visible_host() {
+    buffer_from_vec_host_visible(Device::host());
+}
+
+#[test]
+fn buffer_wait_host() {
+    buffer_wait(Device::host());
+}
+
+#[test]
+fn buffer_to_vec_nonblocking_host() {
+    buffer_to_vec_nonblocking(Device::host());
+}
+
+#[test]
+fn buffer_uninit_with_usage_host() {
+    buffer_uninit_with_usage(Device::host());
+}
+
+#[test]
+fn buffer_uninit_with_memory_host() {
+    buffer_uninit_with_memory(Device::host());
+}
+
+#[test]
+fn buffer_scan_host() {
+    buffer_scan(Device::host());
+}
+
+#[test]
+fn buffer_for_each_host_host() {
+    buffer_for_each_host(Device::host());
+}
+
+#[test]
+fn buffer_overlaps_host() {
+    buffer_overlaps(Device::host());
+}
+
+#[test]
+fn buffer_resize_host() {
+    buffer_resize(Device::host());
+}
+
+#[test]
+fn buffer_chunks_host() {
+    buffer_chunks(Device::host());
+}
+
+#[test]
+fn buffer_get_host() {
+    buffer_get(Device::host());
+}
+
+#[test]
+fn buffer_raw_vulkan_handle_host() {
+    buffer_raw_vulkan_handle(Device::host());
+}
+
+#[test]
+fn buffer_streaming_uploader_host() {
+    buffer_streaming_uploader(Device::host());
+}
+
+#[test]
+fn buffer_argmax_host() {
+    buffer_argmax(Device::host());
+}
+
+#[test]
+fn buffer_pod_round_trip_host() {
+    buffer_pod_round_trip(Device::host());
+}
+
+#[test]
+fn buffer_map_host() {
+    buffer_map(Device::host());
+}
+
+macro_for!($T in [u8, u16, u32, u64] {
+    paste! {
+        #[test]
+        fn [<buffer_copy_into_at_ $T _host>]() {
+            buffer_copy_into_at::<$T>(Device::host());
+        }
+    }
+});
+
 #[cfg(target_family = "wasm")]
 macro_for!($T in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
     paste! {
@@ -294,6 +2095,10 @@ macro_for!($T in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
         fn [<buffer_fill_ $T _host>]() {
             buffer_fill::<$T>(Device::host());
         }
+        #[test]
+        fn [<buffer_from_elem_ $T _host>]() {
+            buffer_from_elem::<$T>(Device::host());
+        }
     }
 });
 
@@ -305,6 +2110,10 @@ macro_for!($X in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
                 buffer_cast::<$X, $Y>(Device::host());
             }
             #[test]
+            fn [<buffer_cast_into_slice_ $X _ $Y _host>]() {
+                buffer_cast_into_slice::<$X, $Y>(Device::host());
+            }
+            #[test]
             fn [<buffer_bitcast_ $X _ $Y _host>]() {
                 buffer_bitcast::<$X, $Y>(Device::host());
             }
@@ -1,16 +1,16 @@
 __krnl_cache!("0.1.1-alpha", "
 abZy8000000@}T[2B[&lWv+056{e=N0.Kh7qiSmB3R.jjH+O%%6$hi2qKpy/FR{aflaRPJ6Oi8ZJv!K7JFLPMf#*dD-UZNU-+iVYbr<%E?$+QP%2kqt?4!C7uhO0yOPA92F7*E[NyLcF!?D%?J5CLcV]@nd)ZJ>DNNbBmZOrcm:<0wGN3xP+Uwq-!sRF!=<+wm}Jug2#A=mtv1<K9p:#of#Myd^b^iZK.ZWAmO+UYYYz4/%n.#Ay%kycCS7)j<trdR:9?f2r*wnYDB2i1?9z&Km^a6%9cAoD-13g#n)&v:8cxjfl@[Q2@7o#CsX&JJgn8GzR$?f65=OC3w$b-R5Io#CHo+YKDsI>{8ivFWD9Y4ir*lqq9aOLuwscd=}?Xu7U4ag+^hF2vP4vc0mKR@rZm{u!w^H)8zVz%b-4Qy#)x{]}A3Jp^4[Ykv6+/C@uyT)*Yg^Ft%5>U$nPY&-.^=@yBRbt8TH+be{<e<iIhOpji2/X%.*^@NoslrtDB?@#FD+]-pci6!/iM.chXj.9&CU=%&{ow*mDV.%IIK4lX).PdwZ}jR}9L!e@8OY<AjnFTMbKsQ<Dg)g<<Tp&S8lSL!3Kf(d<Qi8B^[/W+f75?k.(DT9L@ycAAIM]w&^oR%N+{W$DCyd<kHmKr}EV[#/BOxU?(c!x+)J#xqex3}b*VI?%k0u!<}mvOZ^QqmPL[m*xJ9:qs&xCQY9@qSgW5l$#!n5BW(w?[S(BnhiCv75G?i-(ZC*{UsQ#8EJtmm[5HRDta}Me&JjK!!4GWieXj}+:#CK0=)@@Q?@R/IA1sZumL}OU9%:}(nUmm::r.wsC/Zqb(**9)0yvv!!4.0<w*E?bfjaUw&QJ%PO74oTt2njeYI/cXM%&f2nHt<ry]f>xa9xKtSNNp]kfZ8MeM6VH(D}^r]8hvBWPf/k]%E-NNB]PH+YX%[YQ)Ub#MFQC^{=E{C*Y8=C34($OmZyz<TbMEtDko8=6w=.DJwM/{S4$@M3vF&N?
-tMdjH-Fu4VtF-G}jUGb0Dbfh8TCEe]>ypxam87N>MlCVKR1C>Eodsg#7JjOzT:]jmdNKkEQmWsOtzUyzFi5?(VKhb!7Xw]rE!ji4rwh<!]RZl^nTZn#16L0]rI.1&tsj=[FP(Pq?u{ig3T{T!Eq}XxrWyQ)(RJus^pZ1c9Z*]QW&R%YUV<PYf9B-B^SY<i0mDb)BT?8@YdX+vEJvL7[clnJQUG]h2J{s8rL}vc6T9x)cu{@Ac<{^MBBHVIIw5$Qq<tTl)hfPv9yF8Vw2M}Y3]MT%Q2$5-!lE^SSs}Ub:9u9sM*B2V0YwQbh(CW7Iup%/x[y:tUR1Sgx#rOoA0bxkifO:*1O]51h@CK>B-6F2m1iUVqQxz?!x}5Oh@EOO1h$f]@cX)*Gjf9k(pux&D39pR=e<ODfh1QQGhURrb{1{JlEYnz*/5n?yhh3yM6#+Ph.Cg&>Eo?X![Gd/Kd#@hN>Dh}99>jLQW5HZ2p6Wtkcvx}u5r9Sju+@{^M6juuYNMM2E7yc{vO@EJ#Krtj@jbr0renvI]0dgfB*8w9r-EW-Up0<gZ:bCWaTvK*<pP(e6kWu<L:iW)@sfDXvjV#NB?#MM!1VP4dQ&Kc#(Bv=LoBxs3!?.&J0JlfZqeS8#G}X[FbS@&@hdN7z3oS>hE)&ApD{SXoLl#-E<298*)ACjCZH(0cdLFXdvSfC.0&N154Oil5iL2x?c9YANz{J*M6m]M#%>gM}UT?:2K(RtOYwr5jsFEv^7r/3(}o.Q2MtiBLI#ax=6jv:9R]Q0[<h+2Z1>2F@S^TU$]!1OEP$OPL4#R]C3@M5jq&C(1YpUa)w-D-b>M#0@wRy4G<zoFCM?gQVV5jD1@>2ZTA!uGL-^nc/4hpT$5UBE9(}63oDEalc5jh9J6L>h.aMzOkPFf>AF-ZiC1#C)6W]t/doXq=JB#8t&68kpx!<Z@b+=utfTa#lRN46[^[:@y<z%dk3G7q&+cD3O8&n[p(*R&h]PBMs5G#x1(f-y
-^?c^f(t8ijx3hhGQZ^[o-Zs5=oEQ}F*-2[rGXa3?qfSl6op+dV/0Rc6n1#OWCVn]BRU1[6vMVpJKcYo{qcj3WGk1iU-/:VB&$yMOR)#l>m0OCuHD06kw=SZ[l.g^Smc4B(fl/k)84TowEq6)[+u8B9JrG*^thke>?rSCcpcV>:ciUiSo.xK!(.QaW-K+KFuB+17ym:.ch^(YeCSj91bh>0mn6VN-mLtbavZGMGsOsr!juT3Em{*t@hmRmysM)awG:VqqtZdptB9C#z4BkHAE.g-@Sxn)2UhvGgkIyZ$CLXBP1e>36Uh3<C2vhNo(D1W6PBLo9?9FtJ(C=6KMDF$IVFA2*XURyf4sM(IocrYV^-k+kzT3h=w4h7p:K@d[L3B}o:>=>PBK1=93B+aoBK1-H3I/ge*)Ud6m>8[y&Q[+S^MNxWXUWQ})Sf.3v!h.w)<v/11-=m+%blw)5PIeCs$.2@3YqBBI$Za])sTjUd-kPlulQiQwZ[&JcrZ>8H1$aya^YF7MOAxS?0qdg{DlDFuQn<fQmVjDuXr.e<[<hyX9hYPUu@JnZOZUWm0=z2AyOZCh&{a<W{R1Xy<(=5VW0GFw60!RKH][OGlQ(PPykVwmGlXs@t$!jEwi<Kf5GgkmKDb1Y]Sey]QVwab(vWND4+#-/Z{xi4*=hbE*SxuFCx/hpTYa@^+B)$:&zC-Yl]2d/(:k3K:Sa)?-#vmFM$fy4}HeUXJRg&E)K5Iu<Wc/2d:5na0N[rls0m.@%+u#jWaSLM*iOBa)jsB1>ws<%dl.9hu0G-ftoZD[09/M&hg)^Ay^@of#1{AUoA7R@4Ec3J@$QS^F>BCjYg0MKDfFH%ar3959U[jlc6{x2fRSuRKL73RS-W4o0:x#ZK*ei7m&vAwhO!PL<a7D8mbi2iB=8ooWM]HUJ&{44:p{Us0L{lKuNg9UQTqfbDY9aVNfar@&2s^1=MT2TCMBzG.V<*=ZmjbL@02?T8=jYlZTPjcCnQBQB[m^
-aBBPa!<Gp)D8$1av&/n.=y1nhq$HKoT06en0<+8c4g8MIQ%CzQsD$WObDC>8y!Qj<zXuwJFZCg0Js*4{N9yZRv(y!CxuR3>:@dI?vt.^gC^NPxd}2!JXfUNPG.ceNwuf:<jJjs<f<tr*>UzU>^%#ARfK2=HdQ/+K}IYZhrxjMXvo7Quz3:ekVr$pdfXMSneYc(XVJFS*+RMxz8b(?U=H-!L5Vj=6tr<0jBob#3GGx&Vsesff?k[:pV+6i$wa?3s^vRdWx9mggvd(>Xew:HO>}?Fjr]]p[!2F])DL9dsDXUVAvdTFifnaV=w).vWNcdRqgV[Hl<=4(NfHZESC>zqhE{?$q{YwqLFB<-JlAtdDYVva2GWz87&NrZ!:#r&f(#]ilGNsu[*S>lV<uHTDFB+53=oIoj!Wc#WzgrI(wvYS[}L1{1i/iOAS#dO21rD9pfs5pvdO-io]Dxf=te/.hJ6m%Agoiye*xX?]P=N-a/ExSHv4o}dl-/qt0(&[cz(uUQ^qsz])#p7MD+0dD9(c#a7/%?U>(D.}6Z[U{ihfQ/9PcE9:[vi+igUPEO?OP{&=Ck>DY03Prufz*Go10T!){>^dO:hQOjjvkT(uX>-+ubu.ZZYg3zDGmX={=0te+g+J8>Q]wFIa2fJ}t^y]YK&tlt)!pH*Yz-WTZ>u]ff*5MS)P0hz0Z+DV{HY0Y)tR}9lIKYDDu1ev2FJiXt&7-iYhuANoF^oNb2}WdF!Cc>nt}Oo457?oVb!cwDGIz%9oJ{3Wj:39r]Vk)M)RW+y}5YP6@icx:GI@EEnIx/.i^6mH<M2c5[&)X92B&6IV6@JxjS-cMTT2M&=5<2.?e.KDC6@JJn^G[>]T2KagKW!</s&z0eBM?u[kr%q-:YryjfUPmN-}rwLehj/iCSe:z5NuHxC&4@]*1{xS>k-J[@E/fOrH:dd!aV!NO2$V&Z*7?yJRMIO-UxB:%5Nv.RDNoWNpq}p*:#N*/u(.Xr&Xb]xBYoRrRZf]
-15uf-m?.D1YwoH+Dys}hW(MeRegl]}rw9#.uu?l9XJ.+B5AKW%jfvj+FQcrDSm#J8}Ytp<uc1blM1H.xI#JelX^ei19&+MNz8sF3Da}R8D{2@}emv%f{<PoLV-4YDT&:@5w=?Nw.KIo(iOgoU9l>bss^w>#=9Qth=9}Bn(cz*pXT}VOi:DdzuGaSREUZ4k9KY[k8PUmC(MN$c4{k9RR2h*IdTgYf(ax=UI(>ZD0Sme-7G$o<=79ys9<^zf%7:6>RrBn1r@}PXZ^^Mum%./{W8e&#/@P&oUl?tpdT9=Y.g{M{r:g<3E6fZ)KjP6oqA^JRde7:Bi+[Y/r}BpClk#2Ttnb.7rMn/vN{:ejD]2h*EV91UZ)5incBz3jN.f#O?kYn(yLL[cUl<Q6t6si=DF2l/)$BokMtl-Y}-?zpF0/U+n3f{tPv]gozN4PT=%%>k)EvX^8t?SNsE0}(D6mCV]GJ>&t$xCY)U-C8@Yc?PL*%y-YE9ncfRoM3xroloa98bX8807Uc)bgkpsodg]t[@[GRb4n.v5]bf[iRi=x{m*L@4Me.w8@w&K6gk}*=i[&G<&wwU^UtUum5C<$A<V8s{>B6-CkL(OS7.2fZcX@Un>QJ}sjX4xBJ?@iYJ+w4>K4Qw)@jY-<w7FCyLeZAH?Ub7mEU*P3+#E}I^bNc$P->?=&M{KI=PfYbg>ubDI5^R3!#T&.KM4{4!J}2K]Y@/n*v1lh:RET{]f3g*}-Pd@NodRD%&tA}2b:aL]rpR#A@v0&s9J$IwAf-M:%8s$4j<=61xr]]qo8dwB4z@HvHfG]:7x9!J%f-J9RgV/GO<=61VT8=knpNE)RqS^hU{Ziz=5=N}E@%d^A>grU}n:Bq}<W[^RGEM+EGX5e#{@rcb2(/Jh@$1uI>0u6sQ&>VZ<W[^Rl4*Y-aDO-ossjQpB0AlEQikDX^}u[EFY:hcI361<TRhwB>=ozsBZOeNw(TI+5-%V@Pd$9:dO<j}4PG4bpe44HkpTrX
-0IZz:yA!cuvG3L%gV)8o+9gzk1c>mo6bdnbJ%S23Z]dT+iTu/{NdVL8v&=O/jzyuCp=UY=!ELTEUl^7p**p.f2SY6{XJF0qN}0AWxXHIxWR=V3o?>%JEBeGHu1fA}PCTz)1rD9po%OQQc@7^<!fo?#F-teWmLfIHg/bgtzg)@Dp/T/r7aq{Lu@OIj>7hKw1v^J>CvEj8Qt2U*?Y4>$tlG*Z6=pUr^k1K*S>.f2C=.4^5-4=%{)%YnBjVPS-J^kNHuub@2A02x[ErD0b#tPeT+hUOVI!s*i-8sGNdC+}7]EN}A@ZprUpemwF-mV+N:tc)82Uu9WH0TXkqknGJmd)F/:<4/dC+}[&{s.v]Mc7uj%]3)F-q4YJ86+Pu7:fH0PNao/fi33=-!+*SMg$WKzP+rAxTPDHLfUVPG11}<3OZQf&jy3U{Pap>pshjWW^Gs}2zo25K$}GAxTxxDLAURPG133p3s:^2yqOIY9se:4LV--JbBjaYAsb4c%(TkAO!K2=%98W{Bs>&2O=C@v7X@Cx9Bg:cnn=3CXmns]cS4wuH@M}Y<#E+PG6aRv&]d2.n@%Q8+r2D/e3l[sfT9V=?2cIKzRf^ARoE]Dbw+=d9=dbjwUIz1D6J4yElu]]nUiuWR}/#DW@wVfWp4uxaDXtqm#G5-O2)O&4t$d}tywOd(JN8tnaN<=]4ICk@sU*ae>ZYGA.M].!RNP1$F^[P]e#yQLWpHt]Y/k/mTtjiXIf#cMr+#t<QpV[w*a5@DWvlti&Wzp=>U]m>@s8*QjmqR1%*ZKuYr!uRl?u}K-I]wF<CXQK0^$mdUF^>okA0C$?%2fNaT^D7.PkSCCSsdTbEo6QR9h+QWfSUl[mOyEmop+kFX14wFfWi-4[f@%C0M(Ud%3SHK.tDX<AGYbt+56T(g#Eio=YY]G-fLO{BP<&ttAn/gYZ*dtF1c?$f7x]jJ8=]4M0ax>bwcq.nss8}TyNN3SsiAV&5<486vVG]R[8wPjrio*:V
-!dj@ii>SF99{9cgQ=ghdy:(MKJye3z^B#>uk&()=wKr4B/?Y6{}n-}yk8O[=K[6W(@p4ok}h<I3H<Hf4?B}]pqc.HAMu1kr]&dnpaG/H$8V#w!qjhqeWc/nqueu1yeMw6n0*Svuhi).Q}DEHOk%xg.hOoH-?74.$nn?X8[)nW[xE-2#F<eIqq{0v%9LOYeoAQ)EwJF{wy?wvL9F/gIJx.Q>*o@@6)5&RnhWdO&!xyhte]Pj3!^NBWCt8im1s?enH@N$(1c@QnqtQ1<d!?z:J<sL!Tu=dQB0/3OWV+8X8dQtKYzzY^VPgkdd@cfK4PCSC*h4nHLJ.g:/f2&/Kifg0z8Jdclnfe$Y&0pe76XJ=FB-m@P.?f#2t%nBFYZHg7}HMbVwww15#q#@PMPiU<>PP@qAmdWew8r^4/7l]l6?&wq.fmVtOi{E5b2:-Z*dKuErnFZA{/X}FW2S2&LFl!K3njXdMjt?fuJ*2+cP:4K)%1M/cm]U2^o%3qY6gh{N#%]Yvw::sqN}68Rzl&{frV)X@MTZHcT&M+@Fsof4>5ejL%-X{0VS^*dsy#Bg6Q}cZ0/Uk<Y(i]Zz4GOE0$$+juxRKeq.=yOkZm9#w&tvqDt3pso=/nX&A3)p+=?*Tjt]^Cf1qPMlgJm16FhAE#x].?2$J[{kLN[/tT@kCJkH//ZmL)A.-B9$86o+iet=oxyH.p=*aYLVt<U:gU/g=H)UO0]p]]GEKT#Cp#9(D[[KMt*bk5^Fy-a1kPD<z:FC<J:FExN7ABc?3Z?hdG9[fU[}f(zTWe5nATdh6oZvlItz^0zPWpXe4ZF{uFhd%{x<S-&lNNDDKQIMJZaNF[ax=2TVk=HE:ip*ylD[&@L.:G4DP?sz7a?1/b+P@)@C.ip4BZQeeaPIK#cY!bwj-^uAM38xs]GL.Be3T67.H]QmPF&{-*DCnh0fQiB<^U-rM[{eipXli0h5@^Kis<o%Ed.8sc%-pFyuj9OlUl/=vM<@$wUp=He[h
-^oi3JcI^2NjD>eoxcz71:St#6@b.>ajhPlPCnuXybJRN>B9n7.{{i4PP(Ri{n4P^i6^eMnRSypdjJ(AGMh69:VSnlL@jjcm4<saU4pr$[/e71nVkdDibcxx3UC3h{uC7zI/nNW^j^zYLVurK6VJkhDv4]P)y=6L)OED]b!3EfmZU@-WBKe$RfiYNxpq>=PzIrD-<:d%dtL6V!/)#oNfWTi:OK/}$klkptfSgj->[h0xs*]MB)uC]xKwp]>1StSt9!Q0iEJiw))qq4{sTDf]WJfUDlg%:[)T88ECzq7xv&9WJ>YW#fBy@+U[FNokOx2m=Cx}$$C!o@/=TyUH[&^NAR$ybLEP<vZYUx0MzEucpwq}]zZs<m8AQ9%Ua:n19MMTFFi[T:-4+nXAr41yRFaYeH=O3-XscquMTuPm4)Hf#V0BIR]l[-[F?z0uacyrO)K>{}Rks*9slE6U:/g-MbInMJ+>^pSf0SP&[gZ3tt[!QsQy#>JB*LC>/+]}K>YO]9.MoK%K<sRJ)a(mb^?N+Jj?H<Q%JP{h0JG3J}eTeM/?IpGpF=dHH}.z${a(2o[UiQD5+?sF/+o4)KXgT[I3hl*tY[evqO[cY-5JR>h@rV#^Ql$FNKJcTFvez.SKO6@Do#S.Vf7S<d*<3D1*s8QLd$(.Zn}e&P3x-4wXa{:X^+ZdwAlg.[sc+)=SF0G<:4Y*4LQQh%y/vk9ijr+m-1[#Qy(jT>@3P/:8Px]]8QV^863gh[-)He=5rggi5kyIxM0&Hl)3/6kJh8!1<jJqmi5Z=1xb)7Dz*o^/v}D<W+@xl*^h$dM}mN4&Jq*t5?3^!3vKv:+^+Qx:g=CioN<IJ8nXlY?5>#=%w!E*nktyUt&Z?NGh]K*Cm0u^FvGZHLXTE!1UK#kjh*wVpDR8Xjt=Cy3jirz{+K.-DDs3oTUWK-7u/0S0uFY^@>t*zn9:*8XMs&OF)b<z=2^P{cOG1o=a]?dE1F7Pon%{Eg.XPDL0Sqt$yjs0W
-C?8=K*0qR8+B<J1MK}s)Hf8dtl[-enW3E9ywKFfcga#@mmTaP8cvYS7kY0!eBUU4Y^I<BA]qU>*u9f1OS>i[ur-bsN-zaQ)Fgz^:}zD6TrM.yOeO>/lPdyiH{6kv+-^J0ms2S^lmT}f?J6YkJ-I=NY^BIH+20%Z4ejqJbqk-.lLJ9Dsc4je*<&OP(^wLTtO-&W)z^@P!wQno7}/Y1&>=XWvv!%<QlX0u&>-lLFN-A!dI5#S6aO#}fkZ$x=-P=<*Q}si.h^^pf*FH=(V@9X<HS@XKp4ibki{MS/V}&3@P%R{#UPj4IyK{J?CYU{[b!}wN7K{6wjq$U@S0AcnsWwbgK@!Wy^bTs7]Qa)<v<YPk+w{ytivi3Zpu?zrq-n%CzR]ty1W9RgsXK(dw9yge3{z3yX[-3FCpdXdCRW%AD<Gv?n&-+sDfgn#QgV8xha):RC*$vM.?)ri0D6z:<4lF0!f%Z75]tQu(p%*p:9u8[!$ZmP{=wPp39.Vt9B{Crry8SaKS8orPVdo!S4&}6qp:+-H-f5r{TCNo0YsORpl0}rQSij(9OcEq8F+MtIAAhtb2owtTvs%nKW>2]s}*29J?K9:^n(o9pB2+=4Uk?ULW/Qyx&i22ED8qPh:OFBC@jg)lYktFh0#9Pn%uf&?Qeda:/hI?m)0t$A.(svgt!>G(*P9t.JOw%M-bXZIqHir}D2$F<+>Q(of[&bqCZ}@nUexk5MMm*2uj<9m5*U!eCgi5S4h.EWps$EeWwzNb#XxA7]?x=lZqH$SpC58ZscO<)*Lmb*ME0tl.IOB6&ZO?M6jI07e#-1g$!UD^>Oin{vVi+9Amp*((x7#O16Jl8{((Eqsr:kS>ufy+a4[{wq1(Q)po4&@-h<bD8nVMqC=G1dx4o)n0Po{d+*knS(#]ydSXjW+ab+mwP(97Tij50n0BaQy.]E7)p931t)%-wc*CE?lWY$qSYVHi=Pzug(@VMsmn$YzU#r]MJNf</jEQ8Evmtdk0>gsa
-VifClo?G+OFBa}4<1<M{Sua^<&)D?7{<iv+^{Llvg=V<FAtprkutn#s6F}*41X?-D:=0u3H:CiH1dGc+I8r[{1E!WYi.+tQcZme=3ed@)VP0Q:X5TOvs&{gt49?welxm(O?6w+B/mj@HiMa2cbHb<?6dacSeydwC*D8SN)VCKscI:SGnLt<6(ZQr7kCvBWLaLMis^?<Lt)NSbf/4m:9->*uTo32hA5kTH/o<i{JMDJG<3c&16d3tT*DpU^k{U+>Uk7<1-ILN6@U1S(bR4=DS3rbvj!B>=)&wTQYUJ-5NblgI}mN]g>fOkR}=17m>7Rev2FO9!.%0BAJS)n[kWIwT>N1eW7yiIpU0ih{2GP)vqu>OwMVy[X+*@V^q{4NrO&XeqZ8AX{BvI!PF.76IN?tV:>+>f+h?N=wh(SBlX]n95[em4O0Svc7d-5VSELEz:>JV)(5X9I65}JXszzPW%R7A?VO]=7Fgslt8?W^))0=q{f24QllY(u5gxtT4iGXEC.Z:Fg8bNilTI89pxXM>+D[-J<G5FPY}KYW8AU^kovC8&9GSKb9#viP2KGEV*Aay=:8@=4cn6fS)hIUuv}VB.GG+s}GGX8&ou0hwOs5E#X<}hdOjUafh9rA7{B1e:nOC5YFLC@R&<S^HKoA2{^ZSGhaKVZbMniOWb2uhZ)T@Sf(vpNHD!K8EC.8WT#:/XnW:>rvnF8/m7^*xp+C?G/:w+LKTjV?9jb&fH178LrJMQ7aCWR<*8nl)H%SNcgNEYR!-Wpm)[fiX8>%l&asF@jIWgz]W$m.XBC6)NR.Xn8=Q6a[k}a&*FX!-0qW[LCF]a*-#GZ5>H&v]FBR]KBg9C^]Q%8:s>G&>a3%8b!>A6-4ojvN2NP(ElB6<FigHFcM>j06emZM0W:pF7JiDEFA-TsUNQbnDNcs+nsVl3AH)*$=t%6Pk(sstxt=+ggH=(8)vB#}./C)5lBE[odKOQJ:r?de&yFfg3mASqTVRSZ}]j-cA@)9&
-0Nmwl4]AfzITMOAjg&ExCrau!bK$idK}eh:6UML:nBeRNy>mBP={+c0gsss@zbTRkpqw$9)CImJOX[L#7&>9*1Cg!+([zDL.he?A2s$wQFhG(&*OKbw}YnGQH$-@fCr23OEiC9X.ccj!0HUor(2Dy95=9/bXRS10.R%s5]Tty]cDF8z@Cr&du>HL<6$wYP{U5%NWe{gj0=B{jkA(&dYI86=x3N*G)oU!h@V8:eu*+BNO5+GZ+%cWVfjyo8pLTsJI6?-nIpp]t}f/+O[awU*7rh%yP.&@8/L:7NsTFA6ZS3bP6z[)MR/he^Ey>s.{GL[KPv?E+.rD3lI@T/Jg2hjSElNhyY+C%EIj-ANGF5qZHSjqFO0U*u@W)dqYw<VkwP[*pwYi<3L%c8P36(gy3C])PZN8HLvyT]lTtFty@VNuK?79NacIBG69rjLHmxX%k^pzce(<DLTIm)axCLeCp{$5}(aK6JpEDB%[XXQW$Eog]/IpionE4bYM:j>0VZLY)TRg=Ry%atop:XP3]o}jf^xl:A]ei0L-Pu!]0Pi0U+>C!mNjD82sV^R7K>XMAOS>d-AJJkh@Uaw6(IS*$=M)gIzx?W=yAP?O#o#AvDGyT3#*t#wxzphEc<nFOwFN</Z*Jh0IyoRp@rc&<=*M2NKHewP-TeL=hqsA{W^#{Z&ZJh<h9-?U^Il2FgYSy+(@4P65Bh<jTMmHY}cFPv)1Z2%=q?fwW2a9Dey-uR]Djx*&4-=S2[>NicWXRxq^=<@V@v^0*u*D*W^cD!HYBJQT&LF3Ms6NcrJT*vBi&D>Ri0X(yB9xi&DgKS!QPQ!]AbU3*5{A{%NeI[EpN@#Rw^v24ben%/F-DiSJbz@lYnGJch>s{u63-[$9G[.GZjeWq:3+Zzb%iW0!Z*j$8)hUzN>A+6op&+:xJZm0>P2/:Zl@>TD:<Np^OloSe3$c>60e]zEgAWFXWXTcfWY3zj597pcgQbs6kZzPJc!@xnxdc0RA%vwiw]+1
-]$$>{kWi{+Hs<7Gpg(IP)0s>^BgX0^g-b$Ou!K8U4kd9jA2mYPEu]<w=xQqbPlJ.QXp2ge?j55K+}ATN9{bV{I^5ILwxIulhwHwz9cB1EBT3=$1>{Zs]K(1@=zkXn5:c(}]P@C@T+9Xf7/B:-BiaP8.X.n}XYtFmH9QF?O.Jwl6M$0+}TYoy6ZrkQt7yYgPK5[6XP0zYRJUa>[M<><nEa4wrF!B/>U1{W}c*KN:<<ROIxly5hJ1M(Y.{Z{)<1ufwOf7DIVaQQdS7fA*8*2Lj7RBzoZaPrHaufL><1Wxq9v(WS<p!WIaV=bIr7?fC)rIkeV}:xd66P5vSSnNoVOcrSI23LX/o?rryTF0.C}d+IeVEFpEqMf.y3C1ov>[L+WiK2xO%1>B#mftEMFcFR^=*^CENc&9>7}mT*)ED*^H2<?0FsSJG)le9{t7WXt*vFrZ]27M0rIt1%U^0/5rhddF^>/G(Xz)R/i!HCRk!s[6VfITf&$&G>an<LHhK>zLan@VLgGbO99z7fV*x2bttD.(*?WfB1Gs$UbcHf]cN.wt*12Co5wKqMuP7pOo]C@*rkg.Qp<?bzNt:Kj7/ZhC17<UK:w2fpwfS^c.o8G6Mbo..r>)fc+8s!UfJ6pvF8C7hMSIlai][Qvl7/rMpUz+j^o!==ja3oVn[m$Q>@b>DQuP(@J(Nod@yLtCSCtfPJ^glU0z%WQ=+/QjG:jT>wJ>h*QZCk!l>e/>}pcI8PVL?Y%$3Hz)ee!Prya-FbcS.y^n@wP)yY>f)VgPun/EK-j]Aw+E2YZW8G4=md&0gu2%ERu2$nv]}TvN1)D]n}frTWcs*Y0ULKM[Mc88)Y^+1P3j2I{A1Ehq4-lG#2JsN]l70kmAPcqR.)4417#?#9-w[v6TWMm-9e2/kGtm#mj!xNb=vIh7hQQlzbKNMv90S(F*U6?A&v@Fm^B}qV6fUjB9r{#9*S@oGCytl$amD!AO.K:C.*e]XH&BrPC0uNG0xyOi:yefX
-?iCA-bd@K7{hY!-(9IHO4}G(JWU!RvXP0t5/TZ^=uQ]aYmiQxbcDT&jz{j>fUq:<ffM[2U8[f9K]x3LS[Ut2f*1$*AWXzOpp*E#:cUa-$KzQ@Jr9N9m:hvCdGu%HlOraAAOrg%@AAM^bV=lJHrYsNQM[ikC[)SP2s7iehrW/5Ibt9x.B2.E+P*49TI:Mz!<t>vcY+RWFW<h3nD:NyZ6F=rIjh?+WRX)qtoN9byxtSM?MhLp=Ux/Bedd=ocJD{SGef)BNoR!rxK:X7!FBb<&6cIYH+s0.Itn3hjW>47odVYm@i02Nz[}N!in:BUAEsyb7/#c9]+jRoPt*AY[GHbrt{%jV.RN^?4FU76VL2}zPo.7^uMRE9PPQevqLHPt}xZ:z#cIX}Wer+EADm<1x:(ZB+Nck*6bKIm1BvaW[XbS:.cHIm$XCW8)YEhFloXz%nW:ggzzI&}v+:>Lo1}iX2ry2SL+Tw6MB9&X[a)}%Wd?valNDRGl?dTB7[VW(Y6UgEC:]!n4aN>/mt1cxGm1coBs=QO1=:XzRG>PwNj>U>R3o=*}A@.8EZnBC{OA>gfUe9ol7qCM+Uy&@s//{4R)O=sLvy%Q<EpzG*zBAhK5ZI?sCHy^[}RVa-pj:5PM!p=(r{)-xR{0<y+V$5kk(uQvPf632mk6(4m?CzbQ{Q({HBB?Hiw+[&9&lkifp$yO9c06#C6B.b-%^wS7wAymYdNQuLo@sN@@&h2J7JEexd+<H5rD.QzPW[3Pecn@gR[Hd]0m1rPgCCzPgAhC<(GT7sg$o0IQdNPl4lb#xGV:RrqHB+w]+3(B2QIc}&]/k>P-()>.e{yc)LwhCxJXVIb9paxvOR7?mJq^1JCtP%g3W?j1l-lnB6cG]9zh01.*bSBx/{vIGDq3=lXDAA7nWD)WLIox:=SEK*G]lH=h>PHL/-2?}]WjOqMRgk6Ey%ro$w.YXj$4+UWrp*7<qT@s6kK!+/r9Y+OeuZsRjCqn7(M>b]6q&v}tb
-@@Hh=8!AHi00000
+tMdjH-Fu4VtF-G}jUGb0Dbfh8TCEe]>ypxam87N>MlCVKR1C>Eodsg#7JjOzT:]jmdNKkEQmWsOtzUyzFi5?(VKhb!7Xw]rE!ji4rwh<!]RZl^nTZn#16L0]rI.1&tsj=[FP(Pq?u{ig3T{T!Eq}XxrWyQ)(RJus^pZ1c9Z*]QW&R%YUV<PYf9B-B^SY<i0mDb)BT?8@YdX+vEJvL7[clnJQUG]h2J{s8rL}vc6T9x)cu{@Ac>^AWBBHVIIXxvZBcMf0)hf-z9Mcdfw4o6)3]=^#&Pw&G**2S.St0gS:9uxAM*B2V0YO:dh%oh?Iuz5sx[y:tUR2+Mx#rOo[b}@-jD>f>1O]51kJ0x%B:=Qim1iUVqT!Wi!x}5OkJ2Dg1h$f]@cX)*Gjf9k(pux&D39rb=e<OEfh6daGhV:Xb{20LlFL?H*&EKiyhRrCM78?QO5P3$>kqUd+q@I6>AkxVKN<^WFtzIIKArSf(40q}n67vVQ:}OeL1iQlqAz>r5BsyI(J3UK:?E-$5orzzm-W{i13<V)DOC!J00SG{Z>md{/Qey4H^>?gK#snHF{-S#Ff/ETF&>1X[&WGM9qOeQknh!BaK*g=K0bOu4KHW*<**0d@7ueSbyyl=4O3Arz?F6jgqoGwwzEg&bi5.7.EO493nPsB1Sm<A:APz3AF9.wJ0x}j4<iTw824CU84VkTCWH?*jM)u#iphIYga(+1zRIAk8).d29G%6.{=bg6KitzX4pYaxk[1?]H#Ev#f)}+0Hl0oKiT]P]](#KUpAYJF4UoVWrQ+02kk8J.U*ZCm7w].2N4w]48!6ktjy9}eh3ub=K9DXSpE4bU*Ho3(6hQ[{D&c1IbU%BR[ol}gNxPyQ@Ik}D[L]-mMOgLFnwSumdFL)U<kUmv<3@d4K8)}K<daM[ih.Z.OzyTl&f}TX{)E!bKiuqQ{]by7KdM[i7oz!$DwB1luMJVGPdZZj-PLAio-fqlA*5Q)9{Sp=<!?*2}atyOTdQz-
+A5#$*l46g%^r@Fb-)&@<Z<9@^G^*PdOM<2hN^-u$b)]X#E5SqH/T*^u[jq5)3Y+T#-/%/jnET/K&N5pLf:=8?Awe%]?=Or1CW7q#(j+3QP9T[S&{Xoi?7W^7D8JInO@0qp}2%NRbJx}>Vm!]$Bnr%qY1bSnuw[ZdRf:lp/Y:+3?kGnGLC]PGE7++2TxlpM.7knttfP#K{:cAx4!12vpB54a@M9U/!-YDXtz$F03^mz6GqRk#(f8[nYAXb03K-OcReNQOMj6)>DHqpxTL@#a*&2bHj9ur2:O0f#(QLga{d&vqzq+6v-fSMg[sQecm&/ef4+dhgZDk0WowPya@8aBjm7a4od.*6M}jOsCE^58Gu?$QQ/E}T)Ci>LV0xH4NpM=lhchB2{HTaPuo5Vc8Hr/P?pA/:lKZEYp[*F6}cCk}7u+cF(jhpzF-c]{04@E[d@n3BvZcIW]>ZKgJfcY?xnB3CjE)JR#DHUqGUyiUfm}HrX/?pxJZkL9#*c>l)UAib/guphG@XzFDM3-Ef>:]?t[ghO.n+v6Q)}X2CPNsHH}4&]F&Q}0E[Z<2ZN[bg>g0TFf@04gO]a(diLG!1?Cq:xSw^)w<4SW]ZCmXWz<)#^2R%91CUIQN%ZFzkA/5=!I/t$)PR1-mUKLR^!JF6JDerqH+2+bdN}5%g7@.IIC5IMBo}:(9-mW^LidF--QA*J$9aIebaa*K)v{8z9vfouL?)1aJbdaY*(}.c4<0.?N*=MEBW)l/$cpPe6d%gXP-WA4D(<X/yZh@@:&Zm-4G@OFp.PR/EC{R^{O%ddQr3FZ(bg7D[ddcsPw{CI34Kn<{7]kPrKa]lWl<%M5-4F1Gs&EqB3@#(*gjp0@S+3YIw./NWJ.4.qeutiB{<x12!.LhasM8{>eM[?}ViB=9{dRD#9GEM+j&z*)n!QZ<r&Ca3>1KZ@b8DL%UD8aC2w7z]0^h%9fWsz9ZM]bEtIzfKk]CNmlT8=jYzb[(GBQg}r:aNJ1vaku(
+9FxE%+Z8*Lf]@Z(xuYzq:@dJHvt.=+CR]Q[lTXFnXb*1VKmTsY1GMN.y?cL>N2IzJ]I9i6dkmg(VOxr>2^Uz%xuYHs<=1FVrxjM$awyg(46JZExA=P}2!886LAm$rO[}ALN5s5KgIgBt6-C2&BS8@e17(t4pBjjyns}RjW<(4mgPX.pVN]QyuIpFf2s:5FWNEXDvrK{jJcRp2iB*jy-*E(Wc=cOh(<S7VJ4R{jGM7Ov)27AMVZT@Pv%kshxuRAIdF*x/(UG$04A]>0PgbdgaBRl(/q+t}DS(W<w?R1nEEDW<1b]BV)1Gy%.T9iM8s]$>A(Hl0S*>*v5Vj+B2^sly>NU)^2WY&:1ctYRut9u:M>@[<K1!e+cvycArRZmJPwvIKFSHzVEb0r+G8AVR2xlo}/zhseWWM)[U<Dw7+}bh-o<.#AnHK4SvIImLLC3%E)NsNJte/.hJ6q{+q(.[RFyfeiu1K*wBq{}lkgCj32v2gi1=P+]v#acPuCFK&&TJqMbF1uh[X9tby+JnP^/QB)2&=n)i6z/tH2MxL6?rxiQ2QHle.KDw6@IZ0*7M@!rH=?1xIXf3WU=rvB<PUX!%ZAgs&C7.Jme5xXk/6cZ=mtkwMV[-t{jqLmZ<I{oSHdPFZGueVAi5)21yb2MU^EcBzEKV)Rbw6/i31a0Gr[ef#tzn3T+//CSw/Am7i{)>g6lrjjkPNv{?bsJ8rR}8uV-T-MLVDbF4V:v{+]t/]AH#qY=<031F]]OCH41oyZ29-sZ]a>?3cunW^VX&)W##eY5uy6@IJ}yeIRIr=HKVa3fb.S/?j<rH/I[S7[fyT(v?kmS<PPzXU=:s3<}>T0MIoT(rp-mJbYuxbdr]fW.xab=-]0N*{Fo&S<exkg}AC1&-FF.NOCLJS)AY6Z(mbihkZv9Nu*YaLN<q&Vb:UZmN8%VwJY6wbpUiCE7qt1dfF:R.JWU!WP6k(edxe/I[[!j5Y@LKgSxh@5Qqf?!HXN4B1](VNROv
+BhhG<>v*PhCdgXvuR+:U>#M#3G5{J0TJFJs+)T0Ad!&aWaemL1BeJ3IfeeE+2Zc1%y#lGpYBjL}X:&CNy%?bXuIyax?9Z<QUz}Ycf3<n{M5??7M<0[&2J6EIB@3uWWPf<**E#2io{*0hfY#S8Ke8KxjGAG!T^I8ER$<xXb(MyAbIgzRrhQb9*dM*)?W-OZUFZ5ys{4]vBZq@E}2NL4K^9QHr]>@-e$HP2BfO-Sh?p]X}9E-QU9[)I^1txX)0*Q-WInXC[g3Xe6XB?E<W+D:GJqiujdzIt&wErer]}S?a>VpM=$YR4M^mJ&<meO+tfr=b3=Kg.tU1g@?Ff=Ho>TTSB8a8YT&+(.xCki(PkM5I^qLd]adhS+!}yFmo{sgZWP/3eEc[c/<29K3r)e^7ljWp=]s:TH!Pg5ID&E.V<QY&s[U/D#(OC2)/dw):6m77OU00&ndR2aD39eamcR)SCI2MO@Wf#l/.Hp@1.AT%<Pr!q4Gv+^F2iF&{{L-9j>r/.ij(+Tl59lgJ=Q/4<EiejpGyA}suW8RogV*u(:@f!tT8=knophD6dzkuPv&)A0bk-6ZZkRX4]a!rD-@8VkiB/=}N4<QXC!Bru/mrVD.Omqs/o5mbZf$3!N7{6VxuSpvq$N*hUEx5L7bjSl{=0)4tCPPjz^eF.>i]y]8s[(=6-CV5]=t*z1lIVJZE%%enbwhuZeWg6@>UR8f*u^O^F)7-yHzI}+NUo:bvQMT>}?D(r]]qoxrc6)@fPfb%4dD-3xd=i}7Y:U5AWtYk4BPxS*>*z+C}6!ko&>H%1g(E2GC@x@0.M05:1M/EA8i4S*>*zzDj#H}W3i[u=<ue7*^q+kF*+u>kMML-@aL<jzywsSYU0^M[Q#eKmq#I7#Esg6YBJM>kMPM-YN{mP0RljS61^+iexmS9%w(t1):8cD#sQasf$Zxx.)V<iz+1Hi=Q?-Q&*d-6N^yUST+0MZ]SAjhnRDS>}=}hk&>P6-du6{!}<Yi]HBQp
+eNW6fw>^@tbfRbLEA1xgp<HFIAIh(#BZ&i4@w0nh7z}pCsQi[}2^UP2xuT&/L{-WE2p!)]Q56t.@wynMKO-/W4i:)(a^TOLB-SJ415nUf/WZIaXYCeeRz2JiQ!y762OYTFLC3%EU1u6Y1i4kwJ9p[ZbV70(Z]fY(NG!S]X@C6Raf4@w+(U>-35[e5^l7l}N.U<%[LfGbm%2Yy<9OXbzhQV0SSBA8fJ[WrqXZc-6T7JfihfQ/9PcC[xZQgAPG0Y7?{v[/<uNYofUTl9RV.(4X](x&sHK&rtl*4b0cv222&<A1AsMy4}Xv7ieL}@tsqgLN8(2Rx*CTSx(<7L[GMhshiqXo%iMj68p[mGKN%%^B==oOJhC/{&FBL6%V<NPhgHLP5[.ak9]blbjkj{]d1g4q9d9/(G{(EhP3(4(mv0>JniTgGkg1I1w]N8vp:Hk3ui3edXj&lz5d9-7kC-Q<b2]8L8To(P6}9n>Yzp.3)?t$S2GWzwFOoGIN7rbOXd9:[R6md<[)oR9[9!As-35)Ew<e+H=?up&l6Z$xNAO!T5=$/l0X%ElV2]8M5vc/9Diu9kVBot8Z[P>y%]d5sA{iByE9<UeFd9=dbjwM*c1i5}3JoqS@PfNz&vOM6iz@g1f*4C<?xaDXt5YP9E&{lY=e@B>4PG4oL7U&O%f#47*AM7z}CwxIY1Ib@ffnO+g@r5tRN:uNIzfO!s.A^j}cuJaTB2qo8YnD=R]p14wk<9&M<Z-#BM5AtB7YDx<XJ.+5oLUVn1zPrb{:Cpnv.iz:G!#3]3g=bT<X7DpmGp<aX^S9DwMgsnGj]-4[&Of]iJ&.#1Qrq9faVMQd3:gSk&]d+MJD=RGN4bq>2D#PC$/XIp}rjHR9O6Rr%JE/(0F#%AY{L3i*x/+vLvnFEMa2%M+)[-TQ!}BY4r7@Fhdj(YzPf-o9(*[p=?lw[c5=SfIP@a2F9x?6?4v2/90NE@yS83uLzTLj$1K8y-HL^luUJ/qkji29y{5J
+M?(o%m#bXk!t8}9JR7i{Iy)}jVt&C7i:fckae)$%Wm*<9cJ03=boIhZ+12q)cq)2}Pc4y>a%lx/9DwnI*)Y+Xb-jbG:X)2{Y}jJW2e>U3CW)zLjsX/OF.5SWS({>PYm]J<n$ZjsR(0uQm1pbNiMPmy!p$BQY<27D3*yv[rnJMb2U^+ytHm1*CQ&I(X5l/jN}YW4YIbloH%p[FX=.G>PZ?i<k?(04Lhc>K@h7PuHNG9V*#Sxl^WAF()V6)8O<qx@1<%&az>MU+cqarLk&)E>^[%eJNh8V>v9Re=-c8=fcU5a4ES{pmDP5[(D$xCu/[4qzp[b.6Dj{hi[s2d8>21e3QS]WguKjTMQ=jhc=+.}:7F[AV2Z0&B{-LEac@xZ/8BCsop8lYls+unY2ED96Sgr+wqyWE=MXwGw:%1Jqfx?%(EtV1ZHBSB>{N%^=61xDzSe[7*rl/]ldkm6YeNiJH{0FMwn5Y6P[*eM/o)EIzh8li%@-Vbb8SrN6E*MpW>rtavxsBt(8s4vit)6#i!sM0NsjZp>?W(:wR8X={=lX%tI09KpP)<N4p=<HYx%dga>{iN<9h{M?M&ob*WI98spKg^#)6gV&yZ<wi]5F.7b-DO>*dtF1hWHtiUPlC^yDIxrfpB#>2VJBZO2AKVbU&r7D]K%#sy8HGrka1W<yZr{i>E(:WFZ#[{Hw=%uhm-%Be:LF2wLTFKG}Hi<?fg}^7aR%m4<B4Pd[mU@o*AAJ86T)N#oq&k3zs*D7YIF}dg%(=*.zn9ckhOWThDSbbLqFf.O#y.TgST7u)@tDQue9dP5C1:Xt{:DU5K.?yDkg]iObEx[B8)i}1{*g=$&4{}UG*5X3=nsns(C]@gID[BWO@[J@GuQ./=n173sFBIM8wO&jYCZoQBO>U6u=:=dqpL6TWUbP(Pu[cxWF=pHYy5@p&Zox81aGD!8?p3mRKeexr&C^cxysk<!z!juh>1uajm<.7WDxfF5(HJ^0gC87p%-@w?NR8(mB
+:3+Y9>9>wGjm=ep&HLXbf^I8CCjJ%tz0?&[akQdp&T.qcFZh}ixYoM>eqqxiarQ2{bVPvSn%$$:j[HpXCuiZw1jywbXBO}}L%o@%D]MIYa.Pc+QrlVTu5M6<bFn=<6)/A38c]6mYy/cTlDE:90)R21nR8.8]0lUhHJ+Uz>UPOL6AskZ=cORmM+mc=eisCJ+EXIWK^brKLYa%)wmL@oXwH.7drV]GQ74o/JLIt[)XJz@Cv/P+BHCaeksmF2=j<!K.fuxepwY4L@J%5cfjZHm0nhLRwE3=aLEQ7(khmqpU8=</aemUfoWYQ?M=A.)O]t:yH1$^f7VK?V[&?&lIoC=U<EB^eKO-+VQvOo+CdSqS}iamoei4AvZ@v@S)1Kj?O)E11M*e96<t*f!fh[A$ul4K6b0S&r]J<$jQP<!x(a6e(jpcy{^I6rgk6>am8>72TZEAJVUMb<hY:lX{)P}Y%cmfkqw$*7X@098[i)[MV:&^[M7Qo88tYg>^*CB=Z9R7@!EoDA@jcm74YOgQBRfIEG-cS2n{D>+mlPc?HT*.ahV3NO3ZxKt=ddtu-Et8Uuk!9#=XKgecc[*76*#k&KumaCrvFpc1aBfcb+spZJiqa.Uvt!QmYfWBxkz5pC[i+=@>Tuko-<<kCS=m1=yhcK*iiA*oyBPkzZ:IREzRTxvD}nPS8P0^JaZXyq-haaC7->RSnM8BMY0a]Z?Srz7?4A}t(AR<J(HHMfhe9:XhebDSLs^8dYEuuXNNe&>Nl&.@d]?gHq)Hs/2VD:}?JRJ5h%5Y!pO8O$N.vVTCAnezLS4S+dmO!Xg356MoFMz7WSLJcFR*vRWN>WYd:=]j}!?<M)J?htM}xh^OTSkdmG26V]ns?<%j[lMRXmVqq&89<APH@$o$/b8<>!}a!:tN{(V5+wTm4q)}yZ9@P6r/o{RIWvyjpVYg<TV2Ev:U>kE(q):x+wDOWj!MB%C7Ce{7#!O})l&QAK.y[*F6M02F>{(iD*/Bf29S
+kJk-rHIGcimdk:vNQ5lpYbkC:qwl:knZZ^KQw2ngC*0:A1SlaLDZT/hk(]xX3E)gB!jBM!{0fWCl3oN+-&XUsdQ2nPX{37<0%L&X+<0ZVz=OsoMBi}GZ<SHd6XBImWhwU*-A}vKk]PYYpb98wvhCX5}cviuP=jodQ{yS%eju5i.=hVN&GS$uPoKbn]J!7#yJ(H6)CiS^*M?fZIs661S]gT^f]L>hsQ+r/I@u)bX[-w8xd536/mJu[90leF@1[Lh[00k9&@ay4jz-57JNgd.9V)pg9x7+9(ftks<iZ5U-d#+/c=g3LXul1TO-xn0nr-%7.^9bgIstihw$*vcW}b.cMp(XJo#h)=q$ap]:b=UbK1FKrkJXiCK2:6dv[){qf$R>CL[<j0g?FEbWV6g=Mner&IUKtO4bWSp[-d-4Fas=L<0<8Salx+2Hku$-{$Np+LI{H>d#U<ci%Ji%Z(o.T?F]r$:4rGQ3:8MewGG(K<5?uY:k=Q%GEsL[19GY/c%3NhMpC*jPmO1fQ#ol3&ZN7l=ME9/Rqcc4B{V@>t@<]RMYSlUj9B.8mSbq84MK3z7(*.jii<EA97I4R=tcdA/e!VTpdnUMpcBQ6pDFYty/uuO^=T4FPy!a:FzyGFL&}NEyM6&%j<*!lLQYF23vx*S^K7)ehxRsl8P>j<<h(Xfc:gCBC)Eh$zc&}TdKPY1Ncm(Xsc+IxYE<v?pd&u/C{W!TNmyJnRw%L^R4Qr]b^C>+TF8/7B>hM[+c]P0.iJlfzdp2Xo:>aiIq=RaItH5e&L49gU53=8z4zgF*F@i75)4*js6xNtW9-ABd]jjrbg$GH}/D/s8l!r&dq:8fy&qN2W)^<h{QG+#K1kd=?&!U^8mC:BMhj.3Mf1nYz+v<hh(CPR:SsIUcA.e^)ZU9J1G]@$OHX^7:D+B0jfg4Vr#$iGXuYxGsgj4C+62HA/}bZSEgk<)[vn3.VoN{(XuYPFr#b!LTI){ns2Jzxo.0wkj&OkMOEjfC
+&k(FkCrvtw^e*X+PPdn=NwUu[D5G.bX8D^82V39u6.7gF1JKKiwo)pU*FWL4V>LR.-u.U4p9Dj}spPPDm<W.p?X7gtz%ow4NIpeUgOb%cYPUJ>zWYoRK73eoVF#JlOStsQ9zo[NnK&-.0z*vGH<bVnN^4ZpoC/V<P0b32B.]EXv#<LZ[@{[Mr.k:hiFCXbo4?(ykC-Fcg(</i5mgYjy]y]dU8HOb1a0S0w%ySAf)SjaLLql1F5nh<opfq1C}vZ5sK[H2]pD.Oo(y%Gces??rkI11m>S1Ekylk-j7byuFZBMOY}GJrD+*SBQ3Ao-Su8]33..FGE1#<1^jYqQ3X[b3YOGUo6j9%TfPE?(E>UarU=mn>45Ac>>dh}dTXFskM5STyCsBSvha4ubJ5XoGUm6?TbMrbrpE=vw21do=qA(:Q&tPU)gy#+M}SVvbF{eypvMJR%!.p<GA0ZCe2a6U>w$<]JtFPeW>%1DBQa-vSac}fP*tVv@:C$m!<8RlDJn6%wW(r2>56i0!JkOZewTFl[XJY6k=.{K.de<oSVhOcsxe=7w96p^Ix]uxpoUu-frRe@1-(#9[1>s[CDv0ao)BJp*CtxVONDaD?+l:{zoV)U^9em$]6]taWiNN&bSbyySoQy4(&=2$fA8}P>}cY=Kjy>h7alJYre-BiWXuzKo}DBj61bajRfXtn8??Z[-5#E+2W$i?<Ew%+o*eMk73aLC%]<zQ5{Lfq([5LEReK8lMk09at*HZK-h&eSm7d/)]jzSFqkbCMd}OX]Ks:xlL^-pFi>CtUojbgV}T%0mPn8=P)65M?0Ic*J3=?%7OkeoNUe%U}/9&Xs!k<Bx9R:iH%<G/G4ZuUOZEJX[O>lf0M}tzcMw{Ed!y#1WJgURf#?1.%I0HUybFn}d]fU{dYDctEQo.U6czhpBhbK]Rs&{l/b]pgtJO!D5F&+7OxbGp4S5-T1g{ND:OO!FswVHbe1irp{4hAtBUn4J+ui05DN<-8N5h?]nw
+6r)M7xCONpFA2-*E0mTd/!1L.+Gn52Jcy[XE2[Si>OlYTvH[5#3RSyhe{=SwIkEB[Ti#3e0{k[:8$ac$9l6FBKO!5Y5vrV>HP#ZxpmM?@k&RbkTu(Zre>8#3{IZE9?0P<d./m6[3/Ii]=u^!y:tzpxKe{3P=[uM^o[Z4?4^$geZLav2laiiDz.0*GI+}c&.1D-#/z2bW58YwUA62VIP}3U}wPv&cBfnkQ{I7ch[2kgzA(XdkjNR9A@}NjL3cL.:i]?IVg>x1eOpick[58?+UGb[lf.Tob?m@N1EHCeYgryjbe-}?V/L*BxEvFpw*F6Jn?!G[7-c#>ppm?R]9qxh!&En&i]ncohRt7A(@ks4cBAc82g>$[--b):9zYa[]9K>I[YpkfubtJD3{q}?XU-7np5xn+=Z05[P.[vv8RhlL)4%:Yk?KClrV0V[S{&1IdDkifhcI}W&uUCr))a>NITh1tCZyCq!vxG>7gy(N>4jmLgYzynQOw2M@*ydsK?uD{RJthwYchY7?ab59oER.e-&>#c-.$bl/X:=3ZD}6L0YMb.<pSqkOvt[S^O:M2pxoSl}!S1iVt<W8Z]^?1Wl{DWJ6BAU(v[EL@l:%%J8PF0.L:w-6f1mM(LEvIh[1oQ>bZX[gP2h]A0P)#u!oVBo*M2NKHh^>aTeL/iqsT6Y^#{Z&ZJh<h9-?U^Il2FgYSy+(@4P65Bh<jTMmA[XcFPv)2uVf!Baw=57miduy*gdVDjx*&J(Yop[>NisWXRxq^=<@V%1Ai>u*D*W^cD!HYBJQT&LOass6NcrJT*vBi&D>Si0YCO]lh^uDhyh)QRr}9AbU3*5{A{%NeI[&pN%2SB{DZmben$gG5=rTJbz#mYnGJch>L6w63-[$9Hj}JZjf9C:3-CVb%iW0/vDC08)hUzN>A+7op&+:xKcKA>P2/:*l(<FD:<Np^OloSj*Z6O60e]zEgAWFXWXTcfWYPPj597tcgIs:6kZCQJc!@xnxfy:RA%wb
+iw]+2]$#e2kWi{+Hs<7Wpg(IP)0s>^]sHNtg-b$Ou!KeW4l<kz[e7o9F0Nh==x*DUPlK1Y{<A%]?<XnM)jS3@aM+(%I^5LMwxIunhwHwz9cB1EBT3=$1>{Zs]K(1$=zkXn5:c(}]P@C@T+9Xf8D6%+D=VDX.X.n}XYtFmH:lX<O.JCn6M$0+}TYoy6ZrkQt7yYgPK5[6X:UEiWV:*5[QocknYPRSrF!B/>Ub0X}c*KN:<<ROIxly5hJ1M(Y.{Z{)<1ugwOf8OIVaQQdS7fA*8*2Lj7RBzoZaPrHaufL><>lFqAW#XS<p!WIaV=bIr7?fC)rIkeV}:xd9F<BvSSnNoVOcrSI23LYC{4tryTF0.C}d+IeVEFpEqMf.y3C1ov>[L+WiN3xO[FwB#mg8EMFcFR^=*^CENi>9}]E1T*)ED*^H2(?0FsSJG)lef6B=>Xt*vFrZ]27M0rIt1%U^0/5rhddF^>/G(Xz)R/i!HCRk!s}{GCU+E5wjG>an<LHiWlzL%&4VLgGbO99z7fV?IybttD:(*?WfB1Gs$UbcHv]cN.wt*12Co5wKqMuP7pOo]C@*rkg.Qp<?bzNt:Kj7/ZhC17<UK:w2fpwfS^c.o8G6Mbo..Th0gn4p.fUfJ74vF8C7kcgvtai]]vvl7/rMpUz=j^pv%=ja3oVn[m$Q>$yRDQuP(@J(Nod@yLtCSCtfPJ^glU0z%WQ=+/QjG:jT>Kg]Y})]?Q!l>e/>}pcI8PVL?Y%$3H[2$-qPSZj:E*JX]y^n@wP)yZRf)VgPuok:O-j]YE+E2YZW8Yg!md&0}u2%Fwu2$nz]}TvN1)D]n}frTWcs*Y0=?<3pM=.q]Y^+1P3j2I{A1Ehq4-lG#2JsN]l70kmAPcqR.)4417#>l<-w[v6TWMm-9e2/kGtm#mj!xNb=vIh7hQQlzbKNMv90S(H*U6?A&v@Fm^B}qV6fUmC9r{#b*S@plCytl$Pyov{P4<<D.*e#ZH&BrPC0uNG0xyOi
+:yefX?iCA-bd@K7{hY!-(9IHO4}G(JWU!RvXP2P//TZ^=uRbm.miQxbcDT&jz{j>fUq:<nfM[2U8[ffM]x88c[W4dv*27)BWXzOpp*E#:cUa-$KzQ@Jrc#vS>FM&oGu%HlOraAAOrg%@AAM^bV=lJHrYsNQM[ikC[)SP2s7iehrW/5Ibt9x.B2.E+P*49TI:Mz!<t>vcY+RWFW<h3nD+T9?6F=rIjh?+WRX)qtoN9byxtSM?MhLp=Ux/Nidd=ocLx&nMef}$boR!rxK:X7!FBb<&6cIYH+s30ntn3hjCp-tEe02v%i02Nz]7JZJs)KuQEsyb7/#c9]+jRoPt*AY[GHbrt{%jV.RN^?4FU76VL2}zPo.8uKMRE9$PQevqV^!-rx.fY3cIYj=er+EEDm<1x:(ZB+Ncl9ebKIm1BvK%@XbT:5cHIm$XCXknYEzRnt?IUDW:gjAzI&}D+:>Op1}iX3ry2SL+Tw6OBdm%oa)}%Wd?valNDRGl?dTB7[VW)D6UgEC:]!n4aN>/mt1cxGm1coBs=QO1=:X$/G>PwNp1+M/3o=*}A@.8E-?#q2OA>gfUe9ol7qCM+Uy&@s*DMmT)VP<qvy%Q<JBIh1zBAhK5ZI?sCH?6@}RVa-pj:5PM!p/)r{)-xc^pwz+V$5kk(uQvPf632mk6(4m?CzbQ{Q({HBB?HsU$qj9&lkifp$EQ9c06#C6B.b-%^w-7wAymYE)ZvLo@sP@@&h2J7JB[xF7%I5rD.QzPW[3PecosgR[Hd]0m1rPgUOBPgStE<(GT7sg$o0IQdQQl4lb#xGV:RrqI0@w]+3(B2QIc}>R}A>P-()>-2GGc)LwiCxJXVIo+tR>HzgO?mJq^1JCtP%g3W?j1l-lnB6cG]9zh01.*bSBx/{vIGDq3=lXDAA7nU@[0>Rpx:=SEK*G]lH=h>PHL/-2?}]WjOqMRgk6Ey%ro$w.YXj$4=}@Z7*zfzU@s6kK!+/r9Y+OeuZsRjCqn7(M>b]6q
+&v}tb@@DrTRB+ZV00000
 ");
\ No newline at end of file
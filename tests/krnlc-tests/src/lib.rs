@@ -65,6 +65,16 @@ mod kernels {
     fn test_with_groups_item(y: SliceMut<u32>) -> Result<()> {
         with_groups_item::builder()?.build(y.device())?.dispatch(y)
     }
+
+    fn test_set_groups(device: Device) -> Result<()> {
+        let mut kernel = with_groups::builder()?.build(device)?.with_groups(1);
+        for groups in 1..4 {
+            kernel.set_groups(groups);
+            kernel.dispatch()?;
+        }
+        kernel.set_global_threads(1000);
+        kernel.dispatch()
+    }
 }
 ```
 ```compile_fail
@@ -83,10 +93,182 @@ mod kernels {
     }
 }
 ```
+```compile_fail
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, device::Device, anyhow::Result};
+
+    #[kernel]
+    fn with_groups() {}
+
+    fn test_set_groups(device: Device) -> Result<()> {
+        let mut kernel = with_groups::builder()?.build(device)?;
+        kernel.set_groups(1);
+        Ok(())
+    }
+}
+```
 */
 #[allow(dead_code)]
 enum WithGroups {}
 
+/**
+```no_run
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, device::Device, anyhow::Result};
+
+    #[kernel]
+    fn foo() {}
+
+    #[kernel]
+    fn specialization<const X: i32>() {}
+
+    fn test_precompile(device: Device) -> Result<()> {
+        device.precompile(&[
+            &foo::builder()?,
+            &foo::builder()?.with_threads(64),
+            &specialization::builder()?.specialize(1),
+        ])
+    }
+}
+```
+```compile_fail
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, device::Device, anyhow::Result};
+
+    #[kernel]
+    fn specialization<const X: i32>() {}
+
+    fn test_precompile(device: Device) -> Result<()> {
+        device.precompile(&[&specialization::builder()?])
+    }
+}
+```
+*/
+#[allow(dead_code)]
+enum Precompile {}
+
+/**
+```no_run
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, device::Device, anyhow::Result};
+
+    #[kernel]
+    fn debug() {}
+
+    #[kernel]
+    fn debug_item(#[item] y: &mut u32) {}
+
+    fn test_debug(device: Device) -> Result<()> {
+        let kernel = debug::builder()?.build(device.clone())?;
+        let _ = format!("{kernel:?}");
+        let kernel = kernel.with_groups(1);
+        let _ = format!("{kernel:?}");
+        let kernel = debug_item::builder()?.build(device)?;
+        let _ = format!("{kernel:?}");
+        Ok(())
+    }
+}
+```
+*/
+#[allow(dead_code)]
+enum Debug {}
+
+/**
+```no_run
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, buffer::SliceMut, anyhow::Result};
+
+    #[kernel]
+    fn safe(x: u32, #[item] y: &mut u32) {
+        *y = x;
+    }
+
+    #[kernel]
+    unsafe fn not_safe(#[global] y: UnsafeSlice<u32>) {}
+
+    fn test_dispatch_safe(y: SliceMut<u32>) -> Result<()> {
+        safe::builder()?.build(y.device())?.dispatch_safe(1, y)
+    }
+
+    fn test_dispatch_safe_unsafe(y: SliceMut<u32>) -> Result<()> {
+        not_safe::builder()?
+            .build(y.device())?
+            .with_groups(1)
+            .dispatch_safe(y)
+    }
+}
+```
+*/
+#[allow(dead_code)]
+enum DispatchSafe {}
+
+/**
+```no_run
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, buffer::{Slice, SliceMut}, anyhow::Result};
+
+    #[kernel]
+    fn tiled(base: u32, n: u32, #[global] x: Slice<u32>) {}
+
+    #[kernel]
+    unsafe fn tiled_mut(#[global] y: UnsafeSlice<u32>, base: u32) {}
+
+    fn test_dispatch_tiled(x: Slice<u32>) -> Result<()> {
+        let n = x.len() as u32;
+        tiled::builder()?.build(x.device())?.dispatch_tiled(n, x, n)
+    }
+
+    fn test_dispatch_tiled_unsafe(y: SliceMut<u32>) -> Result<()> {
+        let n = y.len() as u32;
+        unsafe { tiled_mut::builder()?.build(y.device())?.dispatch_tiled(y, n) }
+    }
+}
+```
+```compile_fail
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::{macros::kernel, buffer::Slice, anyhow::Result};
+
+    #[kernel]
+    fn not_tiled(n: u32, #[global] x: Slice<u32>) {}
+
+    fn test_dispatch_tiled(x: Slice<u32>) -> Result<()> {
+        let n = x.len() as u32;
+        not_tiled::builder()?.build(x.device())?.dispatch_tiled(n, x, n)
+    }
+}
+```
+*/
+#[allow(dead_code)]
+enum DispatchTiled {}
+
 #[module]
 pub mod kernels {
     use dry::macro_for;